@@ -63,6 +63,9 @@ pub struct AgentMetadata {
     /// File/directory scope patterns (e.g. ["src/**/*.rs", "tests/"])
     #[serde(default)]
     pub scope: Vec<String>,
+    /// Skills whose allow-listed scripts the agent can call as tools
+    #[serde(default)]
+    pub skills: Vec<String>,
     /// Fallback models to try if the primary model is unavailable
     #[serde(default)]
     pub model_fallback: Vec<String>,
@@ -40,6 +40,7 @@ pub struct AgentDefaults {
     pub model_fallback: Vec<String>,
     pub tags: Vec<String>,
     pub stacks: Vec<String>,
+    pub skills: Vec<String>,
 }
 
 /// One declared agent.
@@ -71,6 +72,8 @@ pub struct AgentDecl {
     pub stacks: Option<Vec<String>>,
     #[serde(default)]
     pub scope: Option<Vec<String>>,
+    #[serde(default)]
+    pub skills: Option<Vec<String>>,
     #[serde(default, deserialize_with = "de_prompt")]
     pub prompt: Vec<PromptStep>,
 }
@@ -195,6 +198,10 @@ pub fn merge_metadata(decl: &AgentDecl, defaults: &AgentDefaults) -> anyhow::Res
             .clone()
             .unwrap_or_else(|| defaults.stacks.clone()),
         scope: decl.scope.clone().unwrap_or_default(),
+        skills: decl
+            .skills
+            .clone()
+            .unwrap_or_else(|| defaults.skills.clone()),
         model_fallback: decl
             .model_fallback
             .clone()
@@ -498,6 +505,7 @@ agents:
             tags: None,
             stacks: None,
             scope: None,
+            skills: None,
             prompt: vec![],
         }
     }
@@ -514,6 +522,7 @@ agents:
             model_fallback: vec!["latest:fast".into()],
             tags: vec!["shared".into()],
             stacks: vec![],
+            skills: vec![],
         }
     }
 
@@ -641,6 +650,7 @@ agents:
             tags: Some(vec!["tag-from-decl".into()]),
             stacks: Some(vec!["stack-from-decl".into()]),
             scope: Some(vec!["scope-from-decl".into()]),
+            skills: Some(vec!["skill-from-decl".into()]),
             prompt: vec![],
         };
         let defaults = AgentDefaults {
@@ -654,6 +664,7 @@ agents:
             model_fallback: vec!["fallback-from-defaults".into()],
             tags: vec!["tag-from-defaults".into()],
             stacks: vec!["stack-from-defaults".into()],
+            skills: vec!["skill-from-defaults".into()],
         };
 
         let m = merge_metadata(&d, &defaults).unwrap();
@@ -668,6 +679,7 @@ agents:
         assert_eq!(m.tags, vec!["tag-from-decl".to_string()]);
         assert_eq!(m.stacks, vec!["stack-from-decl".to_string()]);
         assert_eq!(m.scope, vec!["scope-from-decl".to_string()]);
+        assert_eq!(m.skills, vec!["skill-from-decl".to_string()]);
         assert_eq!(m.model_fallback, vec!["fallback-from-decl".to_string()]);
         assert_eq!(m.cost_limit, None);
         assert_eq!(m.rate_limit, None);
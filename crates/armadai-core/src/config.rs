@@ -183,6 +183,8 @@ impl Default for DefaultsConfig {
 pub struct StorageConfig {
     pub mode: String,
     pub path: String,
+    pub retention: RetentionConfig,
+    pub redaction: RedactionMode,
 }
 
 impl Default for StorageConfig {
@@ -198,6 +200,45 @@ impl Default for StorageConfig {
                 .join("armadai.sqlite")
                 .to_string_lossy()
                 .into_owned(),
+            retention: RetentionConfig::default(),
+            redaction: RedactionMode::default(),
+        }
+    }
+}
+
+/// `storage.redaction`: how secrets found in run inputs and agent outputs
+/// are rewritten before they are stored. API keys from the secrets store
+/// are masked whatever the mode.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RedactionMode {
+    /// Store content as produced.
+    Off,
+    /// Replace each secret with `[REDACTED]`.
+    #[default]
+    Mask,
+    /// Replace each secret with `[REDACTED:<sha256 prefix>]`, so the same
+    /// secret can be recognised across runs without being stored.
+    Hash,
+}
+
+/// `storage.retention`: history pruning applied automatically when the
+/// database is opened (same effect as `armadai db prune`).
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct RetentionConfig {
+    /// Maximum run age, e.g. `90d`, `12w`, `48h`. `None` keeps everything.
+    pub older_than: Option<String>,
+    /// Fold pruned runs into daily cost rollups so `armadai costs` totals
+    /// survive the pruning.
+    pub keep_costs: bool,
+}
+
+impl Default for RetentionConfig {
+    fn default() -> Self {
+        Self {
+            older_than: None,
+            keep_costs: true,
         }
     }
 }
@@ -365,6 +406,17 @@ impl AppPaths {
     }
 }
 
+/// Directories whose secrets layer over the global config dir's, lowest
+/// first: the config dir [`AppPaths::resolve`] picks (`.armadai/`, then
+/// `./config/`), then the current project's `.armadai/`.
+pub fn secrets_overlay_dirs() -> Vec<PathBuf> {
+    let mut dirs = vec![AppPaths::resolve().config_dir];
+    if let Some((root, _)) = crate::project::find_project_config() {
+        dirs.push(crate::project::project_dir(&root));
+    }
+    dirs
+}
+
 /// Check if a project-local `config/settings.yaml` exists and print a
 /// migration hint to stderr.
 pub fn check_migration_hint() {
@@ -397,6 +449,10 @@ storage:
   # (~/.local/share/armadai/armadai.sqlite, or $XDG_DATA_HOME/armadai if set)
   # so History/Costs are consistent regardless of CWD. Set explicitly here to
   # override.
+  # retention:
+  #   older_than: 90d    # prune runs older than this on startup (h/d/w)
+  #   keep_costs: true   # keep per-day cost aggregates of pruned runs
+  # redaction: mask       # secrets in stored runs: off | mask | hash
 
 rate_limits:
   anthropic: 50
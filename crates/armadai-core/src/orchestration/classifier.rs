@@ -337,6 +337,7 @@ mod tests {
                 tags: tags.iter().map(|s| s.to_string()).collect(),
                 stacks: vec![],
                 scope: vec![],
+                skills: vec![],
                 model_fallback: vec![],
                 cost_limit: None,
                 rate_limit: None,
@@ -869,6 +869,7 @@ mod tests {
                 tags: vec![],
                 stacks: vec![],
                 scope: vec![],
+                skills: vec![],
                 model_fallback: vec![],
                 cost_limit: None,
                 rate_limit: None,
@@ -1412,6 +1413,7 @@ mod tests {
                     tags: vec![],
                     stacks: vec![],
                     scope: vec![],
+                    skills: vec![],
                     model_fallback: vec![],
                     cost_limit: None,
                     rate_limit: None,
@@ -1903,6 +1905,7 @@ mod tests {
                     tags: vec![],
                     stacks: vec![],
                     scope: vec![],
+                    skills: vec![],
                     model_fallback: vec![],
                     cost_limit: None,
                     rate_limit: None,
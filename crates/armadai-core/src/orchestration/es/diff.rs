@@ -0,0 +1,503 @@
+//! Side-by-side comparison of two event logs (`armadai runs diff`).
+//!
+//! Each log is cut into *steps*: one per output-producing event
+//! (`AgentObserved`, `BoardEntryAdded`, `ContributionAdded`, `VoteCast`,
+//! `Synthesized`, `AgentFailed`). A step is keyed by agent, the enclosing
+//! blackboard round / ring lap (if any), the event kind, and its occurrence
+//! number within that triple — so "b's 2nd output in round 3" lines up with
+//! the same step of the other run even if other agents spoke in between.
+//! Pure over `ExecutionEvent`s: no storage, no I/O.
+
+use serde::Serialize;
+
+use super::event::ExecutionEvent;
+use super::state::fold;
+
+/// Above this many line pairs, outputs are diffed as a whole (all removed,
+/// all added) instead of line-by-line, to bound the LCS table.
+const MAX_LCS_CELLS: usize = 4_000_000;
+
+/// One line of a line diff.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "op", content = "text", rename_all = "snake_case")]
+pub enum DiffLine {
+    Same(String),
+    Removed(String),
+    Added(String),
+}
+
+/// Totals and outcome of one run.
+#[derive(Debug, Clone, Serialize)]
+pub struct RunSide {
+    pub run_id: String,
+    pub pattern: String,
+    pub tokens_in: u64,
+    pub tokens_out: u64,
+    pub cost: f64,
+    /// `Completed` content, or `halted: <reason>`; `None` while running.
+    pub final_result: Option<String>,
+}
+
+/// One step as it happened in one run.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct StepOutput {
+    pub content: String,
+    /// Board entry kind, ring contribution action, or vote position.
+    pub detail: Option<String>,
+    pub tokens_in: u32,
+    pub tokens_out: u32,
+    pub cost: f64,
+}
+
+/// A step aligned across both runs (`None` on the side that lacks it).
+#[derive(Debug, Clone, Serialize)]
+pub struct StepDiff {
+    pub agent: String,
+    /// `round 2`, `lap 1`, or empty outside rounds/laps.
+    pub phase: String,
+    /// `output`, `board`, `contribution`, `vote`, `synthesis` or `failure`.
+    pub kind: &'static str,
+    /// 1-based occurrence of (agent, phase, kind).
+    pub occurrence: usize,
+    pub a: Option<StepOutput>,
+    pub b: Option<StepOutput>,
+    pub output_diff: Vec<DiffLine>,
+}
+
+impl StepDiff {
+    /// Human label, e.g. `reviewer [round 2] board #2`.
+    pub fn label(&self) -> String {
+        let mut label = self.agent.clone();
+        if !self.phase.is_empty() {
+            label.push_str(&format!(" [{}]", self.phase));
+        }
+        label.push(' ');
+        label.push_str(self.kind);
+        if self.occurrence > 1 {
+            label.push_str(&format!(" #{}", self.occurrence));
+        }
+        label
+    }
+
+    /// Whether both sides exist and are identical.
+    pub fn is_unchanged(&self) -> bool {
+        matches!((&self.a, &self.b), (Some(a), Some(b)) if a == b)
+    }
+
+    /// Kind/action/position change, e.g. `finding -> challenge`.
+    pub fn detail_change(&self) -> Option<String> {
+        let a = self.a.as_ref()?.detail.as_deref()?;
+        let b = self.b.as_ref()?.detail.as_deref()?;
+        (a != b).then(|| format!("{a} -> {b}"))
+    }
+
+    /// Token/cost deltas `b - a` (a missing side counts as zero).
+    pub fn deltas(&self) -> (i64, i64, f64) {
+        let side = |s: &Option<StepOutput>| {
+            s.as_ref().map_or((0, 0, 0.0), |s| {
+                (i64::from(s.tokens_in), i64::from(s.tokens_out), s.cost)
+            })
+        };
+        let (ai, ao, ac) = side(&self.a);
+        let (bi, bo, bc) = side(&self.b);
+        (bi - ai, bo - ao, bc - ac)
+    }
+}
+
+/// Full comparison of run `a` against run `b`.
+#[derive(Debug, Clone, Serialize)]
+pub struct RunDiff {
+    pub a: RunSide,
+    pub b: RunSide,
+    pub steps: Vec<StepDiff>,
+    pub final_changed: bool,
+    pub final_diff: Vec<DiffLine>,
+}
+
+impl RunDiff {
+    /// Whether the two runs produced the same steps and final result.
+    pub fn is_identical(&self) -> bool {
+        !self.final_changed && self.steps.iter().all(StepDiff::is_unchanged)
+    }
+}
+
+#[derive(Clone, PartialEq, Eq)]
+struct StepKey {
+    agent: String,
+    phase: String,
+    kind: &'static str,
+    occurrence: usize,
+}
+
+/// Cut a log into keyed steps, in log order.
+fn steps(events: &[ExecutionEvent]) -> Vec<(StepKey, StepOutput)> {
+    let mut phase = String::new();
+    let mut out: Vec<(StepKey, StepOutput)> = Vec::new();
+    for event in events {
+        let (agent, kind, step) = match event {
+            ExecutionEvent::RoundStarted { round } => {
+                phase = format!("round {round}");
+                continue;
+            }
+            ExecutionEvent::LapStarted { lap } => {
+                phase = format!("lap {lap}");
+                continue;
+            }
+            ExecutionEvent::AgentObserved {
+                agent,
+                content,
+                tokens_in,
+                tokens_out,
+                cost,
+                ..
+            } => (
+                agent,
+                "output",
+                StepOutput {
+                    content: content.clone(),
+                    detail: None,
+                    tokens_in: *tokens_in,
+                    tokens_out: *tokens_out,
+                    cost: *cost,
+                },
+            ),
+            ExecutionEvent::BoardEntryAdded {
+                agent,
+                kind,
+                content,
+                tokens_in,
+                tokens_out,
+                cost,
+                ..
+            } => (
+                agent,
+                "board",
+                StepOutput {
+                    content: content.clone(),
+                    detail: Some(kind.clone()),
+                    tokens_in: *tokens_in,
+                    tokens_out: *tokens_out,
+                    cost: *cost,
+                },
+            ),
+            ExecutionEvent::ContributionAdded {
+                agent,
+                action,
+                content,
+                tokens_in,
+                tokens_out,
+                cost,
+                ..
+            } => (
+                agent,
+                "contribution",
+                StepOutput {
+                    content: content.clone(),
+                    detail: Some(action.clone()),
+                    tokens_in: *tokens_in,
+                    tokens_out: *tokens_out,
+                    cost: *cost,
+                },
+            ),
+            ExecutionEvent::VoteCast {
+                agent,
+                position,
+                concerns,
+                ..
+            } => (
+                agent,
+                "vote",
+                StepOutput {
+                    content: concerns.join("\n"),
+                    detail: Some(position.clone()),
+                    tokens_in: 0,
+                    tokens_out: 0,
+                    cost: 0.0,
+                },
+            ),
+            ExecutionEvent::Synthesized { agent, content } => (
+                agent,
+                "synthesis",
+                StepOutput {
+                    content: content.clone(),
+                    detail: None,
+                    tokens_in: 0,
+                    tokens_out: 0,
+                    cost: 0.0,
+                },
+            ),
+            ExecutionEvent::AgentFailed { agent, error } => (
+                agent,
+                "failure",
+                StepOutput {
+                    content: error.clone(),
+                    detail: None,
+                    tokens_in: 0,
+                    tokens_out: 0,
+                    cost: 0.0,
+                },
+            ),
+            _ => continue,
+        };
+        let occurrence = 1 + out
+            .iter()
+            .filter(|(k, _)| k.agent == *agent && k.phase == phase && k.kind == kind)
+            .count();
+        out.push((
+            StepKey {
+                agent: agent.clone(),
+                phase: phase.clone(),
+                kind,
+                occurrence,
+            },
+            step,
+        ));
+    }
+    out
+}
+
+fn side(run_id: &str, events: &[ExecutionEvent]) -> RunSide {
+    let state = fold(events);
+    let final_result = events.iter().rev().find_map(|e| match e {
+        ExecutionEvent::Completed { content } => Some(content.clone()),
+        ExecutionEvent::Halted { reason } => Some(format!("halted: {reason}")),
+        _ => None,
+    });
+    RunSide {
+        run_id: run_id.to_string(),
+        pattern: state.pattern,
+        tokens_in: state.budget_tokens_in,
+        tokens_out: state.budget_tokens_out,
+        cost: state.budget_cost,
+        final_result,
+    }
+}
+
+/// Compare two runs' event logs.
+pub fn diff_runs(
+    a_id: &str,
+    a_events: &[ExecutionEvent],
+    b_id: &str,
+    b_events: &[ExecutionEvent],
+) -> RunDiff {
+    let a_steps = steps(a_events);
+    let mut b_steps: Vec<Option<(StepKey, StepOutput)>> =
+        steps(b_events).into_iter().map(Some).collect();
+
+    let mut diffs = Vec::new();
+    let mut take_b = |key: &StepKey| {
+        b_steps
+            .iter_mut()
+            .find(|s| s.as_ref().is_some_and(|(k, _)| k == key))
+            .and_then(Option::take)
+            .map(|(_, s)| s)
+    };
+    for (key, a) in a_steps {
+        let b = take_b(&key);
+        diffs.push(step_diff(key, Some(a), b));
+    }
+    for (key, b) in b_steps.into_iter().flatten() {
+        diffs.push(step_diff(key, None, Some(b)));
+    }
+
+    let a = side(a_id, a_events);
+    let b = side(b_id, b_events);
+    let final_changed = a.final_result != b.final_result;
+    let final_diff = line_diff(
+        a.final_result.as_deref().unwrap_or(""),
+        b.final_result.as_deref().unwrap_or(""),
+    );
+    RunDiff {
+        a,
+        b,
+        steps: diffs,
+        final_changed,
+        final_diff,
+    }
+}
+
+fn step_diff(key: StepKey, a: Option<StepOutput>, b: Option<StepOutput>) -> StepDiff {
+    let output_diff = line_diff(
+        a.as_ref().map_or("", |s| s.content.as_str()),
+        b.as_ref().map_or("", |s| s.content.as_str()),
+    );
+    StepDiff {
+        agent: key.agent,
+        phase: key.phase,
+        kind: key.kind,
+        occurrence: key.occurrence,
+        a,
+        b,
+        output_diff,
+    }
+}
+
+/// Line diff of `a` -> `b` via longest common subsequence.
+pub fn line_diff(a: &str, b: &str) -> Vec<DiffLine> {
+    let a: Vec<&str> = a.lines().collect();
+    let b: Vec<&str> = b.lines().collect();
+    if a.len().saturating_mul(b.len()) > MAX_LCS_CELLS {
+        return a
+            .iter()
+            .map(|l| DiffLine::Removed(l.to_string()))
+            .chain(b.iter().map(|l| DiffLine::Added(l.to_string())))
+            .collect();
+    }
+
+    // lcs[i][j] = LCS length of a[i..] and b[j..].
+    let mut lcs = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            lcs[i][j] = if a[i] == b[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    let mut out = Vec::new();
+    while i < a.len() && j < b.len() {
+        if a[i] == b[j] {
+            out.push(DiffLine::Same(a[i].to_string()));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            out.push(DiffLine::Removed(a[i].to_string()));
+            i += 1;
+        } else {
+            out.push(DiffLine::Added(b[j].to_string()));
+            j += 1;
+        }
+    }
+    out.extend(a[i..].iter().map(|l| DiffLine::Removed(l.to_string())));
+    out.extend(b[j..].iter().map(|l| DiffLine::Added(l.to_string())));
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ExecutionEvent as E;
+
+    fn started() -> E {
+        E::RunStarted {
+            run_id: "r".into(),
+            pattern: "blackboard".into(),
+            agents: vec!["a".into(), "b".into()],
+            input: "task".into(),
+            project: None,
+            roster: Default::default(),
+        }
+    }
+
+    fn entry(agent: &str, kind: &str, content: &str, tokens_out: u32) -> E {
+        E::BoardEntryAdded {
+            agent: agent.into(),
+            round: 1,
+            kind: kind.into(),
+            content: content.into(),
+            refs: vec![],
+            confidence: 0.5,
+            tokens_in: 10,
+            tokens_out,
+            cost: f64::from(tokens_out) / 1000.0,
+        }
+    }
+
+    #[test]
+    fn line_diff_marks_changed_lines() {
+        let d = line_diff("one\ntwo\nthree", "one\n2\nthree\nfour");
+        assert_eq!(
+            d,
+            vec![
+                DiffLine::Same("one".into()),
+                DiffLine::Removed("two".into()),
+                DiffLine::Added("2".into()),
+                DiffLine::Same("three".into()),
+                DiffLine::Added("four".into()),
+            ]
+        );
+        assert!(
+            line_diff("x", "x")
+                .iter()
+                .all(|l| matches!(l, DiffLine::Same(_)))
+        );
+    }
+
+    #[test]
+    fn identical_logs_diff_as_identical() {
+        let log = vec![
+            started(),
+            E::RoundStarted { round: 1 },
+            entry("a", "finding", "x", 5),
+            E::Completed {
+                content: "done".into(),
+            },
+        ];
+        let d = diff_runs("r1", &log, "r2", &log);
+        assert!(d.is_identical());
+        assert_eq!(d.steps.len(), 1);
+        assert_eq!(d.steps[0].label(), "a [round 1] board");
+    }
+
+    #[test]
+    fn steps_align_by_agent_and_round_and_report_changes() {
+        let a = vec![
+            started(),
+            E::RoundStarted { round: 1 },
+            entry("a", "finding", "same", 5),
+            entry("b", "finding", "old view", 5),
+            E::Completed {
+                content: "v1".into(),
+            },
+        ];
+        // Order of speakers differs; b changed kind and content; b has an
+        // extra entry; the final result changed.
+        let b = vec![
+            started(),
+            E::RoundStarted { round: 1 },
+            entry("b", "challenge", "new view", 8),
+            entry("a", "finding", "same", 5),
+            entry("b", "finding", "more", 2),
+            E::Completed {
+                content: "v2".into(),
+            },
+        ];
+        let d = diff_runs("r1", &a, "r2", &b);
+        assert!(!d.is_identical());
+        assert_eq!(d.steps.len(), 3);
+
+        let a_step = &d.steps[0];
+        assert_eq!(a_step.agent, "a");
+        assert!(a_step.is_unchanged());
+
+        let b_step = &d.steps[1];
+        assert_eq!(b_step.agent, "b");
+        assert_eq!(
+            b_step.detail_change().as_deref(),
+            Some("finding -> challenge")
+        );
+        assert_eq!(b_step.deltas().1, 3);
+        assert!(
+            b_step
+                .output_diff
+                .contains(&DiffLine::Added("new view".into()))
+        );
+
+        let extra = &d.steps[2];
+        assert!(extra.a.is_none());
+        assert_eq!(extra.label(), "b [round 1] board #2");
+
+        assert!(d.final_changed);
+        assert_eq!(d.a.tokens_out, 10);
+        assert_eq!(d.b.tokens_out, 15);
+    }
+
+    #[test]
+    fn json_shape_tags_diff_lines() {
+        let json = serde_json::to_value(DiffLine::Added("x".into())).unwrap();
+        assert_eq!(json, serde_json::json!({"op": "added", "text": "x"}));
+    }
+}
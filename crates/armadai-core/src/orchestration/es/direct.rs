@@ -363,6 +363,7 @@ mod tests {
                 tags: vec![],
                 stacks: vec![],
                 scope: vec![],
+                skills: vec![],
                 model_fallback: vec![],
                 cost_limit: None,
                 rate_limit: None,
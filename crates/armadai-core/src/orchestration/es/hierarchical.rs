@@ -1554,6 +1554,7 @@ mod tests {
                     tags: vec![],
                     stacks: vec![],
                     scope: vec![],
+                    skills: vec![],
                     model_fallback: vec![],
                     cost_limit: None,
                     rate_limit: None,
@@ -2880,6 +2881,7 @@ mod tests {
                     tags: vec![],
                     stacks: vec![],
                     scope: vec![],
+                    skills: vec![],
                     model_fallback: vec![],
                     cost_limit: None,
                     rate_limit: None,
@@ -3319,6 +3321,7 @@ mod tests {
                 tags: vec![],
                 stacks: vec![],
                 scope: vec![],
+                skills: vec![],
                 model_fallback: vec![],
                 cost_limit: None,
                 rate_limit: None,
@@ -1471,6 +1471,7 @@ mod tests {
                     tags: vec![],
                     stacks: vec![],
                     scope: vec![],
+                    skills: vec![],
                     model_fallback: vec![],
                     cost_limit: None,
                     rate_limit: None,
@@ -1789,6 +1790,7 @@ mod tests {
                     tags: vec![],
                     stacks: vec![],
                     scope: vec![],
+                    skills: vec![],
                     model_fallback: vec![],
                     cost_limit: None,
                     rate_limit: None,
@@ -2211,6 +2213,7 @@ mod tests {
                     tags: vec![],
                     stacks: vec![],
                     scope: vec![],
+                    skills: vec![],
                     model_fallback: vec![],
                     cost_limit: None,
                     rate_limit: None,
@@ -183,6 +183,16 @@ pub struct OrchestrationConfig {
     #[serde(default)]
     pub free_agents: Vec<String>,
 
+    /// Per-agent allow/deny lists over Bash, Write and Edit, enforced by the
+    /// Claude Code policy gate.
+    #[serde(default)]
+    pub agent_tools: std::collections::BTreeMap<String, policy::ToolRules>,
+
+    /// Spend (USD) past which the policy gate refuses further tool calls in
+    /// a Claude Code session.
+    #[serde(default)]
+    pub session_budget: Option<f64>,
+
     // ── Shared limits (all patterns) ───────────────────────────
     /// Max delegation depth (default: 5).
     pub max_depth: Option<u32>,
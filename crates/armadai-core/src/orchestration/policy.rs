@@ -5,6 +5,10 @@
 //! anyway. This module is the decision half of the fix: a pure function over
 //! `OrchestrationConfig`, called from a Claude Code `PreToolUse` hook.
 //!
+//! Beyond delegation, the same gate judges what a sub-agent does: the
+//! tools it may call (`agent_tools`), the files it may write (its `scope`),
+//! and whether the session is still within `session_budget`.
+//!
 //! Design rule: **a gate that refuses because it did not understand is a gate
 //! that gets uninstalled.** Every uncertainty degrades to `Ok` — the refusal
 //! must come from an established violation, never from a doubt.
@@ -21,10 +25,26 @@ pub enum PolicyMode {
     /// upgrading ArmadAI never changes an existing project's behaviour.
     #[default]
     Off,
+    /// Violations are reported (logged, and shown in the Workroom) but
+    /// nothing is refused — the way to try a policy on a live project.
+    Warn,
     /// Anything not declared is refused.
     Strict,
 }
 
+/// Tools the gate judges against `agent_tools` and `scope`: the ones that
+/// change the machine. Anything else is never judged per agent.
+pub const GATED_TOOLS: &[&str] = &["Bash", "Write", "Edit"];
+
+/// What one agent may call among [`GATED_TOOLS`]. An empty `allow` allows
+/// every tool not in `deny`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(default, deny_unknown_fields)]
+pub struct ToolRules {
+    pub allow: Vec<String>,
+    pub deny: Vec<String>,
+}
+
 /// A delegation the declared topology does not allow.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct PolicyViolation {
@@ -60,7 +80,7 @@ pub fn check_delegation(
     config: &OrchestrationConfig,
 ) -> Result<(), PolicyViolation> {
     // Silent unless explicitly enabled.
-    if config.policy != PolicyMode::Strict {
+    if config.policy == PolicyMode::Off {
         return Ok(());
     }
     // No declared coordinator means no topology to violate (direct,
@@ -135,6 +155,143 @@ pub fn check_delegation(
     }
 }
 
+/// The gated tool `tool` stands for: `MultiEdit` edits like `Edit`, so
+/// splitting an edit cannot be a way around the rules.
+fn gated_tool(tool: &str) -> Option<&'static str> {
+    let tool = if tool == "MultiEdit" { "Edit" } else { tool };
+    GATED_TOOLS.iter().copied().find(|t| *t == tool)
+}
+
+/// May `agent` call `tool` under `config.agent_tools`?
+pub fn check_tool(
+    agent: &str,
+    tool: &str,
+    config: &OrchestrationConfig,
+) -> Result<(), PolicyViolation> {
+    if config.policy == PolicyMode::Off {
+        return Ok(());
+    }
+    let (Some(tool), Some(rules)) = (gated_tool(tool), config.agent_tools.get(agent)) else {
+        return Ok(());
+    };
+    let denied = rules.deny.iter().any(|t| t == tool);
+    let not_allowed = !rules.allow.is_empty() && !rules.allow.iter().any(|t| t == tool);
+    if !denied && !not_allowed {
+        return Ok(());
+    }
+    let reason = if rules.allow.is_empty() {
+        format!(
+            "{agent} may not use {tool} (orchestration.agent_tools.{agent}.deny); hand the \
+             step back to an agent that may"
+        )
+    } else {
+        format!(
+            "{agent} may only use [{}] among {}; hand the step back to an agent that may \
+             use {tool}, or allow it in orchestration.agent_tools.{agent}",
+            rules.allow.join(", "),
+            GATED_TOOLS.join("/")
+        )
+    };
+    Err(PolicyViolation {
+        target: tool.to_string(),
+        allowed: rules.allow.clone(),
+        reason,
+    })
+}
+
+/// May `agent`, confined to `scope`, write `path` (relative to the project
+/// root, `/`-separated)? An agent without a scope may write anywhere.
+pub fn check_path(
+    agent: &str,
+    scope: &[String],
+    path: &str,
+    config: &OrchestrationConfig,
+) -> Result<(), PolicyViolation> {
+    if config.policy == PolicyMode::Off || scope.is_empty() {
+        return Ok(());
+    }
+    if scope.iter().any(|pattern| scope_matches(pattern, path)) {
+        return Ok(());
+    }
+    Err(PolicyViolation {
+        target: path.to_string(),
+        allowed: scope.to_vec(),
+        reason: format!(
+            "{path} is outside {agent}'s scope [{}]; leave that file to the agent that \
+             owns it, or widen {agent}'s scope",
+            scope.join(", ")
+        ),
+    })
+}
+
+/// Is the session, having spent `spent` USD, still within
+/// `config.session_budget`?
+pub fn check_budget(spent: f64, config: &OrchestrationConfig) -> Result<(), PolicyViolation> {
+    if config.policy == PolicyMode::Off {
+        return Ok(());
+    }
+    match config.session_budget {
+        Some(budget) if spent > budget => Err(PolicyViolation {
+            target: "session_budget".to_string(),
+            allowed: Vec::new(),
+            reason: format!(
+                "this session has spent ${spent:.2}, over its ${budget:.2} budget \
+                 (orchestration.session_budget); stop here and report what is left to do"
+            ),
+        }),
+        _ => Ok(()),
+    }
+}
+
+/// Does the `scope` entry `pattern` cover `path`? A trailing `/` or a
+/// pattern without wildcards covers everything below it; `*` and `?` match
+/// within one path segment, `**` any number of segments.
+pub fn scope_matches(pattern: &str, path: &str) -> bool {
+    let pattern = pattern.trim_start_matches("./");
+    let path = path.trim_start_matches("./");
+    if !pattern.contains(['*', '?']) {
+        let dir = pattern.trim_end_matches('/');
+        return path == dir || path.starts_with(&format!("{dir}/"));
+    }
+    let pattern: Vec<&str> = pattern.split('/').filter(|s| !s.is_empty()).collect();
+    let path: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+    segments_match(&pattern, &path)
+}
+
+fn segments_match(pattern: &[&str], path: &[&str]) -> bool {
+    match pattern.split_first() {
+        None => path.is_empty(),
+        Some((&"**", rest)) => (0..=path.len()).any(|skip| segments_match(rest, &path[skip..])),
+        Some((first, rest)) => path
+            .split_first()
+            .is_some_and(|(seg, tail)| segment_matches(first, seg) && segments_match(rest, tail)),
+    }
+}
+
+fn segment_matches(pattern: &str, seg: &str) -> bool {
+    let p: Vec<char> = pattern.chars().collect();
+    let s: Vec<char> = seg.chars().collect();
+    // Classic wildcard match with single-star backtracking.
+    let (mut pi, mut si) = (0, 0);
+    let mut star: Option<(usize, usize)> = None;
+    while si < s.len() {
+        if pi < p.len() && (p[pi] == '?' || p[pi] == s[si]) {
+            pi += 1;
+            si += 1;
+        } else if pi < p.len() && p[pi] == '*' {
+            star = Some((pi, si));
+            pi += 1;
+        } else if let Some((sp, ss)) = star {
+            pi = sp + 1;
+            si = ss + 1;
+            star = Some((sp, ss + 1));
+        } else {
+            return false;
+        }
+    }
+    p[pi..].iter().all(|c| *c == '*')
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -251,4 +408,70 @@ mod tests {
         let c = cfg(PolicyMode::Strict, flat());
         assert!(check_delegation(None, "", &c).is_ok());
     }
+
+    #[test]
+    fn warn_mode_still_reports_violations() {
+        let c = cfg(PolicyMode::Warn, flat());
+        assert!(check_delegation(None, "qa-specialist", &c).is_err());
+    }
+
+    #[test]
+    fn tool_rules_allow_and_deny_gated_tools_only() {
+        let mut c = cfg(PolicyMode::Strict, flat());
+        c.agent_tools.insert(
+            "qa-specialist".into(),
+            ToolRules {
+                allow: vec!["Bash".into()],
+                deny: Vec::new(),
+            },
+        );
+        c.agent_tools.insert(
+            "core-specialist".into(),
+            ToolRules {
+                allow: Vec::new(),
+                deny: vec!["Bash".into()],
+            },
+        );
+        assert!(check_tool("qa-specialist", "Bash", &c).is_ok());
+        let v = check_tool("qa-specialist", "MultiEdit", &c).unwrap_err();
+        assert_eq!(v.target, "Edit", "MultiEdit is judged as Edit");
+        assert!(check_tool("qa-specialist", "Read", &c).is_ok(), "not gated");
+        assert!(check_tool("core-specialist", "Bash", &c).is_err());
+        assert!(check_tool("core-specialist", "Write", &c).is_ok());
+        assert!(check_tool("unlisted", "Bash", &c).is_ok());
+    }
+
+    #[test]
+    fn paths_outside_the_scope_are_refused() {
+        let c = cfg(PolicyMode::Strict, flat());
+        let scope = vec!["src/**/*.rs".to_string(), "tests/".to_string()];
+        assert!(check_path("a", &scope, "src/core/lib.rs", &c).is_ok());
+        assert!(check_path("a", &scope, "src/lib.rs", &c).is_ok());
+        assert!(check_path("a", &scope, "tests/e2e/x.txt", &c).is_ok());
+        let v = check_path("a", &scope, "docs/readme.md", &c).unwrap_err();
+        assert!(v.reason.contains("outside a's scope"), "{}", v.reason);
+        assert!(check_path("a", &[], "anywhere", &c).is_ok());
+    }
+
+    #[test]
+    fn scope_globs_match_per_segment() {
+        assert!(scope_matches("docs/*.md", "docs/a.md"));
+        assert!(!scope_matches("docs/*.md", "docs/sub/a.md"));
+        assert!(scope_matches("src/core/**", "src/core/a/b.rs"));
+        assert!(scope_matches("src", "src/main.rs"));
+        assert!(!scope_matches("src", "srcs/main.rs"));
+        assert!(scope_matches("a?c.rs", "abc.rs"));
+    }
+
+    #[test]
+    fn the_budget_refuses_once_exceeded() {
+        let mut c = cfg(PolicyMode::Strict, flat());
+        assert!(check_budget(100.0, &c).is_ok(), "no budget, no limit");
+        c.session_budget = Some(5.0);
+        assert!(check_budget(4.99, &c).is_ok());
+        let v = check_budget(5.5, &c).unwrap_err();
+        assert!(v.reason.contains("$5.50"), "{}", v.reason);
+        c.policy = PolicyMode::Off;
+        assert!(check_budget(5.5, &c).is_ok());
+    }
 }
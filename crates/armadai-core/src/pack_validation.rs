@@ -160,6 +160,25 @@ pub fn validate_pack(pack_root: &Path) -> Vec<ValidationIssue> {
         }
     }
 
+    // R7: Validate the version and dependency ranges
+    if let Err(e) = pack.semver() {
+        issues.push(ValidationIssue::error("pack.yaml:version", e.to_string()));
+    }
+    for (dep, range) in &pack.dependencies {
+        let loc = format!("pack.yaml:dependencies['{dep}']");
+        if dep == &pack.name {
+            issues.push(ValidationIssue::error(
+                loc,
+                "A pack cannot depend on itself",
+            ));
+        } else if let Err(e) = semver::VersionReq::parse(range) {
+            issues.push(ValidationIssue::error(
+                loc,
+                format!("Invalid version range '{range}': {e}"),
+            ));
+        }
+    }
+
     // R6: Validate agent ## Triggers sections
     if agents_dir.is_dir() {
         for entry in std::fs::read_dir(&agents_dir)
@@ -547,6 +566,28 @@ mod tests {
         assert!(issues[0].location.contains("missing-prompt"));
     }
 
+    #[test]
+    fn test_validate_pack_r7_version_and_dependencies() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(
+            dir.path().join("pack.yaml"),
+            "name: test\ndescription: Test\nversion: one\ndependencies:\n  base: \"^1.2\"\n  test: \"*\"\n  other: \"~>1\"\n",
+        )
+        .unwrap();
+
+        let issues = validate_pack(dir.path());
+        let locations: Vec<&str> = issues.iter().map(|i| i.location.as_str()).collect();
+        assert_eq!(
+            locations,
+            [
+                "pack.yaml:version",
+                "pack.yaml:dependencies['other']",
+                "pack.yaml:dependencies['test']"
+            ]
+        );
+        assert!(issues.iter().all(|i| i.severity == Severity::Error));
+    }
+
     #[test]
     fn test_validate_pack_r5_apply_to_invalid_target() {
         let dir = tempfile::tempdir().unwrap();
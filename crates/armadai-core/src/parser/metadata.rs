@@ -15,6 +15,7 @@ pub fn parse_metadata(raw: &str) -> anyhow::Result<AgentMetadata> {
     let mut tags = Vec::new();
     let mut stacks = Vec::new();
     let mut scope = Vec::new();
+    let mut skills = Vec::new();
     let mut model_fallback = Vec::new();
     let mut cost_limit = None;
     let mut rate_limit = None;
@@ -45,6 +46,7 @@ pub fn parse_metadata(raw: &str) -> anyhow::Result<AgentMetadata> {
             "tags" => tags = parse_string_list(value),
             "stacks" => stacks = parse_string_list(value),
             "scope" => scope = parse_string_list(value),
+            "skills" => skills = parse_string_list(value),
             "model_fallback" | "model_fallbacks" => model_fallback = parse_string_list(value),
             "cost_limit" => cost_limit = Some(value.parse().context("invalid cost_limit")?),
             "rate_limit" => rate_limit = Some(value.to_string()),
@@ -90,6 +92,7 @@ pub fn parse_metadata(raw: &str) -> anyhow::Result<AgentMetadata> {
         tags,
         stacks,
         scope,
+        skills,
         model_fallback,
         cost_limit,
         rate_limit,
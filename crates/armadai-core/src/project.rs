@@ -231,6 +231,19 @@ pub fn find_project_config() -> Option<(PathBuf, ProjectConfig)> {
     find_project_config_from(&start)
 }
 
+/// The `.armadai/` directory of the project rooted at `project_root`.
+pub fn project_dir(project_root: &Path) -> PathBuf {
+    project_root.join(PROJECT_DIR)
+}
+
+/// The config file of the project rooted at `project_root`, in the order
+/// `find_project_config_from` looks for it.
+pub fn config_file(project_root: &Path) -> Option<PathBuf> {
+    std::iter::once(project_root.join(PROJECT_DIR).join(PROJECT_DIR_CONFIG))
+        .chain(PROJECT_FILENAMES.iter().map(|f| project_root.join(f)))
+        .find(|p| p.is_file())
+}
+
 /// Testable version that takes an explicit start directory.
 pub fn find_project_config_from(start: &Path) -> Option<(PathBuf, ProjectConfig)> {
     let mut dir = start.to_path_buf();
@@ -26,12 +26,21 @@ pub enum SourceKind {
 }
 
 /// A single custom registry source.
-#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
 pub struct RegistrySource {
     pub url: String,
     /// Delivery kind. Absent = inferred from the URL (see `resolved_kind`).
     #[serde(default)]
     pub kind: Option<SourceKind>,
+    /// Minisign public key (`RW…`) pinned for this source. Signatures found
+    /// in the source (an archive's `.minisig`, a pack's `pack.yaml.minisig`)
+    /// must verify against it. Starter sources only.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub public_key: Option<String>,
+    /// Refuse the source unless it is signed by `public_key` and every pack
+    /// file is covered by its `pack.yaml` checksums.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub require_signature: bool,
 }
 
 impl RegistrySource {
@@ -201,21 +210,24 @@ registries:
             wrapper.registries.agents,
             vec![RegistrySource {
                 url: "https://example.com/agents.git".to_string(),
-                kind: None
+                kind: None,
+                ..Default::default()
             }]
         );
         assert_eq!(
             wrapper.registries.skills,
             vec![RegistrySource {
                 url: "https://example.com/skills.git".to_string(),
-                kind: None
+                kind: None,
+                ..Default::default()
             }]
         );
         assert_eq!(
             wrapper.registries.models,
             vec![RegistrySource {
                 url: "https://example.com/models.json".to_string(),
-                kind: None
+                kind: None,
+                ..Default::default()
             }]
         );
         assert!(wrapper.registries.starters.is_empty());
@@ -263,10 +275,12 @@ registries:
                 RegistrySource {
                     url: "https://default.example/a".to_string(),
                     kind: None,
+                    ..Default::default()
                 }, // duplicate of a default
                 RegistrySource {
                     url: "https://user.example/b".to_string(),
                     kind: None,
+                    ..Default::default()
                 },
             ],
             ..Default::default()
@@ -276,10 +290,12 @@ registries:
                 RegistrySource {
                     url: "https://user.example/b".to_string(),
                     kind: None,
+                    ..Default::default()
                 }, // duplicate of a user source
                 RegistrySource {
                     url: "https://project.example/c".to_string(),
                     kind: None,
+                    ..Default::default()
                 },
             ],
             ..Default::default()
@@ -308,6 +324,7 @@ registries:
             skills: vec![RegistrySource {
                 url: "https://user.example/skills".to_string(),
                 kind: None,
+                ..Default::default()
             }],
             ..Default::default()
         };
@@ -396,11 +413,31 @@ starters:
         assert_eq!(c.starters[2].kind, Some(SourceKind::Archive));
     }
 
+    #[test]
+    fn signature_settings_round_trip_and_stay_out_of_plain_sources() {
+        let yaml = r#"
+starters:
+  - url: "https://x.com/p.tar.gz"
+    public_key: RWQf6LRCGA9i53mlYecO4IzT51TGPpvWucNSCh1CBM0QTaLn73Y7GFO3
+    require_signature: true
+  - url: "https://github.com/me/starters.git"
+"#;
+        let c: RegistriesConfig = serde_yaml_ng::from_str(yaml).unwrap();
+        assert!(c.starters[0].require_signature);
+        assert!(c.starters[0].public_key.is_some());
+        assert!(!c.starters[1].require_signature);
+
+        let out = serde_yaml_ng::to_string(&c).unwrap();
+        assert_eq!(out.matches("public_key").count(), 1, "{out}");
+        assert_eq!(out.matches("require_signature").count(), 1, "{out}");
+    }
+
     #[test]
     fn test_infer_defaults_to_git() {
         let s = RegistrySource {
             url: "https://host/repo".to_string(),
             kind: None,
+            ..Default::default()
         };
         assert_eq!(s.resolved_kind(), SourceKind::Git);
     }
@@ -411,6 +448,7 @@ starters:
             starters: vec![RegistrySource {
                 url: "u".into(),
                 kind: None,
+                ..Default::default()
             }],
             ..Default::default()
         };
@@ -418,6 +456,7 @@ starters:
             starters: vec![RegistrySource {
                 url: "p".into(),
                 kind: None,
+                ..Default::default()
             }],
             ..Default::default()
         };
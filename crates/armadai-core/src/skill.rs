@@ -18,8 +18,11 @@ pub struct SkillFrontmatter {
     pub name: Option<String>,
     pub description: Option<String>,
     pub version: Option<String>,
+    /// Scripts (file name or stem) API-mode agents may call as tools.
     #[serde(default)]
     pub tools: Vec<String>,
+    /// Seconds a script may run when called as a tool.
+    pub timeout: Option<u64>,
 }
 
 #[derive(Debug, Clone)]
@@ -28,6 +31,7 @@ pub struct Skill {
     pub description: Option<String>,
     pub version: Option<String>,
     pub tools: Vec<String>,
+    pub timeout: Option<u64>,
     pub body: String,
     pub source: PathBuf,
     pub scripts: Vec<PathBuf>,
@@ -66,6 +70,7 @@ impl Skill {
             description: fm.description,
             version: fm.version,
             tools: fm.tools,
+            timeout: fm.timeout,
             body: body.to_string(),
             source: dir.to_path_buf(),
             scripts,
@@ -3,7 +3,7 @@ use std::path::{Path, PathBuf};
 use include_dir::{Dir, include_dir};
 use serde::Deserialize;
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use super::config::{
     load_user_config, user_agents_dir, user_prompts_dir, user_skills_dir, user_starters_dir,
@@ -19,12 +19,23 @@ static EMBEDDED_STARTERS: Dir = include_dir!("$CARGO_MANIFEST_DIR/starters");
 pub struct StarterPack {
     pub name: String,
     pub description: String,
+    /// Semver version, stamped by `armadai pack build`.
+    #[serde(default)]
+    pub version: Option<String>,
     #[serde(default)]
     pub agents: Vec<String>,
     #[serde(default)]
     pub prompts: Vec<String>,
     #[serde(default)]
     pub skills: Vec<String>,
+    /// `sha256-<hex>` of the pack's files, by path relative to the pack
+    /// directory. Checked before a pack from a remote source is used.
+    #[serde(default)]
+    pub checksums: BTreeMap<String, String>,
+    /// Other packs this one needs, by name, with a semver range (`^1.2`,
+    /// `>=0.3, <0.5`, `*`). Installed first.
+    #[serde(default)]
+    pub dependencies: BTreeMap<String, String>,
 }
 
 // ---------------------------------------------------------------------------
@@ -43,6 +54,28 @@ impl StarterPack {
         Ok(pack)
     }
 
+    /// The pack's `version`, parsed; `None` for an unversioned pack.
+    pub fn semver(&self) -> anyhow::Result<Option<semver::Version>> {
+        self.version
+            .as_deref()
+            .map(|v| {
+                semver::Version::parse(v).map_err(|e| {
+                    anyhow::anyhow!("pack '{}': invalid version '{v}': {e}", self.name)
+                })
+            })
+            .transpose()
+    }
+
+    /// Whether this pack satisfies `req`. An unversioned pack only
+    /// satisfies `*`.
+    pub fn satisfies(&self, req: &semver::VersionReq) -> bool {
+        match self.semver() {
+            Ok(Some(v)) => req.matches(&v),
+            Ok(None) => req.comparators.is_empty(),
+            Err(_) => false,
+        }
+    }
+
     /// Install the starter pack's agents, prompts, and skills to the user library.
     ///
     /// Copies agent `.md` files from `<pack_dir>/agents/` to `~/.config/armadai/agents/`,
@@ -159,6 +192,51 @@ impl StarterPack {
 
         Ok((agents_count, prompts_count, skills_count))
     }
+
+    /// The files [`install`](Self::install) copies from `pack_dir`, as paths
+    /// relative to both `pack_dir` and the config directory
+    /// (`agents/<name>.md`, `prompts/<name>.md`, `skills/<name>/...`).
+    pub fn library_files(&self, pack_dir: &Path) -> Vec<String> {
+        let md = |name: &str| {
+            if name.ends_with(".md") {
+                name.to_string()
+            } else {
+                format!("{name}.md")
+            }
+        };
+        let mut files = Vec::new();
+        for (kind, names) in [("agents", &self.agents), ("prompts", &self.prompts)] {
+            for name in names.iter().filter(|n| !is_unsafe_pack_component(n)) {
+                let rel = format!("{kind}/{}", md(name));
+                if pack_dir.join(&rel).is_file() {
+                    files.push(rel);
+                }
+            }
+        }
+        for name in self.skills.iter().filter(|n| !is_unsafe_pack_component(n)) {
+            let rel = format!("skills/{name}");
+            collect_files(pack_dir, &rel, &mut files);
+        }
+        files
+    }
+}
+
+/// Push every file under `root/rel` (recursively) as a `/`-separated path
+/// relative to `root`.
+fn collect_files(root: &Path, rel: &str, out: &mut Vec<String>) {
+    let Ok(entries) = std::fs::read_dir(root.join(rel)) else {
+        return;
+    };
+    let mut entries: Vec<_> = entries.flatten().collect();
+    entries.sort_by_key(|e| e.file_name());
+    for entry in entries {
+        let child = format!("{rel}/{}", entry.file_name().to_string_lossy());
+        if entry.path().is_dir() {
+            collect_files(root, &child, out);
+        } else {
+            out.push(child);
+        }
+    }
 }
 
 /// Reject pack-provided agent/prompt/skill names that could escape the
@@ -168,7 +246,7 @@ impl StarterPack {
 /// input: without this guard, a name like `../../evil` (or an absolute
 /// path, or a Windows-style `..\evil`) would let `Path::join` write outside
 /// the target dir entirely.
-fn is_unsafe_pack_component(name: &str) -> bool {
+pub fn is_unsafe_pack_component(name: &str) -> bool {
     name.contains('/') || name.contains('\\') || name.contains("..")
 }
 
@@ -434,6 +512,34 @@ pub fn find_remote_pack_by_name(name: &str) -> Option<PathBuf> {
     find_remote_pack_by_name_in(&starters_cache_dir(), name)
 }
 
+/// Find the pack named `name` with the highest version matching `req`,
+/// across the remote starters cache and the local starter directories
+/// (local wins a tie).
+pub fn find_pack_matching(name: &str, req: &semver::VersionReq) -> Option<PathBuf> {
+    let mut candidates: Vec<PathBuf> = remote_starter_pack_dirs();
+    candidates.extend(all_starters_dirs().into_iter().map(|d| d.join(name)));
+    best_match(candidates, name, req)
+}
+
+/// Testable core of [`find_pack_matching`]: `candidates` in ascending
+/// priority.
+fn best_match(candidates: Vec<PathBuf>, name: &str, req: &semver::VersionReq) -> Option<PathBuf> {
+    let mut best: Option<(Option<semver::Version>, PathBuf)> = None;
+    for dir in candidates {
+        let Ok(pack) = StarterPack::load(&dir) else {
+            continue;
+        };
+        if pack.name != name || !pack.satisfies(req) {
+            continue;
+        }
+        let version = pack.semver().ok().flatten();
+        if best.as_ref().is_none_or(|(v, _)| &version >= v) {
+            best = Some((version, dir));
+        }
+    }
+    best.map(|(_, dir)| dir)
+}
+
 /// Load all starter packs from all source directories.
 ///
 /// Packs are deduplicated by name: later directories (user, custom) override
@@ -503,6 +609,38 @@ pub fn list_available_packs() -> Vec<String> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn best_match_takes_the_highest_matching_version() {
+        let tmp = tempfile::tempdir().unwrap();
+        let mut dirs = Vec::new();
+        for (dir, version) in [("a", "1.2.0"), ("b", "1.4.1"), ("c", "2.0.0"), ("d", "")] {
+            let path = tmp.path().join(dir);
+            std::fs::create_dir_all(&path).unwrap();
+            let version = if version.is_empty() {
+                String::new()
+            } else {
+                format!("version: {version}\n")
+            };
+            std::fs::write(
+                path.join("pack.yaml"),
+                format!("name: base\ndescription: d\n{version}"),
+            )
+            .unwrap();
+            dirs.push(path);
+        }
+        let req = |r: &str| semver::VersionReq::parse(r).unwrap();
+        assert_eq!(
+            best_match(dirs.clone(), "base", &req("^1.2")),
+            Some(tmp.path().join("b"))
+        );
+        assert_eq!(
+            best_match(dirs.clone(), "base", &req("*")),
+            Some(tmp.path().join("c"))
+        );
+        assert_eq!(best_match(dirs.clone(), "base", &req("^3")), None);
+        assert_eq!(best_match(dirs, "other", &req("*")), None);
+    }
+
     #[test]
     fn remote_cache_packs_are_discovered() {
         // A pack under the starters cache dir should be found by find_pack_dir.
@@ -75,12 +75,14 @@ struct ApiErrorDetail {
 // --- Cost calculation ---
 
 fn cost_for_model(model: &str, input_tokens: u32, output_tokens: u32) -> f64 {
-    let (input_rate, output_rate) = match model {
-        m if m.contains("opus") => (15.0, 75.0),
-        m if m.contains("haiku") => (0.80, 4.0),
-        _ => (3.0, 15.0), // sonnet pricing as default
-    };
-    (input_tokens as f64 * input_rate + output_tokens as f64 * output_rate) / 1_000_000.0
+    crate::pricing::anthropic_cost(
+        model,
+        &crate::pricing::AnthropicUsage {
+            input_tokens: input_tokens.into(),
+            output_tokens: output_tokens.into(),
+            ..Default::default()
+        },
+    )
 }
 
 // --- SSE parsing ---
@@ -224,17 +224,19 @@ fn create_cli_provider(agent: &Agent) -> anyhow::Result<Box<dyn Provider>> {
 fn create_api_provider(provider: &str, _agent: &Agent) -> anyhow::Result<Box<dyn Provider>> {
     match provider {
         "anthropic" => {
-            let api_key = get_api_key("ANTHROPIC_API_KEY", "anthropic")?;
+            let secrets = provider_secrets("anthropic");
+            let api_key = get_api_key("ANTHROPIC_API_KEY", "anthropic", secrets.as_ref())?;
             let mut p = super::api::anthropic::AnthropicProvider::new(api_key);
-            if let Ok(url) = std::env::var("ANTHROPIC_BASE_URL") {
+            if let Some(url) = get_base_url("ANTHROPIC_BASE_URL", secrets.as_ref()) {
                 p.base_url = url;
             }
             Ok(Box::new(p))
         }
         "google" => {
-            let api_key = get_api_key("GOOGLE_API_KEY", "google")?;
+            let secrets = provider_secrets("google");
+            let api_key = get_api_key("GOOGLE_API_KEY", "google", secrets.as_ref())?;
             let mut p = super::api::google::GoogleProvider::new(api_key);
-            if let Ok(url) = std::env::var("GOOGLE_BASE_URL") {
+            if let Some(url) = get_base_url("GOOGLE_BASE_URL", secrets.as_ref()) {
                 p.base_url = url;
             }
             Ok(Box::new(p))
@@ -254,28 +256,57 @@ fn create_api_provider(provider: &str, _agent: &Agent) -> anyhow::Result<Box<dyn
     )
 }
 
-/// Resolve an API key from environment variable or secrets file.
+/// The secrets stored for `provider_name`: the user's, with the config
+/// dir's (`.armadai/`, `./config/`) and the current project's layered over
+/// them.
 #[cfg(feature = "api")]
-fn get_api_key(env_var: &str, provider_name: &str) -> anyhow::Result<String> {
+fn provider_secrets(provider_name: &str) -> Option<armadai_secrets::ProviderCredentials> {
+    armadai_secrets::load_layered(
+        &armadai_core::config::config_dir(),
+        &armadai_core::config::secrets_overlay_dirs(),
+    )
+    .providers
+    .remove(provider_name)
+}
+
+/// Resolve an API key from environment variable or the provider's secrets.
+#[cfg(feature = "api")]
+fn get_api_key(
+    env_var: &str,
+    provider_name: &str,
+    secrets: Option<&armadai_secrets::ProviderCredentials>,
+) -> anyhow::Result<String> {
     if let Ok(key) = std::env::var(env_var)
         && !key.is_empty()
     {
         return Ok(key);
     }
 
-    let config_dir = armadai_core::config::AppPaths::resolve().config_dir;
-    if let Ok(secrets) = armadai_secrets::load_secrets(&config_dir)
-        && let Some(creds) = secrets.providers.get(provider_name)
-    {
-        return Ok(creds.api_key.clone());
+    if let Some(key) = secrets.and_then(|c| c.get("api_key")) {
+        return Ok(key);
     }
 
     anyhow::bail!(
         "No API key found for '{provider_name}'. \
-         Set {env_var} or add to config/providers.secret.yaml"
+         Set {env_var} or run: armadai secrets set {provider_name}"
     )
 }
 
+/// Resolve a provider's base URL from environment variable or the
+/// `base_url` secrets field; `None` keeps the provider's default.
+#[cfg(feature = "api")]
+fn get_base_url(
+    env_var: &str,
+    secrets: Option<&armadai_secrets::ProviderCredentials>,
+) -> Option<String> {
+    if let Ok(url) = std::env::var(env_var)
+        && !url.is_empty()
+    {
+        return Some(url);
+    }
+    secrets.and_then(|c| c.get("base_url"))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -385,6 +416,7 @@ mod tests {
                 tags: vec![],
                 stacks: vec![],
                 scope: vec![],
+                skills: vec![],
                 model_fallback: vec![],
                 cost_limit: None,
                 rate_limit: Some("1/sec".to_string()),
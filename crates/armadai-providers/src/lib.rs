@@ -4,6 +4,7 @@ pub mod cli;
 pub mod factory;
 pub mod json_runner;
 pub mod model_registry;
+pub mod pricing;
 #[cfg(feature = "api")]
 pub mod proxy;
 pub mod rate_limiter;
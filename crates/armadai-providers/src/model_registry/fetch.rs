@@ -468,6 +468,7 @@ mod tests {
             models: vec![RegistrySource {
                 url: "https://example.com/custom-models.json".to_string(),
                 kind: None,
+                ..Default::default()
             }],
             ..Default::default()
         };
@@ -0,0 +1,55 @@
+//! List prices used to cost a completion. Kept outside the `api` feature so
+//! callers that only read usage (the policy gate's session budget) price
+//! tokens exactly like the API providers do.
+
+/// Anthropic list prices in USD per million tokens, input then output, by
+/// model family.
+pub fn anthropic_rates(model: &str) -> (f64, f64) {
+    match model {
+        m if m.contains("opus") => (15.0, 75.0),
+        m if m.contains("haiku") => (0.80, 4.0),
+        _ => (3.0, 15.0), // sonnet pricing as default
+    }
+}
+
+/// Cache writes are billed at 1.25× the input rate, cache reads at 0.1×.
+const CACHE_WRITE_FACTOR: f64 = 1.25;
+const CACHE_READ_FACTOR: f64 = 0.1;
+
+/// Token counts of one Anthropic message, as its `usage` block reports them.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AnthropicUsage {
+    pub input_tokens: u64,
+    pub cache_creation_input_tokens: u64,
+    pub cache_read_input_tokens: u64,
+    pub output_tokens: u64,
+}
+
+/// Cost in USD of `usage` on `model` at list prices.
+pub fn anthropic_cost(model: &str, usage: &AnthropicUsage) -> f64 {
+    let (input, output) = anthropic_rates(model);
+    (usage.input_tokens as f64 * input
+        + usage.cache_creation_input_tokens as f64 * input * CACHE_WRITE_FACTOR
+        + usage.cache_read_input_tokens as f64 * input * CACHE_READ_FACTOR
+        + usage.output_tokens as f64 * output)
+        / 1_000_000.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cache_tokens_are_priced_off_the_input_rate() {
+        let usage = AnthropicUsage {
+            input_tokens: 1_000_000,
+            cache_creation_input_tokens: 1_000_000,
+            cache_read_input_tokens: 1_000_000,
+            output_tokens: 1_000_000,
+        };
+        let cost = anthropic_cost("claude-sonnet-4-5", &usage);
+        assert!((cost - (3.0 + 3.75 + 0.3 + 15.0)).abs() < 1e-9, "{cost}");
+        assert_eq!(anthropic_rates("claude-opus-4-6"), (15.0, 75.0));
+        assert_eq!(anthropic_rates("claude-haiku-4-5"), (0.80, 4.0));
+    }
+}
@@ -0,0 +1,147 @@
+//! age keys: generating the key file `config secrets init` writes, and
+//! finding the identities that can open a SOPS file.
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use age::secrecy::ExposeSecret;
+use age::x25519::{Identity, Recipient};
+
+/// Name of the key file kept next to the encrypted secrets.
+pub const KEY_FILE: &str = "age-key.txt";
+
+/// Generate a new age key at `path`, in the format `age-keygen` writes, and
+/// return its public key. The file is readable by its owner only.
+pub fn generate_key_file(path: &Path) -> anyhow::Result<String> {
+    let identity = Identity::generate();
+    let public = identity.to_public().to_string();
+    let content = format!(
+        "# created: {}\n# public key: {public}\n{}\n",
+        chrono::Utc::now().format("%Y-%m-%dT%H:%M:%SZ"),
+        identity.to_string().expose_secret()
+    );
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    use std::io::Write;
+    options.open(path)?.write_all(content.as_bytes())?;
+    Ok(public)
+}
+
+/// The public key of the age key file at `path`: its `# public key:` line,
+/// or derived from the secret key when the comment is missing.
+pub fn public_key(path: &Path) -> anyhow::Result<String> {
+    let content = std::fs::read_to_string(path)?;
+    if let Some(public) = content
+        .lines()
+        .find_map(|l| l.strip_prefix("# public key: "))
+    {
+        return Ok(public.trim().to_string());
+    }
+    parse_identities(&content)
+        .first()
+        .map(|id| id.to_public().to_string())
+        .ok_or_else(|| anyhow::anyhow!("no age key found in {}", path.display()))
+}
+
+/// Check that `recipient` is an age public key (`age1…`).
+pub fn parse_recipient(recipient: &str) -> anyhow::Result<Recipient> {
+    Recipient::from_str(recipient.trim())
+        .map_err(|e| anyhow::anyhow!("invalid age recipient '{recipient}': {e}"))
+}
+
+/// Every `AGE-SECRET-KEY-` line in `content`; comments and anything else
+/// are skipped.
+fn parse_identities(content: &str) -> Vec<Identity> {
+    content
+        .lines()
+        .map(str::trim)
+        .filter(|l| l.starts_with("AGE-SECRET-KEY-"))
+        .filter_map(|l| Identity::from_str(l).ok())
+        .collect()
+}
+
+/// Key files looked at, in order, the way `sops` looks for them:
+/// `SOPS_AGE_KEY_FILE`, the key `config secrets init` wrote in
+/// `config_dir`, then sops' own `~/.config/sops/age/keys.txt`.
+fn key_files(config_dir: &Path) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    if let Ok(file) = std::env::var("SOPS_AGE_KEY_FILE") {
+        files.push(PathBuf::from(file));
+    }
+    files.push(config_dir.join(KEY_FILE));
+    let user_config = std::env::var("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|_| std::env::var("HOME").map(|h| PathBuf::from(h).join(".config")));
+    if let Ok(dir) = user_config {
+        files.push(dir.join("sops").join("age").join("keys.txt"));
+    }
+    files
+}
+
+/// Every age identity available to open a SOPS file kept in `config_dir`:
+/// the inline `SOPS_AGE_KEY`, then each key file that exists.
+pub fn identities(config_dir: &Path) -> anyhow::Result<Vec<Identity>> {
+    let mut found = Vec::new();
+    if let Ok(inline) = std::env::var("SOPS_AGE_KEY") {
+        found.extend(parse_identities(&inline));
+    }
+    for file in key_files(config_dir) {
+        if let Ok(content) = std::fs::read_to_string(&file) {
+            found.extend(parse_identities(&content));
+        }
+    }
+    if found.is_empty() {
+        anyhow::bail!(
+            "no age key found (looked at SOPS_AGE_KEY, SOPS_AGE_KEY_FILE and {}); \
+             run 'armadai config secrets init' or set SOPS_AGE_KEY_FILE",
+            config_dir.join(KEY_FILE).display()
+        );
+    }
+    Ok(found)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_generated_key_file_reads_back_like_age_keygen_output() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(KEY_FILE);
+        let public = generate_key_file(&path).unwrap();
+        assert!(public.starts_with("age1"));
+        assert_eq!(public_key(&path).unwrap(), public);
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        let ids = parse_identities(&content);
+        assert_eq!(ids.len(), 1);
+        assert_eq!(ids[0].to_public().to_string(), public);
+        assert!(
+            generate_key_file(&path).is_err(),
+            "an existing key is never overwritten"
+        );
+
+        // Without the comment, the public key is derived from the secret.
+        let bare: String = content.lines().filter(|l| !l.starts_with('#')).collect();
+        std::fs::write(&path, bare).unwrap();
+        assert_eq!(public_key(&path).unwrap(), public);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn a_generated_key_file_is_private() {
+        use std::os::unix::fs::PermissionsExt;
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(KEY_FILE);
+        generate_key_file(&path).unwrap();
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+}
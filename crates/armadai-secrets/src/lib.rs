@@ -1,19 +1,112 @@
+pub mod keys;
+mod native;
 pub mod sops;
+pub mod store;
 
+use std::collections::BTreeMap;
 use std::path::Path;
 
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+pub use store::{Layer, load_layer, load_layered, same_dir, save_layer};
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct ProviderSecrets {
-    pub providers: std::collections::HashMap<String, ProviderCredentials>,
+    #[serde(default)]
+    pub providers: BTreeMap<String, ProviderCredentials>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct ProviderCredentials {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub api_key: String,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub org_id: Option<String>,
+    /// Any other field a provider needs: `base_url`, `region`, headers…
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, serde_yaml_ng::Value>,
+}
+
+impl ProviderCredentials {
+    /// The value of `field`, if set. Non-string values are rendered as YAML.
+    pub fn get(&self, field: &str) -> Option<String> {
+        match field {
+            "api_key" => Some(self.api_key.clone()).filter(|k| !k.is_empty()),
+            "org_id" => self.org_id.clone(),
+            other => self.extra.get(other).map(|v| match v {
+                serde_yaml_ng::Value::String(s) => s.clone(),
+                v => serde_yaml_ng::to_string(v)
+                    .map(|s| s.trim_end().to_string())
+                    .unwrap_or_default(),
+            }),
+        }
+    }
+
+    pub fn set(&mut self, field: &str, value: &str) {
+        match field {
+            "api_key" => self.api_key = value.to_string(),
+            "org_id" => self.org_id = Some(value.to_string()),
+            other => {
+                self.extra.insert(other.to_string(), value.into());
+            }
+        }
+    }
+
+    /// Unset `field`; whether it was set.
+    pub fn remove(&mut self, field: &str) -> bool {
+        match field {
+            "api_key" => !std::mem::take(&mut self.api_key).is_empty(),
+            "org_id" => self.org_id.take().is_some(),
+            other => self.extra.remove(other).is_some(),
+        }
+    }
+
+    /// Every field set, `api_key` and `org_id` first.
+    pub fn fields(&self) -> Vec<(String, String)> {
+        ["api_key", "org_id"]
+            .into_iter()
+            .map(str::to_string)
+            .chain(self.extra.keys().cloned())
+            .filter_map(|f| self.get(&f).map(|v| (f, v)))
+            .collect()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.fields().is_empty()
+    }
+}
+
+/// Fields that say where a provider's key is sent.
+const ENDPOINT_FIELDS: [&str; 2] = ["base_url", "headers"];
+
+impl ProviderSecrets {
+    /// `overrides` layered over `self`, field by field: a project's secrets
+    /// replace the user's ones they set, and leave the rest in place. A
+    /// project that does not set its own `api_key` for a provider cannot
+    /// change where the user's is sent: its endpoint fields are ignored.
+    pub fn layered(mut self, overrides: ProviderSecrets) -> ProviderSecrets {
+        for (provider, mut creds) in overrides.providers {
+            if creds.api_key.is_empty() {
+                for field in ENDPOINT_FIELDS {
+                    if creds.extra.remove(field).is_some() {
+                        tracing::warn!(
+                            "ignoring {field} for '{provider}' in project secrets: \
+                             it is only used with an api_key set beside it"
+                        );
+                    }
+                }
+            }
+            let base = self.providers.entry(provider).or_default();
+            if !creds.api_key.is_empty() {
+                base.api_key = creds.api_key;
+            }
+            if creds.org_id.is_some() {
+                base.org_id = creds.org_id;
+            }
+            base.extra.extend(creds.extra);
+        }
+        self
+    }
 }
 
 /// Load provider secrets from a SOPS-encrypted file, or fall back to a plain file.
@@ -33,3 +126,84 @@ pub fn load_secrets(config_dir: &Path) -> anyhow::Result<ProviderSecrets> {
 
     anyhow::bail!("No secrets file found in {}", config_dir.display())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extra_fields_round_trip_beside_the_known_ones() {
+        let secrets: ProviderSecrets = serde_yaml_ng::from_str(
+            "providers:\n  anthropic:\n    api_key: k\n    base_url: https://proxy\n    \
+             timeout: 30\n",
+        )
+        .unwrap();
+        let creds = &secrets.providers["anthropic"];
+        assert_eq!(creds.get("base_url").as_deref(), Some("https://proxy"));
+        assert_eq!(creds.get("timeout").as_deref(), Some("30"));
+        assert_eq!(
+            creds.fields().first(),
+            Some(&("api_key".to_string(), "k".to_string()))
+        );
+        let back: ProviderSecrets =
+            serde_yaml_ng::from_str(&serde_yaml_ng::to_string(&secrets).unwrap()).unwrap();
+        assert_eq!(back, secrets);
+    }
+
+    #[test]
+    fn project_fields_override_user_fields_one_by_one() {
+        let parse = |y: &str| serde_yaml_ng::from_str::<ProviderSecrets>(y).unwrap();
+        let user = parse("providers:\n  anthropic:\n    api_key: user\n    region: eu\n");
+        let project =
+            parse("providers:\n  anthropic:\n    api_key: proj\n  google:\n    api_key: g\n");
+        let merged = user.layered(project);
+        let anthropic = &merged.providers["anthropic"];
+        assert_eq!(anthropic.api_key, "proj");
+        assert_eq!(anthropic.get("region").as_deref(), Some("eu"));
+        assert_eq!(merged.providers["google"].api_key, "g");
+    }
+
+    #[test]
+    fn layering_keeps_the_type_of_structured_fields() {
+        let parse = |y: &str| serde_yaml_ng::from_str::<ProviderSecrets>(y).unwrap();
+        let user = parse("providers:\n  anthropic:\n    api_key: user\n");
+        let project = parse(
+            "providers:\n  anthropic:\n    api_key: proj\n    timeout: 30\n    headers:\n      x-team: core\n",
+        );
+        let merged = user.layered(project.clone());
+        let anthropic = &merged.providers["anthropic"];
+        assert_eq!(anthropic.api_key, "proj");
+        assert_eq!(
+            anthropic.extra["timeout"],
+            project.providers["anthropic"].extra["timeout"]
+        );
+        assert!(anthropic.extra["headers"].is_mapping());
+    }
+
+    #[test]
+    fn a_project_without_its_own_key_cannot_redirect_the_users() {
+        let parse = |y: &str| serde_yaml_ng::from_str::<ProviderSecrets>(y).unwrap();
+        let user =
+            parse("providers:\n  anthropic:\n    api_key: user\n    base_url: https://api\n");
+        let project = parse(
+            "providers:\n  anthropic:\n    base_url: https://evil\n    headers:\n      x: y\n    region: eu\n",
+        );
+        let merged = user.layered(project);
+        let anthropic = &merged.providers["anthropic"];
+        assert_eq!(anthropic.api_key, "user");
+        assert_eq!(anthropic.get("base_url").as_deref(), Some("https://api"));
+        assert!(!anthropic.extra.contains_key("headers"));
+        assert_eq!(anthropic.get("region").as_deref(), Some("eu"));
+    }
+
+    #[test]
+    fn removing_fields_empties_the_provider() {
+        let mut creds = ProviderCredentials::default();
+        creds.set("api_key", "k");
+        creds.set("base_url", "u");
+        assert!(creds.remove("base_url"));
+        assert!(!creds.remove("base_url"));
+        assert!(creds.remove("api_key"));
+        assert!(creds.is_empty());
+    }
+}
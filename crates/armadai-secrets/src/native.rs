@@ -0,0 +1,442 @@
+//! SOPS files read and written in-process, for files encrypted to age
+//! recipients — no `sops` binary needed.
+//!
+//! A SOPS document keeps its structure in clear and encrypts each leaf
+//! value with AES-256-GCM under one random data key, as
+//! `ENC[AES256_GCM,data:…,iv:…,tag:…,type:…]`, authenticated with the path
+//! of keys leading to it (`providers:anthropic:api_key:`). The `sops:`
+//! block carries the data key encrypted to each age recipient, and a MAC
+//! over every plaintext value so a swapped or removed value is caught.
+use std::io::Read;
+
+use aes_gcm::AesGcm;
+use aes_gcm::aead::consts::U32;
+use aes_gcm::aead::rand_core::RngCore;
+use aes_gcm::aead::{Aead, KeyInit, OsRng, Payload};
+use aes_gcm::aes::Aes256;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as B64;
+use serde_yaml_ng::{Mapping, Value};
+use sha2::{Digest, Sha512};
+
+/// SOPS uses 32-byte GCM nonces rather than the usual 12.
+type Cipher = AesGcm<Aes256, U32>;
+
+const KEY_LEN: usize = 32;
+const IV_LEN: usize = 32;
+const TAG_LEN: usize = 16;
+
+/// Format version written in the `sops:` block.
+const SOPS_VERSION: &str = "3.9.0";
+
+/// Keys ending with this suffix are left in clear, as `sops` does by default.
+pub const UNENCRYPTED_SUFFIX: &str = "_unencrypted";
+
+/// Which leaves a document encrypts, from its `sops:` block.
+struct Rules {
+    unencrypted_suffix: Option<String>,
+    encrypted_suffix: Option<String>,
+}
+
+impl Rules {
+    fn from_meta(meta: &Mapping) -> anyhow::Result<Self> {
+        for unsupported in [
+            "unencrypted_regex",
+            "encrypted_regex",
+            "unencrypted_comment_regex",
+            "encrypted_comment_regex",
+        ] {
+            if meta_str(meta, unsupported).is_some_and(|r| !r.is_empty()) {
+                anyhow::bail!("{unsupported} is not supported by the built-in SOPS reader");
+            }
+        }
+        if meta.get("mac_only_encrypted").and_then(Value::as_bool) == Some(true) {
+            anyhow::bail!("mac_only_encrypted is not supported by the built-in SOPS reader");
+        }
+        Ok(Self {
+            unencrypted_suffix: meta_str(meta, "unencrypted_suffix").map(str::to_string),
+            encrypted_suffix: meta_str(meta, "encrypted_suffix").map(str::to_string),
+        })
+    }
+
+    fn encrypts(&self, path: &[String]) -> bool {
+        if let Some(suffix) = &self.unencrypted_suffix
+            && path.iter().any(|k| k.ends_with(suffix.as_str()))
+        {
+            return false;
+        }
+        match &self.encrypted_suffix {
+            Some(suffix) => path.iter().any(|k| k.ends_with(suffix.as_str())),
+            None => true,
+        }
+    }
+}
+
+fn meta_str<'a>(meta: &'a Mapping, key: &str) -> Option<&'a str> {
+    meta.get(key).and_then(Value::as_str)
+}
+
+/// A leaf's plaintext as SOPS hashes and encrypts it, with its type tag.
+fn leaf_bytes(value: &Value) -> Option<(String, &'static str)> {
+    match value {
+        Value::String(s) => Some((s.clone(), "str")),
+        Value::Bool(b) => Some((b.to_string(), "bool")),
+        Value::Number(n) if n.is_f64() => Some((n.to_string(), "float")),
+        Value::Number(n) => Some((n.to_string(), "int")),
+        _ => None,
+    }
+}
+
+/// What the MAC hashes for a leaf; booleans are capitalised there, unlike
+/// in their encrypted form.
+fn mac_bytes(value: &Value) -> Option<String> {
+    match value {
+        Value::Bool(true) => Some("True".to_string()),
+        Value::Bool(false) => Some("False".to_string()),
+        other => leaf_bytes(other).map(|(s, _)| s),
+    }
+}
+
+/// Additional data binding a value to its place in the document.
+fn aad(path: &[String]) -> String {
+    let mut out = path.join(":");
+    out.push(':');
+    out
+}
+
+fn encrypt_value(key: &[u8], plain: &str, kind: &str, aad: &str) -> anyhow::Result<String> {
+    if plain.is_empty() {
+        return Ok(String::new());
+    }
+    let mut iv = [0u8; IV_LEN];
+    OsRng.fill_bytes(&mut iv);
+    let cipher = Cipher::new_from_slice(key).map_err(|e| anyhow::anyhow!("bad data key: {e}"))?;
+    let sealed = cipher
+        .encrypt(
+            (&iv).into(),
+            Payload {
+                msg: plain.as_bytes(),
+                aad: aad.as_bytes(),
+            },
+        )
+        .map_err(|_| anyhow::anyhow!("AES-GCM encryption failed"))?;
+    let (data, tag) = sealed.split_at(sealed.len() - TAG_LEN);
+    Ok(format!(
+        "ENC[AES256_GCM,data:{},iv:{},tag:{},type:{kind}]",
+        B64.encode(data),
+        B64.encode(iv),
+        B64.encode(tag)
+    ))
+}
+
+/// Decrypt one `ENC[…]` value back to a typed YAML value.
+fn decrypt_value(key: &[u8], enc: &str, aad: &str) -> anyhow::Result<Value> {
+    if enc.is_empty() {
+        return Ok(Value::String(String::new()));
+    }
+    let fields = enc
+        .strip_prefix("ENC[AES256_GCM,")
+        .and_then(|s| s.strip_suffix(']'))
+        .ok_or_else(|| anyhow::anyhow!("not a SOPS AES256_GCM value"))?;
+    let field = |name: &str| {
+        fields
+            .split(',')
+            .find_map(|f| f.strip_prefix(name)?.strip_prefix(':'))
+            .ok_or_else(|| anyhow::anyhow!("SOPS value without {name}"))
+    };
+    let mut sealed = B64.decode(field("data")?)?;
+    sealed.extend(B64.decode(field("tag")?)?);
+    let iv = B64.decode(field("iv")?)?;
+    if iv.len() != IV_LEN {
+        anyhow::bail!("SOPS value with a {}-byte iv", iv.len());
+    }
+    let cipher = Cipher::new_from_slice(key).map_err(|e| anyhow::anyhow!("bad data key: {e}"))?;
+    let plain = cipher
+        .decrypt(
+            iv.as_slice().into(),
+            Payload {
+                msg: &sealed,
+                aad: aad.as_bytes(),
+            },
+        )
+        .map_err(|_| {
+            anyhow::anyhow!("could not decrypt the value at '{aad}' (wrong key or tampered file)")
+        })?;
+    let plain = String::from_utf8(plain)?;
+    Ok(match field("type")? {
+        "int" => Value::Number(plain.parse::<i64>()?.into()),
+        "float" => Value::Number(plain.parse::<f64>()?.into()),
+        "bool" => Value::Bool(plain.eq_ignore_ascii_case("true")),
+        _ => Value::String(plain),
+    })
+}
+
+/// Rebuild `value`, calling `leaf` on every scalar with its key path.
+/// Sequence items share their sequence's path, as in SOPS.
+fn walk(
+    value: &Value,
+    path: &mut Vec<String>,
+    leaf: &mut dyn FnMut(&Value, &[String]) -> anyhow::Result<Value>,
+) -> anyhow::Result<Value> {
+    Ok(match value {
+        Value::Mapping(map) => {
+            let mut out = Mapping::new();
+            for (k, v) in map {
+                let name = match k {
+                    Value::String(s) => s.clone(),
+                    other => leaf_bytes(other)
+                        .map(|(s, _)| s)
+                        .ok_or_else(|| anyhow::anyhow!("unsupported mapping key {other:?}"))?,
+                };
+                path.push(name);
+                let v = walk(v, path, leaf)?;
+                path.pop();
+                out.insert(k.clone(), v);
+            }
+            Value::Mapping(out)
+        }
+        Value::Sequence(items) => Value::Sequence(
+            items
+                .iter()
+                .map(|v| walk(v, path, leaf))
+                .collect::<anyhow::Result<_>>()?,
+        ),
+        Value::Tagged(_) => anyhow::bail!("tagged YAML values are not supported in SOPS files"),
+        Value::Null => Value::Null,
+        scalar => leaf(scalar, path)?,
+    })
+}
+
+fn mac_hex(hash: Sha512) -> String {
+    hash.finalize().iter().map(|b| format!("{b:02X}")).collect()
+}
+
+/// Split a SOPS document into its data and its `sops:` block.
+fn split(doc: &str) -> anyhow::Result<(Value, Mapping)> {
+    let mut value: Value = serde_yaml_ng::from_str(doc)?;
+    let meta = value
+        .as_mapping_mut()
+        .and_then(|m| m.remove("sops"))
+        .and_then(|m| match m {
+            Value::Mapping(m) => Some(m),
+            _ => None,
+        })
+        .ok_or_else(|| anyhow::anyhow!("not a SOPS file (no sops metadata)"))?;
+    Ok((value, meta))
+}
+
+/// The age recipients a SOPS document is encrypted to, with their
+/// armored copy of the data key.
+fn age_stanzas(meta: &Mapping) -> Vec<(String, String)> {
+    meta.get("age")
+        .and_then(Value::as_sequence)
+        .into_iter()
+        .flatten()
+        .filter_map(|s| {
+            let field = |k: &str| s.get(k).and_then(Value::as_str).map(str::to_string);
+            Some((field("recipient")?, field("enc")?))
+        })
+        .collect()
+}
+
+/// Public keys of the age recipients `doc` is encrypted to.
+pub fn recipients(doc: &str) -> anyhow::Result<Vec<String>> {
+    let (_, meta) = split(doc)?;
+    Ok(age_stanzas(&meta).into_iter().map(|(r, _)| r).collect())
+}
+
+/// Decrypt a SOPS document with the first identity that opens one of its
+/// age stanzas, checking its MAC.
+pub fn decrypt(doc: &str, identities: &[age::x25519::Identity]) -> anyhow::Result<Value> {
+    let (data, meta) = split(doc)?;
+    let rules = Rules::from_meta(&meta)?;
+    let stanzas = age_stanzas(&meta);
+    if stanzas.is_empty() {
+        anyhow::bail!("the file has no age recipient (encrypted with another key type)");
+    }
+    let key = stanzas
+        .iter()
+        .find_map(|(_, enc)| {
+            identities.iter().find_map(|id| {
+                let decryptor =
+                    age::Decryptor::new_buffered(age::armor::ArmoredReader::new(enc.as_bytes()))
+                        .ok()?;
+                let mut reader = decryptor
+                    .decrypt(std::iter::once(id as &dyn age::Identity))
+                    .ok()?;
+                let mut key = Vec::new();
+                reader.read_to_end(&mut key).ok()?;
+                Some(key)
+            })
+        })
+        .ok_or_else(|| {
+            anyhow::anyhow!(
+                "none of the available age keys matches the file's recipients ({})",
+                stanzas
+                    .iter()
+                    .map(|(r, _)| r.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )
+        })?;
+    if key.len() != KEY_LEN {
+        anyhow::bail!(
+            "the decrypted data key is {} bytes, not {KEY_LEN}",
+            key.len()
+        );
+    }
+
+    let mut hash = Sha512::new();
+    let plain = walk(&data, &mut Vec::new(), &mut |v, path| {
+        let value = match v.as_str() {
+            Some(enc) if rules.encrypts(path) => decrypt_value(&key, enc, &aad(path))?,
+            _ => v.clone(),
+        };
+        if let Some(bytes) = mac_bytes(&value) {
+            hash.update(bytes.as_bytes());
+        }
+        Ok(value)
+    })?;
+
+    let lastmodified = meta_str(&meta, "lastmodified")
+        .ok_or_else(|| anyhow::anyhow!("SOPS metadata without lastmodified"))?;
+    let mac = meta_str(&meta, "mac").ok_or_else(|| anyhow::anyhow!("SOPS metadata without mac"))?;
+    let expected = decrypt_value(&key, mac, lastmodified)?;
+    if expected.as_str() != Some(mac_hex(hash).as_str()) {
+        anyhow::bail!("MAC mismatch: the file was modified outside SOPS");
+    }
+    Ok(plain)
+}
+
+/// Encrypt `data` (a YAML mapping) to the age `recipients` under a fresh
+/// data key, leaving keys ending in [`UNENCRYPTED_SUFFIX`] in clear.
+pub fn encrypt(data: &Value, recipients: &[String]) -> anyhow::Result<String> {
+    if !data.is_mapping() {
+        anyhow::bail!("SOPS documents must be a YAML mapping");
+    }
+    if recipients.is_empty() {
+        anyhow::bail!("no age recipient to encrypt to");
+    }
+    let mut key = [0u8; KEY_LEN];
+    OsRng.fill_bytes(&mut key);
+    let rules = Rules {
+        unencrypted_suffix: Some(UNENCRYPTED_SUFFIX.to_string()),
+        encrypted_suffix: None,
+    };
+
+    let mut hash = Sha512::new();
+    let encrypted = walk(data, &mut Vec::new(), &mut |v, path| {
+        if let Some(bytes) = mac_bytes(v) {
+            hash.update(bytes.as_bytes());
+        }
+        match leaf_bytes(v) {
+            Some((plain, kind)) if rules.encrypts(path) => Ok(Value::String(encrypt_value(
+                &key,
+                &plain,
+                kind,
+                &aad(path),
+            )?)),
+            _ => Ok(v.clone()),
+        }
+    })?;
+
+    let mut stanzas = Vec::new();
+    for recipient in recipients {
+        let parsed = crate::keys::parse_recipient(recipient)?;
+        let enc = age::encrypt_and_armor(&parsed, &key)
+            .map_err(|e| anyhow::anyhow!("age encryption failed: {e}"))?;
+        let mut stanza = Mapping::new();
+        stanza.insert("recipient".into(), recipient.trim().into());
+        stanza.insert("enc".into(), enc.into());
+        stanzas.push(Value::Mapping(stanza));
+    }
+    let lastmodified = chrono::Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string();
+    let mac = encrypt_value(&key, &mac_hex(hash), "str", &lastmodified)?;
+
+    let mut meta = Mapping::new();
+    meta.insert("age".into(), Value::Sequence(stanzas));
+    meta.insert("lastmodified".into(), lastmodified.into());
+    meta.insert("mac".into(), mac.into());
+    meta.insert("unencrypted_suffix".into(), UNENCRYPTED_SUFFIX.into());
+    meta.insert("version".into(), SOPS_VERSION.into());
+    let mut doc = encrypted;
+    if let Some(map) = doc.as_mapping_mut() {
+        map.insert("sops".into(), Value::Mapping(meta));
+    }
+    Ok(serde_yaml_ng::to_string(&doc)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keypair() -> (age::x25519::Identity, String) {
+        let id = age::x25519::Identity::generate();
+        let public = id.to_public().to_string();
+        (id, public)
+    }
+
+    fn sample() -> Value {
+        serde_yaml_ng::from_str(
+            "providers:\n  anthropic:\n    api_key: sk-ant-1\n    org_id: ''\n  \
+             openai:\n    api_key: sk-2\n    retries: 3\n    ratio: 0.5\n    beta: true\n    \
+             region_unencrypted: eu\n",
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn round_trips_typed_values_and_leaves_the_suffix_in_clear() {
+        let (id, public) = keypair();
+        let doc = encrypt(&sample(), std::slice::from_ref(&public)).unwrap();
+        assert!(!doc.contains("sk-ant-1"), "{doc}");
+        assert!(doc.contains("type:int"), "{doc}");
+        assert!(doc.contains("region_unencrypted: eu"), "{doc}");
+        assert_eq!(recipients(&doc).unwrap(), vec![public]);
+        assert_eq!(decrypt(&doc, &[id]).unwrap(), sample());
+    }
+
+    #[test]
+    fn any_matching_identity_opens_the_file() {
+        let (stranger, _) = keypair();
+        let (id, public) = keypair();
+        let (_, other) = keypair();
+        let doc = encrypt(&sample(), &[other, public]).unwrap();
+        assert_eq!(decrypt(&doc, &[stranger.clone(), id]).unwrap(), sample());
+        let err = decrypt(&doc, &[stranger]).unwrap_err().to_string();
+        assert!(err.contains("none of the available age keys"), "{err}");
+    }
+
+    #[test]
+    fn a_moved_or_edited_value_is_refused() {
+        let (id, public) = keypair();
+        let doc = encrypt(&sample(), &[public]).unwrap();
+        let (mut data, meta) = split(&doc).unwrap();
+        let openai = data["providers"]["openai"]["api_key"].clone();
+        data["providers"]["anthropic"]["api_key"] = openai;
+        data.as_mapping_mut()
+            .unwrap()
+            .insert("sops".into(), Value::Mapping(meta.clone()));
+        let swapped = serde_yaml_ng::to_string(&data).unwrap();
+        let err = decrypt(&swapped, std::slice::from_ref(&id))
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("providers:anthropic:api_key:"), "{err}");
+
+        let edited = doc.replace("region_unencrypted: eu", "region_unencrypted: us");
+        let err = decrypt(&edited, &[id]).unwrap_err().to_string();
+        assert!(err.contains("MAC mismatch"), "{err}");
+    }
+
+    #[test]
+    fn files_without_age_stanzas_are_left_to_the_sops_cli() {
+        let (id, _) = keypair();
+        let doc = "a: ENC[AES256_GCM,data:AA==,iv:AA==,tag:AA==,type:str]\n\
+                   sops:\n  kms:\n    - arn: x\n  version: 3.8.1\n";
+        let err = decrypt(doc, std::slice::from_ref(&id))
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("no age recipient"), "{err}");
+        assert!(decrypt("a: b\n", &[id]).is_err(), "plain YAML is not SOPS");
+    }
+}
@@ -2,49 +2,105 @@ use std::path::Path;
 use std::process::Command;
 
 use super::ProviderSecrets;
+use crate::{keys, native};
 
-/// Decrypt a SOPS-encrypted YAML file using the `sops` CLI.
+/// Decrypt a SOPS-encrypted YAML file into provider secrets.
 pub fn decrypt_file(path: &Path) -> anyhow::Result<ProviderSecrets> {
-    let output = Command::new("sops")
-        .args(["--decrypt", &path.display().to_string()])
-        .output()?;
+    Ok(serde_yaml_ng::from_value(decrypt_value(path)?)?)
+}
+
+/// Decrypt a SOPS-encrypted YAML file, in-process when it is encrypted to
+/// an available age key. The `sops` CLI is only tried when that fails (a
+/// KMS or PGP key, a rule the built-in reader does not support), so a
+/// machine without it still reads age-encrypted secrets.
+pub fn decrypt_value(path: &Path) -> anyhow::Result<serde_yaml_ng::Value> {
+    decrypt_value_with_keys(path, &[path.parent().unwrap_or(Path::new("."))])
+}
+
+/// [`decrypt_value`], looking for `age-key.txt` in each of `key_dirs` rather
+/// than only next to the file — a project's secrets open with the user's key.
+pub fn decrypt_value_with_keys(
+    path: &Path,
+    key_dirs: &[&Path],
+) -> anyhow::Result<serde_yaml_ng::Value> {
+    let doc = std::fs::read_to_string(path)?;
+    let native_err = match identities(key_dirs).and_then(|ids| native::decrypt(&doc, &ids)) {
+        Ok(value) => return Ok(value),
+        Err(e) => e,
+    };
+    match decrypt_with_cli(path) {
+        Some(Ok(decrypted)) => Ok(serde_yaml_ng::from_str(&decrypted)?),
+        Some(Err(cli_err)) => {
+            anyhow::bail!("SOPS decryption failed: {native_err} (sops CLI: {cli_err})")
+        }
+        None => anyhow::bail!("SOPS decryption failed: {native_err}"),
+    }
+}
+
+/// The identities found for any of `key_dirs`; the first directory's error
+/// when none has a key.
+fn identities(key_dirs: &[&Path]) -> anyhow::Result<Vec<age::x25519::Identity>> {
+    let mut first_err = None;
+    let mut found = Vec::new();
+    for dir in key_dirs {
+        match keys::identities(dir) {
+            Ok(ids) => found.extend(ids),
+            Err(e) => {
+                first_err.get_or_insert(e);
+            }
+        }
+    }
+    match first_err {
+        Some(e) if found.is_empty() => Err(e),
+        _ => Ok(found),
+    }
+}
 
+/// Decrypt with the `sops` CLI; `None` when it is not installed.
+fn decrypt_with_cli(path: &Path) -> Option<anyhow::Result<String>> {
+    let output = match Command::new("sops")
+        .args(["--decrypt", &path.display().to_string()])
+        .output()
+    {
+        Ok(output) => output,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return None,
+        Err(e) => return Some(Err(e.into())),
+    };
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr);
-        anyhow::bail!("SOPS decryption failed: {stderr}");
+        return Some(Err(anyhow::anyhow!("{}", stderr.trim())));
     }
+    Some(String::from_utf8(output.stdout).map_err(Into::into))
+}
+
+/// Encrypt `data` to the age `recipients` and write it to `path` as a SOPS
+/// file `sops` itself can open.
+pub fn encrypt_file(
+    path: &Path,
+    data: &serde_yaml_ng::Value,
+    recipients: &[String],
+) -> anyhow::Result<()> {
+    let doc = native::encrypt(data, recipients)?;
+    std::fs::write(path, doc)?;
+    Ok(())
+}
 
-    let decrypted = String::from_utf8(output.stdout)?;
-    let secrets: ProviderSecrets = serde_yaml_ng::from_str(&decrypted)?;
-    Ok(secrets)
+/// The age recipients the SOPS file at `path` is encrypted to, to re-encrypt
+/// it for the same readers after an edit.
+pub fn file_recipients(path: &Path) -> anyhow::Result<Vec<String>> {
+    native::recipients(&std::fs::read_to_string(path)?)
 }
 
 /// Initialize SOPS + age for this project.
 pub fn init_sops(config_dir: &Path) -> anyhow::Result<()> {
     // Generate age key if not present
-    let key_path = config_dir.join("age-key.txt");
+    let key_path = config_dir.join(keys::KEY_FILE);
     if !key_path.exists() {
-        let output = Command::new("age-keygen")
-            .args(["-o", &key_path.display().to_string()])
-            .output()?;
-
-        if !output.status.success() {
-            anyhow::bail!(
-                "age-keygen failed. Is age installed? (brew install age)\n{}",
-                String::from_utf8_lossy(&output.stderr)
-            );
-        }
-
+        keys::generate_key_file(&key_path)?;
         println!("Age key generated at: {}", key_path.display());
     }
 
-    // Read public key from key file
-    let key_content = std::fs::read_to_string(&key_path)?;
-    let public_key = key_content
-        .lines()
-        .find(|l| l.starts_with("# public key:"))
-        .and_then(|l| l.strip_prefix("# public key: "))
-        .ok_or_else(|| anyhow::anyhow!("Could not extract public key from age key file"))?;
+    let public_key = keys::public_key(&key_path)?;
 
     // Create .sops.yaml
     let sops_config = format!(
@@ -65,3 +121,22 @@ pub fn init_sops(config_dir: &Path) -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_file_encrypted_here_decrypts_with_the_key_next_to_it() {
+        let dir = tempfile::tempdir().unwrap();
+        let public = keys::generate_key_file(&dir.path().join(keys::KEY_FILE)).unwrap();
+        let path = dir.path().join("providers.sops.yaml");
+        let data: serde_yaml_ng::Value =
+            serde_yaml_ng::from_str("providers:\n  anthropic:\n    api_key: sk-ant-1\n").unwrap();
+        encrypt_file(&path, &data, std::slice::from_ref(&public)).unwrap();
+
+        assert_eq!(file_recipients(&path).unwrap(), vec![public]);
+        let secrets = decrypt_file(&path).unwrap();
+        assert_eq!(secrets.providers["anthropic"].api_key, "sk-ant-1");
+    }
+}
@@ -0,0 +1,209 @@
+//! Secrets layers: the user's, in the config directory, and a project's, in
+//! its `.armadai/` directory, each kept in `providers.sops.yaml` (or the
+//! unencrypted `providers.secret.yaml`). Both open with the user's age key.
+use std::path::{Path, PathBuf};
+
+use crate::{ProviderSecrets, keys, sops};
+
+const SOPS_FILE: &str = "providers.sops.yaml";
+const PLAIN_FILE: &str = "providers.secret.yaml";
+
+/// Where a layer lives and which key opens it.
+#[derive(Debug, Clone)]
+pub struct Layer {
+    /// Directory holding the layer's secrets file.
+    pub dir: PathBuf,
+    /// Directory holding the user's `age-key.txt`.
+    pub key_dir: PathBuf,
+}
+
+impl Layer {
+    /// The user's layer, in the config directory that also holds the key.
+    pub fn user(config_dir: &Path) -> Self {
+        Self {
+            dir: config_dir.to_path_buf(),
+            key_dir: config_dir.to_path_buf(),
+        }
+    }
+
+    /// A project's layer in `dir`, opened with the user's key.
+    pub fn project(dir: &Path, config_dir: &Path) -> Self {
+        Self {
+            dir: dir.to_path_buf(),
+            key_dir: config_dir.to_path_buf(),
+        }
+    }
+
+    /// The file holding this layer's secrets, if any; the encrypted one
+    /// wins when both exist, as in [`crate::load_secrets`].
+    pub fn file(&self) -> Option<PathBuf> {
+        [SOPS_FILE, PLAIN_FILE]
+            .into_iter()
+            .map(|name| self.dir.join(name))
+            .find(|p| p.exists())
+    }
+}
+
+fn is_encrypted(path: &Path) -> bool {
+    path.file_name().is_some_and(|n| n == SOPS_FILE)
+}
+
+/// The secrets of `layer`; empty when it has no file yet.
+pub fn load_layer(layer: &Layer) -> anyhow::Result<ProviderSecrets> {
+    let Some(path) = layer.file() else {
+        return Ok(ProviderSecrets::default());
+    };
+    let value = if is_encrypted(&path) {
+        sops::decrypt_value_with_keys(&path, &[&layer.key_dir])?
+    } else {
+        serde_yaml_ng::from_str(&std::fs::read_to_string(&path)?)?
+    };
+    Ok(serde_yaml_ng::from_value(value)?)
+}
+
+/// Write `secrets` back to `layer`, in the file it already uses: re-encrypted
+/// to the same recipients, or in clear if it was. A new layer is encrypted
+/// to the user's age key. Returns the file written.
+pub fn save_layer(layer: &Layer, secrets: &ProviderSecrets) -> anyhow::Result<PathBuf> {
+    let value = serde_yaml_ng::to_value(secrets)?;
+    match layer.file() {
+        Some(path) if is_encrypted(&path) => {
+            let recipients = sops::file_recipients(&path)?;
+            sops::encrypt_file(&path, &value, &recipients)?;
+            Ok(path)
+        }
+        Some(path) => {
+            std::fs::write(&path, serde_yaml_ng::to_string(&value)?)?;
+            Ok(path)
+        }
+        None => {
+            let key_file = layer.key_dir.join(keys::KEY_FILE);
+            let recipient = keys::public_key(&key_file).map_err(|_| {
+                anyhow::anyhow!(
+                    "no age key at {} to encrypt secrets with; run 'armadai config secrets init' first",
+                    key_file.display()
+                )
+            })?;
+            std::fs::create_dir_all(&layer.dir)?;
+            let path = layer.dir.join(SOPS_FILE);
+            sops::encrypt_file(&path, &value, &[recipient])?;
+            Ok(path)
+        }
+    }
+}
+
+/// Whether `a` and `b` name the same directory.
+pub fn same_dir(a: &Path, b: &Path) -> bool {
+    match (a.canonicalize(), b.canonicalize()) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => a == b,
+    }
+}
+
+/// The secrets providers use: the user's, with each of `overlays` layered
+/// over them field by field, in order. A directory already read is skipped.
+/// An unreadable layer is skipped with a warning, so one broken file does
+/// not hide the others' keys.
+pub fn load_layered(config_dir: &Path, overlays: &[PathBuf]) -> ProviderSecrets {
+    let read = |layer: Layer| {
+        load_layer(&layer).unwrap_or_else(|e| {
+            tracing::warn!("ignoring secrets in {}: {e}", layer.dir.display());
+            ProviderSecrets::default()
+        })
+    };
+    let mut seen = vec![config_dir.to_path_buf()];
+    let mut secrets = read(Layer::user(config_dir));
+    for dir in overlays {
+        if seen.iter().any(|s| same_dir(s, dir)) {
+            continue;
+        }
+        secrets = secrets.layered(read(Layer::project(dir, config_dir)));
+        seen.push(dir.clone());
+    }
+    secrets
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_new_project_layer_is_encrypted_to_the_user_key() {
+        let config = tempfile::tempdir().unwrap();
+        let project = tempfile::tempdir().unwrap();
+        let layer = Layer::project(&project.path().join(".armadai"), config.path());
+
+        let mut secrets = ProviderSecrets::default();
+        secrets
+            .providers
+            .entry("anthropic".into())
+            .or_default()
+            .set("base_url", "https://proxy");
+        let err = save_layer(&layer, &secrets).unwrap_err().to_string();
+        assert!(err.contains("config secrets init"), "{err}");
+
+        keys::generate_key_file(&config.path().join(keys::KEY_FILE)).unwrap();
+        let path = save_layer(&layer, &secrets).unwrap();
+        assert!(is_encrypted(&path));
+        assert!(!std::fs::read_to_string(&path).unwrap().contains("proxy"));
+        assert_eq!(load_layer(&layer).unwrap(), secrets);
+    }
+
+    #[test]
+    fn a_key_kept_beside_a_project_layer_does_not_open_it() {
+        let config = tempfile::tempdir().unwrap();
+        let project = tempfile::tempdir().unwrap();
+        keys::generate_key_file(&project.path().join(keys::KEY_FILE)).unwrap();
+        let mut secrets = ProviderSecrets::default();
+        secrets
+            .providers
+            .entry("anthropic".into())
+            .or_default()
+            .set("api_key", "k");
+        // Encrypted to the project's own key, as a committed key would allow.
+        save_layer(&Layer::user(project.path()), &secrets).unwrap();
+
+        let layer = Layer::project(project.path(), config.path());
+        assert!(load_layer(&layer).is_err());
+        let err = save_layer(
+            &Layer::project(&project.path().join("sub"), config.path()),
+            &secrets,
+        )
+        .unwrap_err()
+        .to_string();
+        assert!(err.contains("config secrets init"), "{err}");
+    }
+
+    #[test]
+    fn a_plain_layer_stays_plain_and_layers_under_the_project() {
+        let config = tempfile::tempdir().unwrap();
+        let project = tempfile::tempdir().unwrap();
+        std::fs::write(
+            config.path().join(PLAIN_FILE),
+            "providers:\n  anthropic:\n    api_key: user\n    region: eu\n",
+        )
+        .unwrap();
+        std::fs::write(
+            project.path().join(PLAIN_FILE),
+            "providers:\n  anthropic:\n    api_key: proj\n",
+        )
+        .unwrap();
+        let user = Layer::user(config.path());
+        let mut secrets = load_layer(&user).unwrap();
+        secrets
+            .providers
+            .get_mut("anthropic")
+            .unwrap()
+            .set("org_id", "o");
+        assert_eq!(
+            save_layer(&user, &secrets).unwrap(),
+            config.path().join(PLAIN_FILE)
+        );
+
+        let merged = load_layered(config.path(), &[project.path().to_path_buf()]);
+        let anthropic = &merged.providers["anthropic"];
+        assert_eq!(anthropic.api_key, "proj");
+        assert_eq!(anthropic.get("region").as_deref(), Some("eu"));
+        assert_eq!(anthropic.org_id.as_deref(), Some("o"));
+    }
+}
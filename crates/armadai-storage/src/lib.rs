@@ -1,3 +1,4 @@
+pub mod maintenance;
 pub mod queries;
 pub mod schema;
 
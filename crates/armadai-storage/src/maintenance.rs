@@ -0,0 +1,411 @@
+//! Database maintenance: retention pruning, `VACUUM` and size statistics
+//! (backs `armadai db prune|vacuum|stats` and `storage.retention`).
+
+use rusqlite::{Connection, params};
+use serde::{Deserialize, Serialize};
+
+use super::Database;
+
+/// Tables `stats` reports on, in display order. `history_fts` is the FTS5
+/// index; its shadow tables (`history_fts_data`, ...) are counted under it.
+const TABLES: &[&str] = &[
+    "runs",
+    "execution_events",
+    "orchestration_runs",
+    "board_entries",
+    "ring_contributions",
+    "ring_votes",
+    "delegation_events",
+    "cost_rollups",
+    "eval_runs",
+    "eval_results",
+    "history_fts",
+];
+
+/// What a prune removed.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PruneReport {
+    /// Distinct runs whose rows were removed.
+    pub runs: usize,
+    /// `execution_events` rows removed.
+    pub events: usize,
+    /// Projection rows removed (`runs` + orchestration child tables).
+    pub projection_rows: usize,
+    /// Runs folded into `cost_rollups` before deletion (`keep_costs` only).
+    pub rolled_up: usize,
+}
+
+/// Row count and on-disk size of one table.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TableStats {
+    pub name: String,
+    pub rows: i64,
+    /// Bytes used, including indexes. `None` when the SQLite build lacks
+    /// the `dbstat` virtual table.
+    pub bytes: Option<i64>,
+}
+
+/// Run count and cost attributed to one project (`None` = no project).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectStats {
+    pub project: Option<String>,
+    pub runs: i64,
+    pub cost: f64,
+}
+
+/// Database-wide statistics.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DbStats {
+    /// `page_count * page_size`: the size of the main database file.
+    pub file_bytes: i64,
+    /// Pages on the freelist (reclaimable by `VACUUM`), in bytes.
+    pub free_bytes: i64,
+    pub tables: Vec<TableStats>,
+    pub projects: Vec<ProjectStats>,
+}
+
+/// Delete every run older than `max_age_secs`: its `execution_events` and all
+/// its projection rows (`runs`, `orchestration_runs`, `board_entries`,
+/// `ring_contributions`, `ring_votes`, `delegation_events`). A run is old when
+/// its `runs` row was created before the cutoff, or — for runs that only
+/// exist in the event log — when its last event predates it.
+///
+/// With `keep_costs`, the pruned `runs` rows are first folded into
+/// `cost_rollups` (per day, agent and project), which `get_costs_summary`
+/// reads alongside `runs`. Runs in one transaction: a failure leaves the
+/// database untouched.
+pub fn prune_older_than(
+    db: &Database,
+    max_age_secs: u64,
+    keep_costs: bool,
+) -> anyhow::Result<PruneReport> {
+    let mut conn = db
+        .lock()
+        .map_err(|e| anyhow::anyhow!("Database lock poisoned: {}", e))?;
+    let modifier = format!("-{max_age_secs} seconds");
+    let tx = conn.transaction()?;
+
+    tx.execute_batch("CREATE TEMP TABLE IF NOT EXISTS prune_ids (run_id TEXT PRIMARY KEY);")?;
+    tx.execute("DELETE FROM temp.prune_ids", [])?;
+    tx.execute(
+        "INSERT OR IGNORE INTO temp.prune_ids (run_id)
+             SELECT id FROM runs WHERE created_at < datetime('now', ?1)",
+        params![modifier],
+    )?;
+    // Event-log-only runs (no projection yet, e.g. interrupted): keep them
+    // while they are recent, and never split a run whose `runs` row is
+    // recent but whose first events are old.
+    tx.execute(
+        "INSERT OR IGNORE INTO temp.prune_ids (run_id)
+             SELECT run_id FROM execution_events
+             WHERE run_id NOT IN (SELECT id FROM runs)
+             GROUP BY run_id HAVING MAX(ts) < datetime('now', ?1)",
+        params![modifier],
+    )?;
+
+    let mut report = PruneReport {
+        runs: tx.query_row("SELECT COUNT(*) FROM temp.prune_ids", [], |r| {
+            r.get::<_, i64>(0)
+        })? as usize,
+        ..PruneReport::default()
+    };
+
+    if keep_costs {
+        report.rolled_up = tx.query_row(
+            "SELECT COUNT(*) FROM runs WHERE id IN (SELECT run_id FROM temp.prune_ids)",
+            [],
+            |r| r.get::<_, i64>(0),
+        )? as usize;
+        tx.execute(
+            "INSERT INTO cost_rollups (day, agent, project, runs, cost, tokens_in, tokens_out)
+                 SELECT date(created_at), agent, COALESCE(project, ''), COUNT(*),
+                        SUM(cost), SUM(tokens_in), SUM(tokens_out)
+                 FROM runs WHERE id IN (SELECT run_id FROM temp.prune_ids)
+                 GROUP BY date(created_at), agent, COALESCE(project, '')
+             ON CONFLICT (day, agent, project) DO UPDATE SET
+                 runs = runs + excluded.runs,
+                 cost = cost + excluded.cost,
+                 tokens_in = tokens_in + excluded.tokens_in,
+                 tokens_out = tokens_out + excluded.tokens_out",
+            [],
+        )?;
+    }
+
+    report.events = tx.execute(
+        "DELETE FROM execution_events WHERE run_id IN (SELECT run_id FROM temp.prune_ids)",
+        [],
+    )?;
+    // Child tables first, then parents — same order as
+    // `queries::delete_projection_for_run`.
+    for table in [
+        "delegation_events",
+        "ring_votes",
+        "ring_contributions",
+        "board_entries",
+        "orchestration_runs",
+    ] {
+        report.projection_rows += tx.execute(
+            &format!("DELETE FROM {table} WHERE run_id IN (SELECT run_id FROM temp.prune_ids)"),
+            [],
+        )?;
+    }
+    report.projection_rows += tx.execute(
+        "DELETE FROM runs WHERE id IN (SELECT run_id FROM temp.prune_ids)",
+        [],
+    )?;
+
+    tx.execute("DELETE FROM temp.prune_ids", [])?;
+    tx.commit()?;
+    Ok(report)
+}
+
+/// Rebuild the database file to reclaim the space freed by pruning, after
+/// merging the FTS index segments.
+pub fn vacuum(db: &Database) -> anyhow::Result<()> {
+    let conn = db
+        .lock()
+        .map_err(|e| anyhow::anyhow!("Database lock poisoned: {}", e))?;
+    conn.execute_batch(
+        "INSERT INTO history_fts (history_fts) VALUES ('optimize');
+         VACUUM;
+         PRAGMA optimize;",
+    )?;
+    Ok(())
+}
+
+/// Row counts and sizes per table, and runs/cost per project.
+pub fn stats(db: &Database) -> anyhow::Result<DbStats> {
+    let conn = db
+        .lock()
+        .map_err(|e| anyhow::anyhow!("Database lock poisoned: {}", e))?;
+
+    let page_size: i64 = conn.query_row("PRAGMA page_size", [], |r| r.get(0))?;
+    let page_count: i64 = conn.query_row("PRAGMA page_count", [], |r| r.get(0))?;
+    let freelist: i64 = conn.query_row("PRAGMA freelist_count", [], |r| r.get(0))?;
+
+    let mut tables = Vec::new();
+    for &name in TABLES {
+        let rows: i64 =
+            conn.query_row(&format!("SELECT COUNT(*) FROM {name}"), [], |r| r.get(0))?;
+        tables.push(TableStats {
+            name: name.to_string(),
+            rows,
+            bytes: table_bytes(&conn, name),
+        });
+    }
+
+    let mut stmt = conn.prepare(
+        "SELECT project, COUNT(*), SUM(cost) FROM runs
+         GROUP BY project ORDER BY COUNT(*) DESC",
+    )?;
+    let rows = stmt.query_map([], |row| {
+        Ok(ProjectStats {
+            project: row.get(0)?,
+            runs: row.get(1)?,
+            cost: row.get(2)?,
+        })
+    })?;
+    let mut projects = Vec::new();
+    for row in rows {
+        projects.push(row?);
+    }
+
+    Ok(DbStats {
+        file_bytes: page_count * page_size,
+        free_bytes: freelist * page_size,
+        tables,
+        projects,
+    })
+}
+
+/// Bytes used by `table` and its indexes (or, for an FTS5 table, its shadow
+/// tables), from the `dbstat` virtual table. `None` if `dbstat` is missing.
+fn table_bytes(conn: &Connection, table: &str) -> Option<i64> {
+    conn.query_row(
+        "SELECT COALESCE(SUM(s.pgsize), 0) FROM dbstat s
+         JOIN sqlite_master m ON m.name = s.name
+         WHERE m.tbl_name = ?1 OR substr(m.tbl_name, 1, length(?1) + 1) = ?1 || '_'",
+        params![table],
+        |r| r.get(0),
+    )
+    .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::open_in_memory;
+    use crate::queries::{
+        BoardEntryRecord, OrchestrationRunRecord, RunRecord, get_costs_summary, get_history,
+        insert_board_entry, insert_orchestration_run, insert_run_with_id,
+    };
+
+    fn run(agent: &str, cost: f64, project: Option<&str>) -> RunRecord {
+        RunRecord {
+            agent: agent.to_string(),
+            input: "in".to_string(),
+            output: "out".to_string(),
+            provider: "anthropic".to_string(),
+            model: "claude-sonnet".to_string(),
+            tokens_in: 10,
+            tokens_out: 20,
+            cost,
+            duration_ms: 5,
+            status: "success".to_string(),
+            project: project.map(str::to_string),
+        }
+    }
+
+    /// Backdate a run (and its events) by `days`.
+    fn age(db: &Database, run_id: &str, days: i64) {
+        let conn = db.lock().unwrap();
+        let modifier = format!("-{days} days");
+        conn.execute(
+            "UPDATE runs SET created_at = datetime('now', ?2) WHERE id = ?1",
+            params![run_id, modifier],
+        )
+        .unwrap();
+        conn.execute(
+            "UPDATE execution_events SET ts = datetime('now', ?2) WHERE run_id = ?1",
+            params![run_id, modifier],
+        )
+        .unwrap();
+    }
+
+    fn add_event(db: &Database, run_id: &str) {
+        db.lock()
+            .unwrap()
+            .execute(
+                "INSERT INTO execution_events (run_id, seq, kind, payload_json)
+                 VALUES (?1, 0, 'run_started', '{}')",
+                params![run_id],
+            )
+            .unwrap();
+    }
+
+    fn seed_blackboard(db: &Database, run_id: &str, agent: &str, cost: f64) {
+        insert_run_with_id(db, run_id, run(agent, cost, Some("/work/app"))).unwrap();
+        insert_orchestration_run(
+            db,
+            OrchestrationRunRecord {
+                run_id: run_id.to_string(),
+                pattern: "blackboard".to_string(),
+                config_json: "{}".to_string(),
+                outcome_json: None,
+                rounds: 1,
+                halt_reason: None,
+                parent_run_id: None,
+            },
+        )
+        .unwrap();
+        insert_board_entry(
+            db,
+            BoardEntryRecord {
+                run_id: run_id.to_string(),
+                agent: agent.to_string(),
+                round: 1,
+                kind: "finding".to_string(),
+                content: "a finding".to_string(),
+                refs_json: "[]".to_string(),
+                confidence: 0.5,
+                tokens_in: 0,
+                tokens_out: 0,
+            },
+        )
+        .unwrap();
+        add_event(db, run_id);
+    }
+
+    #[test]
+    fn prune_removes_old_runs_events_and_projections_only() {
+        let db = open_in_memory().unwrap();
+        seed_blackboard(&db, "old", "a", 1.0);
+        seed_blackboard(&db, "new", "a", 2.0);
+        age(&db, "old", 120);
+
+        let report = prune_older_than(&db, 90 * 86_400, false).unwrap();
+        assert_eq!(report.runs, 1);
+        assert_eq!(report.events, 1);
+        // runs + orchestration_runs + board_entries
+        assert_eq!(report.projection_rows, 3);
+        assert_eq!(report.rolled_up, 0);
+
+        let history = get_history(&db, None, 10).unwrap();
+        assert_eq!(history.len(), 1);
+        assert!((history[0].cost - 2.0).abs() < 1e-9);
+        let s = stats(&db).unwrap();
+        let rows = |name: &str| s.tables.iter().find(|t| t.name == name).unwrap().rows;
+        assert_eq!(rows("execution_events"), 1);
+        assert_eq!(rows("board_entries"), 1);
+        assert_eq!(rows("history_fts"), 2); // the surviving run + its entry
+    }
+
+    #[test]
+    fn prune_keep_costs_preserves_cost_totals() {
+        let db = open_in_memory().unwrap();
+        seed_blackboard(&db, "old-1", "a", 1.0);
+        seed_blackboard(&db, "old-2", "a", 0.5);
+        seed_blackboard(&db, "new", "a", 2.0);
+        age(&db, "old-1", 100);
+        age(&db, "old-2", 100);
+
+        let report = prune_older_than(&db, 90 * 86_400, true).unwrap();
+        assert_eq!(report.runs, 2);
+        assert_eq!(report.rolled_up, 2);
+
+        let costs = get_costs_summary(&db, Some("a")).unwrap();
+        assert_eq!(costs.len(), 1);
+        assert_eq!(costs[0].total_runs, 3);
+        assert!((costs[0].total_cost - 3.5).abs() < 1e-9);
+        assert_eq!(costs[0].total_tokens_in, 30);
+
+        // A second prune of the same day folds into the existing rollup row.
+        seed_blackboard(&db, "old-3", "a", 0.25);
+        age(&db, "old-3", 100);
+        prune_older_than(&db, 90 * 86_400, true).unwrap();
+        let costs = get_costs_summary(&db, Some("a")).unwrap();
+        assert_eq!(costs[0].total_runs, 4);
+        assert!((costs[0].total_cost - 3.75).abs() < 1e-9);
+    }
+
+    #[test]
+    fn prune_event_log_only_runs_by_last_event() {
+        let db = open_in_memory().unwrap();
+        add_event(&db, "interrupted-old");
+        add_event(&db, "interrupted-new");
+        age(&db, "interrupted-old", 30);
+
+        let report = prune_older_than(&db, 7 * 86_400, false).unwrap();
+        assert_eq!(report.runs, 1);
+        assert_eq!(report.events, 1);
+    }
+
+    #[test]
+    fn stats_counts_rows_and_groups_runs_by_project() {
+        let db = open_in_memory().unwrap();
+        seed_blackboard(&db, "r1", "a", 1.0);
+        insert_run_with_id(&db, "r2", run("b", 0.5, None)).unwrap();
+
+        let s = stats(&db).unwrap();
+        assert!(s.file_bytes > 0);
+        let runs = s.tables.iter().find(|t| t.name == "runs").unwrap();
+        assert_eq!(runs.rows, 2);
+        assert_eq!(s.projects.len(), 2);
+        let app = s
+            .projects
+            .iter()
+            .find(|p| p.project.as_deref() == Some("/work/app"))
+            .unwrap();
+        assert_eq!(app.runs, 1);
+        assert!((app.cost - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn vacuum_succeeds_after_prune() {
+        let db = open_in_memory().unwrap();
+        seed_blackboard(&db, "old", "a", 1.0);
+        age(&db, "old", 10);
+        prune_older_than(&db, 86_400, false).unwrap();
+        vacuum(&db).unwrap();
+    }
+}
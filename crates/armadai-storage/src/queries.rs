@@ -115,7 +115,99 @@ pub fn get_history(
     Ok(records)
 }
 
-/// Get cost summary grouped by agent.
+/// A full-text search hit: the matching run plus a short excerpt of the best
+/// matching text (run input/output, board entry or ring contribution), with
+/// the matched terms wrapped in `[` `]`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistoryHit {
+    pub run_id: String,
+    pub run: RunRecord,
+    pub snippet: String,
+}
+
+/// Turn free-form user input into an FTS5 match expression.
+///
+/// Every whitespace-separated word becomes a quoted string (implicit AND), so
+/// punctuation a user naturally types (`sql-injection`, `db.rs`, `"`) cannot
+/// trip FTS5's query syntax. A trailing `*` is kept as a prefix query.
+/// Returns `None` when the input holds no searchable word.
+fn fts_match_expr(query: &str) -> Option<String> {
+    let terms: Vec<String> = query
+        .split_whitespace()
+        .filter_map(|word| {
+            let (word, prefix) = match word.strip_suffix('*') {
+                Some(w) => (w, "*"),
+                None => (word, ""),
+            };
+            let word = word.replace('"', "");
+            (!word.is_empty()).then(|| format!("\"{word}\"{prefix}"))
+        })
+        .collect();
+    (!terms.is_empty()).then(|| terms.join(" "))
+}
+
+/// Full-text search over run history (run inputs/outputs, board entries and
+/// ring contributions, via the `history_fts` index), optionally filtered by
+/// agent name. Returns one hit per run, best match first.
+pub fn search_history(
+    db: &Database,
+    query: &str,
+    agent: Option<&str>,
+    limit: u32,
+) -> anyhow::Result<Vec<HistoryHit>> {
+    let Some(expr) = fts_match_expr(query) else {
+        return Ok(Vec::new());
+    };
+    let conn = db
+        .lock()
+        .map_err(|e| anyhow::anyhow!("Database lock poisoned: {}", e))?;
+    // One FTS row per indexed text, so a run can match several times
+    // (input + a few board entries...). Rows come back best-rank first;
+    // keep the first hit per run until `limit` distinct runs are collected.
+    let mut stmt = conn.prepare(
+        "SELECT r.id, snippet(history_fts, 3, '[', ']', '…', 12),
+                r.agent, r.input, r.output, r.provider, r.model, r.tokens_in, r.tokens_out,
+                r.cost, r.duration_ms, r.status, r.project
+         FROM history_fts JOIN runs r ON r.id = history_fts.run_id
+         WHERE history_fts MATCH ?1 AND (?2 IS NULL OR r.agent = ?2)
+         ORDER BY rank, r.created_at DESC",
+    )?;
+    let rows = stmt.query_map(params![expr, agent], |row| {
+        Ok(HistoryHit {
+            run_id: row.get(0)?,
+            snippet: row.get(1)?,
+            run: RunRecord {
+                agent: row.get(2)?,
+                input: row.get(3)?,
+                output: row.get(4)?,
+                provider: row.get(5)?,
+                model: row.get(6)?,
+                tokens_in: row.get(7)?,
+                tokens_out: row.get(8)?,
+                cost: row.get(9)?,
+                duration_ms: row.get(10)?,
+                status: row.get(11)?,
+                project: row.get(12)?,
+            },
+        })
+    })?;
+    let mut hits: Vec<HistoryHit> = Vec::new();
+    for row in rows {
+        let hit = row?;
+        if hits.iter().any(|h| h.run_id == hit.run_id) {
+            continue;
+        }
+        hits.push(hit);
+        if hits.len() >= limit as usize {
+            break;
+        }
+    }
+    Ok(hits)
+}
+
+/// Get cost summary grouped by agent. Includes the `cost_rollups` aggregates
+/// left behind by `maintenance::prune_older_than(.., keep_costs = true)`, so
+/// totals don't drop when old runs are pruned.
 pub fn get_costs_summary(
     db: &Database,
     agent_filter: Option<&str>,
@@ -123,49 +215,30 @@ pub fn get_costs_summary(
     let conn = db
         .lock()
         .map_err(|e| anyhow::anyhow!("Database lock poisoned: {}", e))?;
+    let mut stmt = conn.prepare(
+        "SELECT agent, SUM(n) AS total_runs, SUM(cost) AS total_cost,
+                SUM(tokens_in) AS total_tokens_in, SUM(tokens_out) AS total_tokens_out
+         FROM (
+             SELECT agent, 1 AS n, cost, tokens_in, tokens_out FROM runs
+             UNION ALL
+             SELECT agent, runs AS n, cost, tokens_in, tokens_out FROM cost_rollups
+         )
+         WHERE ?1 IS NULL OR agent = ?1
+         GROUP BY agent ORDER BY total_cost DESC",
+    )?;
+    let rows = stmt.query_map(params![agent_filter], |row| {
+        Ok(CostSummary {
+            agent: row.get(0)?,
+            total_runs: row.get(1)?,
+            total_cost: row.get(2)?,
+            total_tokens_in: row.get(3)?,
+            total_tokens_out: row.get(4)?,
+        })
+    })?;
     let mut summaries = Vec::new();
-
-    match agent_filter {
-        Some(name) => {
-            let mut stmt = conn.prepare(
-                "SELECT agent, COUNT(*) AS total_runs, SUM(cost) AS total_cost,
-                        SUM(tokens_in) AS total_tokens_in, SUM(tokens_out) AS total_tokens_out
-                 FROM runs WHERE agent = ?1 GROUP BY agent",
-            )?;
-            let rows = stmt.query_map(params![name], |row| {
-                Ok(CostSummary {
-                    agent: row.get(0)?,
-                    total_runs: row.get(1)?,
-                    total_cost: row.get(2)?,
-                    total_tokens_in: row.get(3)?,
-                    total_tokens_out: row.get(4)?,
-                })
-            })?;
-            for row in rows {
-                summaries.push(row?);
-            }
-        }
-        None => {
-            let mut stmt = conn.prepare(
-                "SELECT agent, COUNT(*) AS total_runs, SUM(cost) AS total_cost,
-                        SUM(tokens_in) AS total_tokens_in, SUM(tokens_out) AS total_tokens_out
-                 FROM runs GROUP BY agent ORDER BY total_cost DESC",
-            )?;
-            let rows = stmt.query_map([], |row| {
-                Ok(CostSummary {
-                    agent: row.get(0)?,
-                    total_runs: row.get(1)?,
-                    total_cost: row.get(2)?,
-                    total_tokens_in: row.get(3)?,
-                    total_tokens_out: row.get(4)?,
-                })
-            })?;
-            for row in rows {
-                summaries.push(row?);
-            }
-        }
+    for row in rows {
+        summaries.push(row?);
     }
-
     Ok(summaries)
 }
 
@@ -689,6 +762,243 @@ pub fn all_event_log_run_ids(db: &Database) -> anyhow::Result<Vec<String>> {
     Ok(run_ids)
 }
 
+/// One raw `execution_events` row, as carried by a run bundle
+/// (`armadai runs export|import`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EventRow {
+    pub seq: i64,
+    pub ts: String,
+    pub kind: String,
+    pub payload_json: String,
+}
+
+/// Get the raw event log of `run_id`, in `seq` order.
+pub fn get_event_rows(db: &Database, run_id: &str) -> anyhow::Result<Vec<EventRow>> {
+    let conn = db
+        .lock()
+        .map_err(|e| anyhow::anyhow!("Database lock poisoned: {}", e))?;
+    let mut stmt = conn.prepare(
+        "SELECT seq, ts, kind, payload_json FROM execution_events
+         WHERE run_id = ?1 ORDER BY seq ASC",
+    )?;
+    let rows = stmt.query_map(params![run_id], |row| {
+        Ok(EventRow {
+            seq: row.get(0)?,
+            ts: row.get(1)?,
+            kind: row.get(2)?,
+            payload_json: row.get(3)?,
+        })
+    })?;
+    let mut events = Vec::new();
+    for row in rows {
+        events.push(row?);
+    }
+    Ok(events)
+}
+
+/// Insert a whole event log for `run_id`, keeping each row's `seq` and `ts`.
+/// Refuses to touch a run that already has events (the log is append-only;
+/// merging two logs under one id would corrupt both). All-or-nothing.
+pub fn insert_event_rows(db: &Database, run_id: &str, events: &[EventRow]) -> anyhow::Result<()> {
+    let mut conn = db
+        .lock()
+        .map_err(|e| anyhow::anyhow!("Database lock poisoned: {}", e))?;
+    // A savepoint rather than a transaction, so the insert nests inside
+    // `in_transaction` (a plain `BEGIN` fails inside another one).
+    let tx = conn.savepoint()?;
+    let existing: i64 = tx.query_row(
+        "SELECT COUNT(*) FROM execution_events WHERE run_id = ?1",
+        params![run_id],
+        |r| r.get(0),
+    )?;
+    if existing > 0 {
+        anyhow::bail!("run '{run_id}' already exists in the event log");
+    }
+    for e in events {
+        tx.execute(
+            "INSERT INTO execution_events (run_id, seq, ts, kind, payload_json)
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![run_id, e.seq, e.ts, e.kind, e.payload_json],
+        )?;
+    }
+    tx.commit()?;
+    Ok(())
+}
+
+/// Run `f` inside one SQLite transaction: committed if it returns `Ok`,
+/// rolled back otherwise. `f` calls the regular query functions, which lock
+/// `db` per statement, so the lock is NOT held across `f` — anything else
+/// writing through the same handle meanwhile joins the transaction. Meant
+/// for short, single-threaded sequences like `armadai runs import`. Query
+/// functions that open their own `conn.transaction()` cannot be used in `f`.
+pub fn in_transaction<T>(
+    db: &Database,
+    f: impl FnOnce() -> anyhow::Result<T>,
+) -> anyhow::Result<T> {
+    let exec = |sql: &str| -> anyhow::Result<()> {
+        db.lock()
+            .map_err(|e| anyhow::anyhow!("Database lock poisoned: {}", e))?
+            .execute_batch(sql)?;
+        Ok(())
+    };
+    exec("BEGIN IMMEDIATE;")?;
+    match f() {
+        Ok(value) => {
+            exec("COMMIT;")?;
+            Ok(value)
+        }
+        Err(e) => {
+            // Best-effort: the original error is the one worth reporting.
+            let _ = exec("ROLLBACK;");
+            Err(e)
+        }
+    }
+}
+
+/// One `armadai eval` invocation of a suite.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EvalRunRecord {
+    pub id: String,
+    pub suite: String,
+    pub passed: i64,
+    pub total: i64,
+    /// Mean case score, `0.0..=1.0`.
+    pub score: f64,
+    pub cost: f64,
+}
+
+/// The scored result of one suite case within an eval.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EvalCaseRecord {
+    pub case_name: String,
+    pub passed: bool,
+    pub score: f64,
+    pub cost: f64,
+    pub tokens_in: i64,
+    pub tokens_out: i64,
+    pub duration_ms: i64,
+    /// The `armadai run` this case executed, when it got that far.
+    pub run_id: Option<String>,
+    /// One message per failed assertion.
+    pub failures: Vec<String>,
+}
+
+/// Insert an eval and its case results in one transaction.
+pub fn insert_eval_run(
+    db: &Database,
+    run: &EvalRunRecord,
+    cases: &[EvalCaseRecord],
+) -> anyhow::Result<()> {
+    let mut conn = db
+        .lock()
+        .map_err(|e| anyhow::anyhow!("Database lock poisoned: {}", e))?;
+    let tx = conn.transaction()?;
+    tx.execute(
+        "INSERT INTO eval_runs (id, suite, passed, total, score, cost)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+        params![
+            run.id, run.suite, run.passed, run.total, run.score, run.cost
+        ],
+    )?;
+    for c in cases {
+        tx.execute(
+            "INSERT INTO eval_results (eval_id, case_name, passed, score, cost, tokens_in,
+                                       tokens_out, duration_ms, run_id, failures_json)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+            params![
+                run.id,
+                c.case_name,
+                c.passed,
+                c.score,
+                c.cost,
+                c.tokens_in,
+                c.tokens_out,
+                c.duration_ms,
+                c.run_id,
+                serde_json::to_string(&c.failures)?,
+            ],
+        )?;
+    }
+    tx.commit()?;
+    Ok(())
+}
+
+fn eval_run_from_row(row: &rusqlite::Row) -> rusqlite::Result<EvalRunRecord> {
+    Ok(EvalRunRecord {
+        id: row.get(0)?,
+        suite: row.get(1)?,
+        passed: row.get(2)?,
+        total: row.get(3)?,
+        score: row.get(4)?,
+        cost: row.get(5)?,
+    })
+}
+
+/// Get an eval by id.
+pub fn get_eval_run(db: &Database, id: &str) -> anyhow::Result<Option<EvalRunRecord>> {
+    let conn = db
+        .lock()
+        .map_err(|e| anyhow::anyhow!("Database lock poisoned: {}", e))?;
+    let mut stmt =
+        conn.prepare("SELECT id, suite, passed, total, score, cost FROM eval_runs WHERE id = ?1")?;
+    let mut rows = stmt.query_map(params![id], eval_run_from_row)?;
+    match rows.next() {
+        Some(row) => Ok(Some(row?)),
+        None => Ok(None),
+    }
+}
+
+/// Get the most recent eval of `suite`.
+pub fn latest_eval_run(db: &Database, suite: &str) -> anyhow::Result<Option<EvalRunRecord>> {
+    let conn = db
+        .lock()
+        .map_err(|e| anyhow::anyhow!("Database lock poisoned: {}", e))?;
+    let mut stmt = conn.prepare(
+        "SELECT id, suite, passed, total, score, cost FROM eval_runs
+         WHERE suite = ?1 ORDER BY created_at DESC, rowid DESC LIMIT 1",
+    )?;
+    let mut rows = stmt.query_map(params![suite], eval_run_from_row)?;
+    match rows.next() {
+        Some(row) => Ok(Some(row?)),
+        None => Ok(None),
+    }
+}
+
+/// Get the case results of an eval, in insertion order.
+pub fn get_eval_results(db: &Database, eval_id: &str) -> anyhow::Result<Vec<EvalCaseRecord>> {
+    let conn = db
+        .lock()
+        .map_err(|e| anyhow::anyhow!("Database lock poisoned: {}", e))?;
+    let mut stmt = conn.prepare(
+        "SELECT case_name, passed, score, cost, tokens_in, tokens_out, duration_ms, run_id,
+                failures_json
+         FROM eval_results WHERE eval_id = ?1 ORDER BY rowid",
+    )?;
+    let rows = stmt.query_map(params![eval_id], |row| {
+        Ok((
+            EvalCaseRecord {
+                case_name: row.get(0)?,
+                passed: row.get(1)?,
+                score: row.get(2)?,
+                cost: row.get(3)?,
+                tokens_in: row.get(4)?,
+                tokens_out: row.get(5)?,
+                duration_ms: row.get(6)?,
+                run_id: row.get(7)?,
+                failures: Vec::new(),
+            },
+            row.get::<_, String>(8)?,
+        ))
+    })?;
+    let mut results = Vec::new();
+    for row in rows {
+        let (mut record, failures_json) = row?;
+        record.failures = serde_json::from_str(&failures_json)?;
+        results.push(record);
+    }
+    Ok(results)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -710,6 +1020,81 @@ mod tests {
         }
     }
 
+    fn event_row(seq: i64) -> EventRow {
+        EventRow {
+            seq,
+            ts: "2026-01-02 03:04:05".to_string(),
+            kind: "warned".to_string(),
+            payload_json: format!(r#"{{"t":"warned","code":"w{seq}"}}"#),
+        }
+    }
+
+    #[test]
+    fn event_rows_round_trip_and_refuse_existing_run() {
+        let db = open_in_memory().unwrap();
+        let rows = vec![event_row(0), event_row(1)];
+        insert_event_rows(&db, "r1", &rows).unwrap();
+        assert_eq!(get_event_rows(&db, "r1").unwrap(), rows);
+
+        let err = insert_event_rows(&db, "r1", &[event_row(2)]).unwrap_err();
+        assert!(err.to_string().contains("already exists"), "{err}");
+        assert_eq!(get_event_rows(&db, "r1").unwrap().len(), 2);
+    }
+
+    #[test]
+    fn in_transaction_rolls_back_on_error_and_commits_on_ok() {
+        let db = open_in_memory().unwrap();
+        let err = in_transaction(&db, || -> anyhow::Result<()> {
+            insert_event_rows(&db, "r1", &[event_row(0)])?;
+            anyhow::bail!("projection failed")
+        })
+        .unwrap_err();
+        assert_eq!(err.to_string(), "projection failed");
+        assert!(get_event_rows(&db, "r1").unwrap().is_empty());
+
+        in_transaction(&db, || insert_event_rows(&db, "r1", &[event_row(0)])).unwrap();
+        assert_eq!(get_event_rows(&db, "r1").unwrap().len(), 1);
+    }
+
+    fn eval_case(name: &str, passed: bool) -> EvalCaseRecord {
+        EvalCaseRecord {
+            case_name: name.to_string(),
+            passed,
+            score: if passed { 1.0 } else { 0.5 },
+            cost: 0.01,
+            tokens_in: 10,
+            tokens_out: 20,
+            duration_ms: 100,
+            run_id: Some(format!("run-{name}")),
+            failures: if passed {
+                vec![]
+            } else {
+                vec!["missing 'x'".to_string()]
+            },
+        }
+    }
+
+    #[test]
+    fn eval_runs_round_trip_and_latest() {
+        let db = open_in_memory().unwrap();
+        let run = |id: &str| EvalRunRecord {
+            id: id.to_string(),
+            suite: "smoke".to_string(),
+            passed: 1,
+            total: 2,
+            score: 0.75,
+            cost: 0.02,
+        };
+        let cases = vec![eval_case("a", true), eval_case("b", false)];
+        insert_eval_run(&db, &run("e1"), &cases).unwrap();
+        insert_eval_run(&db, &run("e2"), &cases).unwrap();
+
+        assert_eq!(get_eval_run(&db, "e1").unwrap(), Some(run("e1")));
+        assert_eq!(get_eval_results(&db, "e1").unwrap(), cases);
+        assert_eq!(latest_eval_run(&db, "smoke").unwrap().unwrap().id, "e2");
+        assert!(latest_eval_run(&db, "other").unwrap().is_none());
+    }
+
     #[test]
     fn test_insert_and_get_history() {
         let db = open_in_memory().unwrap();
@@ -740,6 +1125,90 @@ mod tests {
         assert_eq!(b.project, None);
     }
 
+    #[test]
+    fn test_fts_match_expr_quotes_words_and_keeps_prefix() {
+        assert_eq!(
+            fts_match_expr("sql-injection db.rs").as_deref(),
+            Some("\"sql-injection\" \"db.rs\"")
+        );
+        assert_eq!(fts_match_expr("injec*").as_deref(), Some("\"injec\"*"));
+        assert_eq!(
+            fts_match_expr("say \"hi\"").as_deref(),
+            Some("\"say\" \"hi\"")
+        );
+        assert_eq!(fts_match_expr("   "), None);
+        assert_eq!(fts_match_expr("\"\" *"), None);
+    }
+
+    #[test]
+    fn test_search_history_matches_input_output_and_board_entries() {
+        let db = open_in_memory().unwrap();
+        let mut reviewed = sample_run("reviewer", 0.01);
+        reviewed.input = "review the login handler".to_string();
+        insert_run_with_id(&db, "run-login", reviewed).unwrap();
+        insert_run_with_id(&db, "run-other", sample_run("writer", 0.02)).unwrap();
+        insert_orchestration_run(
+            &db,
+            OrchestrationRunRecord {
+                run_id: "run-other".to_string(),
+                pattern: "blackboard".to_string(),
+                config_json: "{}".to_string(),
+                outcome_json: None,
+                rounds: 1,
+                halt_reason: None,
+                parent_run_id: None,
+            },
+        )
+        .unwrap();
+        for content in ["SQL injection in the search form", "another SQL injection"] {
+            insert_board_entry(
+                &db,
+                BoardEntryRecord {
+                    run_id: "run-other".to_string(),
+                    agent: "security".to_string(),
+                    round: 0,
+                    kind: "finding".to_string(),
+                    content: content.to_string(),
+                    refs_json: "[]".to_string(),
+                    confidence: 0.9,
+                    tokens_in: 0,
+                    tokens_out: 0,
+                },
+            )
+            .unwrap();
+        }
+
+        let login = search_history(&db, "login", None, 10).unwrap();
+        assert_eq!(login.len(), 1);
+        assert_eq!(login[0].run_id, "run-login");
+        assert!(login[0].snippet.contains("[login]"));
+
+        // Two matching board entries still yield a single hit for the run.
+        let sqli = search_history(&db, "sql injection", None, 10).unwrap();
+        assert_eq!(sqli.len(), 1);
+        assert_eq!(sqli[0].run_id, "run-other");
+        assert_eq!(sqli[0].run.agent, "writer");
+
+        assert!(
+            search_history(&db, "injection", Some("reviewer"), 10)
+                .unwrap()
+                .is_empty()
+        );
+        assert!(search_history(&db, "", None, 10).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_search_history_forgets_deleted_projection() {
+        let db = open_in_memory().unwrap();
+        let mut run = sample_run("reviewer", 0.01);
+        run.output = "flagged a race condition".to_string();
+        insert_run_with_id(&db, "run-1", run).unwrap();
+        assert_eq!(search_history(&db, "race", None, 10).unwrap().len(), 1);
+
+        delete_projection_for_run(&db, "run-1").unwrap();
+        assert!(search_history(&db, "race", None, 10).unwrap().is_empty());
+    }
+
     #[test]
     fn test_costs_summary() {
         let db = open_in_memory().unwrap();
@@ -2,7 +2,7 @@ use rusqlite::Connection;
 
 /// Current schema version. Bumped whenever a migration is added.
 #[allow(dead_code)] // not yet consumed outside tests; will back future migration tooling (Lot 2+)
-pub const SCHEMA_VERSION: i64 = 3;
+pub const SCHEMA_VERSION: i64 = 8;
 
 /// Apply the database schema: create base tables (target schema) then run migrations.
 pub fn apply(conn: &Connection) -> anyhow::Result<()> {
@@ -114,6 +114,43 @@ pub fn apply(conn: &Connection) -> anyhow::Result<()> {
         );
 
         CREATE INDEX IF NOT EXISTS idx_execution_events_run ON execution_events(run_id, seq);
+
+        CREATE TABLE IF NOT EXISTS cost_rollups (
+            day        TEXT NOT NULL,
+            agent      TEXT NOT NULL,
+            project    TEXT NOT NULL DEFAULT '',
+            runs       INTEGER NOT NULL DEFAULT 0,
+            cost       REAL NOT NULL DEFAULT 0.0,
+            tokens_in  INTEGER NOT NULL DEFAULT 0,
+            tokens_out INTEGER NOT NULL DEFAULT 0,
+            PRIMARY KEY (day, agent, project)
+        );
+
+        CREATE TABLE IF NOT EXISTS eval_runs (
+            id          TEXT PRIMARY KEY,
+            suite       TEXT NOT NULL,
+            passed      INTEGER NOT NULL,
+            total       INTEGER NOT NULL,
+            score       REAL NOT NULL,
+            cost        REAL NOT NULL DEFAULT 0.0,
+            created_at  TEXT NOT NULL DEFAULT (datetime('now'))
+        );
+
+        CREATE INDEX IF NOT EXISTS idx_eval_runs_suite ON eval_runs(suite);
+
+        CREATE TABLE IF NOT EXISTS eval_results (
+            eval_id       TEXT NOT NULL REFERENCES eval_runs(id),
+            case_name     TEXT NOT NULL,
+            passed        INTEGER NOT NULL,
+            score         REAL NOT NULL,
+            cost          REAL NOT NULL DEFAULT 0.0,
+            tokens_in     INTEGER NOT NULL DEFAULT 0,
+            tokens_out    INTEGER NOT NULL DEFAULT 0,
+            duration_ms   INTEGER NOT NULL DEFAULT 0,
+            run_id        TEXT,
+            failures_json TEXT NOT NULL DEFAULT '[]',
+            PRIMARY KEY (eval_id, case_name)
+        );
         ",
     )?;
 
@@ -148,6 +185,26 @@ fn migrate(conn: &Connection) -> anyhow::Result<()> {
         migrate_to_v3(conn)?;
         conn.execute_batch("PRAGMA user_version = 3;")?;
     }
+    if version < 4 {
+        migrate_to_v4(conn)?;
+        conn.execute_batch("PRAGMA user_version = 4;")?;
+    }
+    if version < 5 {
+        migrate_to_v5(conn)?;
+        conn.execute_batch("PRAGMA user_version = 5;")?;
+    }
+    if version < 6 {
+        migrate_to_v6(conn)?;
+        conn.execute_batch("PRAGMA user_version = 6;")?;
+    }
+    if version < 7 {
+        migrate_to_v7(conn)?;
+        conn.execute_batch("PRAGMA user_version = 7;")?;
+    }
+    if version < 8 {
+        migrate_to_v8(conn)?;
+        conn.execute_batch("PRAGMA user_version = 8;")?;
+    }
     Ok(())
 }
 
@@ -238,6 +295,222 @@ fn migrate_to_v3(conn: &Connection) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// v3 → v4: add `history_fts`, an FTS5 index over run inputs/outputs, board
+/// entries and ring contributions (backs `armadai history --search`).
+///
+/// Unlike the other tables, `history_fts` is deliberately NOT part of
+/// `apply`'s base batch: it is a derived index, so this migration both
+/// creates it and backfills it from the rows already present. Afterwards the
+/// `*_fts_ai`/`*_fts_ad` triggers keep it in sync, including when the
+/// projector (`queries::delete_projection_for_run`) wipes and re-derives a
+/// run. `ref_id` is the source row's id for `board`/`ring` rows (NULL for
+/// `run` rows, whose key is `run_id` itself), so a delete trigger removes
+/// exactly the row it indexed.
+fn migrate_to_v4(conn: &Connection) -> anyhow::Result<()> {
+    conn.execute_batch(
+        "
+        CREATE VIRTUAL TABLE IF NOT EXISTS history_fts USING fts5(
+            run_id UNINDEXED,
+            source UNINDEXED,
+            ref_id UNINDEXED,
+            agent,
+            content,
+            tokenize = 'unicode61 remove_diacritics 2'
+        );
+
+        CREATE TRIGGER IF NOT EXISTS runs_fts_ai AFTER INSERT ON runs BEGIN
+            INSERT INTO history_fts (run_id, source, ref_id, agent, content)
+            VALUES (new.id, 'run', NULL, new.agent, new.input || char(10) || new.output);
+        END;
+        CREATE TRIGGER IF NOT EXISTS runs_fts_ad AFTER DELETE ON runs BEGIN
+            DELETE FROM history_fts WHERE source = 'run' AND run_id = old.id;
+        END;
+
+        CREATE TRIGGER IF NOT EXISTS board_entries_fts_ai AFTER INSERT ON board_entries BEGIN
+            INSERT INTO history_fts (run_id, source, ref_id, agent, content)
+            VALUES (new.run_id, 'board', new.id, new.agent, new.content);
+        END;
+        CREATE TRIGGER IF NOT EXISTS board_entries_fts_ad AFTER DELETE ON board_entries BEGIN
+            DELETE FROM history_fts WHERE source = 'board' AND ref_id = old.id;
+        END;
+
+        CREATE TRIGGER IF NOT EXISTS ring_contributions_fts_ai AFTER INSERT ON ring_contributions BEGIN
+            INSERT INTO history_fts (run_id, source, ref_id, agent, content)
+            VALUES (new.run_id, 'ring', new.id, new.agent, new.content);
+        END;
+        CREATE TRIGGER IF NOT EXISTS ring_contributions_fts_ad AFTER DELETE ON ring_contributions BEGIN
+            DELETE FROM history_fts WHERE source = 'ring' AND ref_id = old.id;
+        END;
+
+        DELETE FROM history_fts;
+        INSERT INTO history_fts (run_id, source, ref_id, agent, content)
+            SELECT id, 'run', NULL, agent, input || char(10) || output FROM runs;
+        INSERT INTO history_fts (run_id, source, ref_id, agent, content)
+            SELECT run_id, 'board', id, agent, content FROM board_entries;
+        INSERT INTO history_fts (run_id, source, ref_id, agent, content)
+            SELECT run_id, 'ring', id, agent, content FROM ring_contributions;
+        ",
+    )?;
+    Ok(())
+}
+
+/// v4 → v5: add `cost_rollups`, per-day/agent/project cost aggregates that
+/// `armadai db prune --keep-costs` (and `storage.retention`) fold pruned
+/// `runs` rows into, so `armadai costs` totals survive a prune. `project` is
+/// `''` rather than NULL for runs without a project, so the composite primary
+/// key can be upserted. Idempotent, like `migrate_to_v3` (a fresh database
+/// already has the table from `apply`'s base batch).
+fn migrate_to_v5(conn: &Connection) -> anyhow::Result<()> {
+    conn.execute_batch(
+        "
+        CREATE TABLE IF NOT EXISTS cost_rollups (
+            day        TEXT NOT NULL,
+            agent      TEXT NOT NULL,
+            project    TEXT NOT NULL DEFAULT '',
+            runs       INTEGER NOT NULL DEFAULT 0,
+            cost       REAL NOT NULL DEFAULT 0.0,
+            tokens_in  INTEGER NOT NULL DEFAULT 0,
+            tokens_out INTEGER NOT NULL DEFAULT 0,
+            PRIMARY KEY (day, agent, project)
+        );
+        ",
+    )?;
+    Ok(())
+}
+
+/// v5 → v6: add `eval_runs` (one row per `armadai eval` invocation) and
+/// `eval_results` (one row per suite case), so an eval can be compared to
+/// the previous one of the same suite. Idempotent, like `migrate_to_v5`.
+fn migrate_to_v6(conn: &Connection) -> anyhow::Result<()> {
+    conn.execute_batch(
+        "
+
+        CREATE TABLE IF NOT EXISTS eval_runs (
+            id          TEXT PRIMARY KEY,
+            suite       TEXT NOT NULL,
+            passed      INTEGER NOT NULL,
+            total       INTEGER NOT NULL,
+            score       REAL NOT NULL,
+            cost        REAL NOT NULL DEFAULT 0.0,
+            created_at  TEXT NOT NULL DEFAULT (datetime('now'))
+        );
+
+        CREATE INDEX IF NOT EXISTS idx_eval_runs_suite ON eval_runs(suite);
+
+        CREATE TABLE IF NOT EXISTS eval_results (
+            eval_id       TEXT NOT NULL REFERENCES eval_runs(id),
+            case_name     TEXT NOT NULL,
+            passed        INTEGER NOT NULL,
+            score         REAL NOT NULL,
+            cost          REAL NOT NULL DEFAULT 0.0,
+            tokens_in     INTEGER NOT NULL DEFAULT 0,
+            tokens_out    INTEGER NOT NULL DEFAULT 0,
+            duration_ms   INTEGER NOT NULL DEFAULT 0,
+            run_id        TEXT,
+            failures_json TEXT NOT NULL DEFAULT '[]',
+            PRIMARY KEY (eval_id, case_name)
+        );
+        ",
+    )?;
+    Ok(())
+}
+
+/// v6 → v7: re-key `history_fts` for `armadai db prune`. The v4 layout
+/// located a row to delete through its UNINDEXED `ref_id`/`run_id` columns,
+/// i.e. a scan of the whole index per deleted source row; pruning thousands
+/// of runs made that quadratic. The index is dropped and rebuilt without
+/// `ref_id`: each FTS row's rowid now encodes its source row as
+/// `rowid * 4 + tag` (tag 0 = `runs`, 1 = `board_entries`, 2 =
+/// `ring_contributions`), so the delete triggers are rowid lookups. The
+/// rebuild backfills from the source tables, so it is safe on any v6
+/// database regardless of the index's current contents.
+fn migrate_to_v7(conn: &Connection) -> anyhow::Result<()> {
+    conn.execute_batch(
+        "
+        DROP TRIGGER IF EXISTS runs_fts_ai;
+        DROP TRIGGER IF EXISTS runs_fts_ad;
+        DROP TRIGGER IF EXISTS board_entries_fts_ai;
+        DROP TRIGGER IF EXISTS board_entries_fts_ad;
+        DROP TRIGGER IF EXISTS ring_contributions_fts_ai;
+        DROP TRIGGER IF EXISTS ring_contributions_fts_ad;
+        DROP TABLE IF EXISTS history_fts;
+
+        CREATE VIRTUAL TABLE IF NOT EXISTS history_fts USING fts5(
+            run_id UNINDEXED,
+            source UNINDEXED,
+            agent,
+            content,
+            tokenize = 'unicode61 remove_diacritics 2'
+        );
+
+        CREATE TRIGGER IF NOT EXISTS runs_fts_ai AFTER INSERT ON runs BEGIN
+            INSERT INTO history_fts (rowid, run_id, source, agent, content)
+            VALUES (new.rowid * 4, new.id, 'run', new.agent, new.input || char(10) || new.output);
+        END;
+        CREATE TRIGGER IF NOT EXISTS runs_fts_ad AFTER DELETE ON runs BEGIN
+            DELETE FROM history_fts WHERE rowid = old.rowid * 4;
+        END;
+
+        CREATE TRIGGER IF NOT EXISTS board_entries_fts_ai AFTER INSERT ON board_entries BEGIN
+            INSERT INTO history_fts (rowid, run_id, source, agent, content)
+            VALUES (new.id * 4 + 1, new.run_id, 'board', new.agent, new.content);
+        END;
+        CREATE TRIGGER IF NOT EXISTS board_entries_fts_ad AFTER DELETE ON board_entries BEGIN
+            DELETE FROM history_fts WHERE rowid = old.id * 4 + 1;
+        END;
+
+        CREATE TRIGGER IF NOT EXISTS ring_contributions_fts_ai AFTER INSERT ON ring_contributions BEGIN
+            INSERT INTO history_fts (rowid, run_id, source, agent, content)
+            VALUES (new.id * 4 + 2, new.run_id, 'ring', new.agent, new.content);
+        END;
+        CREATE TRIGGER IF NOT EXISTS ring_contributions_fts_ad AFTER DELETE ON ring_contributions BEGIN
+            DELETE FROM history_fts WHERE rowid = old.id * 4 + 2;
+        END;
+
+        INSERT INTO history_fts (rowid, run_id, source, agent, content)
+            SELECT rowid * 4, id, 'run', agent, input || char(10) || output FROM runs;
+        INSERT INTO history_fts (rowid, run_id, source, agent, content)
+            SELECT id * 4 + 1, run_id, 'board', agent, content FROM board_entries;
+        INSERT INTO history_fts (rowid, run_id, source, agent, content)
+            SELECT id * 4 + 2, run_id, 'ring', agent, content FROM ring_contributions;
+        ",
+    )?;
+    Ok(())
+}
+
+/// v7 → v8: key `runs`' FTS rows on a stored column. v7 used the implicit
+/// rowid of `runs`, whose primary key is TEXT, and `VACUUM` (run by
+/// `armadai db vacuum`) may renumber implicit rowids, leaving FTS hits on
+/// the wrong runs. `fts_key` is assigned once, on insert, and kept as is;
+/// existing runs take their current rowid, and their FTS rows are rebuilt.
+fn migrate_to_v8(conn: &Connection) -> anyhow::Result<()> {
+    conn.execute_batch(
+        "
+        ALTER TABLE runs ADD COLUMN fts_key INTEGER;
+        UPDATE runs SET fts_key = rowid;
+        CREATE UNIQUE INDEX IF NOT EXISTS idx_runs_fts_key ON runs(fts_key);
+
+        DROP TRIGGER IF EXISTS runs_fts_ai;
+        DROP TRIGGER IF EXISTS runs_fts_ad;
+        CREATE TRIGGER runs_fts_ai AFTER INSERT ON runs BEGIN
+            UPDATE runs SET fts_key = (SELECT COALESCE(MAX(fts_key), 0) + 1 FROM runs)
+                WHERE id = new.id;
+            INSERT INTO history_fts (rowid, run_id, source, agent, content)
+                SELECT fts_key * 4, id, 'run', agent, input || char(10) || output
+                FROM runs WHERE id = new.id;
+        END;
+        CREATE TRIGGER runs_fts_ad AFTER DELETE ON runs BEGIN
+            DELETE FROM history_fts WHERE rowid = old.fts_key * 4;
+        END;
+
+        DELETE FROM history_fts WHERE source = 'run';
+        INSERT INTO history_fts (rowid, run_id, source, agent, content)
+            SELECT fts_key * 4, id, 'run', agent, input || char(10) || output FROM runs;
+        ",
+    )?;
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -274,6 +547,9 @@ mod tests {
         assert!(has_column(&conn, "orchestration_runs", "parent_run_id"));
         assert!(has_column(&conn, "runs", "project"));
         assert!(has_table(&conn, "execution_events"));
+        assert!(has_table(&conn, "cost_rollups"));
+        assert!(has_table(&conn, "eval_runs"));
+        assert!(has_table(&conn, "eval_results"));
         // delegation_events table exists
         let n: i64 = conn
             .query_row(
@@ -516,4 +792,227 @@ mod tests {
         )
         .unwrap();
     }
+
+    /// A v3 database (has `execution_events`, predates `history_fts`)
+    /// migrates to v4 by creating the FTS index AND backfilling it from the
+    /// rows already present, so `armadai history --search` finds runs
+    /// recorded before the upgrade.
+    #[test]
+    fn v3_db_migrates_to_v4_backfilling_history_fts() {
+        let conn = Connection::open_in_memory().unwrap();
+        // Seed rows the v4 migration must pick up.
+        apply_up_to_v3(&conn);
+        conn.execute_batch(
+            "
+            INSERT INTO runs (id, agent, input, output, provider, model)
+                VALUES ('r1', 'reviewer', 'review db.rs', 'looks fine', 'p', 'm');
+            INSERT INTO orchestration_runs (run_id, pattern, config_json) VALUES ('r1', 'blackboard', '{}');
+            INSERT INTO board_entries (run_id, agent, round, kind, content)
+                VALUES ('r1', 'security', 1, 'finding', 'SQL injection in query builder');
+            ",
+        )
+        .unwrap();
+        assert_eq!(user_version(&conn), 3);
+        assert!(!has_table(&conn, "history_fts"));
+
+        apply(&conn).unwrap();
+
+        assert_eq!(user_version(&conn), SCHEMA_VERSION);
+        let hits: Vec<(String, String)> = conn
+            .prepare("SELECT run_id, source FROM history_fts WHERE history_fts MATCH 'injection'")
+            .unwrap()
+            .query_map([], |r| Ok((r.get(0)?, r.get(1)?)))
+            .unwrap()
+            .map(Result::unwrap)
+            .collect();
+        assert_eq!(hits, vec![("r1".to_string(), "board".to_string())]);
+    }
+
+    /// The triggers keep `history_fts` in sync on insert AND delete — the
+    /// projector deletes and re-inserts a run's rows on every rebuild, which
+    /// must not leave stale or duplicated index rows behind.
+    #[test]
+    fn history_fts_triggers_follow_inserts_and_deletes() {
+        let conn = Connection::open_in_memory().unwrap();
+        apply(&conn).unwrap();
+        conn.execute_batch(
+            "
+            INSERT INTO runs (id, agent, input, output, provider, model)
+                VALUES ('r1', 'ring', 'pick a language', 'use rust', 'p', 'm');
+            INSERT INTO orchestration_runs (run_id, pattern, config_json) VALUES ('r1', 'ring', '{}');
+            INSERT INTO ring_contributions (run_id, agent, lap, position_in_lap, action, content)
+                VALUES ('r1', 'challenger', 0, 1, 'contest', 'golang compiles faster');
+            ",
+        )
+        .unwrap();
+        let count = |q: &str| -> i64 {
+            conn.query_row(
+                "SELECT COUNT(*) FROM history_fts WHERE history_fts MATCH ?1",
+                [q],
+                |r| r.get(0),
+            )
+            .unwrap()
+        };
+        assert_eq!(count("rust"), 1);
+        assert_eq!(count("golang"), 1);
+
+        conn.execute_batch(
+            "
+            DELETE FROM ring_contributions WHERE run_id = 'r1';
+            DELETE FROM orchestration_runs WHERE run_id = 'r1';
+            DELETE FROM runs WHERE id = 'r1';
+            ",
+        )
+        .unwrap();
+        assert_eq!(count("rust"), 0);
+        assert_eq!(count("golang"), 0);
+    }
+
+    /// A v6 database still carries the v4 `history_fts` layout (with
+    /// `ref_id`); v7 rebuilds it rowid-keyed, keeps every row searchable, and
+    /// `content` becomes column 3 (what `queries::search_history` snippets).
+    #[test]
+    fn v6_db_migrates_to_v7_rekeying_history_fts() {
+        let conn = Connection::open_in_memory().unwrap();
+        apply_up_to_v3(&conn);
+        migrate_to_v4(&conn).unwrap();
+        migrate_to_v5(&conn).unwrap();
+        migrate_to_v6(&conn).unwrap();
+        conn.execute_batch("PRAGMA user_version = 6;").unwrap();
+        conn.execute_batch(
+            "
+            INSERT INTO runs (id, agent, input, output, provider, model)
+                VALUES ('r1', 'reviewer', 'review db.rs', 'looks fine', 'p', 'm');
+            INSERT INTO orchestration_runs (run_id, pattern, config_json) VALUES ('r1', 'blackboard', '{}');
+            INSERT INTO board_entries (run_id, agent, round, kind, content)
+                VALUES ('r1', 'security', 1, 'finding', 'SQL injection in query builder');
+            ",
+        )
+        .unwrap();
+        assert!(has_column(&conn, "history_fts", "ref_id"));
+
+        apply(&conn).unwrap();
+
+        assert_eq!(user_version(&conn), SCHEMA_VERSION);
+        assert!(!has_column(&conn, "history_fts", "ref_id"));
+        let snippet: String = conn
+            .query_row(
+                "SELECT snippet(history_fts, 3, '[', ']', '…', 12) FROM history_fts
+                 WHERE history_fts MATCH 'injection'",
+                [],
+                |r| r.get(0),
+            )
+            .unwrap();
+        assert!(snippet.contains("[injection]"), "{snippet}");
+
+        conn.execute_batch("DELETE FROM board_entries WHERE run_id = 'r1';")
+            .unwrap();
+        let left: i64 = conn
+            .query_row(
+                "SELECT COUNT(*) FROM history_fts WHERE history_fts MATCH 'injection'",
+                [],
+                |r| r.get(0),
+            )
+            .unwrap();
+        assert_eq!(left, 0);
+    }
+
+    /// Run FTS rows follow `runs.fts_key`, which `VACUUM` leaves alone, so
+    /// deletes after a vacuum still remove the right run's row.
+    #[test]
+    fn run_fts_rows_survive_a_vacuum() {
+        let conn = Connection::open_in_memory().unwrap();
+        apply(&conn).unwrap();
+        let insert = |id: &str, input: &str| {
+            conn.execute(
+                "INSERT INTO runs (id, agent, input, output, provider, model)
+                 VALUES (?1, 'a', ?2, '', 'p', 'm')",
+                [id, input],
+            )
+            .unwrap();
+        };
+        insert("r1", "alpha");
+        insert("r2", "bravo");
+        insert("r3", "charlie");
+        conn.execute_batch("DELETE FROM runs WHERE id = 'r1'; VACUUM;")
+            .unwrap();
+        insert("r4", "delta");
+        conn.execute_batch("DELETE FROM runs WHERE id = 'r2';")
+            .unwrap();
+        let hit = |term: &str| -> Vec<String> {
+            let mut stmt = conn
+                .prepare("SELECT run_id FROM history_fts WHERE history_fts MATCH ?1")
+                .unwrap();
+            stmt.query_map([term], |r| r.get(0))
+                .unwrap()
+                .map(Result::unwrap)
+                .collect()
+        };
+        assert!(hit("bravo").is_empty());
+        assert_eq!(hit("charlie"), ["r3"]);
+        assert_eq!(hit("delta"), ["r4"]);
+        let keys: Vec<i64> = conn
+            .prepare("SELECT fts_key FROM runs ORDER BY id")
+            .unwrap()
+            .query_map([], |r| r.get(0))
+            .unwrap()
+            .map(Result::unwrap)
+            .collect();
+        assert_eq!(keys, [3, 4]);
+    }
+
+    /// A v3 database: the tables `migrate_to_v4` indexes, plus
+    /// `execution_events`, at user_version 3.
+    fn apply_up_to_v3(conn: &Connection) {
+        conn.execute_batch(
+            "
+            CREATE TABLE runs (id TEXT PRIMARY KEY, agent TEXT NOT NULL, input TEXT NOT NULL,
+                output TEXT NOT NULL, provider TEXT NOT NULL, model TEXT NOT NULL,
+                tokens_in INTEGER NOT NULL DEFAULT 0, tokens_out INTEGER NOT NULL DEFAULT 0,
+                cost REAL NOT NULL DEFAULT 0.0, duration_ms INTEGER NOT NULL DEFAULT 0,
+                status TEXT NOT NULL DEFAULT 'success', created_at TEXT NOT NULL DEFAULT (datetime('now')),
+                project TEXT);
+            CREATE TABLE orchestration_runs (
+                run_id        TEXT PRIMARY KEY REFERENCES runs(id),
+                pattern       TEXT NOT NULL CHECK (pattern IN ('direct', 'blackboard', 'ring', 'hierarchical')),
+                config_json   TEXT NOT NULL,
+                outcome_json  TEXT,
+                rounds        INTEGER NOT NULL DEFAULT 0,
+                halt_reason   TEXT,
+                parent_run_id TEXT,
+                created_at    TEXT NOT NULL DEFAULT (datetime('now')),
+                finished_at   TEXT
+            );
+            CREATE TABLE board_entries (
+                id          INTEGER PRIMARY KEY AUTOINCREMENT,
+                run_id      TEXT NOT NULL REFERENCES orchestration_runs(run_id),
+                agent       TEXT NOT NULL,
+                round       INTEGER NOT NULL,
+                kind        TEXT NOT NULL,
+                content     TEXT NOT NULL,
+                refs_json   TEXT NOT NULL DEFAULT '[]',
+                confidence  REAL NOT NULL DEFAULT 0.5,
+                tokens_in   INTEGER NOT NULL DEFAULT 0,
+                tokens_out  INTEGER NOT NULL DEFAULT 0,
+                created_at  TEXT NOT NULL DEFAULT (datetime('now'))
+            );
+            CREATE TABLE ring_contributions (
+                id              INTEGER PRIMARY KEY AUTOINCREMENT,
+                run_id          TEXT NOT NULL REFERENCES orchestration_runs(run_id),
+                agent           TEXT NOT NULL,
+                lap             INTEGER NOT NULL,
+                position_in_lap INTEGER NOT NULL,
+                action          TEXT NOT NULL,
+                content         TEXT NOT NULL,
+                reactions_json  TEXT NOT NULL DEFAULT '[]',
+                tokens_in       INTEGER NOT NULL DEFAULT 0,
+                tokens_out      INTEGER NOT NULL DEFAULT 0,
+                created_at      TEXT NOT NULL DEFAULT (datetime('now'))
+            );
+            ",
+        )
+        .unwrap();
+        migrate_to_v3(conn).unwrap();
+        conn.execute_batch("PRAGMA user_version = 3;").unwrap();
+    }
 }
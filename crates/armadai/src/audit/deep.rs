@@ -96,8 +96,9 @@ fn truncate_chars(text: &str, n: usize) -> String {
 ///
 /// Reuses the exact patterns A11 (`rules::references::secret_res`) uses to
 /// *detect* secrets, so the deep pass never sends to an external LLM CLI a
-/// secret that the static pass simultaneously flags as leaked.
-fn redact_secrets(text: &str) -> String {
+/// secret that the static pass simultaneously flags as leaked. Also used by
+/// `armadai runs export --redact` on run bundles.
+pub(crate) fn redact_secrets(text: &str) -> String {
     let mut redacted = text.to_string();
     for re in secret_res() {
         redacted = re.replace_all(&redacted, "[REDACTED]").into_owned();
@@ -255,9 +256,11 @@ pub(crate) fn parse_deep_response(text: &str) -> DeepOutcome {
                 rule,
                 severity: map_severity(&item.severity),
                 file: item.file.into(),
+                line: None,
                 related: Vec::new(),
                 message: format!("[deep] {}", item.message),
                 suggestion: item.suggestion,
+                edits: Vec::new(),
             })
         })
         .collect();
@@ -297,9 +300,11 @@ mod tests {
             rule: "A08",
             severity: Severity::Info,
             file: ".claude/agents/reviewer.md".into(),
+            line: None,
             related: vec![],
             message: "inherits all tools".into(),
             suggestion: None,
+            edits: Vec::new(),
         }];
         let json = build_payload(&config, &findings, 100);
         let v: serde_json::Value = serde_json::from_str(&json).unwrap();
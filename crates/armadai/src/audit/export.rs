@@ -0,0 +1,411 @@
+//! Machine-readable audit reports: a plain JSON document, and SARIF 2.1.0
+//! for code-scanning dashboards.
+
+use serde_json::{Value, json};
+use sha2::{Digest, Sha256};
+
+use super::report::AuditReport;
+use super::rules::{CATALOG, Finding, RuleInfo, Severity};
+
+/// Output format of `armadai audit --format`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum ReportFormat {
+    Markdown,
+    Html,
+    Json,
+    Sarif,
+}
+
+impl ReportFormat {
+    /// Format implied by a `--report` file extension; markdown otherwise.
+    pub fn from_extension(path: &std::path::Path) -> Self {
+        let ext = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        match ext.as_str() {
+            "html" | "htm" => Self::Html,
+            "json" => Self::Json,
+            "sarif" => Self::Sarif,
+            _ => Self::Markdown,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Markdown => "Markdown",
+            Self::Html => "HTML",
+            Self::Json => "JSON",
+            Self::Sarif => "SARIF",
+        }
+    }
+}
+
+const JSON_VERSION: u32 = 1;
+
+fn severity_name(s: Severity) -> &'static str {
+    match s {
+        Severity::Critical => "critical",
+        Severity::Warning => "warning",
+        Severity::Info => "info",
+    }
+}
+
+fn sarif_level(s: Severity) -> &'static str {
+    match s {
+        Severity::Critical => "error",
+        Severity::Warning => "warning",
+        Severity::Info => "note",
+    }
+}
+
+/// Percent-encode a relative path for a SARIF URI, keeping `/`.
+fn uri_path(path: &str) -> String {
+    let mut out = String::new();
+    for b in path.replace('\\', "/").bytes() {
+        if b.is_ascii_alphanumeric() || b"-._~/".contains(&b) {
+            out.push(b as char);
+        } else {
+            out.push_str(&format!("%{b:02X}"));
+        }
+    }
+    out
+}
+
+/// Stable identity of a finding across commits: rule, file and message,
+/// but not the line, so an edit above it does not make it a new result.
+fn fingerprint(rule: &str, file: &str, message: &str) -> String {
+    let digest = Sha256::digest(format!("{rule}\0{file}\0{message}").as_bytes());
+    digest[..16].iter().map(|b| format!("{b:02x}")).collect()
+}
+
+impl AuditReport {
+    /// Built-in rules, then the project's custom ones: the SARIF rule table.
+    fn rules(&self) -> impl Iterator<Item = &RuleInfo> {
+        CATALOG.iter().chain(&self.custom_rules)
+    }
+
+    /// Paths a finding can point at: relative to the root, `None` for the
+    /// root itself (a project-wide finding).
+    fn location_path(&self, p: &std::path::Path) -> Option<String> {
+        let rel = self.rel(p);
+        (!rel.is_empty() && rel != ".").then_some(rel)
+    }
+
+    fn finding_json(&self, f: &Finding) -> Value {
+        json!({
+            "rule": f.rule,
+            "name": self.rules().find(|r| r.id == f.rule).map(|r| r.name),
+            "severity": severity_name(f.severity),
+            "file": self.rel(&f.file),
+            "line": f.line,
+            "related": f.related.iter().map(|p| self.rel(p)).collect::<Vec<_>>(),
+            "message": f.message,
+            "suggestion": f.suggestion,
+            "fingerprint": fingerprint(f.rule, &self.rel(&f.file), &f.message),
+        })
+    }
+
+    /// The whole report as one JSON document.
+    pub fn to_json(&self) -> String {
+        let doc = json!({
+            "version": JSON_VERSION,
+            "root": self.root.display().to_string(),
+            "detected": self.detected,
+            "agents": self.agent_count,
+            "skills": self.skill_count,
+            "summary": {
+                "critical": self.count(Severity::Critical),
+                "warning": self.count(Severity::Warning),
+                "info": self.count(Severity::Info),
+            },
+            "findings": self.findings.iter().map(|f| self.finding_json(f)).collect::<Vec<_>>(),
+            "deep_raw": self.deep_raw,
+            "usage": self.usage,
+        });
+        serde_json::to_string_pretty(&doc).unwrap_or_default() + "\n"
+    }
+
+    fn sarif_location(&self, path: &std::path::Path, line: Option<usize>) -> Option<Value> {
+        let uri = uri_path(&self.location_path(path)?);
+        let mut physical = json!({
+            "artifactLocation": { "uri": uri, "uriBaseId": "SRCROOT" },
+        });
+        if let Some(line) = line {
+            physical["region"] = json!({ "startLine": line });
+        }
+        Some(json!({ "physicalLocation": physical }))
+    }
+
+    fn sarif_result(&self, f: &Finding) -> Value {
+        let mut result = json!({
+            "ruleId": f.rule,
+            "level": sarif_level(f.severity),
+            "message": { "text": f.message },
+            "partialFingerprints": {
+                "armadaiFinding/v1": fingerprint(f.rule, &self.rel(&f.file), &f.message),
+            },
+        });
+        if let Some(index) = self.rules().position(|r| r.id == f.rule) {
+            result["ruleIndex"] = json!(index);
+        }
+        if let Some(location) = self.sarif_location(&f.file, f.line) {
+            result["locations"] = json!([location]);
+        }
+        let related: Vec<Value> = f
+            .related
+            .iter()
+            .filter_map(|p| self.sarif_location(p, None))
+            .enumerate()
+            .map(|(id, mut location)| {
+                location["id"] = json!(id);
+                location
+            })
+            .collect();
+        if !related.is_empty() {
+            result["relatedLocations"] = json!(related);
+        }
+        // A suggestion is advice, not an edit: SARIF `fixes` must describe
+        // real replacements, so it travels in the property bag instead.
+        if let Some(suggestion) = &f.suggestion {
+            result["properties"] = json!({ "suggestion": suggestion });
+        }
+        if let Some(fix) = self.sarif_fix(f) {
+            result["fixes"] = json!([fix]);
+        }
+        result
+    }
+
+    /// The finding's `--fix` edits as one SARIF fix, one `artifactChange`
+    /// per file. Edits only carry a line, so each is located on the file as
+    /// it is now; if any no longer applies, the fix is left out rather than
+    /// emitted partially (the same all-or-nothing rule as `--fix`).
+    fn sarif_fix(&self, f: &Finding) -> Option<Value> {
+        if f.edits.is_empty() {
+            return None;
+        }
+        let mut changes: Vec<(String, Vec<Value>)> = Vec::new();
+        for edit in &f.edits {
+            let path = self.location_path(&edit.file)?;
+            let text = std::fs::read_to_string(&edit.file).ok()?;
+            let line = text.lines().nth(edit.line.checked_sub(1)?)?;
+            let start = line.find(&edit.old)?;
+            // SARIF columns are 1-based UTF-16 code units by default.
+            let start_column = line[..start].encode_utf16().count() + 1;
+            let replacement = json!({
+                "deletedRegion": {
+                    "startLine": edit.line,
+                    "startColumn": start_column,
+                    "endColumn": start_column + edit.old.encode_utf16().count(),
+                },
+                "insertedContent": { "text": edit.new },
+            });
+            match changes.iter_mut().find(|(p, _)| *p == path) {
+                Some((_, replacements)) => replacements.push(replacement),
+                None => changes.push((path, vec![replacement])),
+            }
+        }
+        let description = f.suggestion.as_deref().unwrap_or(&f.message);
+        Some(json!({
+            "description": { "text": description },
+            "artifactChanges": changes
+                .into_iter()
+                .map(|(path, replacements)| json!({
+                    "artifactLocation": { "uri": uri_path(&path), "uriBaseId": "SRCROOT" },
+                    "replacements": replacements,
+                }))
+                .collect::<Vec<_>>(),
+        }))
+    }
+
+    /// The report as a SARIF 2.1.0 log with one run.
+    pub fn to_sarif(&self) -> String {
+        let rules: Vec<Value> = self
+            .rules()
+            .map(|r| {
+                json!({
+                    "id": r.id,
+                    "name": r.name,
+                    "shortDescription": { "text": r.summary },
+                    "defaultConfiguration": { "level": sarif_level(r.severity) },
+                    "helpUri": concat!(env!("CARGO_PKG_REPOSITORY"), "/blob/main/docs/wiki/audit.md"),
+                })
+            })
+            .collect();
+        let root = self
+            .root
+            .canonicalize()
+            .unwrap_or_else(|_| self.root.clone());
+        let mut root_uri = uri_path(&root.display().to_string());
+        if !root_uri.starts_with('/') {
+            root_uri.insert(0, '/');
+        }
+        if !root_uri.ends_with('/') {
+            root_uri.push('/');
+        }
+        let log = json!({
+            "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+            "version": "2.1.0",
+            "runs": [{
+                "tool": {
+                    "driver": {
+                        "name": "armadai",
+                        "version": env!("CARGO_PKG_VERSION"),
+                        "informationUri": env!("CARGO_PKG_REPOSITORY"),
+                        "rules": rules,
+                    },
+                },
+                "originalUriBaseIds": {
+                    "SRCROOT": { "uri": format!("file://{root_uri}") },
+                },
+                "results": self.findings.iter().map(|f| self.sarif_result(f)).collect::<Vec<_>>(),
+            }],
+        });
+        serde_json::to_string_pretty(&log).unwrap_or_default() + "\n"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn report(findings: Vec<Finding>) -> AuditReport {
+        AuditReport {
+            root: PathBuf::from("/proj"),
+            detected: vec!["claude".into()],
+            agent_count: 2,
+            skill_count: 0,
+            findings,
+            deep_raw: None,
+            usage: Vec::new(),
+            custom_rules: Vec::new(),
+        }
+    }
+
+    fn finding(rule: &'static str, file: &str, line: Option<usize>) -> Finding {
+        Finding {
+            rule,
+            severity: Severity::Critical,
+            file: PathBuf::from(file),
+            line,
+            related: vec![PathBuf::from("/proj/.claude/agents/b c.md")],
+            message: "uses deprecated model".into(),
+            suggestion: Some("replace with 'claude-opus-4-6'".into()),
+            edits: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn sarif_carries_rules_locations_and_suggestions() {
+        let r = report(vec![
+            finding("A03", "/proj/.claude/agents/a.md", Some(3)),
+            finding("U02", ".", None),
+        ]);
+        let log: Value = serde_json::from_str(&r.to_sarif()).unwrap();
+        assert_eq!(log["version"], "2.1.0");
+        let run = &log["runs"][0];
+        assert_eq!(
+            run["tool"]["driver"]["rules"].as_array().unwrap().len(),
+            CATALOG.len()
+        );
+        let first = &run["results"][0];
+        assert_eq!(first["ruleId"], "A03");
+        assert_eq!(first["level"], "error");
+        assert_eq!(
+            run["tool"]["driver"]["rules"][first["ruleIndex"].as_u64().unwrap() as usize]["id"],
+            "A03"
+        );
+        let location = &first["locations"][0]["physicalLocation"];
+        assert_eq!(location["artifactLocation"]["uri"], ".claude/agents/a.md");
+        assert_eq!(location["region"]["startLine"], 3);
+        assert_eq!(
+            first["relatedLocations"][0]["physicalLocation"]["artifactLocation"]["uri"],
+            ".claude/agents/b%20c.md"
+        );
+        assert_eq!(
+            first["properties"]["suggestion"],
+            "replace with 'claude-opus-4-6'"
+        );
+        assert!(first.get("fixes").is_none(), "a suggestion is not a fix");
+        // A project-wide finding has no location.
+        let second = &run["results"][1];
+        assert!(second.get("locations").is_none());
+    }
+
+    #[test]
+    fn sarif_fixes_replace_the_edited_span() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join(".claude/agents/a.md");
+        std::fs::create_dir_all(file.parent().unwrap()).unwrap();
+        std::fs::write(&file, "---\nname: é\nmodel: gpt-4-turbo # é\n---\n").unwrap();
+        let mut r = report(vec![Finding {
+            edits: vec![crate::audit::rules::Edit {
+                file: file.clone(),
+                line: 3,
+                old: "gpt-4-turbo".into(),
+                new: "gpt-4o".into(),
+            }],
+            ..finding("A03", file.to_str().unwrap(), Some(3))
+        }]);
+        r.root = dir.path().to_path_buf();
+        let log: Value = serde_json::from_str(&r.to_sarif()).unwrap();
+        let fix = &log["runs"][0]["results"][0]["fixes"][0];
+        assert_eq!(fix["description"]["text"], "replace with 'claude-opus-4-6'");
+        let change = &fix["artifactChanges"][0];
+        assert_eq!(change["artifactLocation"]["uri"], ".claude/agents/a.md");
+        let replacement = &change["replacements"][0];
+        assert_eq!(replacement["deletedRegion"]["startLine"], 3);
+        assert_eq!(replacement["deletedRegion"]["startColumn"], 8);
+        assert_eq!(replacement["deletedRegion"]["endColumn"], 19);
+        assert_eq!(replacement["insertedContent"]["text"], "gpt-4o");
+
+        // An edit that no longer applies drops the whole fix.
+        std::fs::write(&file, "---\nmodel: gpt-4o\n---\n").unwrap();
+        let log: Value = serde_json::from_str(&r.to_sarif()).unwrap();
+        assert!(log["runs"][0]["results"][0].get("fixes").is_none());
+    }
+
+    #[test]
+    fn sarif_lists_custom_rules_after_the_catalog() {
+        let mut r = report(vec![finding("TEAM01", "/proj/.claude/agents/a.md", None)]);
+        r.custom_rules.push(RuleInfo {
+            id: "TEAM01",
+            name: "TeamModels",
+            summary: "Agents must not use retired models.",
+            severity: Severity::Critical,
+        });
+        let log: Value = serde_json::from_str(&r.to_sarif()).unwrap();
+        let run = &log["runs"][0];
+        assert_eq!(run["results"][0]["ruleIndex"], CATALOG.len());
+        assert_eq!(
+            run["tool"]["driver"]["rules"][CATALOG.len()]["name"],
+            "TeamModels"
+        );
+    }
+
+    #[test]
+    fn json_lists_findings_with_relative_paths_and_stable_fingerprints() {
+        let r = report(vec![finding("A03", "/proj/.claude/agents/a.md", Some(3))]);
+        let doc: Value = serde_json::from_str(&r.to_json()).unwrap();
+        assert_eq!(doc["summary"]["critical"], 1);
+        let f = &doc["findings"][0];
+        assert_eq!(f["file"], ".claude/agents/a.md");
+        assert_eq!(f["name"], "DeprecatedModel");
+        assert_eq!(f["line"], 3);
+
+        let moved = report(vec![finding("A03", "/proj/.claude/agents/a.md", Some(9))]);
+        let moved: Value = serde_json::from_str(&moved.to_json()).unwrap();
+        assert_eq!(moved["findings"][0]["fingerprint"], f["fingerprint"]);
+    }
+
+    #[test]
+    fn format_follows_the_report_extension() {
+        let f = |p: &str| ReportFormat::from_extension(std::path::Path::new(p));
+        assert_eq!(f("out.SARIF"), ReportFormat::Sarif);
+        assert_eq!(f("out.json"), ReportFormat::Json);
+        assert_eq!(f("out.htm"), ReportFormat::Html);
+        assert_eq!(f("out.md"), ReportFormat::Markdown);
+    }
+}
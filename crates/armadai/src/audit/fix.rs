@@ -0,0 +1,328 @@
+//! `armadai audit --fix`: apply the mechanical edits findings carry.
+//!
+//! Edits are planned against the files as they are now, so a line changed
+//! since the audit skips its finding rather than corrupting the file. All
+//! files are written through sibling temporaries and renamed only once
+//! every temporary is on disk, after a copy of each original is saved.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+
+use super::rules::{Edit, Finding};
+
+/// Lines of context around each changed line in a diff preview.
+const CONTEXT: usize = 3;
+
+/// The planned new content of one file.
+pub struct FilePatch {
+    pub file: PathBuf,
+    before: String,
+    after: String,
+    /// 1-based lines that differ.
+    changed: BTreeSet<usize>,
+}
+
+/// What `--fix` would do.
+#[derive(Default)]
+pub struct FixPlan {
+    pub patches: Vec<FilePatch>,
+    /// Findings whose edits all apply.
+    pub fixed: Vec<Finding>,
+    /// Findings with edits that no longer apply, and why.
+    pub skipped: Vec<(Finding, String)>,
+}
+
+impl FixPlan {
+    pub fn is_empty(&self) -> bool {
+        self.patches.is_empty()
+    }
+}
+
+/// Lines of a file with their endings, so untouched lines round-trip.
+fn split_lines(text: &str) -> Vec<String> {
+    text.split_inclusive('\n').map(str::to_string).collect()
+}
+
+fn apply_edit(lines: &mut [String], edit: &Edit) -> Result<(), String> {
+    let line = edit
+        .line
+        .checked_sub(1)
+        .and_then(|i| lines.get_mut(i))
+        .ok_or_else(|| format!("line {} no longer exists", edit.line))?;
+    if !line.contains(&edit.old) {
+        return Err(format!("line {} changed since the audit", edit.line));
+    }
+    *line = line.replacen(&edit.old, &edit.new, 1);
+    Ok(())
+}
+
+/// Plan the edits of every fixable finding. Findings carrying the same
+/// edits (one file audited through several CLIs) are applied once.
+pub fn plan(findings: &[Finding]) -> FixPlan {
+    let mut files: BTreeMap<PathBuf, (String, Vec<String>, BTreeSet<usize>)> = BTreeMap::new();
+    let mut applied: Vec<&[Edit]> = Vec::new();
+    let mut out = FixPlan::default();
+    for finding in findings.iter().filter(|f| !f.edits.is_empty()) {
+        if applied.contains(&finding.edits.as_slice()) {
+            out.fixed.push(finding.clone());
+            continue;
+        }
+        // All of a finding's edits land, or none does.
+        let mut staged: BTreeMap<PathBuf, Vec<String>> = BTreeMap::new();
+        let mut failure = None;
+        for edit in &finding.edits {
+            if !staged.contains_key(&edit.file) {
+                let lines = match files.get(&edit.file) {
+                    Some((_, lines, _)) => lines.clone(),
+                    None => match std::fs::read_to_string(&edit.file) {
+                        Ok(text) => {
+                            let lines = split_lines(&text);
+                            files.insert(edit.file.clone(), (text, lines.clone(), BTreeSet::new()));
+                            lines
+                        }
+                        Err(e) => {
+                            failure = Some(format!("cannot read {}: {e}", edit.file.display()));
+                            break;
+                        }
+                    },
+                };
+                staged.insert(edit.file.clone(), lines);
+            }
+            let lines = staged.get_mut(&edit.file).expect("staged above");
+            if let Err(reason) = apply_edit(lines, edit) {
+                failure = Some(reason);
+                break;
+            }
+        }
+        if let Some(reason) = failure {
+            out.skipped.push((finding.clone(), reason));
+            continue;
+        }
+        for (file, lines) in staged {
+            let entry = files.get_mut(&file).expect("read above");
+            entry.1 = lines;
+        }
+        for edit in &finding.edits {
+            files
+                .get_mut(&edit.file)
+                .expect("read above")
+                .2
+                .insert(edit.line);
+        }
+        applied.push(&finding.edits);
+        out.fixed.push(finding.clone());
+    }
+    out.patches = files
+        .into_iter()
+        .filter(|(_, (_, _, changed))| !changed.is_empty())
+        .map(|(file, (before, lines, changed))| FilePatch {
+            file,
+            before,
+            after: lines.concat(),
+            changed,
+        })
+        .collect();
+    out
+}
+
+impl FilePatch {
+    /// Unified diff of this patch, labelled with `name`. Keys the secret
+    /// rule replaces are masked, so a preview never prints them.
+    pub fn unified_diff(&self, name: &str) -> String {
+        let before: Vec<&str> = self.before.lines().collect();
+        let after: Vec<&str> = self.after.lines().collect();
+        let mask = |l: &str| super::deep::redact_secrets(l);
+        let mut out = format!("--- a/{name}\n+++ b/{name}\n");
+        // Edits replace text within a line, so both sides keep the same
+        // line numbers and hunks only need grouping.
+        let mut hunks: Vec<(usize, usize)> = Vec::new();
+        for &line in &self.changed {
+            let start = line.saturating_sub(CONTEXT).max(1);
+            let end = (line + CONTEXT).min(before.len());
+            match hunks.last_mut() {
+                Some((_, last_end)) if start <= *last_end + 1 => *last_end = end,
+                _ => hunks.push((start, end)),
+            }
+        }
+        for (start, end) in hunks {
+            let len = end + 1 - start;
+            out.push_str(&format!("@@ -{start},{len} +{start},{len} @@\n"));
+            for n in start..=end {
+                if self.changed.contains(&n) {
+                    out.push_str(&format!("-{}\n", mask(before[n - 1])));
+                    out.push_str(&format!("+{}\n", mask(after[n - 1])));
+                } else {
+                    out.push_str(&format!(" {}\n", before[n - 1]));
+                }
+            }
+        }
+        out
+    }
+}
+
+/// Path of `file` relative to `root`, for diffs and backups.
+pub fn relative(root: &Path, file: &Path) -> PathBuf {
+    file.strip_prefix(root).unwrap_or(file).to_path_buf()
+}
+
+/// Where `apply` saves the original of `file`: its path under the root, or
+/// for a file outside the root (a user-level config), its absolute path
+/// re-rooted under `external/`. Only normal components are kept, so the
+/// backup can never land on the file itself or outside `dir`.
+fn backup_path(dir: &Path, root: &Path, file: &Path) -> PathBuf {
+    let (base, rest) = match file.strip_prefix(root) {
+        Ok(rel) => (dir.to_path_buf(), rel),
+        Err(_) => (dir.join("external"), file),
+    };
+    rest.components()
+        .filter_map(|c| match c {
+            std::path::Component::Normal(part) => Some(part),
+            _ => None,
+        })
+        .fold(base, |path, part| path.join(part))
+}
+
+/// Save the originals under `.armadai/backups/audit-<timestamp>/`, then
+/// write every patched file. Returns the backup directory.
+pub fn apply(root: &Path, plan: &FixPlan) -> anyhow::Result<PathBuf> {
+    let backups = root.join(".armadai/backups");
+    let dir = backups.join(format!(
+        "audit-{}",
+        chrono::Local::now().format("%Y%m%d-%H%M%S")
+    ));
+    std::fs::create_dir_all(&dir)?;
+    // Originals can hold the very secrets the fix removed.
+    let ignore = backups.join(".gitignore");
+    if !ignore.exists() {
+        std::fs::write(&ignore, "*\n")?;
+    }
+    for patch in &plan.patches {
+        let backup = backup_path(&dir, root, &patch.file);
+        if let Some(parent) = backup.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&backup, &patch.before)?;
+    }
+
+    let mut temps: Vec<(PathBuf, &Path)> = Vec::new();
+    for patch in &plan.patches {
+        let name = patch
+            .file
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let tmp = patch.file.with_file_name(format!(".{name}.armadai-fix"));
+        if let Err(e) = std::fs::write(&tmp, &patch.after) {
+            let _ = std::fs::remove_file(&tmp);
+            for (written, _) in &temps {
+                let _ = std::fs::remove_file(written);
+            }
+            anyhow::bail!("cannot write {}: {e}; nothing was changed", tmp.display());
+        }
+        temps.push((tmp, &patch.file));
+    }
+    for (tmp, file) in temps {
+        std::fs::rename(&tmp, file)?;
+    }
+    Ok(dir)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audit::rules::Severity;
+
+    fn finding(rule: &'static str, edits: Vec<Edit>) -> Finding {
+        Finding {
+            rule,
+            severity: Severity::Critical,
+            file: edits[0].file.clone(),
+            line: Some(edits[0].line),
+            related: Vec::new(),
+            message: String::new(),
+            suggestion: None,
+            edits,
+        }
+    }
+
+    fn edit(file: &Path, line: usize, old: &str, new: &str) -> Edit {
+        Edit {
+            file: file.to_path_buf(),
+            line,
+            old: old.into(),
+            new: new.into(),
+        }
+    }
+
+    #[test]
+    fn plans_diffs_and_applies_with_a_backup() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join(".claude/agents/a.md");
+        std::fs::create_dir_all(file.parent().unwrap()).unwrap();
+        let text = "---\nname: a\nmodel: gpt-4-turbo\n---\n\nBody\n";
+        std::fs::write(&file, text).unwrap();
+
+        let fix = finding("A03", vec![edit(&file, 3, "gpt-4-turbo", "gpt-4o")]);
+        let stale = finding("A10", vec![edit(&file, 6, "@ghost", "@host")]);
+        let plan = plan(&[fix.clone(), fix, stale]);
+        assert_eq!(plan.fixed.len(), 2, "the duplicate counts as fixed");
+        assert_eq!(plan.skipped.len(), 1);
+        assert!(plan.skipped[0].1.contains("changed since the audit"));
+
+        let diff = plan.patches[0].unified_diff("a.md");
+        assert!(diff.contains("@@ -1,6 +1,6 @@\n"), "{diff}");
+        assert!(
+            diff.contains("-model: gpt-4-turbo\n+model: gpt-4o\n"),
+            "{diff}"
+        );
+
+        let backup = apply(dir.path(), &plan).unwrap();
+        assert_eq!(
+            std::fs::read_to_string(&file).unwrap(),
+            text.replace("gpt-4-turbo", "gpt-4o")
+        );
+        let saved = backup.join(".claude/agents/a.md");
+        assert_eq!(std::fs::read_to_string(saved).unwrap(), text);
+        assert!(dir.path().join(".armadai/backups/.gitignore").exists());
+    }
+
+    #[test]
+    fn files_outside_the_root_are_backed_up_under_external() {
+        let dir = Path::new("/proj/.armadai/backups/audit-1");
+        assert_eq!(
+            backup_path(
+                dir,
+                Path::new("/proj"),
+                Path::new("/proj/.claude/agents/a.md")
+            ),
+            dir.join(".claude/agents/a.md")
+        );
+        assert_eq!(
+            backup_path(
+                dir,
+                Path::new("/proj"),
+                Path::new("/home/u/.claude/CLAUDE.md")
+            ),
+            dir.join("external/home/u/.claude/CLAUDE.md")
+        );
+        assert_eq!(
+            backup_path(dir, Path::new("/proj"), Path::new("/proj/../etc/x.md")),
+            dir.join("etc/x.md")
+        );
+    }
+
+    #[test]
+    fn diff_masks_replaced_keys() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("CLAUDE.md");
+        let key = format!("sk-ant-{}", "a".repeat(24));
+        std::fs::write(&file, format!("Use {key} here.\n")).unwrap();
+        let plan = plan(&[finding(
+            "A11",
+            vec![edit(&file, 1, &key, "${ANTHROPIC_API_KEY}")],
+        )]);
+        let diff = plan.patches[0].unified_diff("CLAUDE.md");
+        assert!(!diff.contains(&key), "{diff}");
+        assert!(diff.contains("+Use ${ANTHROPIC_API_KEY} here."), "{diff}");
+    }
+}
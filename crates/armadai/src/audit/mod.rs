@@ -1,8 +1,11 @@
 //! Audit of native agentic configurations (adoption funnel).
 //!
-//! Reads native CLI configs (Claude Code first) through `ReverseLinker`s,
-//! runs static rules over the imported assets and produces an `AuditReport`.
+//! Reads native CLI configs (Claude Code, Gemini CLI, Codex, Copilot,
+//! OpenCode, Cursor) through `ReverseLinker`s, runs static rules over the
+//! imported assets and produces an `AuditReport`.
 pub mod deep;
+pub mod export;
+pub mod fix;
 pub mod proposal;
 pub mod report;
 pub mod reverse;
@@ -14,46 +17,94 @@ use std::path::Path;
 use report::AuditReport;
 use reverse::ReverseLinker;
 
-/// Detect and parse every native surface under `root`.
-/// Shared by the audit run and `--propose` (which needs the raw imports).
+/// Every native surface we can read, in precedence order: when the same
+/// asset name appears in several surfaces, the first one wins the merge.
+fn reverse_linkers() -> Vec<Box<dyn ReverseLinker>> {
+    vec![
+        Box::new(reverse::claude::ClaudeReverseLinker),
+        Box::new(reverse::gemini::GeminiReverseLinker),
+        Box::new(reverse::codex::CodexReverseLinker),
+        Box::new(reverse::copilot::CopilotReverseLinker),
+        Box::new(reverse::opencode::OpencodeReverseLinker),
+        Box::new(reverse::cursor::CursorReverseLinker),
+    ]
+}
+
+/// The reverse linker for one native CLI (`claude`, `gemini`, ...).
+pub fn reverse_linker(name: &str) -> Option<Box<dyn ReverseLinker>> {
+    reverse_linkers().into_iter().find(|r| r.name() == name)
+}
+
+/// Detect and parse each native surface under `root`, kept apart.
+pub fn import_each(root: &Path) -> Vec<(&'static str, reverse::ImportedConfig)> {
+    reverse_linkers()
+        .iter()
+        .filter(|linker| linker.detect(root))
+        .map(|linker| (linker.name(), linker.parse(root)))
+        .collect()
+}
+
+/// Detect and parse every native surface under `root`, merged into one
+/// config. A project linked to several CLIs carries the same agents once
+/// per surface, so an agent or skill name already imported is skipped.
+/// Shared by `--propose` and `--deep` (which need the raw imports).
 pub fn import_surfaces(root: &Path) -> (Vec<String>, reverse::ImportedConfig) {
-    let linkers: Vec<Box<dyn ReverseLinker>> = vec![Box::new(reverse::claude::ClaudeReverseLinker)];
     let mut detected = Vec::new();
     let mut config = reverse::ImportedConfig::default();
-    for linker in &linkers {
-        if linker.detect(root) {
-            detected.push(linker.name().to_string());
-            let parsed = linker.parse(root);
-            config.agents.extend(parsed.agents);
-            config.skills.extend(parsed.skills);
-            if config.instructions.is_none() {
-                config.instructions = parsed.instructions;
+    for (name, parsed) in import_each(root) {
+        detected.push(name.to_string());
+        for agent in parsed.agents {
+            if !config.agents.iter().any(|a| a.name == agent.name) {
+                config.agents.push(agent);
+            }
+        }
+        for skill in parsed.skills {
+            if !config.skills.iter().any(|s| s.name == skill.name) {
+                config.skills.push(skill);
             }
         }
+        if config.instructions.is_none() {
+            config.instructions = parsed.instructions;
+        }
     }
     (detected, config)
 }
 
 /// Detect, import and analyse every native surface under `root`.
+///
+/// Rules run once per surface: each CLI routes among its own agents, so
+/// collisions and duplication only matter within one surface. Observed
+/// usage comes from Claude Code transcripts and only applies to the
+/// `claude` surface.
 pub fn run_audit(
     root: &Path,
     settings: &rules::AuditSettings,
-    usage: Option<&usage::UsageFacts>,
+    usage: &[usage::UsageFacts],
 ) -> AuditReport {
-    let (detected, config) = import_surfaces(root);
-    let ctx = rules::AuditContext {
-        config: &config,
-        settings,
-        usage,
-    };
+    let mut detected = Vec::new();
+    let mut findings = Vec::new();
+    let (mut agent_count, mut skill_count) = (0, 0);
+    for (name, config) in import_each(root) {
+        let ctx = rules::AuditContext {
+            config: &config,
+            settings,
+            usage: usage.iter().find(|u| u.cli() == name),
+        };
+        findings.extend(rules::run_rules(&ctx));
+        detected.push(name.to_string());
+        agent_count += config.agents.len();
+        skill_count += config.skills.len();
+    }
+    findings.sort_by(|a, b| (a.severity, &a.file, a.rule).cmp(&(b.severity, &b.file, b.rule)));
     AuditReport {
         root: root.to_path_buf(),
         detected,
-        agent_count: config.agents.len(),
-        skill_count: config.skills.len(),
-        findings: rules::run_rules(&ctx),
+        agent_count,
+        skill_count,
+        findings,
         deep_raw: None,
-        usage: usage.cloned(),
+        usage: usage.to_vec(),
+        custom_rules: settings.rules.iter().map(|r| r.info()).collect(),
     }
 }
 
@@ -76,6 +127,27 @@ mod tests {
         assert_eq!(config.agents.len(), 1);
     }
 
+    #[test]
+    fn surfaces_merge_by_name_but_are_audited_apart() {
+        let dir = tempfile::tempdir().unwrap();
+        for (rel, prompt) in [
+            (".claude/agents/a.md", "Claude body"),
+            (".gemini/agents/a.md", "Gemini body"),
+        ] {
+            let path = dir.path().join(rel);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, format!("---\nname: a\ndescription: d\n---\n{prompt}")).unwrap();
+        }
+        let (detected, config) = import_surfaces(dir.path());
+        assert_eq!(detected, vec!["claude".to_string(), "gemini".to_string()]);
+        assert_eq!(config.agents.len(), 1);
+        assert_eq!(config.agents[0].system_prompt, "Claude body");
+
+        let report = run_audit(dir.path(), &rules::AuditSettings::default(), &[]);
+        assert_eq!(report.agent_count, 2);
+        assert!(report.findings.iter().all(|f| f.rule != "C01"));
+    }
+
     #[test]
     fn run_audit_accepts_observed_usage() {
         let dir = tempfile::tempdir().unwrap();
@@ -89,7 +161,11 @@ mod tests {
         let mut usage = usage::UsageFacts::default();
         usage.record_delegation(usage::facts::ROOT_AGENT, "a", "claude-opus-5");
 
-        let report = run_audit(dir.path(), &rules::AuditSettings::default(), Some(&usage));
+        let report = run_audit(
+            dir.path(),
+            &rules::AuditSettings::default(),
+            std::slice::from_ref(&usage),
+        );
         assert_eq!(report.agent_count, 1);
     }
 }
@@ -25,12 +25,54 @@ pub(crate) fn portable_model(model: Option<&str>) -> String {
     if is_latest_placeholder(&resolved) {
         return resolved;
     }
-    match classify_model_tier(&resolved, "anthropic") {
+    // OpenCode and Copilot prefix models with their provider.
+    let bare = resolved.rsplit('/').next().unwrap_or(&resolved);
+    let api = match model_family(bare) {
+        "gemini" => "google",
+        "gpt" => "openai",
+        _ => "anthropic",
+    };
+    match classify_model_tier(bare, api) {
         Some(tier) => tier_placeholder(tier).to_string(),
         None => resolved,
     }
 }
 
+/// Unified provider name (`claude`, `gemini`, `gpt`) a model id belongs to.
+pub(crate) fn model_family(model: &str) -> &'static str {
+    let m = model.to_ascii_lowercase();
+    if m.contains("gemini") {
+        "gemini"
+    } else if m.starts_with("gpt")
+        || m.contains("codex")
+        || (m.starts_with('o') && m[1..].starts_with(|c: char| c.is_ascii_digit()))
+    {
+        "gpt"
+    } else {
+        "claude"
+    }
+}
+
+/// Provider for an imported agent: the family of its concrete model, else
+/// the CLI it was imported from. Copilot, OpenCode and Cursor run any
+/// model, so they fall back to `claude` like model-less Claude agents.
+pub(crate) fn native_provider(agent: &ImportedAgent) -> &'static str {
+    if let Some(model) = agent.metadata.model.as_deref()
+        && !model.trim().eq_ignore_ascii_case("inherit")
+        && !is_latest_placeholder(model)
+    {
+        return model_family(model.rsplit('/').next().unwrap_or(model));
+    }
+    let from = |dir: &str| agent.source_path.components().any(|c| c.as_os_str() == dir);
+    if from(".gemini") {
+        "gemini"
+    } else if from(".codex") {
+        "gpt"
+    } else {
+        "claude"
+    }
+}
+
 /// Collapse any whitespace (incl. newlines) to single spaces so a description
 /// is safe on one blockquote line and one `- description:` metadata line.
 fn one_line(s: &str) -> String {
@@ -62,7 +104,7 @@ fn one_line(s: &str) -> String {
 /// text instead), which closes the whole class of residual setext headings
 /// while leaving real thematic breaks (already preceded by a blank line)
 /// untouched.
-fn demote_headings(prompt: &str) -> String {
+pub(crate) fn demote_headings(prompt: &str) -> String {
     let mut out: Vec<String> = Vec::new();
     let mut in_fence = false;
 
@@ -137,13 +179,13 @@ pub(crate) fn render_agent(agent: &ImportedAgent) -> String {
         .metadata
         .description
         .as_deref()
-        .unwrap_or("Imported from native Claude Code configuration.");
+        .unwrap_or("Imported from native agent configuration.");
     // Native descriptions can be multiline (e.g. YAML `description: |` blocks);
     // collapse to one line so it stays a single blockquote / metadata line.
     let description = one_line(description);
     let _ = writeln!(md, "> {description}\n");
     let _ = writeln!(md, "## Metadata");
-    let _ = writeln!(md, "- provider: claude");
+    let _ = writeln!(md, "- provider: {}", native_provider(agent));
     let _ = writeln!(
         md,
         "- model: {}",
@@ -695,6 +737,24 @@ mod tests {
         assert_eq!(portable_model(None), "latest:pro");
         // Deprecated alias resolved first, then classified.
         assert_eq!(portable_model(Some("gemini-3.0-pro")), "latest:pro");
+        // Non-Claude families and provider-prefixed ids.
+        assert_eq!(portable_model(Some("gemini-2.5-flash")), "latest:fast");
+        assert_eq!(portable_model(Some("gpt-4o-mini")), "latest:fast");
+        assert_eq!(
+            portable_model(Some("anthropic/claude-haiku-4-5")),
+            "latest:fast"
+        );
+    }
+
+    #[test]
+    fn native_provider_follows_model_then_surface() {
+        let mut agent = crate::audit::rules::test_support::agent("a", "Body");
+        assert_eq!(native_provider(&agent), "claude");
+        agent.metadata.model = Some("gemini-2.5-pro".into());
+        assert_eq!(native_provider(&agent), "gemini");
+        agent.metadata.model = None;
+        agent.source_path = ".codex/agents/a.toml".into();
+        assert_eq!(native_provider(&agent), "gpt");
     }
 
     #[test]
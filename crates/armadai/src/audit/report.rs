@@ -1,7 +1,7 @@
 use std::fmt::Write as _;
 use std::path::PathBuf;
 
-use super::rules::{Finding, Severity};
+use super::rules::{Finding, RuleInfo, Severity};
 
 /// Assembled result of one audit run.
 pub struct AuditReport {
@@ -13,12 +13,16 @@ pub struct AuditReport {
     /// Raw, unstructured deep-analysis text (set when the LLM's deep-audit
     /// response could not be parsed as structured D0x findings).
     pub deep_raw: Option<String>,
-    /// Observed usage, when transcripts were found for this project.
-    pub usage: Option<crate::audit::usage::UsageFacts>,
+    /// Observed usage, one entry per CLI whose sessions were found for
+    /// this project.
+    pub usage: Vec<crate::audit::usage::UsageFacts>,
+    /// The project's custom rules (`audit.rules:`), listed after the
+    /// built-in catalog in machine-readable reports.
+    pub custom_rules: Vec<RuleInfo>,
 }
 
 /// Sorted, truncated view data shared by `AuditReport::usage_markdown` and
-/// `AuditReport::print_usage` — see `AuditReport::usage_view`.
+/// `AuditReport::print_usage` — see `AuditReport::usage_views`.
 struct UsageView<'a> {
     usage: &'a crate::audit::usage::UsageFacts,
     agents: Vec<(&'a String, &'a crate::audit::usage::facts::AgentUsage)>,
@@ -36,6 +40,16 @@ impl UsageView<'_> {
     fn skills_hidden(&self) -> usize {
         self.usage.skills.len().saturating_sub(self.skills.len())
     }
+
+    /// Display name of the CLI whose sessions were read.
+    fn cli(&self) -> &str {
+        match self.usage.cli() {
+            "claude" => "Claude Code",
+            "gemini" => "Gemini CLI",
+            "codex" => "Codex",
+            other => other,
+        }
+    }
 }
 
 /// `12 invocation(s), 87 turn(s)` — or just the invocations when the agent's own
@@ -58,7 +72,7 @@ impl AuditReport {
         self.count(Severity::Critical)
     }
 
-    fn count(&self, s: Severity) -> usize {
+    pub(super) fn count(&self, s: Severity) -> usize {
         self.findings.iter().filter(|f| f.severity == s).count()
     }
 
@@ -72,7 +86,7 @@ impl AuditReport {
     }
 
     /// Findings paths relative to the audited root (falls back to the raw path).
-    fn rel(&self, p: &std::path::Path) -> String {
+    pub(super) fn rel(&self, p: &std::path::Path) -> String {
         p.strip_prefix(&self.root)
             .unwrap_or(p)
             .display()
@@ -142,14 +156,20 @@ impl AuditReport {
         lines
     }
 
-    /// Sorted, top-10-truncated view of `self.usage`, shared by
-    /// `usage_markdown` and `print_usage` — `None` when nothing was observed
-    /// (no transcripts found, or `usage` is `None`). Extracted so the
-    /// sort/tie-break and truncation logic — the part most likely to drift
-    /// under a future change — exists exactly once; each renderer still does
-    /// its own, format-specific printing.
-    fn usage_view(&self) -> Option<UsageView<'_>> {
-        let usage = self.usage.as_ref().filter(|u| !u.is_empty())?;
+    /// Sorted, top-10-truncated views of `self.usage`, one per CLI where
+    /// something was observed, shared by `usage_markdown` and `print_usage`.
+    /// Extracted so the sort/tie-break and truncation logic — the part most
+    /// likely to drift under a future change — exists exactly once; each
+    /// renderer still does its own, format-specific printing.
+    fn usage_views(&self) -> Vec<UsageView<'_>> {
+        self.usage
+            .iter()
+            .filter(|u| !u.is_empty())
+            .map(Self::usage_view)
+            .collect()
+    }
+
+    fn usage_view(usage: &crate::audit::usage::UsageFacts) -> UsageView<'_> {
         let mut agents: Vec<_> = usage.agents.iter().collect();
         // Rank by observed work, not by how often an agent was asked for. An
         // agent spawned only from inside other sub-agents has zero invocations
@@ -163,20 +183,25 @@ impl AuditReport {
         let mut skills: Vec<_> = usage.skills.iter().collect();
         skills.sort_by(|a, b| b.1.cmp(a.1).then(a.0.cmp(b.0)));
         skills.truncate(10);
-        Some(UsageView {
+        UsageView {
             usage,
             agents,
             skills,
-        })
+        }
     }
 
     /// Markdown block describing what was observed. Empty when nothing was.
     fn usage_markdown(&self) -> String {
-        let Some(view) = self.usage_view() else {
-            return String::new();
-        };
         let mut out = String::new();
-        let _ = writeln!(out, "\n## Observed usage\n");
+        for view in self.usage_views() {
+            out.push_str(&Self::usage_markdown_for(&view));
+        }
+        out
+    }
+
+    fn usage_markdown_for(view: &UsageView) -> String {
+        let mut out = String::new();
+        let _ = writeln!(out, "\n## Observed usage ({})\n", view.cli());
         let _ = writeln!(out, "- Sessions scanned: {}", view.usage.sessions);
         if view.usage.observed_depth > 0 {
             let _ = writeln!(
@@ -215,13 +240,16 @@ impl AuditReport {
     /// before the findings so the report states what was measured first.
     /// Silent when nothing was observed.
     fn print_usage(&self) {
-        let Some(view) = self.usage_view() else {
-            return;
-        };
+        for view in self.usage_views() {
+            Self::print_usage_for(&view);
+        }
+    }
+
+    fn print_usage_for(view: &UsageView) {
         let h = crate::cli::style::header();
         let m = crate::cli::style::muted();
         anstream::println!();
-        anstream::println!("  {h}Observed usage{h:#}");
+        anstream::println!("  {h}Observed usage ({}){h:#}", view.cli());
         anstream::println!("  {m}Sessions scanned:{m:#} {}", view.usage.sessions);
         if view.usage.observed_depth > 0 {
             anstream::println!(
@@ -260,11 +288,20 @@ impl AuditReport {
     /// `record_skill_turn` (sanitized of control characters, but not of HTML
     /// metacharacters). Empty when nothing was observed.
     fn usage_html(&self) -> String {
-        let Some(view) = self.usage_view() else {
-            return String::new();
-        };
         let mut out = String::new();
-        out.push_str("<section class=\"usage\">\n<h2>Observed usage</h2>\n<ul>\n");
+        for view in self.usage_views() {
+            out.push_str(&Self::usage_html_for(&view));
+        }
+        out
+    }
+
+    fn usage_html_for(view: &UsageView) -> String {
+        let mut out = String::new();
+        let _ = writeln!(
+            out,
+            "<section class=\"usage\">\n<h2>Observed usage ({})</h2>\n<ul>",
+            view.cli()
+        );
         let _ = writeln!(out, "<li>Sessions scanned: {}</li>", view.usage.sessions);
         if view.usage.observed_depth > 0 {
             let _ = writeln!(
@@ -826,7 +863,8 @@ mod tests {
             skill_count: 1,
             findings,
             deep_raw: None,
-            usage: None,
+            usage: Vec::new(),
+            custom_rules: Vec::new(),
         }
     }
 
@@ -835,9 +873,11 @@ mod tests {
             rule,
             severity,
             file: ".claude/agents/x.md".into(),
+            line: None,
             related: Vec::new(),
             message: "msg".into(),
             suggestion: Some("fix".into()),
+            edits: Vec::new(),
         }
     }
 
@@ -846,11 +886,13 @@ mod tests {
             rule,
             severity,
             file: ".claude/agents/x.md".into(),
+            line: None,
             related: (0..related)
                 .map(|i| format!(".claude/agents/r{i}.md").into())
                 .collect(),
             message: "msg".into(),
             suggestion: Some("fix".into()),
+            edits: Vec::new(),
         }
     }
 
@@ -1006,7 +1048,8 @@ mod tests {
             skill_count: 0,
             findings: vec![],
             deep_raw: None,
-            usage: Some(usage),
+            usage: vec![usage],
+            custom_rules: Vec::new(),
         };
         let md = report.to_markdown();
         assert!(md.contains("Observed usage"), "{md}");
@@ -1031,7 +1074,8 @@ mod tests {
             skill_count: 0,
             findings: vec![],
             deep_raw: None,
-            usage: Some(usage),
+            usage: vec![usage],
+            custom_rules: Vec::new(),
         };
         let html = report.to_html();
         assert!(html.contains("Observed usage"), "{html}");
@@ -1062,7 +1106,8 @@ mod tests {
             skill_count: 0,
             findings: vec![],
             deep_raw: None,
-            usage: Some(usage),
+            usage: vec![usage],
+            custom_rules: Vec::new(),
         };
         let md = report.to_markdown();
         assert!(
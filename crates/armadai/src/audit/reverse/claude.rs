@@ -68,7 +68,7 @@ impl ReverseLinker for ClaudeReverseLinker {
 /// nested subdirectories (e.g. `.claude/agents/backend/dev.md`).
 const MAX_AGENT_SCAN_DEPTH: u32 = 3;
 
-fn parse_agents(dir: &Path) -> Vec<ImportedAgent> {
+pub(super) fn parse_agents(dir: &Path) -> Vec<ImportedAgent> {
     let mut files = Vec::new();
     collect_agent_files(dir, MAX_AGENT_SCAN_DEPTH, &mut files);
     let mut agents: Vec<ImportedAgent> = files.iter().map(|p| parse_agent_file(p)).collect();
@@ -78,7 +78,7 @@ fn parse_agents(dir: &Path) -> Vec<ImportedAgent> {
 
 /// Recursively collects `*.md` files under `dir`, up to `depth` levels of
 /// nesting. Directories named `foo.md` are skipped: only real files count.
-fn collect_agent_files(dir: &Path, depth: u32, out: &mut Vec<std::path::PathBuf>) {
+pub(super) fn collect_agent_files(dir: &Path, depth: u32, out: &mut Vec<std::path::PathBuf>) {
     let Ok(entries) = std::fs::read_dir(dir) else {
         return;
     };
@@ -103,7 +103,7 @@ struct SkillFm {
     extra: BTreeMap<String, serde_yaml_ng::Value>,
 }
 
-fn parse_skills(dir: &Path) -> Vec<ImportedSkill> {
+pub(super) fn parse_skills(dir: &Path) -> Vec<ImportedSkill> {
     let Ok(entries) = std::fs::read_dir(dir) else {
         return Vec::new();
     };
@@ -161,7 +161,7 @@ fn parse_skill_dir(dir: &Path) -> ImportedSkill {
     }
 }
 
-fn parse_instructions(path: &Path) -> Option<ImportedInstructions> {
+pub(super) fn parse_instructions(path: &Path) -> Option<ImportedInstructions> {
     let content = std::fs::read_to_string(path).ok()?;
     Some(ImportedInstructions {
         source_path: path.to_path_buf(),
@@ -174,6 +174,17 @@ fn parse_agent_file(path: &Path) -> ImportedAgent {
         .file_stem()
         .map(|s| s.to_string_lossy().to_string())
         .unwrap_or_else(|| "unknown".to_string());
+    parse_markdown_agent(path, stem, true)
+}
+
+/// Parse a Markdown agent with Claude-style frontmatter (`name`,
+/// `description`, `model`, `tools`). Other CLIs share this shape; those
+/// where frontmatter is optional pass `require_frontmatter = false`.
+pub(super) fn parse_markdown_agent(
+    path: &Path,
+    stem: String,
+    require_frontmatter: bool,
+) -> ImportedAgent {
     let content = match std::fs::read_to_string(path) {
         Ok(c) => c,
         Err(e) => {
@@ -200,7 +211,9 @@ fn parse_agent_file(path: &Path) -> ImportedAgent {
             }
         }),
         None => {
-            issues.push(issue(path, "missing YAML frontmatter".to_string()));
+            if require_frontmatter {
+                issues.push(issue(path, "missing YAML frontmatter".to_string()));
+            }
             ClaudeAgentFrontmatter::default()
         }
     };
@@ -223,7 +236,7 @@ fn parse_agent_file(path: &Path) -> ImportedAgent {
 /// and block scalars (`|`, `>`) are skipped so historical fallbacks (file
 /// stem) keep working, and indented lines (nested keys, block-scalar
 /// bodies) never masquerade as a top-level key.
-fn salvage_field(raw: &str, key: &str) -> Option<String> {
+pub(super) fn salvage_field(raw: &str, key: &str) -> Option<String> {
     raw.lines().find_map(|line| {
         // A top-level key starts at column 0: nested keys and block-scalar
         // bodies are indented and must not match.
@@ -258,7 +271,7 @@ fn salvage_field(raw: &str, key: &str) -> Option<String> {
 ///
 /// `content` is the full file content (to calculate line offset correctly).
 /// `raw` is the extracted frontmatter YAML (between `---` delimiters).
-fn describe_yaml_error(content: &str, raw: &str, err: &serde_yaml_ng::Error) -> String {
+pub(super) fn describe_yaml_error(content: &str, raw: &str, err: &serde_yaml_ng::Error) -> String {
     if let Some(loc) = err.location() {
         // Calculate how many lines precede the frontmatter in the original file.
         // extract_frontmatter() does trim_start(), so we must count stripped lines.
@@ -289,7 +302,7 @@ fn describe_yaml_error(content: &str, raw: &str, err: &serde_yaml_ng::Error) ->
     format!("invalid YAML frontmatter: {err}")
 }
 
-fn issue(path: &Path, message: String) -> ParseIssue {
+pub(super) fn issue(path: &Path, message: String) -> ParseIssue {
     ParseIssue {
         file: path.to_path_buf(),
         message,
@@ -0,0 +1,238 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use super::claude::{issue, parse_instructions, parse_skills};
+use super::{ImportedAgent, ImportedConfig, PartialMetadata, ReverseLinker};
+
+/// Reads native Codex configuration surfaces.
+///
+/// Agents are declared in `.codex/config.toml` as `[agents.<name>]` tables
+/// (`description`, `config_file`), each pointing at a TOML file carrying
+/// `model` and `developer_instructions`. Agent files present under
+/// `.codex/agents/` but not declared are imported too, so A02 can report
+/// their missing description.
+pub struct CodexReverseLinker;
+
+impl ReverseLinker for CodexReverseLinker {
+    fn name(&self) -> &'static str {
+        "codex"
+    }
+
+    fn detect(&self, root: &Path) -> bool {
+        root.join(".codex/config.toml").is_file()
+            || root.join(".codex/agents").is_dir()
+            || root.join(".codex/skills").is_dir()
+            || root.join("AGENTS.md").is_file()
+            || root.join(".codex/AGENTS.md").is_file()
+    }
+
+    fn parse(&self, root: &Path) -> ImportedConfig {
+        let codex_dir = root.join(".codex");
+        ImportedConfig {
+            agents: parse_agents(&codex_dir),
+            skills: parse_skills(&codex_dir.join("skills")),
+            instructions: parse_instructions(&root.join("AGENTS.md"))
+                .or_else(|| parse_instructions(&codex_dir.join("AGENTS.md"))),
+        }
+    }
+}
+
+fn parse_agents(codex_dir: &Path) -> Vec<ImportedAgent> {
+    let config_path = codex_dir.join("config.toml");
+    let mut agents = Vec::new();
+    let mut seen_files: Vec<PathBuf> = Vec::new();
+
+    match read_table(&config_path) {
+        Ok(Some(config)) => {
+            let declared = config.get("agents").and_then(toml::Value::as_table);
+            for (name, entry) in declared.into_iter().flatten() {
+                let Some(entry) = entry.as_table() else {
+                    agents.push(broken(
+                        name,
+                        &config_path,
+                        format!("[agents.{name}] must be a table"),
+                    ));
+                    continue;
+                };
+                let description = entry
+                    .get("description")
+                    .and_then(toml::Value::as_str)
+                    .map(str::to_string);
+                let agent = match entry.get("config_file").and_then(toml::Value::as_str) {
+                    Some(rel) => {
+                        let file = codex_dir.join(rel);
+                        seen_files.push(file.clone());
+                        parse_agent_file(name, &file)
+                    }
+                    // An inline agent keeps its settings in the table itself.
+                    None => agent_from_table(name, &config_path, entry),
+                };
+                agents.push(ImportedAgent {
+                    metadata: PartialMetadata {
+                        description: description.or(agent.metadata.description),
+                        ..agent.metadata
+                    },
+                    ..agent
+                });
+            }
+        }
+        Ok(None) => {}
+        Err(message) => agents.push(broken("config", &config_path, message)),
+    }
+
+    let mut undeclared = Vec::new();
+    if let Ok(entries) = std::fs::read_dir(codex_dir.join("agents")) {
+        for path in entries.flatten().map(|e| e.path()) {
+            if path.extension().is_some_and(|ext| ext == "toml")
+                && path.is_file()
+                && !seen_files.contains(&path)
+            {
+                undeclared.push(path);
+            }
+        }
+    }
+    for path in undeclared {
+        let stem = path
+            .file_stem()
+            .map(|s| s.to_string_lossy().to_string())
+            .unwrap_or_else(|| "unknown".to_string());
+        agents.push(parse_agent_file(&stem, &path));
+    }
+
+    agents.sort_by(|a, b| a.name.cmp(&b.name));
+    agents
+}
+
+/// `Ok(None)` when the file does not exist; `Err` carries the parse error.
+fn read_table(path: &Path) -> Result<Option<toml::Table>, String> {
+    let Ok(content) = std::fs::read_to_string(path) else {
+        return Ok(None);
+    };
+    content
+        .parse::<toml::Table>()
+        .map(Some)
+        .map_err(|e| format!("invalid TOML: {}", e.message()))
+}
+
+fn parse_agent_file(name: &str, path: &Path) -> ImportedAgent {
+    match read_table(path) {
+        Ok(Some(table)) => agent_from_table(name, path, &table),
+        Ok(None) => broken(name, path, "agent config file not found".to_string()),
+        Err(message) => broken(name, path, message),
+    }
+}
+
+fn agent_from_table(name: &str, path: &Path, table: &toml::Table) -> ImportedAgent {
+    let text = |key: &str| table.get(key).and_then(toml::Value::as_str);
+    let extra: BTreeMap<String, serde_yaml_ng::Value> = table
+        .iter()
+        .filter(|(k, _)| {
+            !matches!(
+                k.as_str(),
+                "description" | "config_file" | "model" | "developer_instructions"
+            )
+        })
+        .filter_map(|(k, v)| Some((k.clone(), serde_yaml_ng::to_value(v).ok()?)))
+        .collect();
+    ImportedAgent {
+        name: name.to_string(),
+        source_path: path.to_path_buf(),
+        metadata: PartialMetadata {
+            description: text("description").map(str::to_string),
+            model: text("model").map(str::to_string),
+            // Codex agents have no per-agent tool list; sandboxing is global.
+            tools: None,
+            extra,
+        },
+        system_prompt: text("developer_instructions")
+            .unwrap_or_default()
+            .trim()
+            .to_string(),
+        issues: Vec::new(),
+    }
+}
+
+fn broken(name: &str, path: &Path, message: String) -> ImportedAgent {
+    ImportedAgent {
+        name: name.to_string(),
+        source_path: path.to_path_buf(),
+        metadata: PartialMetadata::default(),
+        system_prompt: String::new(),
+        issues: vec![issue(path, message)],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write(root: &Path, rel: &str, content: &str) {
+        let p = root.join(rel);
+        std::fs::create_dir_all(p.parent().unwrap()).unwrap();
+        std::fs::write(p, content).unwrap();
+    }
+
+    #[test]
+    fn parses_declared_and_undeclared_agents() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(!CodexReverseLinker.detect(dir.path()));
+        write(
+            dir.path(),
+            ".codex/config.toml",
+            "[agents.reviewer]\ndescription = \"Reviews code\"\nconfig_file = \"agents/reviewer.toml\"\n",
+        );
+        write(
+            dir.path(),
+            ".codex/agents/reviewer.toml",
+            "model = \"gpt-5\"\nmodel_reasoning_effort = \"high\"\ndeveloper_instructions = \"\"\"\nReview the diff.\n\"\"\"\n",
+        );
+        write(
+            dir.path(),
+            ".codex/agents/stray.toml",
+            "developer_instructions = \"Help.\"\n",
+        );
+        write(dir.path(), "AGENTS.md", "# Agents\n");
+        assert!(CodexReverseLinker.detect(dir.path()));
+
+        let config = CodexReverseLinker.parse(dir.path());
+        assert_eq!(config.agents.len(), 2);
+        let reviewer = &config.agents[0];
+        assert_eq!(reviewer.name, "reviewer");
+        assert_eq!(
+            reviewer.metadata.description.as_deref(),
+            Some("Reviews code")
+        );
+        assert_eq!(reviewer.metadata.model.as_deref(), Some("gpt-5"));
+        assert!(
+            reviewer
+                .metadata
+                .extra
+                .contains_key("model_reasoning_effort")
+        );
+        assert_eq!(reviewer.system_prompt, "Review the diff.");
+        let stray = &config.agents[1];
+        assert_eq!(stray.name, "stray");
+        assert!(stray.metadata.description.is_none());
+        assert!(stray.issues.is_empty());
+        assert_eq!(config.instructions.unwrap().content, "# Agents\n");
+    }
+
+    #[test]
+    fn broken_toml_becomes_issue() {
+        let dir = tempfile::tempdir().unwrap();
+        write(dir.path(), ".codex/config.toml", "[agents.x\n");
+        write(
+            dir.path(),
+            ".codex/agents/missing.toml",
+            "model = \"gpt-5\"\ndeveloper_instructions = \"\"\"\nunterminated",
+        );
+        let config = CodexReverseLinker.parse(dir.path());
+        assert_eq!(config.agents.len(), 2);
+        assert!(config.agents.iter().all(|a| a.issues.len() == 1));
+        assert!(
+            config.agents[0].issues[0]
+                .message
+                .starts_with("invalid TOML")
+        );
+    }
+}
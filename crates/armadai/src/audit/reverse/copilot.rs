@@ -0,0 +1,95 @@
+use std::path::Path;
+
+use super::claude::{collect_agent_files, parse_instructions, parse_markdown_agent, parse_skills};
+use super::{ImportedAgent, ImportedConfig, ReverseLinker};
+
+/// Reads native GitHub Copilot configuration surfaces: custom agents
+/// (`.github/agents/*.agent.md`), legacy chat modes
+/// (`.github/chatmodes/*.chatmode.md`), skills and
+/// `.github/copilot-instructions.md`.
+///
+/// Copilot frontmatter is optional, so a file without one is not an issue.
+pub struct CopilotReverseLinker;
+
+impl ReverseLinker for CopilotReverseLinker {
+    fn name(&self) -> &'static str {
+        "copilot"
+    }
+
+    fn detect(&self, root: &Path) -> bool {
+        root.join(".github/agents").is_dir()
+            || root.join(".github/chatmodes").is_dir()
+            || root.join(".github/skills").is_dir()
+            || root.join(".github/copilot-instructions.md").is_file()
+    }
+
+    fn parse(&self, root: &Path) -> ImportedConfig {
+        let mut agents = parse_agent_dir(&root.join(".github/agents"), ".agent");
+        agents.extend(parse_agent_dir(
+            &root.join(".github/chatmodes"),
+            ".chatmode",
+        ));
+        agents.sort_by(|a, b| a.name.cmp(&b.name));
+        ImportedConfig {
+            agents,
+            skills: parse_skills(&root.join(".github/skills")),
+            instructions: parse_instructions(&root.join(".github/copilot-instructions.md")),
+        }
+    }
+}
+
+fn parse_agent_dir(dir: &Path, suffix: &str) -> Vec<ImportedAgent> {
+    let mut files = Vec::new();
+    collect_agent_files(dir, 0, &mut files);
+    files
+        .iter()
+        .map(|path| {
+            let stem = path
+                .file_stem()
+                .map(|s| s.to_string_lossy().to_string())
+                .unwrap_or_else(|| "unknown".to_string());
+            let stem = stem.strip_suffix(suffix).unwrap_or(&stem).to_string();
+            parse_markdown_agent(path, stem, false)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write(root: &Path, rel: &str, content: &str) {
+        let p = root.join(rel);
+        std::fs::create_dir_all(p.parent().unwrap()).unwrap();
+        std::fs::write(p, content).unwrap();
+    }
+
+    #[test]
+    fn parses_agents_with_and_without_frontmatter() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(!CopilotReverseLinker.detect(dir.path()));
+        write(
+            dir.path(),
+            ".github/agents/reviewer.agent.md",
+            "---\ndescription: Reviews code\ntools: ['search', 'edit']\nmodel: GPT-4.1\n---\nReview.",
+        );
+        write(
+            dir.path(),
+            ".github/chatmodes/planner.chatmode.md",
+            "Plan the work.",
+        );
+        write(dir.path(), ".github/copilot-instructions.md", "Be brief.");
+        assert!(CopilotReverseLinker.detect(dir.path()));
+
+        let config = CopilotReverseLinker.parse(dir.path());
+        let names: Vec<&str> = config.agents.iter().map(|a| a.name.as_str()).collect();
+        assert_eq!(names, vec!["planner", "reviewer"]);
+        assert!(config.agents.iter().all(|a| a.issues.is_empty()));
+        assert_eq!(
+            config.agents[1].metadata.tools,
+            Some(vec!["search".to_string(), "edit".to_string()])
+        );
+        assert_eq!(config.agents[0].system_prompt, "Plan the work.");
+        assert_eq!(config.instructions.unwrap().content, "Be brief.");
+    }
+}
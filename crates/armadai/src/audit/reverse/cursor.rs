@@ -0,0 +1,182 @@
+use std::path::{Path, PathBuf};
+
+use super::{
+    ImportedAgent, ImportedConfig, ImportedInstructions, ParseIssue, PartialMetadata, ReverseLinker,
+};
+use armadai_core::parser::frontmatter::extract_frontmatter;
+
+/// Reads Cursor project rules (`.cursor/rules/**/*.mdc`) and the legacy
+/// root `.cursorrules` file.
+///
+/// Each rule becomes an agent: `description` maps to the description and
+/// `globs` to path claims (read by the scope rules). Cursor's
+/// frontmatter is not strict YAML (`globs: *.ts` is a YAML alias), so it is
+/// read line by line rather than through serde.
+pub struct CursorReverseLinker;
+
+impl ReverseLinker for CursorReverseLinker {
+    fn name(&self) -> &'static str {
+        "cursor"
+    }
+
+    fn detect(&self, root: &Path) -> bool {
+        root.join(".cursor/rules").is_dir() || root.join(".cursorrules").is_file()
+    }
+
+    fn parse(&self, root: &Path) -> ImportedConfig {
+        let mut files = Vec::new();
+        collect_rule_files(&root.join(".cursor/rules"), MAX_RULE_SCAN_DEPTH, &mut files);
+        let mut agents: Vec<ImportedAgent> = files.iter().map(|p| parse_rule_file(p)).collect();
+        agents.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let legacy = root.join(".cursorrules");
+        let instructions =
+            std::fs::read_to_string(&legacy)
+                .ok()
+                .map(|content| ImportedInstructions {
+                    source_path: legacy,
+                    content,
+                });
+        ImportedConfig {
+            agents,
+            skills: Vec::new(),
+            instructions,
+        }
+    }
+}
+
+/// Cursor reads rules from nested folders under `.cursor/rules/`.
+const MAX_RULE_SCAN_DEPTH: u32 = 3;
+
+fn collect_rule_files(dir: &Path, depth: u32, out: &mut Vec<PathBuf>) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let p = entry.path();
+        if p.is_file() {
+            if p.extension().is_some_and(|ext| ext == "mdc") {
+                out.push(p);
+            }
+        } else if p.is_dir() && depth > 0 {
+            collect_rule_files(&p, depth - 1, out);
+        }
+    }
+}
+
+fn parse_rule_file(path: &Path) -> ImportedAgent {
+    let name = path
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_else(|| "unknown".to_string());
+    let content = match std::fs::read_to_string(path) {
+        Ok(c) => c,
+        Err(e) => {
+            return ImportedAgent {
+                name,
+                source_path: path.to_path_buf(),
+                metadata: PartialMetadata::default(),
+                system_prompt: String::new(),
+                issues: vec![ParseIssue {
+                    file: path.to_path_buf(),
+                    message: format!("unreadable file: {e}"),
+                }],
+            };
+        }
+    };
+
+    let (fm_raw, body) = extract_frontmatter(&content);
+    let mut issues = Vec::new();
+    let mut metadata = PartialMetadata::default();
+    match fm_raw {
+        Some(raw) => {
+            for (key, value) in raw.lines().filter_map(|l| l.split_once(':')) {
+                let value = value.trim().trim_matches('"').trim_matches('\'');
+                match key.trim() {
+                    "description" if !value.is_empty() => {
+                        metadata.description = Some(value.to_string());
+                    }
+                    "globs" => {
+                        let globs: Vec<serde_yaml_ng::Value> = value
+                            .trim_matches(|c| c == '[' || c == ']')
+                            .split(',')
+                            .map(|g| g.trim().trim_matches('"').trim_matches('\''))
+                            .filter(|g| !g.is_empty())
+                            .map(|g| serde_yaml_ng::Value::String(g.to_string()))
+                            .collect();
+                        if !globs.is_empty() {
+                            metadata
+                                .extra
+                                .insert("globs".into(), serde_yaml_ng::Value::Sequence(globs));
+                        }
+                    }
+                    "alwaysApply" => {
+                        metadata.extra.insert(
+                            "alwaysApply".into(),
+                            serde_yaml_ng::Value::Bool(value == "true"),
+                        );
+                    }
+                    _ => {}
+                }
+            }
+        }
+        None => issues.push(ParseIssue {
+            file: path.to_path_buf(),
+            message: "missing rule frontmatter (description/globs/alwaysApply)".to_string(),
+        }),
+    }
+
+    ImportedAgent {
+        name,
+        source_path: path.to_path_buf(),
+        metadata,
+        system_prompt: body.trim().to_string(),
+        issues,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write(root: &Path, rel: &str, content: &str) {
+        let p = root.join(rel);
+        std::fs::create_dir_all(p.parent().unwrap()).unwrap();
+        std::fs::write(p, content).unwrap();
+    }
+
+    #[test]
+    fn parses_rules_with_cursor_style_globs() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(!CursorReverseLinker.detect(dir.path()));
+        write(
+            dir.path(),
+            ".cursor/rules/frontend/react.mdc",
+            "---\ndescription: React conventions\nglobs: *.tsx,src/**/*.ts\nalwaysApply: false\n---\n\nUse hooks.\n",
+        );
+        write(dir.path(), ".cursorrules", "Be concise.\n");
+        assert!(CursorReverseLinker.detect(dir.path()));
+
+        let config = CursorReverseLinker.parse(dir.path());
+        assert_eq!(config.agents.len(), 1);
+        let rule = &config.agents[0];
+        assert_eq!(rule.name, "react");
+        assert_eq!(
+            rule.metadata.description.as_deref(),
+            Some("React conventions")
+        );
+        assert_eq!(rule.metadata.scope_globs(), vec!["*.tsx", "src/**/*.ts"]);
+        assert_eq!(rule.system_prompt, "Use hooks.");
+        assert!(rule.issues.is_empty());
+        assert_eq!(config.instructions.unwrap().content, "Be concise.\n");
+    }
+
+    #[test]
+    fn rule_without_frontmatter_is_an_issue() {
+        let dir = tempfile::tempdir().unwrap();
+        write(dir.path(), ".cursor/rules/plain.mdc", "Just text.");
+        let config = CursorReverseLinker.parse(dir.path());
+        assert_eq!(config.agents[0].issues.len(), 1);
+        assert_eq!(config.agents[0].system_prompt, "Just text.");
+    }
+}
@@ -0,0 +1,64 @@
+use std::path::Path;
+
+use super::claude::{parse_agents, parse_instructions, parse_skills};
+use super::{ImportedConfig, ReverseLinker};
+
+/// Reads native Gemini CLI configuration surfaces.
+///
+/// Gemini subagents (`.gemini/agents/*.md`) use the same frontmatter shape
+/// as Claude Code agents, and skills follow the Agent Skills layout, so the
+/// Claude parsers are reused as-is.
+pub struct GeminiReverseLinker;
+
+impl ReverseLinker for GeminiReverseLinker {
+    fn name(&self) -> &'static str {
+        "gemini"
+    }
+
+    fn detect(&self, root: &Path) -> bool {
+        root.join(".gemini/agents").is_dir()
+            || root.join(".gemini/skills").is_dir()
+            || root.join("GEMINI.md").is_file()
+            || root.join(".gemini/GEMINI.md").is_file()
+    }
+
+    fn parse(&self, root: &Path) -> ImportedConfig {
+        ImportedConfig {
+            agents: parse_agents(&root.join(".gemini/agents")),
+            skills: parse_skills(&root.join(".gemini/skills")),
+            // `armadai link gemini` writes `.gemini/GEMINI.md`; hand-written
+            // projects usually keep it at the root.
+            instructions: parse_instructions(&root.join("GEMINI.md"))
+                .or_else(|| parse_instructions(&root.join(".gemini/GEMINI.md"))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_agents_skills_and_nested_instructions() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(!GeminiReverseLinker.detect(dir.path()));
+        let agents = dir.path().join(".gemini/agents");
+        std::fs::create_dir_all(&agents).unwrap();
+        std::fs::write(
+            agents.join("reviewer.md"),
+            "---\nname: reviewer\ndescription: \"Reviews code\"\nmodel: gemini-2.5-pro\ntemperature: 0.2\n---\n\nReview the diff.\n",
+        )
+        .unwrap();
+        std::fs::write(dir.path().join(".gemini/GEMINI.md"), "# Team\n").unwrap();
+        assert!(GeminiReverseLinker.detect(dir.path()));
+
+        let config = GeminiReverseLinker.parse(dir.path());
+        assert_eq!(config.agents.len(), 1);
+        let agent = &config.agents[0];
+        assert_eq!(agent.metadata.model.as_deref(), Some("gemini-2.5-pro"));
+        assert_eq!(agent.metadata.description.as_deref(), Some("Reviews code"));
+        assert!(agent.metadata.extra.contains_key("temperature"));
+        assert_eq!(agent.system_prompt, "Review the diff.");
+        assert_eq!(config.instructions.unwrap().content, "# Team\n");
+    }
+}
@@ -2,6 +2,11 @@ use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 
 pub mod claude;
+pub mod codex;
+pub mod copilot;
+pub mod cursor;
+pub mod gemini;
+pub mod opencode;
 
 /// Metadata recovered from a native agent file. Everything is optional:
 /// a partial native config must never abort the audit (it IS the report).
@@ -17,9 +22,10 @@ pub struct PartialMetadata {
 }
 
 impl PartialMetadata {
-    /// Path claims from the non-standard `paths:` field (YAML list or CSV string).
+    /// Path claims from the non-standard `paths:` field, or Cursor's
+    /// `globs:` (YAML list or CSV string).
     pub fn scope_globs(&self) -> Vec<String> {
-        match self.extra.get("paths") {
+        match self.extra.get("paths").or_else(|| self.extra.get("globs")) {
             Some(serde_yaml_ng::Value::Sequence(seq)) => seq
                 .iter()
                 .filter_map(|v| v.as_str().map(str::to_string))
@@ -0,0 +1,233 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use serde::Deserialize;
+
+use super::claude::{
+    collect_agent_files, describe_yaml_error, issue, parse_instructions, parse_skills,
+    salvage_field,
+};
+use super::{ImportedAgent, ImportedConfig, PartialMetadata, ReverseLinker};
+use armadai_core::parser::frontmatter::extract_frontmatter;
+
+/// Reads native OpenCode configuration surfaces: Markdown agents
+/// (`.opencode/agents/` or `.opencode/agent/`), the `agent` map of
+/// `opencode.json`, skills and the project instructions.
+///
+/// OpenCode's `tools` is a map of switches over an all-enabled default.
+/// When it disables anything, the enabled keys become the agent's tool
+/// list (possibly empty); otherwise the agent counts as unrestricted.
+pub struct OpencodeReverseLinker;
+
+impl ReverseLinker for OpencodeReverseLinker {
+    fn name(&self) -> &'static str {
+        "opencode"
+    }
+
+    fn detect(&self, root: &Path) -> bool {
+        root.join("opencode.json").is_file() || root.join(".opencode").is_dir()
+    }
+
+    fn parse(&self, root: &Path) -> ImportedConfig {
+        let mut files = Vec::new();
+        for dir in [".opencode/agents", ".opencode/agent"] {
+            collect_agent_files(&root.join(dir), 3, &mut files);
+        }
+        let mut agents: Vec<ImportedAgent> = files.iter().map(|p| parse_agent_file(p)).collect();
+        agents.extend(parse_json_agents(root, &root.join("opencode.json")));
+        agents.sort_by(|a, b| a.name.cmp(&b.name));
+        ImportedConfig {
+            agents,
+            skills: parse_skills(&root.join(".opencode/skills")),
+            // `armadai link opencode` writes `.opencode/instructions.md`;
+            // OpenCode itself also reads the root `AGENTS.md`.
+            instructions: parse_instructions(&root.join(".opencode/instructions.md"))
+                .or_else(|| parse_instructions(&root.join("AGENTS.md"))),
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct OpencodeAgent {
+    description: Option<String>,
+    model: Option<String>,
+    tools: Option<BTreeMap<String, bool>>,
+    /// Only set in `opencode.json`: inline text or `{file:./path}`.
+    prompt: Option<String>,
+    #[serde(flatten)]
+    extra: BTreeMap<String, serde_yaml_ng::Value>,
+}
+
+fn tool_list(tools: Option<BTreeMap<String, bool>>) -> Option<Vec<String>> {
+    let tools = tools?;
+    if tools.values().all(|enabled| *enabled) {
+        return None;
+    }
+    Some(
+        tools
+            .into_iter()
+            .filter(|(_, enabled)| *enabled)
+            .map(|(name, _)| name)
+            .collect(),
+    )
+}
+
+fn agent_from(
+    name: String,
+    path: &Path,
+    fm: OpencodeAgent,
+    prompt: String,
+    issues: Vec<super::ParseIssue>,
+) -> ImportedAgent {
+    ImportedAgent {
+        name,
+        source_path: path.to_path_buf(),
+        metadata: PartialMetadata {
+            description: fm.description,
+            model: fm.model,
+            tools: tool_list(fm.tools),
+            extra: fm.extra,
+        },
+        system_prompt: prompt.trim().to_string(),
+        issues,
+    }
+}
+
+fn parse_agent_file(path: &Path) -> ImportedAgent {
+    let stem = path
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_else(|| "unknown".to_string());
+    let content = match std::fs::read_to_string(path) {
+        Ok(c) => c,
+        Err(e) => {
+            let issues = vec![issue(path, format!("unreadable file: {e}"))];
+            return agent_from(stem, path, OpencodeAgent::default(), String::new(), issues);
+        }
+    };
+    let mut issues = Vec::new();
+    let (fm_raw, body) = extract_frontmatter(&content);
+    let fm = match fm_raw {
+        Some(raw) => serde_yaml_ng::from_str(raw).unwrap_or_else(|e| {
+            issues.push(issue(path, describe_yaml_error(&content, raw, &e)));
+            OpencodeAgent {
+                description: salvage_field(raw, "description"),
+                model: salvage_field(raw, "model"),
+                ..Default::default()
+            }
+        }),
+        None => {
+            issues.push(issue(path, "missing YAML frontmatter".to_string()));
+            OpencodeAgent::default()
+        }
+    };
+    agent_from(stem, path, fm, body.to_string(), issues)
+}
+
+fn parse_json_agents(root: &Path, path: &Path) -> Vec<ImportedAgent> {
+    let Ok(content) = std::fs::read_to_string(path) else {
+        return Vec::new();
+    };
+    #[derive(Deserialize)]
+    struct OpencodeJson {
+        #[serde(default)]
+        agent: BTreeMap<String, serde_json::Value>,
+    }
+    let config: OpencodeJson = match serde_json::from_str(&content) {
+        Ok(c) => c,
+        Err(e) => {
+            let issues = vec![issue(path, format!("invalid JSON: {e}"))];
+            let fm = OpencodeAgent::default();
+            return vec![agent_from(
+                "opencode.json".into(),
+                path,
+                fm,
+                String::new(),
+                issues,
+            )];
+        }
+    };
+    config
+        .agent
+        .into_iter()
+        .map(|(name, value)| {
+            let mut issues = Vec::new();
+            let fm: OpencodeAgent = serde_json::from_value(value).unwrap_or_else(|e| {
+                issues.push(issue(path, format!("agent '{name}': {e}")));
+                OpencodeAgent::default()
+            });
+            let prompt = match fm.prompt.as_deref() {
+                Some(p) => match p
+                    .strip_prefix("{file:")
+                    .and_then(|rest| rest.strip_suffix('}'))
+                {
+                    Some(rel) => std::fs::read_to_string(root.join(rel)).unwrap_or_else(|e| {
+                        issues.push(issue(path, format!("agent '{name}': prompt {rel}: {e}")));
+                        String::new()
+                    }),
+                    None => p.to_string(),
+                },
+                None => String::new(),
+            };
+            agent_from(name, path, fm, prompt, issues)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write(root: &Path, rel: &str, content: &str) {
+        let p = root.join(rel);
+        std::fs::create_dir_all(p.parent().unwrap()).unwrap();
+        std::fs::write(p, content).unwrap();
+    }
+
+    #[test]
+    fn parses_markdown_and_json_agents() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(!OpencodeReverseLinker.detect(dir.path()));
+        write(
+            dir.path(),
+            ".opencode/agents/reviewer.md",
+            "---\ndescription: Reviews code\nmode: subagent\nmodel: anthropic/claude-sonnet-4-5\n\
+             tools:\n  write: false\n  read: true\n---\n\nReview the diff.\n",
+        );
+        write(dir.path(), "prompts/docs.md", "Write docs.\n");
+        write(
+            dir.path(),
+            "opencode.json",
+            r#"{"agent": {"docs": {"description": "Writes docs", "prompt": "{file:./prompts/docs.md}"}}}"#,
+        );
+        write(dir.path(), "AGENTS.md", "# Project\n");
+        assert!(OpencodeReverseLinker.detect(dir.path()));
+
+        let config = OpencodeReverseLinker.parse(dir.path());
+        assert_eq!(config.agents.len(), 2);
+        let docs = &config.agents[0];
+        assert_eq!(docs.name, "docs");
+        assert_eq!(docs.system_prompt, "Write docs.");
+        assert!(docs.metadata.tools.is_none());
+        let reviewer = &config.agents[1];
+        assert_eq!(reviewer.metadata.tools, Some(vec!["read".to_string()]));
+        assert!(reviewer.metadata.extra.contains_key("mode"));
+        assert_eq!(reviewer.system_prompt, "Review the diff.");
+        assert!(config.agents.iter().all(|a| a.issues.is_empty()));
+        assert_eq!(config.instructions.unwrap().content, "# Project\n");
+    }
+
+    #[test]
+    fn invalid_json_becomes_issue() {
+        let dir = tempfile::tempdir().unwrap();
+        write(dir.path(), "opencode.json", "{\"agent\": ");
+        let config = OpencodeReverseLinker.parse(dir.path());
+        assert_eq!(config.agents.len(), 1);
+        assert!(
+            config.agents[0].issues[0]
+                .message
+                .starts_with("invalid JSON")
+        );
+    }
+}
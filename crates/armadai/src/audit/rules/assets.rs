@@ -11,9 +11,11 @@ pub(super) fn a01_unparsable(ctx: &AuditContext) -> Vec<Finding> {
             rule: "A01",
             severity: Severity::Critical,
             file: i.file.clone(),
+            line: None,
             related: Vec::new(),
             message: i.message.clone(),
             suggestion: Some("fix the YAML frontmatter so tools can read this file".to_string()),
+            edits: Vec::new(),
         })
         .collect()
 }
@@ -31,11 +33,13 @@ pub(super) fn a02_missing_fields(ctx: &AuditContext) -> Vec<Finding> {
             rule: "A02",
             severity: Severity::Warning,
             file: a.source_path.clone(),
+            line: None,
             related: Vec::new(),
             message: format!("agent '{}' has no description", a.name),
             suggestion: Some(
                 "add a `description:` field (used for routing and discovery)".to_string(),
             ),
+            edits: Vec::new(),
         })
         .collect()
 }
@@ -54,6 +58,7 @@ pub(super) fn a05_oversized_prompt(ctx: &AuditContext) -> Vec<Finding> {
                 rule: "A05",
                 severity: Severity::Warning,
                 file: a.source_path.clone(),
+                line: None,
                 related: Vec::new(),
                 message: format!(
                     "agent '{}' prompt is ~{estimate} tokens (threshold {})",
@@ -62,6 +67,7 @@ pub(super) fn a05_oversized_prompt(ctx: &AuditContext) -> Vec<Finding> {
                 suggestion: Some(
                     "split shared conventions into a reusable prompt fragment".to_string(),
                 ),
+                edits: Vec::new(),
             })
         })
         .collect()
@@ -98,6 +104,7 @@ pub(super) fn a08_permissive_tools(ctx: &AuditContext) -> Vec<Finding> {
         rule: "A08",
         severity,
         file: first.source_path.clone(),
+        line: None,
         related: offenders[1..]
             .iter()
             .map(|a| a.source_path.clone())
@@ -108,6 +115,7 @@ pub(super) fn a08_permissive_tools(ctx: &AuditContext) -> Vec<Finding> {
             agents.len()
         ),
         suggestion: Some("declare the minimal `tools:` list each agent needs".to_string()),
+        edits: Vec::new(),
     }]
 }
 
@@ -133,19 +141,23 @@ pub(super) fn a09_malformed_skill(ctx: &AuditContext) -> Vec<Finding> {
                 rule: "A09",
                 severity: Severity::Warning,
                 file: s.source_path.clone(),
+                line: None,
                 related: Vec::new(),
                 message: format!("skill '{}': {}", s.name, problems.join(", ")),
                 suggestion: Some(
                     "follow the Agent Skills standard: SKILL.md with name + description"
                         .to_string(),
                 ),
+                edits: Vec::new(),
             })
         })
         .collect()
 }
 
-/// Frontmatter fields documented by Claude Code (beyond the typed ones).
+/// Frontmatter fields documented by the CLIs we import from (beyond the
+/// typed ones).
 const DOCUMENTED_AGENT_FIELDS: &[&str] = &[
+    // Claude Code
     "effort",
     "color",
     "permissionMode",
@@ -158,6 +170,27 @@ const DOCUMENTED_AGENT_FIELDS: &[&str] = &[
     "background",
     "isolation",
     "initialPrompt",
+    // Gemini CLI
+    "temperature",
+    "max_turns",
+    "timeout_mins",
+    // Codex
+    "model_reasoning_effort",
+    "sandbox_mode",
+    "approval_policy",
+    // GitHub Copilot
+    "argument-hint",
+    "handoffs",
+    "target",
+    "mcp-servers",
+    // OpenCode
+    "mode",
+    "top_p",
+    "permission",
+    "disable",
+    // Cursor
+    "globs",
+    "alwaysApply",
 ];
 // `tools` is deliberately excluded from the skill allowlist: it is
 // non-standard for skills (Claude Code ignores it there) — `allowed-tools`
@@ -231,6 +264,7 @@ pub(super) fn a12_nonstandard_fields(ctx: &AuditContext) -> Vec<Finding> {
         rule: "A12",
         severity: Severity::Info,
         file: first,
+        line: None,
         related: files[1..].to_vec(),
         message: format!(
             "non-standard frontmatter field(s) across {} file(s): {}",
@@ -240,6 +274,7 @@ pub(super) fn a12_nonstandard_fields(ctx: &AuditContext) -> Vec<Finding> {
         suggestion: Some(
             "fields are kept as-is; document them or align with Claude Code standards".to_string(),
         ),
+        edits: Vec::new(),
     }]
 }
 
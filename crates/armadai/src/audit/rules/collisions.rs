@@ -30,12 +30,14 @@ pub(super) fn c01_name_collisions(ctx: &AuditContext) -> Vec<Finding> {
                     rule: "C01",
                     severity: Severity::Critical,
                     file: paths[0].to_path_buf(),
+                    line: None,
                     related: paths[1..].iter().map(|p| p.to_path_buf()).collect(),
                     message: format!(
                         "{} {kind} files share the name '{name}' — routing is ambiguous",
                         paths.len()
                     ),
                     suggestion: Some("rename or remove the duplicates".to_string()),
+                    edits: Vec::new(),
                 });
             }
         }
@@ -46,9 +48,11 @@ pub(super) fn c01_name_collisions(ctx: &AuditContext) -> Vec<Finding> {
                 rule: "C01",
                 severity: Severity::Warning,
                 file: agent_paths[0].to_path_buf(),
+                line: None,
                 related: skill_paths.iter().map(|p| p.to_path_buf()).collect(),
                 message: format!("agent and skill share the name '{name}'"),
                 suggestion: Some("give the skill or the agent a distinct name".to_string()),
+                edits: Vec::new(),
             });
         }
     }
@@ -117,6 +121,7 @@ pub(super) fn c03_activation_overlap(ctx: &AuditContext) -> Vec<Finding> {
                 rule: "C03",
                 severity: Severity::Warning,
                 file: first_path.to_path_buf(),
+                line: None,
                 related,
                 message: format!(
                     "{} assets have overlapping activation descriptions — routing is ambiguous: {}",
@@ -126,6 +131,7 @@ pub(super) fn c03_activation_overlap(ctx: &AuditContext) -> Vec<Finding> {
                 suggestion: Some(
                     "sharpen the descriptions so each one triggers on distinct intents".to_string(),
                 ),
+                edits: Vec::new(),
             }
         })
         .collect()
@@ -208,6 +214,7 @@ pub(super) fn c02_scope_overlap(ctx: &AuditContext) -> Vec<Finding> {
                 rule: "C02",
                 severity: Severity::Warning,
                 file: scoped[members[0]].0.source_path.clone(),
+                line: None,
                 related: members[1..]
                     .iter()
                     .map(|&i| scoped[i].0.source_path.clone())
@@ -220,6 +227,7 @@ pub(super) fn c02_scope_overlap(ctx: &AuditContext) -> Vec<Finding> {
                 suggestion: Some(
                     "split the scopes or make the ownership hierarchy explicit".to_string(),
                 ),
+                edits: Vec::new(),
             }
         })
         .collect()
@@ -275,6 +283,7 @@ pub(super) fn c05_inconsistent_tools(ctx: &AuditContext) -> Vec<Finding> {
                 rule: "C05",
                 severity: Severity::Info,
                 file: scoped[members[0]].0.source_path.clone(),
+                line: None,
                 related: members[1..]
                     .iter()
                     .map(|&i| scoped[i].0.source_path.clone())
@@ -287,6 +296,7 @@ pub(super) fn c05_inconsistent_tools(ctx: &AuditContext) -> Vec<Finding> {
                 suggestion: Some(
                     "align the tool policies of agents working on the same files".to_string(),
                 ),
+                edits: Vec::new(),
             }
         })
         .collect()
@@ -349,6 +359,7 @@ pub(super) fn c04_double_ownership(ctx: &AuditContext) -> Vec<Finding> {
             rule: "C04",
             severity: Severity::Warning,
             file: instructions.source_path.clone(),
+            line: None,
             related: Vec::new(),
             message: format!(
                 "agents {} are all declared owners of '{path}'",
@@ -361,6 +372,7 @@ pub(super) fn c04_double_ownership(ctx: &AuditContext) -> Vec<Finding> {
             suggestion: Some(
                 "pick a single owner per module or document the shared ownership".to_string(),
             ),
+            edits: Vec::new(),
         })
         .collect()
 }
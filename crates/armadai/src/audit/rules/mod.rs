@@ -4,6 +4,7 @@ use super::reverse::ImportedConfig;
 
 mod assets;
 mod collisions;
+pub mod custom;
 mod models;
 pub(crate) mod references;
 mod similarity;
@@ -35,14 +36,30 @@ pub struct Finding {
     pub rule: &'static str,
     pub severity: Severity,
     pub file: PathBuf,
+    /// 1-based line in `file` the finding anchors to, when it has one.
+    pub line: Option<usize>,
     /// Other files carried by an aggregated finding; `file` stays the anchor.
     pub related: Vec<PathBuf>,
     pub message: String,
     pub suggestion: Option<String>,
+    /// Mechanical edits applying the suggestion (`armadai audit --fix`).
+    /// Empty when the fix needs judgement.
+    pub edits: Vec<Edit>,
+}
+
+/// One text replacement in a native file: on 1-based `line` of `file`,
+/// the first occurrence of `old` becomes `new`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Edit {
+    pub file: PathBuf,
+    pub line: usize,
+    pub old: String,
+    pub new: String,
 }
 
 /// Tunable thresholds (spec §8). Defaults are embedded; the optional
-/// `audit:` section of armadai.yaml overrides them (Task 11).
+/// `audit:` section of armadai.yaml overrides them (Task 11) and declares
+/// custom rules.
 #[derive(Debug, Clone)]
 pub struct AuditSettings {
     /// A05: estimated token count above which a prompt is flagged.
@@ -58,6 +75,8 @@ pub struct AuditSettings {
     /// default, so existing configs are unaffected. `--no-usage` on the CLI
     /// always wins over this when both are set — see `cli::audit::execute`.
     pub usage: bool,
+    /// Project-defined rules (`audit.rules:`), run after the built-in ones.
+    pub rules: Vec<custom::CustomRule>,
 }
 
 impl Default for AuditSettings {
@@ -67,14 +86,17 @@ impl Default for AuditSettings {
             activation_similarity: 0.6,
             deep_prompt_truncation: 2000,
             usage: true,
+            rules: Vec::new(),
         }
     }
 }
 
 impl AuditSettings {
     /// Read the optional `audit:` section of the project config, if any.
-    /// Missing file, missing section or unreadable YAML all yield defaults.
-    pub fn from_project(root: &std::path::Path) -> Self {
+    /// Missing file, missing section or unreadable YAML all yield defaults;
+    /// an invalid custom rule is an error, since dropping it silently would
+    /// let the audit pass on exactly what the rule was written to catch.
+    pub fn from_project(root: &std::path::Path) -> anyhow::Result<Self> {
         #[derive(serde::Deserialize, Default)]
         #[serde(default)]
         struct AuditYaml {
@@ -87,6 +109,7 @@ impl AuditSettings {
             activation_similarity: Option<f64>,
             deep_prompt_truncation: Option<usize>,
             usage: Option<bool>,
+            rules: Option<serde_yaml_ng::Value>,
         }
         let mut settings = Self::default();
         for candidate in ["armadai.yaml", ".armadai/config.yaml"] {
@@ -108,10 +131,14 @@ impl AuditSettings {
                 if let Some(u) = section.usage {
                     settings.usage = u;
                 }
+                if let Some(rules) = section.rules {
+                    settings.rules = custom::parse_rules(rules)
+                        .map_err(|e| e.context(format!("in {}", root.join(candidate).display())))?;
+                }
             }
             break;
         }
-        settings
+        Ok(settings)
     }
 }
 
@@ -151,7 +178,8 @@ pub struct AuditContext<'a> {
 
 type RuleFn = fn(&AuditContext) -> Vec<Finding>;
 
-/// Static rule registry: adding a rule = one module + one entry here.
+/// Static rule registry: adding a rule = one module + one entry here (and
+/// one in `CATALOG`).
 fn registry() -> Vec<RuleFn> {
     vec![
         assets::a01_unparsable,
@@ -178,13 +206,212 @@ fn registry() -> Vec<RuleFn> {
     ]
 }
 
-/// Run every registered rule and return findings sorted by severity then file.
+/// Static description of one rule id, for machine-readable reports.
+pub struct RuleInfo {
+    pub id: &'static str,
+    /// PascalCase name (SARIF `reportingDescriptor.name`).
+    pub name: &'static str,
+    pub summary: &'static str,
+    /// Usual severity; a few rules raise or lower it per finding.
+    pub severity: Severity,
+}
+
+const fn rule(
+    id: &'static str,
+    name: &'static str,
+    summary: &'static str,
+    severity: Severity,
+) -> RuleInfo {
+    RuleInfo {
+        id,
+        name,
+        summary,
+        severity,
+    }
+}
+
+/// Every rule id a finding can carry, static rules and the deep pass alike.
+/// A new rule needs an entry here as well as in `registry`.
+pub const CATALOG: &[RuleInfo] = &[
+    rule(
+        "A01",
+        "Unparsable",
+        "A native file could not be fully parsed.",
+        Severity::Critical,
+    ),
+    rule(
+        "A02",
+        "MissingFields",
+        "Required descriptive fields are missing.",
+        Severity::Warning,
+    ),
+    rule(
+        "A03",
+        "DeprecatedModel",
+        "The model is a known deprecated alias.",
+        Severity::Critical,
+    ),
+    rule(
+        "A04",
+        "UnknownModel",
+        "The model is absent from the models.dev catalog.",
+        Severity::Warning,
+    ),
+    rule(
+        "A05",
+        "OversizedPrompt",
+        "The system prompt exceeds the token threshold.",
+        Severity::Warning,
+    ),
+    rule(
+        "A06",
+        "DuplicatedBlocks",
+        "Agents share duplicated prompt content.",
+        Severity::Warning,
+    ),
+    rule(
+        "A07",
+        "RedundantAgents",
+        "Two agents look interchangeable.",
+        Severity::Info,
+    ),
+    rule(
+        "A08",
+        "PermissiveTools",
+        "Agents run without any tool restriction.",
+        Severity::Warning,
+    ),
+    rule(
+        "A09",
+        "MalformedSkill",
+        "A skill does not follow the Agent Skills layout.",
+        Severity::Warning,
+    ),
+    rule(
+        "A10",
+        "BrokenReference",
+        "The instructions mention an @agent that does not exist.",
+        Severity::Warning,
+    ),
+    rule(
+        "A11",
+        "PlaintextSecret",
+        "A prompt contains what looks like a plaintext API key.",
+        Severity::Critical,
+    ),
+    rule(
+        "A12",
+        "NonstandardFields",
+        "Frontmatter carries fields the CLI does not document.",
+        Severity::Info,
+    ),
+    rule(
+        "C01",
+        "NameCollision",
+        "Two assets claim the same name.",
+        Severity::Critical,
+    ),
+    rule(
+        "C02",
+        "ScopeOverlap",
+        "Agents claim overlapping path scopes.",
+        Severity::Warning,
+    ),
+    rule(
+        "C03",
+        "ActivationOverlap",
+        "Activation descriptions are too similar to route reliably.",
+        Severity::Warning,
+    ),
+    rule(
+        "C04",
+        "DoubleOwnership",
+        "Two agents are declared owners of the same module.",
+        Severity::Warning,
+    ),
+    rule(
+        "C05",
+        "InconsistentTools",
+        "Agents with the same scope restrict tools differently.",
+        Severity::Warning,
+    ),
+    rule(
+        "U01",
+        "DeclaredNeverUsed",
+        "A declared agent or skill never ran in the observed sessions.",
+        Severity::Warning,
+    ),
+    rule(
+        "U02",
+        "UsedButUndeclared",
+        "A sub-agent ran without being declared in the project.",
+        Severity::Info,
+    ),
+    rule(
+        "U03",
+        "CoordinatorBypassed",
+        "Delegations bypass the coordinator the instructions name.",
+        Severity::Warning,
+    ),
+    rule(
+        "U04",
+        "SkillActivity",
+        "How many turns a declared skill governed.",
+        Severity::Info,
+    ),
+    rule(
+        "D01",
+        "RoleOverlap",
+        "Agents' responsibilities overlap (deep pass).",
+        Severity::Warning,
+    ),
+    rule(
+        "D02",
+        "VaguePrompt",
+        "A system prompt is vague or contradictory (deep pass).",
+        Severity::Warning,
+    ),
+    rule(
+        "D03",
+        "SemanticDuplication",
+        "Content expresses the same idea in several places (deep pass).",
+        Severity::Info,
+    ),
+    rule(
+        "D04",
+        "TeamTopology",
+        "A coordinator and teams structure is suggested (deep pass).",
+        Severity::Info,
+    ),
+    rule(
+        "D05",
+        "InstructionsContradiction",
+        "The root instructions contradict an agent's prompt (deep pass).",
+        Severity::Warning,
+    ),
+];
+
+/// Run every registered rule, then the project's custom rules, and return
+/// findings sorted by severity then file.
 pub fn run_rules(ctx: &AuditContext) -> Vec<Finding> {
     let mut findings: Vec<Finding> = registry().iter().flat_map(|rule| rule(ctx)).collect();
+    findings.extend(
+        ctx.settings
+            .rules
+            .iter()
+            .flat_map(|rule| rule.evaluate(ctx)),
+    );
     findings.sort_by(|a, b| (a.severity, &a.file, a.rule).cmp(&(b.severity, &b.file, b.rule)));
     findings
 }
 
+/// 1-based number of the first line of `path` matching `pred`, for
+/// findings that anchor to a line. `None` when the file cannot be read.
+pub(super) fn line_in(path: &std::path::Path, pred: impl Fn(&str) -> bool) -> Option<usize> {
+    let text = std::fs::read_to_string(path).ok()?;
+    text.lines().position(pred).map(|i| i + 1)
+}
+
 /// Rough token estimate (chars / 4) — good enough for thresholds and savings.
 pub(crate) fn estimate_tokens(text: &str) -> usize {
     text.chars().count() / 4
@@ -230,6 +457,19 @@ mod tests {
         assert!(Severity::Warning < Severity::Info);
     }
 
+    #[test]
+    fn catalog_covers_every_registered_and_deep_rule() {
+        assert_eq!(
+            CATALOG.len(),
+            registry().len() + crate::audit::deep::DEEP_RULES.len()
+        );
+        for id in crate::audit::deep::DEEP_RULES {
+            assert!(CATALOG.iter().any(|r| r.id == id), "{id}");
+        }
+        let ids: std::collections::HashSet<&str> = CATALOG.iter().map(|r| r.id).collect();
+        assert_eq!(ids.len(), CATALOG.len(), "duplicate rule id in CATALOG");
+    }
+
     #[test]
     fn estimate_tokens_is_chars_over_four() {
         assert_eq!(estimate_tokens("abcdefgh"), 2);
@@ -255,17 +495,48 @@ mod tests {
             "audit:\n  prompt_token_threshold: 1234\n  activation_similarity: 0.75\n  deep_prompt_truncation: 500\n  usage: false\n",
         )
         .unwrap();
-        let s = AuditSettings::from_project(dir.path());
+        let s = AuditSettings::from_project(dir.path()).unwrap();
         assert_eq!(s.prompt_token_threshold, 1234);
         assert!((s.activation_similarity - 0.75).abs() < f64::EPSILON);
         assert_eq!(s.deep_prompt_truncation, 500);
         assert!(!s.usage, "usage: false in config must be honoured");
     }
 
+    #[test]
+    fn custom_rules_run_with_the_built_in_ones() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("armadai.yaml"),
+            "audit:\n  rules:\n    - id: TEAM01\n      severity: critical\n      forbidden_models: [\"claude-sonnet-*\"]\n",
+        )
+        .unwrap();
+        let settings = AuditSettings::from_project(dir.path()).unwrap();
+        let config = test_support::config_with(vec![test_support::agent("a", "P.")]);
+        let findings = run_rules(&AuditContext {
+            config: &config,
+            settings: &settings,
+            usage: None,
+        });
+        let f = findings.iter().find(|f| f.rule == "TEAM01").unwrap();
+        assert_eq!(f.severity, Severity::Critical);
+        assert_eq!(
+            f.message,
+            "agent 'a' uses forbidden model 'claude-sonnet-5'"
+        );
+
+        std::fs::write(
+            dir.path().join("armadai.yaml"),
+            "audit:\n  rules:\n    - id: TEAM01\n",
+        )
+        .unwrap();
+        let err = AuditSettings::from_project(dir.path()).unwrap_err();
+        assert!(format!("{err:#}").contains("invalid audit rule 'TEAM01'"));
+    }
+
     #[test]
     fn from_project_defaults_without_config() {
         let dir = tempfile::tempdir().unwrap();
-        let s = AuditSettings::from_project(dir.path());
+        let s = AuditSettings::from_project(dir.path()).unwrap();
         assert_eq!(s.prompt_token_threshold, 4000);
         assert!((s.activation_similarity - 0.6).abs() < f64::EPSILON);
         assert_eq!(s.deep_prompt_truncation, 2000);
@@ -281,9 +552,11 @@ mod tests {
             rule: "A06",
             severity: Severity::Warning,
             file: "a.md".into(),
+            line: None,
             related: vec!["b.md".into(), "c.md".into()],
             message: String::new(),
             suggestion: None,
+            edits: Vec::new(),
         };
         assert_eq!(f.related.len(), 2);
     }
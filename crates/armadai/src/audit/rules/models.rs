@@ -1,4 +1,9 @@
-use super::{AuditContext, Finding, Severity};
+use super::{AuditContext, Edit, Finding, Severity, line_in};
+
+/// Line of `path` declaring `model`.
+pub(super) fn model_line(path: &std::path::Path, model: &str) -> Option<usize> {
+    line_in(path, |l| l.contains("model") && l.contains(model))
+}
 
 /// A03 — model is a known deprecated alias.
 /// Deliberately fires on salvaged values too: a deprecated model is a
@@ -17,13 +22,27 @@ pub(super) fn a03_with_resolver(
         .filter_map(|a| {
             let model = a.metadata.model.as_deref()?;
             let replacement = resolve(model)?;
+            let line = model_line(&a.source_path, model);
+            // Native files need a concrete id; a portable tier is advice only.
+            let edits = line
+                .filter(|_| !replacement.starts_with("latest:"))
+                .map(|line| Edit {
+                    file: a.source_path.clone(),
+                    line,
+                    old: model.to_string(),
+                    new: replacement.clone(),
+                })
+                .into_iter()
+                .collect();
             Some(Finding {
                 rule: "A03",
                 severity: Severity::Critical,
                 file: a.source_path.clone(),
+                line,
                 related: Vec::new(),
                 message: format!("agent '{}' uses deprecated model '{model}'", a.name),
                 suggestion: Some(format!("replace with '{replacement}'")),
+                edits,
             })
         })
         .collect()
@@ -66,9 +85,11 @@ pub(super) fn a04_with_catalog<F: Fn(&str) -> bool>(
                 rule: "A04",
                 severity: Severity::Warning,
                 file: a.source_path.clone(),
+                line: model_line(&a.source_path, model),
                 related: Vec::new(),
                 message: format!("agent '{}' uses unknown model '{model}'", a.name),
                 suggestion: Some("check the spelling against `armadai models`".to_string()),
+                edits: Vec::new(),
             })
         })
         .collect()
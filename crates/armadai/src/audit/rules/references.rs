@@ -3,7 +3,8 @@ use std::sync::OnceLock;
 
 use regex::Regex;
 
-use super::{AuditContext, Finding, Severity};
+use super::{AuditContext, Edit, Finding, Severity, line_in};
+use crate::text::edit_distance;
 
 fn mention_re() -> &'static Regex {
     static RE: OnceLock<Regex> = OnceLock::new();
@@ -49,17 +50,128 @@ pub(super) fn a10_broken_references(ctx: &AuditContext) -> Vec<Finding> {
         .filter_map(|c| c.get(1))
         .map(|m| m.as_str())
         .filter(|slug| !known.contains(slug) && seen.insert(slug.to_string()))
-        .map(|slug| Finding {
-            rule: "A10",
-            severity: Severity::Warning,
-            file: instructions.source_path.clone(),
-            related: Vec::new(),
-            message: format!("mentions '@{slug}' but no such agent exists"),
-            suggestion: Some("create the agent or remove the stale mention".to_string()),
+        .map(|slug| {
+            let mention = format!("@{slug}");
+            let lines: Vec<(usize, usize)> = instructions
+                .content
+                .lines()
+                .enumerate()
+                .filter_map(|(i, l)| {
+                    let n = mention_re()
+                        .captures_iter(l)
+                        .filter(|c| c.get(1).is_some_and(|m| m.as_str() == slug))
+                        .count();
+                    (n > 0).then_some((i + 1, n))
+                })
+                .collect();
+            let target = closest_name(slug, &known);
+            let edits = match target {
+                // Skip the fix when a longer mention shares the prefix:
+                // replacing the first `@slug` on that line would hit it.
+                Some(target)
+                    if lines.iter().all(|(i, n)| {
+                        instructions
+                            .content
+                            .lines()
+                            .nth(i - 1)
+                            .is_some_and(|l| l.matches(&mention).count() == *n)
+                    }) =>
+                {
+                    lines
+                        .iter()
+                        .flat_map(|(line, n)| {
+                            std::iter::repeat_n(
+                                Edit {
+                                    file: instructions.source_path.clone(),
+                                    line: *line,
+                                    old: mention.clone(),
+                                    new: format!("@{target}"),
+                                },
+                                *n,
+                            )
+                        })
+                        .collect()
+                }
+                _ => Vec::new(),
+            };
+            Finding {
+                rule: "A10",
+                severity: Severity::Warning,
+                file: instructions.source_path.clone(),
+                line: lines.first().map(|(line, _)| *line),
+                related: Vec::new(),
+                message: format!("mentions '@{slug}' but no such agent exists"),
+                suggestion: Some(match target {
+                    Some(target) => format!("did you mean '@{target}'?"),
+                    None => "create the agent or remove the stale mention".to_string(),
+                }),
+                edits,
+            }
         })
         .collect()
 }
 
+/// The one known agent name within two edits of `slug`, if exactly one is.
+fn closest_name<'a>(slug: &str, known: &HashSet<&'a str>) -> Option<&'a str> {
+    let mut close = known.iter().filter(|name| edit_distance(slug, name) <= 2);
+    let first = close.next()?;
+    close.next().is_none().then_some(*first)
+}
+
+/// Environment variable conventionally holding a key of this shape. A key
+/// of no known provider is named after its own prefix (`xyz_...` becomes
+/// `XYZ_API_KEY`).
+fn secret_env_var(secret: &str) -> String {
+    let known = if secret.starts_with("sk-ant-") {
+        "ANTHROPIC_API_KEY"
+    } else if secret.starts_with("sk-") {
+        "OPENAI_API_KEY"
+    } else if secret.starts_with("AIza") {
+        "GEMINI_API_KEY"
+    } else if secret.starts_with("ghp_") {
+        "GITHUB_TOKEN"
+    } else {
+        let prefix: String = secret
+            .chars()
+            .take_while(char::is_ascii_alphanumeric)
+            .collect();
+        return if prefix.is_empty() || prefix.len() == secret.len() {
+            "API_KEY".to_string()
+        } else {
+            format!("{}_API_KEY", prefix.to_ascii_uppercase())
+        };
+    };
+    known.to_string()
+}
+
+/// Replace every key in `path` with a reference to its usual variable.
+fn secret_edits(path: &std::path::Path) -> Vec<Edit> {
+    let Ok(text) = std::fs::read_to_string(path) else {
+        return Vec::new();
+    };
+    let mut edits = Vec::new();
+    for (i, l) in text.lines().enumerate() {
+        for re in secret_res() {
+            for m in re.find_iter(l) {
+                let edit = Edit {
+                    file: path.to_path_buf(),
+                    line: i + 1,
+                    old: m.as_str().to_string(),
+                    new: format!("${{{}}}", secret_env_var(m.as_str())),
+                };
+                // `sk-` also matches inside an `sk-ant-` key's span.
+                if !edits
+                    .iter()
+                    .any(|e: &Edit| e.line == edit.line && e.old.contains(&edit.old))
+                {
+                    edits.push(edit);
+                }
+            }
+        }
+    }
+    edits
+}
+
 /// A11 — plaintext API key patterns inside prompts or instructions.
 /// The finding never echoes the matched secret.
 pub(super) fn a11_plaintext_secret(ctx: &AuditContext) -> Vec<Finding> {
@@ -82,9 +194,11 @@ pub(super) fn a11_plaintext_secret(ctx: &AuditContext) -> Vec<Finding> {
             rule: "A11",
             severity: Severity::Critical,
             file: path.to_path_buf(),
+            line: line_in(path, |l| secret_res().iter().any(|re| re.is_match(l))),
             related: Vec::new(),
             message: "contains what looks like a plaintext API key".to_string(),
             suggestion: Some("move the secret to an env var or a secrets manager".to_string()),
+            edits: secret_edits(path),
         })
         .collect()
 }
@@ -114,6 +228,28 @@ mod tests {
         assert_eq!(f[0].severity, Severity::Warning);
     }
 
+    #[test]
+    fn a10_fixes_a_near_miss_mention() {
+        let mut config = config_with(vec![agent("reviewer", "Body")]);
+        config.instructions = Some(ImportedInstructions {
+            source_path: "CLAUDE.md".into(),
+            content: "Ask @reveiwer.\nThen @reveiwer again, and @reveiwer twice.".into(),
+        });
+        let settings = AuditSettings::default();
+        let f = a10_broken_references(&AuditContext {
+            config: &config,
+            settings: &settings,
+            usage: None,
+        });
+        assert_eq!(
+            f[0].suggestion.as_deref(),
+            Some("did you mean '@reviewer'?")
+        );
+        let lines: Vec<usize> = f[0].edits.iter().map(|e| e.line).collect();
+        assert_eq!(lines, vec![1, 2, 2]);
+        assert!(f[0].edits.iter().all(|e| e.new == "@reviewer"));
+    }
+
     #[test]
     fn a10_is_silent_without_imported_agents() {
         let mut config = config_with(vec![]);
@@ -180,4 +316,14 @@ mod tests {
         assert_eq!(f[0].rule, "A11");
         assert!(!f[0].message.contains(&key));
     }
+
+    #[test]
+    fn secret_env_var_follows_the_key_prefix() {
+        assert_eq!(secret_env_var("sk-ant-abc"), "ANTHROPIC_API_KEY");
+        assert_eq!(secret_env_var("sk-proj-abc"), "OPENAI_API_KEY");
+        assert_eq!(secret_env_var("AIzaabc"), "GEMINI_API_KEY");
+        assert_eq!(secret_env_var("ghp_abc"), "GITHUB_TOKEN");
+        assert_eq!(secret_env_var("xai-abc"), "XAI_API_KEY");
+        assert_eq!(secret_env_var("abc123"), "API_KEY");
+    }
 }
@@ -90,6 +90,7 @@ pub(super) fn a06_duplicated_blocks(ctx: &AuditContext) -> Vec<Finding> {
                 rule: "A06",
                 severity: Severity::Warning,
                 file: agents[members[0]].source_path.clone(),
+                line: None,
                 related: members[1..]
                     .iter()
                     .map(|&i| agents[i].source_path.clone())
@@ -102,6 +103,7 @@ pub(super) fn a06_duplicated_blocks(ctx: &AuditContext) -> Vec<Finding> {
                 suggestion: Some(
                     "extract the shared block into one reusable prompt fragment".to_string(),
                 ),
+                edits: Vec::new(),
             }
         })
         .collect()
@@ -124,12 +126,14 @@ pub(super) fn a07_redundant_agents(ctx: &AuditContext) -> Vec<Finding> {
                     rule: "A07",
                     severity: Severity::Info,
                     file: agents[i].source_path.clone(),
+                    line: None,
                     related: Vec::new(),
                     message: format!(
                         "agents '{}' and '{}' have near-identical descriptions",
                         agents[i].name, agents[j].name
                     ),
                     suggestion: Some("consider merging them or sharpening their roles".to_string()),
+                    edits: Vec::new(),
                 });
             }
         }
@@ -15,6 +15,20 @@ use crate::audit::usage::facts::ROOT_AGENT;
 /// CLI's own thread, not a declarable asset.
 const BUILTIN_AGENTS: &[&str] = &["general-purpose", "Explore", "Plan", "claude"];
 
+/// The same for Gemini CLI and Codex, whose usage is audited too.
+const GEMINI_BUILTIN_AGENTS: &[&str] = &["codebase_investigator"];
+const CODEX_BUILTIN_AGENTS: &[&str] = &["default", "explorer", "worker"];
+
+/// Where the CLI that produced `usage` declares sub-agents, its display
+/// name, and the sub-agents it provides itself.
+fn native_cli(usage: &UsageFacts) -> (&'static str, &'static str, &'static [&'static str]) {
+    match usage.cli() {
+        "gemini" => ("`.gemini/agents/`", "Gemini CLI", GEMINI_BUILTIN_AGENTS),
+        "codex" => ("`.codex/config.toml`", "Codex", CODEX_BUILTIN_AGENTS),
+        _ => ("`.claude/agents/`", "Claude Code", BUILTIN_AGENTS),
+    }
+}
+
 /// Share of delegations below which a declared coordinator counts as bypassed.
 const COORDINATOR_SHARE: f64 = 0.5;
 
@@ -48,12 +62,14 @@ pub(super) fn u01_declared_never_used(ctx: &AuditContext) -> Vec<Finding> {
             rule: "U01",
             severity: Severity::Warning,
             file: agent.source_path.clone(),
+            line: None,
             related: vec![],
             message: format!(
                 "agent '{}' is declared but was never invoked across {} observed session(s)",
                 agent.name, usage.sessions
             ),
             suggestion: Some(UNUSED_ASSET_SUGGESTION.to_string()),
+            edits: Vec::new(),
         });
     }
     for skill in &ctx.config.skills {
@@ -64,12 +80,14 @@ pub(super) fn u01_declared_never_used(ctx: &AuditContext) -> Vec<Finding> {
             rule: "U01",
             severity: Severity::Warning,
             file: skill.source_path.clone(),
+            line: None,
             related: vec![],
             message: format!(
                 "skill '{}' is declared but was never used across {} observed session(s)",
                 skill.name, usage.sessions
             ),
             suggestion: Some(UNUSED_ASSET_SUGGESTION.to_string()),
+            edits: Vec::new(),
         });
     }
     findings
@@ -81,16 +99,17 @@ pub(super) fn u02_used_but_undeclared(ctx: &AuditContext) -> Vec<Finding> {
         return Vec::new();
     };
     let declared: Vec<&str> = ctx.config.agents.iter().map(|a| a.name.as_str()).collect();
+    let (agents_dir, cli, builtins) = native_cli(usage);
     let mut findings = Vec::new();
     for (name, stats) in &usage.agents {
-        if name == ROOT_AGENT {
+        if name == ROOT_AGENT || name == usage.cli() {
             // The native CLI's own thread, not a declarable agent asset.
             continue;
         }
         if declared.contains(&name.as_str()) {
             continue;
         }
-        let builtin = BUILTIN_AGENTS.contains(&name.as_str());
+        let builtin = builtins.contains(&name.as_str());
         findings.push(Finding {
             rule: "U02",
             severity: Severity::Info,
@@ -100,14 +119,15 @@ pub(super) fn u02_used_but_undeclared(ctx: &AuditContext) -> Vec<Finding> {
                 .as_ref()
                 .map(|i| i.source_path.clone())
                 .unwrap_or_else(|| PathBuf::from(".")),
+            line: None,
             related: vec![],
-            // Not "declared nowhere": this check only sees `.claude/agents/`
-            // on this project, so a plugin-provided agent (invisible on that
-            // side by design, out of scope for this check) would also land
-            // here — the message must not claim more than the code knows.
+            // Not "declared nowhere": this check only sees the CLI's own
+            // declarations (`.claude/agents/`, ...) on this project, so a
+            // plugin-provided agent (invisible on that side by design, out
+            // of scope for this check) would also land here — the message
+            // must not claim more than the code knows.
             message: format!(
-                "sub-agent '{}' ran {} but is not declared in this project's \
-                 `.claude/agents/`{}",
+                "sub-agent '{}' ran {} but is not declared in this project's {}{}",
                 name,
                 // `invocations` counts main-thread delegations only. An agent
                 // spawned solely from inside another sub-agent has none, so
@@ -118,11 +138,13 @@ pub(super) fn u02_used_but_undeclared(ctx: &AuditContext) -> Vec<Finding> {
                 } else {
                     format!("{} time(s)", stats.invocations)
                 },
+                agents_dir,
                 if builtin {
-                    " (it is built into Claude Code)"
+                    format!(" (it is built into {cli})")
                 } else {
                     " (if this isn't a typo, it may come from a plugin, which is out of scope \
                      for this check)"
+                        .to_string()
                 }
             ),
             suggestion: Some(
@@ -130,6 +152,7 @@ pub(super) fn u02_used_but_undeclared(ctx: &AuditContext) -> Vec<Finding> {
                  so a migrated fleet keeps the same workers"
                     .to_string(),
             ),
+            edits: Vec::new(),
         });
     }
     findings
@@ -201,11 +224,7 @@ pub(super) fn u03_coordinator_bypassed(ctx: &AuditContext) -> Vec<Finding> {
     // least once", not how many times, since `edges` is a `BTreeSet` with no
     // per-edge counter. Getting the exact count would need one; that's a
     // concern for a future lot, not this fix.
-    let root = if usage.root_agent.is_empty() {
-        ROOT_AGENT
-    } else {
-        usage.root_agent.as_str()
-    };
+    let root = usage.cli();
     let total: u32 = usage
         .edges
         .get(root)
@@ -242,6 +261,7 @@ pub(super) fn u03_coordinator_bypassed(ctx: &AuditContext) -> Vec<Finding> {
         rule: "U03",
         severity: Severity::Warning,
         file: instructions.source_path.clone(),
+        line: None,
         related: vec![agent.source_path.clone()],
         message: format!(
             "'{}' is named as coordinator but received {}/{} delegation(s) ({:.0}%)",
@@ -255,6 +275,7 @@ pub(super) fn u03_coordinator_bypassed(ctx: &AuditContext) -> Vec<Finding> {
              --propose emits the observed root, with this one kept as a comment"
                 .to_string(),
         ),
+        edits: Vec::new(),
     }]
 }
 
@@ -284,12 +305,14 @@ pub(super) fn u04_skill_activity(ctx: &AuditContext) -> Vec<Finding> {
             rule: "U04",
             severity: Severity::Info,
             file: skill.source_path.clone(),
+            line: None,
             related: vec![],
             message: format!(
                 "skill '{}' governed {} turn(s) across {} scanned session(s)",
                 skill.name, turns, usage.sessions
             ),
             suggestion: None,
+            edits: Vec::new(),
         });
     }
     findings
@@ -448,6 +471,31 @@ mod tests {
         assert!(f[0].message.contains("general-purpose"));
     }
 
+    #[test]
+    fn u02_names_the_cli_the_usage_came_from() {
+        let config = config_with(vec![agent("qa", "p")]);
+        let settings = AuditSettings::default();
+        let mut usage = UsageFacts {
+            root_agent: "gemini".to_string(),
+            ..Default::default()
+        };
+        usage.record_delegation("gemini", "gemini", "m");
+        usage.record_delegation("gemini", "codebase_investigator", "m");
+
+        let f = u02_used_but_undeclared(&ctx(&config, &settings, &usage));
+        assert_eq!(f.len(), 1, "the Gemini main thread is skipped: {f:?}");
+        assert!(
+            f[0].message.contains("`.gemini/agents/`"),
+            "{}",
+            f[0].message
+        );
+        assert!(
+            f[0].message.contains("built into Gemini CLI"),
+            "{}",
+            f[0].message
+        );
+    }
+
     #[test]
     fn u03_flags_a_bypassed_declared_coordinator() {
         let mut config = config_with(vec![agent("dev-lead", "p"), agent("qa", "p")]);
@@ -0,0 +1,273 @@
+//! Observed usage from Codex session rollouts.
+//!
+//! Codex writes one JSONL rollout per session under
+//! `~/.codex/sessions/YYYY/MM/DD/rollout-*.jsonl`, for every project alike.
+//! Each line is `{timestamp, type, payload}`: the `session_meta` line that
+//! opens the file states the session's `cwd`, `turn_context` lines carry the
+//! model in use, and `response_item` lines carry the tool calls.
+use std::io::BufRead;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use regex::Regex;
+use serde_json::Value;
+
+use super::discovery::root_forms;
+use super::facts::UsageFacts;
+use super::scan::str_field;
+
+/// The Codex main thread, root of its delegation tree.
+pub const ROOT_AGENT: &str = "codex";
+
+/// Tool spawning a sub-agent; its role is the `agent_type` argument.
+const SPAWN_TOOL: &str = "spawn_agent";
+
+/// Lines read looking for `session_meta` before a file is given up on.
+const MAX_HEAD_LINES: usize = 20;
+
+/// Root holding Codex's dated session directories. `ARMADAI_CODEX_SESSIONS_DIR`
+/// overrides it (used by tests), then `CODEX_HOME`.
+pub fn sessions_root() -> Option<PathBuf> {
+    if let Ok(dir) = std::env::var("ARMADAI_CODEX_SESSIONS_DIR") {
+        return Some(PathBuf::from(dir));
+    }
+    if let Ok(home) = std::env::var("CODEX_HOME") {
+        return Some(PathBuf::from(home).join("sessions"));
+    }
+    let home = std::env::var("HOME").ok()?;
+    Some(PathBuf::from(home).join(".codex").join("sessions"))
+}
+
+/// Every rollout under `dir`, down the `YYYY/MM/DD` levels.
+fn rollouts_in(dir: &Path, depth: usize, out: &mut Vec<PathBuf>) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    for path in entries.flatten().map(|e| e.path()) {
+        if path.is_dir() {
+            if depth > 0 {
+                rollouts_in(&path, depth - 1, out);
+            }
+        } else if path.extension().is_some_and(|e| e == "jsonl")
+            && path
+                .file_name()
+                .is_some_and(|n| n.to_string_lossy().starts_with("rollout-"))
+        {
+            out.push(path);
+        }
+    }
+}
+
+/// Every rollout on this machine, oldest first: the dated directories and
+/// the timestamp opening each file name sort chronologically.
+pub fn rollouts() -> Vec<PathBuf> {
+    let Some(sessions) = sessions_root() else {
+        return Vec::new();
+    };
+    let mut all = Vec::new();
+    rollouts_in(&sessions, 3, &mut all);
+    all.sort();
+    all
+}
+
+/// The payload of the `session_meta` line opening a rollout, if any.
+pub fn session_meta(file: &Path) -> Option<Value> {
+    let handle = std::fs::File::open(file).ok()?;
+    for line in std::io::BufReader::new(handle).lines().take(MAX_HEAD_LINES) {
+        let Ok(line) = line else { continue };
+        let Ok(mut v) = serde_json::from_str::<Value>(&line) else {
+            continue;
+        };
+        if str_field(&v, "type") == Some("session_meta") {
+            return v.get_mut("payload").map(Value::take);
+        }
+    }
+    None
+}
+
+/// The `cwd` a rollout's `session_meta` line declares, if any.
+fn session_cwd(file: &Path) -> Option<String> {
+    let meta = session_meta(file)?;
+    let cwd = str_field(&meta, "cwd")?;
+    Some(cwd.trim_end_matches(['/', '\\']).to_string())
+}
+
+/// Every rollout whose session ran in `root`. Rollouts of all projects
+/// share one tree, so each file's head is read to find its `cwd`.
+pub fn session_files(root: &Path) -> Vec<PathBuf> {
+    let forms = root_forms(root);
+    rollouts()
+        .into_iter()
+        .filter(|f| session_cwd(f).is_some_and(|cwd| forms.contains(&cwd)))
+        .collect()
+}
+
+/// `skills/<name>/SKILL.md` in a tool call: Codex has no skill tool, it
+/// reads the skill's file when the skill applies.
+fn skill_re() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(r"skills[/\\]([A-Za-z0-9_.-]+)[/\\]SKILL\.md")
+            .unwrap_or_else(|e| unreachable!("hardcoded regex must compile: {e}"))
+    })
+}
+
+/// Aggregate every rollout belonging to `root`, streaming line by line.
+pub fn scan(root: &Path) -> UsageFacts {
+    let mut facts = UsageFacts {
+        root_agent: ROOT_AGENT.to_string(),
+        ..Default::default()
+    };
+    for file in session_files(root) {
+        let Ok(handle) = std::fs::File::open(&file) else {
+            continue;
+        };
+        facts.sessions += 1;
+        let mut model = String::new();
+        for line in std::io::BufReader::new(handle).lines() {
+            let line = match line {
+                Ok(line) => line,
+                Err(e) if e.kind() == std::io::ErrorKind::InvalidData => continue,
+                Err(_) => break,
+            };
+            let Ok(v) = serde_json::from_str::<Value>(&line) else {
+                continue;
+            };
+            scan_line(&v, &mut model, &mut facts);
+        }
+    }
+    facts
+}
+
+fn scan_line(v: &Value, model: &mut String, facts: &mut UsageFacts) {
+    if let Some(ts) = str_field(v, "timestamp") {
+        facts.observe_timestamp(ts);
+    }
+    let Some(payload) = v.get("payload") else {
+        return;
+    };
+    match str_field(v, "type") {
+        Some("turn_context") => {
+            if let Some(m) = str_field(payload, "model") {
+                *model = m.to_string();
+            }
+        }
+        Some("response_item") => {
+            let (name, input) = match str_field(payload, "type") {
+                Some("function_call") => (
+                    str_field(payload, "name"),
+                    str_field(payload, "arguments")
+                        .unwrap_or_default()
+                        .to_string(),
+                ),
+                Some("custom_tool_call") => (
+                    str_field(payload, "name"),
+                    str_field(payload, "input").unwrap_or_default().to_string(),
+                ),
+                Some("local_shell_call") => (
+                    Some("local_shell"),
+                    payload
+                        .get("action")
+                        .map(Value::to_string)
+                        .unwrap_or_default(),
+                ),
+                _ => return,
+            };
+            let Some(name) = name else {
+                return;
+            };
+            facts.record_tool(name);
+            if name == SPAWN_TOOL {
+                let args: Value = serde_json::from_str(&input).unwrap_or(Value::Null);
+                let role = str_field(&args, "agent_type").unwrap_or("default");
+                facts.record_delegation(ROOT_AGENT, role, model);
+            }
+            let mut seen = Vec::new();
+            for c in skill_re().captures_iter(&input) {
+                let Some(skill) = c.get(1).map(|m| m.as_str()) else {
+                    continue;
+                };
+                if !seen.contains(&skill) {
+                    seen.push(skill);
+                    facts.record_skill_turn(skill);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Points `ARMADAI_CODEX_SESSIONS_DIR` at a test directory for the guard's lifetime,
+    /// serialised via `ENV_MUTEX` like `discovery`'s `ProjectsDirGuard`, and
+    /// unset on drop even when an assertion panics.
+    struct CodexSessionsDirGuard {
+        _lock: std::sync::MutexGuard<'static, ()>,
+    }
+
+    impl CodexSessionsDirGuard {
+        fn set(path: &Path) -> Self {
+            let lock = armadai_core::config::ENV_MUTEX.lock().unwrap();
+            // SAFETY: modifies the global environment; serialised via ENV_MUTEX.
+            unsafe { std::env::set_var("ARMADAI_CODEX_SESSIONS_DIR", path) }
+            Self { _lock: lock }
+        }
+    }
+
+    impl Drop for CodexSessionsDirGuard {
+        fn drop(&mut self) {
+            // SAFETY: restoring env state at end of test scope.
+            unsafe { std::env::remove_var("ARMADAI_CODEX_SESSIONS_DIR") }
+        }
+    }
+
+    #[test]
+    fn reads_spawns_skills_and_tools_from_matching_rollouts() {
+        let sessions = tempfile::tempdir().unwrap();
+        let project = tempfile::tempdir().unwrap();
+        let root = project.path().canonicalize().unwrap();
+        let day = sessions.path().join("2026/09/01");
+        std::fs::create_dir_all(&day).unwrap();
+        let meta = |cwd: &str| {
+            format!(
+                r#"{{"timestamp":"2026-09-01T10:00:00Z","type":"session_meta","payload":{{"id":"s","cwd":"{cwd}"}}}}"#
+            )
+        };
+        let lines = [
+            meta(&root.to_string_lossy()),
+            r#"{"timestamp":"2026-09-01T10:00:01Z","type":"turn_context","payload":{"cwd":"x","model":"gpt-5-codex"}}"#.to_string(),
+            r#"{"timestamp":"2026-09-01T10:00:02Z","type":"response_item","payload":{"type":"function_call","name":"spawn_agent","arguments":"{\"agent_type\":\"reviewer\",\"message\":\"go\"}","call_id":"c1"}}"#.to_string(),
+            r#"{"timestamp":"2026-09-01T10:00:03Z","type":"response_item","payload":{"type":"function_call","name":"shell","arguments":"{\"command\":[\"cat\",\".codex/skills/release-notes/SKILL.md\"]}","call_id":"c2"}}"#.to_string(),
+            "not json".to_string(),
+        ];
+        std::fs::write(day.join("rollout-a.jsonl"), lines.join("\n")).unwrap();
+        std::fs::write(
+            day.join("rollout-b.jsonl"),
+            format!(
+                "{}\n{}",
+                meta("/elsewhere"),
+                r#"{"type":"response_item","payload":{"type":"function_call","name":"spawn_agent","arguments":"{}"}}"#
+            ),
+        )
+        .unwrap();
+        let _env = CodexSessionsDirGuard::set(sessions.path());
+        let facts = scan(&root);
+
+        assert_eq!(facts.sessions, 1, "the other project's rollout is ignored");
+        assert_eq!(facts.root_agent, "codex");
+        assert_eq!(facts.agents["reviewer"].invocations, 1);
+        assert_eq!(facts.dominant_model("reviewer"), Some("gpt-5-codex"));
+        assert_eq!(facts.skills["release-notes"], 1);
+        assert_eq!(facts.tools["shell"], 1);
+        assert_eq!(
+            facts.window,
+            Some((
+                "2026-09-01T10:00:00Z".to_string(),
+                "2026-09-01T10:00:03Z".to_string()
+            ))
+        );
+    }
+}
@@ -86,7 +86,7 @@ pub fn transcript_files(root: &Path) -> Vec<PathBuf> {
 /// When `root` is relative and does not canonicalize (e.g. it no longer
 /// exists on disk), this yields no forms at all: nothing here could ever
 /// match, so an empty result is the honest answer rather than a guess.
-fn root_forms(root: &Path) -> Vec<String> {
+pub(super) fn root_forms(root: &Path) -> Vec<String> {
     let mut forms = Vec::new();
     if root.is_absolute() {
         let s = strip_trailing_sep(&root.to_string_lossy());
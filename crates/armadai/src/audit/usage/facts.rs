@@ -217,6 +217,16 @@ impl UsageFacts {
     pub fn is_empty(&self) -> bool {
         self.agents.is_empty() && self.skills.is_empty() && self.tools.is_empty()
     }
+
+    /// The CLI these sessions came from, named like its audit surface
+    /// (`claude`, `gemini`, `codex`).
+    pub fn cli(&self) -> &str {
+        if self.root_agent.is_empty() {
+            ROOT_AGENT
+        } else {
+            &self.root_agent
+        }
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,251 @@
+//! Observed usage from Gemini CLI chat recordings.
+//!
+//! Gemini CLI keeps per-project state under `~/.gemini/tmp/<hash>/`, where
+//! `<hash>` is the SHA-256 of the project's absolute path, and records each
+//! session as one JSON document in `chats/session-*.json`: a `messages`
+//! array whose `gemini` entries carry `model`, `timestamp` and the
+//! `toolCalls` made in that turn. Unlike Claude Code's JSONL, a recording is
+//! rewritten in place, so each file is read whole; they stay small.
+use std::path::{Path, PathBuf};
+
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+use super::discovery::root_forms;
+use super::facts::UsageFacts;
+use super::scan::str_field;
+
+/// The Gemini CLI main thread, root of its delegation tree.
+pub const ROOT_AGENT: &str = "gemini";
+
+/// Tool delegating a turn to a named sub-agent (`agent_name` argument).
+const DELEGATE_TOOL: &str = "delegate_to_agent";
+/// Tool loading a skill's instructions (`name` argument).
+const SKILL_TOOL: &str = "activate_skill";
+
+/// Root holding Gemini CLI's per-project directories.
+/// `ARMADAI_GEMINI_TMP_DIR` overrides it (used by tests).
+pub fn tmp_root() -> Option<PathBuf> {
+    if let Ok(dir) = std::env::var("ARMADAI_GEMINI_TMP_DIR") {
+        return Some(PathBuf::from(dir));
+    }
+    let home = std::env::var("HOME").ok()?;
+    Some(PathBuf::from(home).join(".gemini").join("tmp"))
+}
+
+/// Gemini CLI's directory name for a project path.
+pub fn project_hash(path: &str) -> String {
+    Sha256::digest(path.as_bytes())
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
+
+/// Every chat recording belonging to `root`. Recent Gemini CLI versions
+/// name the directory after the project and record its path in a
+/// `.project_root` file, so those directories are matched on that file
+/// when no hashed directory exists.
+pub fn session_files(root: &Path) -> Vec<PathBuf> {
+    let Some(tmp) = tmp_root() else {
+        return Vec::new();
+    };
+    let forms = root_forms(root);
+    let mut dirs: Vec<PathBuf> = forms
+        .iter()
+        .map(|form| tmp.join(project_hash(form)))
+        .filter(|dir| dir.is_dir())
+        .collect();
+    if dirs.is_empty()
+        && let Ok(entries) = std::fs::read_dir(&tmp)
+    {
+        dirs = entries
+            .flatten()
+            .map(|e| e.path())
+            .filter(|dir| {
+                std::fs::read_to_string(dir.join(".project_root")).is_ok_and(|recorded| {
+                    let recorded = recorded.trim().trim_end_matches(['/', '\\']);
+                    forms.iter().any(|f| f == recorded)
+                })
+            })
+            .collect();
+    }
+    let mut files: Vec<PathBuf> = dirs
+        .iter()
+        .filter_map(|dir| std::fs::read_dir(dir.join("chats")).ok())
+        .flat_map(|entries| entries.flatten().map(|e| e.path()))
+        .filter(|p| {
+            p.extension().is_some_and(|e| e == "json")
+                && p.file_name()
+                    .is_some_and(|n| n.to_string_lossy().starts_with("session-"))
+        })
+        .collect();
+    files.sort();
+    files.dedup();
+    files
+}
+
+/// Names of the sub-agents declared in `.gemini/agents/`. Gemini CLI also
+/// exposes each one as a tool of the same name, so a call to such a tool is
+/// a delegation.
+pub fn declared_agents(root: &Path) -> Vec<String> {
+    crate::audit::reverse_linker(ROOT_AGENT)
+        .map(|linker| linker.parse(root).agents)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|a| a.name)
+        .collect()
+}
+
+/// The sub-agent a call to tool `name` delegates to, if it does.
+pub fn delegated_agent<'a>(name: &'a str, args: &'a Value, declared: &[String]) -> Option<&'a str> {
+    match name {
+        DELEGATE_TOOL => str_field(args, "agent_name"),
+        _ if declared.iter().any(|d| d == name) => Some(name),
+        _ => None,
+    }
+}
+
+/// Aggregate every chat recording belonging to `root`. Unreadable files and
+/// unexpected shapes are skipped, as in the Claude Code scan.
+pub fn scan(root: &Path) -> UsageFacts {
+    let mut facts = UsageFacts {
+        root_agent: ROOT_AGENT.to_string(),
+        ..Default::default()
+    };
+    let declared = declared_agents(root);
+    for file in session_files(root) {
+        let Some(session) = std::fs::read_to_string(&file)
+            .ok()
+            .and_then(|raw| serde_json::from_str::<Value>(&raw).ok())
+        else {
+            continue;
+        };
+        facts.sessions += 1;
+        let messages = session.get("messages").and_then(Value::as_array);
+        for message in messages.into_iter().flatten() {
+            scan_message(message, &declared, &mut facts);
+        }
+    }
+    facts
+}
+
+fn scan_message(message: &Value, declared: &[String], facts: &mut UsageFacts) {
+    if let Some(ts) = str_field(message, "timestamp") {
+        facts.observe_timestamp(ts);
+    }
+    if str_field(message, "type") != Some("gemini") {
+        return;
+    }
+    let model = str_field(message, "model").unwrap_or_default();
+    let calls = message.get("toolCalls").and_then(Value::as_array);
+    let mut fanout = 0;
+    for call in calls.into_iter().flatten() {
+        let Some(name) = str_field(call, "name") else {
+            continue;
+        };
+        let args = call.get("args").unwrap_or(&Value::Null);
+        if let Some(agent) = delegated_agent(name, args, declared) {
+            fanout += 1;
+            facts.record_delegation(ROOT_AGENT, agent, model);
+        } else if name == SKILL_TOOL
+            && let Some(skill) = str_field(args, "name")
+        {
+            facts.record_skill_turn(skill);
+        }
+        facts.record_tool(name);
+    }
+    facts.max_fanout = facts.max_fanout.max(fanout);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Points `ARMADAI_GEMINI_TMP_DIR` at a test directory for the guard's lifetime,
+    /// serialised via `ENV_MUTEX` like `discovery`'s `ProjectsDirGuard`, and
+    /// unset on drop even when an assertion panics.
+    struct GeminiTmpDirGuard {
+        _lock: std::sync::MutexGuard<'static, ()>,
+    }
+
+    impl GeminiTmpDirGuard {
+        fn set(path: &Path) -> Self {
+            let lock = armadai_core::config::ENV_MUTEX.lock().unwrap();
+            // SAFETY: modifies the global environment; serialised via ENV_MUTEX.
+            unsafe { std::env::set_var("ARMADAI_GEMINI_TMP_DIR", path) }
+            Self { _lock: lock }
+        }
+    }
+
+    impl Drop for GeminiTmpDirGuard {
+        fn drop(&mut self) {
+            // SAFETY: restoring env state at end of test scope.
+            unsafe { std::env::remove_var("ARMADAI_GEMINI_TMP_DIR") }
+        }
+    }
+
+    #[test]
+    fn reads_delegations_skills_and_tools_from_chat_recordings() {
+        let tmp = tempfile::tempdir().unwrap();
+        let project = tempfile::tempdir().unwrap();
+        let agents = project.path().join(".gemini/agents");
+        std::fs::create_dir_all(&agents).unwrap();
+        std::fs::write(
+            agents.join("reviewer.md"),
+            "---\nname: reviewer\ndescription: d\n---\nReview.",
+        )
+        .unwrap();
+        let root = project.path().canonicalize().unwrap();
+        let chats = tmp
+            .path()
+            .join(project_hash(&root.to_string_lossy()))
+            .join("chats");
+        std::fs::create_dir_all(&chats).unwrap();
+        std::fs::write(
+            chats.join("session-2026-09-01T10-00-abc.json"),
+            r#"{"sessionId":"abc","messages":[
+                {"type":"user","timestamp":"2026-09-01T10:00:00Z","content":"go"},
+                {"type":"gemini","timestamp":"2026-09-01T10:00:05Z","model":"gemini-2.5-pro",
+                 "toolCalls":[
+                    {"name":"reviewer","args":{"query":"diff"}},
+                    {"name":"delegate_to_agent","args":{"agent_name":"codebase_investigator"}},
+                    {"name":"activate_skill","args":{"name":"release-notes"}},
+                    {"name":"read_file","args":{"path":"a"}}]}
+            ]}"#,
+        )
+        .unwrap();
+        let _env = GeminiTmpDirGuard::set(tmp.path());
+        let facts = scan(&root);
+
+        assert_eq!(facts.sessions, 1);
+        assert_eq!(facts.root_agent, "gemini");
+        assert_eq!(facts.agents["reviewer"].invocations, 1);
+        assert_eq!(facts.dominant_model("reviewer"), Some("gemini-2.5-pro"));
+        assert_eq!(facts.agents["codebase_investigator"].invocations, 1);
+        assert_eq!(facts.skills["release-notes"], 1);
+        assert_eq!(facts.tools["read_file"], 1);
+        assert_eq!(facts.max_fanout, 2);
+        assert_eq!(
+            facts.window,
+            Some((
+                "2026-09-01T10:00:00Z".to_string(),
+                "2026-09-01T10:00:05Z".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn matches_named_project_directories_by_their_recorded_root() {
+        let tmp = tempfile::tempdir().unwrap();
+        let project = tempfile::tempdir().unwrap();
+        let root = project.path().canonicalize().unwrap();
+        let dir = tmp.path().join("my-project");
+        std::fs::create_dir_all(dir.join("chats")).unwrap();
+        std::fs::write(dir.join(".project_root"), format!("{}\n", root.display())).unwrap();
+        std::fs::write(dir.join("chats/session-1.json"), "{}").unwrap();
+        std::fs::write(dir.join("chats/notes.json"), "{}").unwrap();
+        let _env = GeminiTmpDirGuard::set(tmp.path());
+        let files = session_files(&root);
+        assert_eq!(files, vec![dir.join("chats/session-1.json")]);
+    }
+}
@@ -1,10 +1,25 @@
-//! Observed usage of native agentic assets, read from Claude Code transcripts.
+//! Observed usage of native agentic assets, read from Claude Code transcripts,
+//! Gemini CLI chat recordings and Codex session rollouts.
 //!
 //! Mirror of `audit::reverse` in the runtime direction: `reverse` reads what a
 //! project *declares*, this module reads what it actually *ran*.
+pub mod codex;
 pub mod discovery;
 pub mod facts;
+pub mod gemini;
 pub mod scan;
 
+use std::path::Path;
+
 pub use facts::UsageFacts;
 pub use scan::scan;
+
+/// Observed usage for every CLI that left sessions for `root`, one
+/// `UsageFacts` each, named by its `root_agent` (`claude`, `gemini`,
+/// `codex`). CLIs with nothing observed are left out.
+pub fn scan_all(root: &Path) -> Vec<UsageFacts> {
+    [scan(root), gemini::scan(root), codex::scan(root)]
+        .into_iter()
+        .filter(|facts| !facts.is_empty())
+        .collect()
+}
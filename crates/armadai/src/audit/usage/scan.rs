@@ -199,7 +199,7 @@ fn scan_subagents(sessions: &[PathBuf], facts: &mut UsageFacts) {
     }
 }
 
-fn str_field<'v>(v: &'v Value, key: &str) -> Option<&'v str> {
+pub(super) fn str_field<'v>(v: &'v Value, key: &str) -> Option<&'v str> {
     v.get(key).and_then(Value::as_str)
 }
 
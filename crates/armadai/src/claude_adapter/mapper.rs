@@ -4,7 +4,7 @@ use armadai_core::events::RunEvent;
 
 use crate::claude_adapter::transcript::{Block, RelevantEntry, ToolResult};
 
-const ROOT: &str = "claude";
+pub(crate) const ROOT: &str = "claude";
 const PROV: &str = "claude";
 const MAX_CONTENT: usize = 2000;
 
@@ -12,7 +12,7 @@ const MAX_CONTENT: usize = 2000;
 /// preceding UTF-8 char boundary. `String::truncate` panics when the byte
 /// offset lands inside a multibyte sequence (accents, emoji, CJK) — this
 /// never does, at the cost of a possibly-shorter-than-`max_bytes` result.
-fn truncate_chars(s: &str, max_bytes: usize) -> String {
+pub(crate) fn truncate_chars(s: &str, max_bytes: usize) -> String {
     if s.len() <= max_bytes {
         return s.to_string();
     }
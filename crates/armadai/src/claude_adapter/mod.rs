@@ -1,18 +1,18 @@
 pub mod mapper;
 pub mod policy_gate;
+pub mod policy_log;
 pub mod session_index;
 pub mod transcript;
 
-use std::io::{BufRead, Read};
-use std::sync::Arc;
+use std::io::Read;
 
-use armadai_core::events::{EventSink, RunEvent};
+use armadai_core::events::RunEvent;
 
 use mapper::Mapper;
 use session_index::SessionRef;
 
 /// Poll interval between reads of the tailed transcript in follow mode.
-const POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(200);
+pub(crate) const POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(200);
 
 /// Abandonment safety net, NOT the normal completion path. In follow mode we
 /// finalize a turn on its terminal `stop_reason` (see [`is_terminal_stop`]);
@@ -21,9 +21,9 @@ const POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(200)
 /// takes — Bash/WebFetch/subagents routinely run for minutes with no
 /// transcript growth, and an idle-timer finalize there would fake a `Result`
 /// and stop following mid-run. This large fallback only guards against a
-/// truly-abandoned, never-completed session so `drive_session` can't hang
+/// truly-abandoned, never-completed session so the watch loop can't hang
 /// forever; it should essentially never fire on a healthy transcript.
-const IDLE_ABANDON_POLLS: u32 = 900; // ~3 min of no growth at POLL_INTERVAL=200ms
+pub(crate) const IDLE_ABANDON_POLLS: u32 = 900; // ~3 min of no growth at POLL_INTERVAL=200ms
 
 /// A turn is genuinely complete only when the last top-level assistant
 /// message carries a TERMINAL `stop_reason`. `"tool_use"` means Claude is
@@ -36,110 +36,95 @@ fn is_terminal_stop(sr: &Option<String>) -> bool {
     )
 }
 
-/// Read `session`'s transcript and emit reconstructed `RunEvent`s to `sink`.
-/// `follow=false` → replay to EOF then `finish()`. `follow=true` → after EOF,
-/// keep polling appended bytes, finalizing (`finish()`) once the last
+/// Incremental reader of a Claude Code transcript, for the shared
+/// `session_adapter::drive` loop. Replaying reads to EOF then `finish()`es;
+/// following keeps polling appended bytes, finalizing once the last
 /// assistant message reports a terminal `stop_reason` (see
 /// [`is_terminal_stop`]); [`IDLE_ABANDON_POLLS`] is only an abandonment
 /// safety net for a session that never completes.
-pub async fn drive_session(
-    session: SessionRef,
-    sink: Arc<dyn EventSink>,
-    follow: bool,
-) -> anyhow::Result<()> {
-    drive_session_tuned(session, sink, follow, POLL_INTERVAL, IDLE_ABANDON_POLLS).await
+///
+/// The policy gate's violation log is followed alongside, so what the gate
+/// refused or warned about in this session shows up as `policy_*` warnings.
+/// That log is global and only grows, so it is followed from its end at the
+/// time the tail is opened rather than re-read from the start.
+pub struct ClaudeTail {
+    lines: crate::session_adapter::LineTail,
+    mapper: Mapper,
+    /// Terminal signal from the LAST top-level assistant message seen so far.
+    last_stop_reason: Option<String>,
+    policy: crate::session_adapter::LineTail,
+    session_id: String,
 }
 
-/// Same as [`drive_session`] but with the poll interval and the abandonment
-/// fallback threshold as parameters, so tests can drive both the
-/// terminal-`stop_reason` finalization path and the keep-polling path without
-/// waiting on real-world timings.
-async fn drive_session_tuned(
-    session: SessionRef,
-    sink: Arc<dyn EventSink>,
-    follow: bool,
-    poll_interval: std::time::Duration,
-    idle_abandon_polls: u32,
-) -> anyhow::Result<()> {
-    let mut mapper = Mapper::new(&session.session_id);
-    let mut offset: u64 = 0;
-    let mut idle_polls: u32 = 0;
-    // Terminal signal from the LAST top-level assistant message seen so far.
-    let mut last_stop_reason: Option<String> = None;
-    loop {
-        let file = match std::fs::File::open(&session.transcript_path) {
-            Ok(f) => f,
-            Err(e) => {
-                sink.emit(&RunEvent::Error {
-                    code: "transcript_unreadable".into(),
-                    msg: format!("{}: {e}", session.transcript_path.display()),
-                });
-                return Ok(());
-            }
+impl ClaudeTail {
+    pub fn new(session: &SessionRef) -> Self {
+        Self::with_policy_log(session, &policy_log::log_path())
+    }
+
+    fn with_policy_log(session: &SessionRef, log: &std::path::Path) -> Self {
+        Self {
+            lines: crate::session_adapter::LineTail::new(&session.transcript_path),
+            mapper: Mapper::new(&session.session_id),
+            last_stop_reason: None,
+            policy: crate::session_adapter::LineTail::at_end(log),
+            session_id: session.session_id.clone(),
+        }
+    }
+
+    /// This session's violations logged since the last poll. No log yet
+    /// simply means no violation yet.
+    fn policy_warnings(&mut self) -> Vec<RunEvent> {
+        let Ok(lines) = self.policy.read_lines() else {
+            return Vec::new();
         };
-        use std::io::Seek;
-        let mut reader = std::io::BufReader::new(file);
-        reader.seek(std::io::SeekFrom::Start(offset))?;
-        let mut consumed = 0u64;
-        let mut lines_read = 0u32;
-        let mut line = String::new();
-        loop {
-            line.clear();
-            let n = reader.read_line(&mut line)?;
-            if n == 0 {
-                break; // EOF
-            }
-            // Only advance past complete (newline-terminated) lines, so a
-            // partially-written trailing line is re-read next poll.
-            if !line.ends_with('\n') {
-                break;
-            }
-            consumed += n as u64;
-            lines_read += 1;
+        lines
+            .iter()
+            .filter_map(|l| serde_json::from_str::<policy_log::ViolationRecord>(l.trim()).ok())
+            .filter(|r| r.session_id == self.session_id)
+            .map(|r| RunEvent::Warning {
+                code: format!("policy_{}", r.kind),
+                from: Some(if r.agent.is_empty() {
+                    mapper::ROOT.to_string()
+                } else {
+                    r.agent
+                }),
+                to: Some(r.target),
+            })
+            .collect()
+    }
+}
+
+impl crate::session_adapter::Tail for ClaudeTail {
+    fn poll(&mut self) -> anyhow::Result<Option<Vec<RunEvent>>> {
+        let lines = self.lines.read_lines()?;
+        let warnings = self.policy_warnings();
+        if lines.is_empty() && warnings.is_empty() {
+            return Ok(None);
+        }
+        let mut out = Vec::new();
+        for line in lines {
             if let Some(entry) = transcript::parse_line(&line) {
                 // Capture the turn-completion signal from the latest assistant
                 // message before handing the entry to the mapper (which moves
                 // it and does not need stop_reason).
                 if let transcript::RelevantEntry::Assistant { stop_reason, .. } = &entry {
-                    last_stop_reason = stop_reason.clone();
-                }
-                for ev in mapper.push(entry) {
-                    sink.emit(&ev);
+                    self.last_stop_reason = stop_reason.clone();
                 }
+                out.extend(self.mapper.push(entry));
             }
         }
-        offset += consumed;
-        if !follow {
-            for ev in mapper.finish() {
-                sink.emit(&ev);
-            }
-            return Ok(());
-        }
-        if lines_read > 0 {
-            // The transcript grew this poll — reset the abandonment counter.
-            idle_polls = 0;
-        } else {
-            // EOF: no new complete line this poll. Finalize on a genuine
-            // terminal signal; keep polling while `tool_use`/`None` means the
-            // turn is still in progress (a slow Bash/WebFetch/subagent).
-            if is_terminal_stop(&last_stop_reason) {
-                for ev in mapper.finish() {
-                    sink.emit(&ev);
-                }
-                return Ok(());
-            }
-            idle_polls += 1;
-            if idle_polls >= idle_abandon_polls {
-                // Abandonment safety net (see IDLE_ABANDON_POLLS): the session
-                // never reached a terminal stop_reason — finalize anyway so we
-                // don't poll a dead transcript forever.
-                for ev in mapper.finish() {
-                    sink.emit(&ev);
-                }
-                return Ok(());
-            }
-        }
-        tokio::time::sleep(poll_interval).await;
+        out.extend(warnings);
+        Ok(Some(out))
+    }
+
+    /// Keep polling while `tool_use`/`None` means the turn is still in
+    /// progress (a slow Bash/WebFetch/subagent).
+    fn turn_complete(&self) -> bool {
+        is_terminal_stop(&self.last_stop_reason)
+    }
+
+    fn finish(&mut self) -> Vec<RunEvent> {
+        self.mapper.finish()
     }
 }
 
@@ -199,9 +184,78 @@ fn register_from_reader(mut r: impl Read) -> anyhow::Result<()> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use armadai_core::events::RunEvent;
+    use armadai_core::events::{EventSink, RunEvent};
     use std::sync::{Arc, Mutex};
 
+    /// Drive `session` through [`ClaudeTail`] with the given poll interval
+    /// and abandonment threshold, so tests can exercise both the
+    /// terminal-`stop_reason` finalization path and the keep-polling path
+    /// without waiting on real-world timings.
+    async fn drive_session_tuned(
+        session: SessionRef,
+        sink: Arc<dyn EventSink>,
+        follow: bool,
+        poll_interval: std::time::Duration,
+        idle_abandon_polls: u32,
+    ) -> anyhow::Result<()> {
+        // A policy log of its own, rather than the user's real one.
+        let log = session.transcript_path.with_extension("policy.jsonl");
+        let tail = Box::new(ClaudeTail::with_policy_log(&session, &log));
+        let polling = crate::session_adapter::Polling {
+            interval: poll_interval,
+            idle_abandon: idle_abandon_polls,
+        };
+        crate::session_adapter::drive(&session, tail, sink, follow, polling).await
+    }
+
+    #[test]
+    fn policy_violations_of_the_session_become_warnings() {
+        use crate::session_adapter::Tail;
+        let dir = tempfile::tempdir().unwrap();
+        let tp = dir.path().join("t.jsonl");
+        std::fs::write(&tp, "").unwrap();
+        let log = dir.path().join("policy.jsonl");
+        let session = session_index::SessionRef {
+            session_id: "s".into(),
+            transcript_path: tp,
+            cwd: "/c".into(),
+            started_at: "t".into(),
+        };
+        let mut tail = ClaudeTail::with_policy_log(&session, &log);
+        assert!(tail.poll().unwrap().is_none(), "no log yet, no violation");
+
+        let record = |session_id: &str, agent: &str| policy_log::ViolationRecord {
+            timestamp: "t".into(),
+            session_id: session_id.into(),
+            mode: "warn".into(),
+            kind: "tool".into(),
+            agent: agent.into(),
+            target: "Bash".into(),
+            reason: "r".into(),
+            refused: false,
+        };
+        policy_log::append(&log, &record("s", "qa")).unwrap();
+        policy_log::append(&log, &record("other", "qa")).unwrap();
+        policy_log::append(&log, &record("s", "")).unwrap();
+        let evs = tail.poll().unwrap().unwrap();
+        assert_eq!(evs.len(), 2, "other sessions are filtered out: {evs:?}");
+        assert!(matches!(
+            &evs[0],
+            RunEvent::Warning { code, from: Some(f), to: Some(t) }
+                if code == "policy_tool" && f == "qa" && t == "Bash"
+        ));
+        assert!(matches!(&evs[1], RunEvent::Warning { from: Some(f), .. } if f == "claude"));
+
+        // A tail opened later skips what the log already holds.
+        let mut later = ClaudeTail::with_policy_log(&session, &log);
+        assert!(
+            later.poll().unwrap().is_none(),
+            "old violations are not replayed"
+        );
+        policy_log::append(&log, &record("s", "qa")).unwrap();
+        assert_eq!(later.poll().unwrap().unwrap().len(), 1);
+    }
+
     struct CapSink(Arc<Mutex<Vec<RunEvent>>>);
     impl armadai_core::events::EventSink for CapSink {
         fn emit(&self, ev: &RunEvent) {
@@ -231,7 +285,9 @@ mod tests {
         };
         let store = Arc::new(Mutex::new(Vec::new()));
         let sink: Arc<dyn armadai_core::events::EventSink> = Arc::new(CapSink(store.clone()));
-        drive_session(session, sink, false).await.unwrap();
+        drive_session_tuned(session, sink, false, POLL_INTERVAL, IDLE_ABANDON_POLLS)
+            .await
+            .unwrap();
         let evs = store.lock().unwrap();
         assert!(matches!(&evs[0], RunEvent::RunStart { run_id, .. } if run_id == "s"));
         assert!(
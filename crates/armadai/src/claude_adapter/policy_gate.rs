@@ -1,8 +1,11 @@
 //! `armadai __claude-policy-gate` — the Claude Code `PreToolUse` adapter.
 //!
-//! Reads the hook payload on stdin, asks
-//! `armadai_core::orchestration::policy::check_delegation` whether the
-//! delegation is allowed, and writes a decision on stdout.
+//! Reads the hook payload on stdin, asks `armadai_core::orchestration::policy`
+//! whether the call is allowed — a delegation against the topology, a
+//! sub-agent's Bash/Write/Edit against its tool rules and `scope`, any gated
+//! call against the session budget — and writes a decision on stdout.
+//! Every violation is also appended to the policy log (`policy_log`), where
+//! `armadai watch` picks it up; in `warn` mode that is all that happens.
 //!
 //! Contract, and the reason this file is deliberately dull: **printing
 //! nothing means "no opinion", which Claude Code treats as allowed.** So every
@@ -13,12 +16,20 @@
 //! Nothing may be written to stdout except the decision JSON — the same
 //! contract `__claude-register-session` observes (tracing goes to stderr).
 
-use std::io::Read;
+use std::collections::HashSet;
+use std::io::{BufRead, Read};
 use std::path::Path;
 
-use armadai_core::orchestration::policy::check_delegation;
-use armadai_core::project::find_project_config_from;
+use armadai_core::orchestration::OrchestrationConfig;
+use armadai_core::orchestration::policy::{
+    PolicyMode, PolicyViolation, check_budget, check_delegation, check_path, check_tool,
+};
+use armadai_core::project::{ProjectConfig, find_project_config_from};
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+use super::policy_log::{self, ViolationRecord};
 
 /// Read a hook payload from stdin and emit a decision. Never fails the hook.
 pub fn gate_from_stdin() -> anyhow::Result<()> {
@@ -84,56 +95,338 @@ fn main_checkout_of(cwd: &Path) -> Option<std::path::PathBuf> {
 /// allowed again, because the policy decides rather than the shape of the call.
 const IMPLICIT_SUBAGENT: &str = "general-purpose";
 
+/// Tools the gate judges: delegations, and the calls that change the
+/// machine. Anything else gets no opinion, so a hook installed without a
+/// matcher cannot have every tool judged.
+const JUDGED_TOOLS: &[&str] = &["Agent", "Task", "Bash", "Write", "Edit", "MultiEdit"];
+
 /// The whole gate, as a pure string→string function so it can be tested
 /// without a subprocess. `None` means "no opinion", which Claude Code
-/// treats as allowed.
+/// treats as allowed — and is also what `warn` mode answers after logging.
 pub fn decide(raw: &str) -> Option<String> {
+    decide_logging_to(raw, &policy_log::log_path())
+}
+
+/// [`decide`], appending violations to the log at `log`.
+fn decide_logging_to(raw: &str, log: &Path) -> Option<String> {
     let v: Value = serde_json::from_str(raw).ok()?;
-    // Only a delegation carries a topology decision. The hook's matcher
-    // should already guarantee this; checking here means a hook installed
-    // without a matcher cannot have every tool judged as a delegation.
     let tool = v.get("tool_name").and_then(Value::as_str)?;
-    if tool != "Agent" && tool != "Task" {
+    if !JUDGED_TOOLS.contains(&tool) {
         return None;
     }
-    // `.and_then`, not `?`: an absent `tool_input` must be judged exactly like
-    // `tool_input: null`, or the shape of the call decides again — the very
-    // asymmetry the implicit-target fix was meant to close.
-    let target = v
-        .get("tool_input")
-        .and_then(|i| i.get("subagent_type"))
-        .and_then(Value::as_str)
-        .filter(|s| !s.is_empty())
-        .unwrap_or(IMPLICIT_SUBAGENT);
     // Claude Code sends an empty `agent_type` on the main thread; a sub-agent
-    // sub-delegating carries its own name.
+    // carries its own name.
     let caller = v
         .get("agent_type")
         .and_then(Value::as_str)
         .filter(|s| !s.is_empty());
     let cwd = v.get("cwd").and_then(Value::as_str)?;
-    let (_, project) = resolve_project(Path::new(cwd))?;
+    let (root, project) = resolve_project(Path::new(cwd))?;
     let orchestration = project.orchestration.as_deref()?;
+    if orchestration.policy == PolicyMode::Off {
+        return None;
+    }
+
+    let (kind, violation) = over_budget(&v, orchestration, &spend_cache_dir(log))
+        .or_else(|| judge(&v, tool, caller, cwd, &root, &project, orchestration))?;
+    let refused = orchestration.policy == PolicyMode::Strict;
+    let record = ViolationRecord {
+        timestamp: chrono::Utc::now().to_rfc3339(),
+        session_id: str_at(&v, "session_id").to_string(),
+        mode: if refused { "strict" } else { "warn" }.to_string(),
+        kind: kind.to_string(),
+        agent: caller.unwrap_or_default().to_string(),
+        target: violation.target.clone(),
+        reason: violation.reason.clone(),
+        refused,
+    };
+    if let Err(e) = policy_log::append(log, &record) {
+        tracing::warn!("failed to log policy violation: {e}");
+    }
+    refused.then(|| {
+        serde_json::json!({
+            "hookSpecificOutput": {
+                "hookEventName": "PreToolUse",
+                "permissionDecision": "deny",
+                "permissionDecisionReason": violation.reason,
+            }
+        })
+        .to_string()
+    })
+}
+
+fn str_at<'a>(v: &'a Value, key: &str) -> &'a str {
+    v.get(key).and_then(Value::as_str).unwrap_or("")
+}
+
+/// The session budget, judged before anything else: it binds the main
+/// thread too.
+fn over_budget(
+    v: &Value,
+    orchestration: &OrchestrationConfig,
+    spend_cache: &Path,
+) -> Option<(&'static str, PolicyViolation)> {
+    orchestration.session_budget?;
+    let transcript = Path::new(str_at(v, "transcript_path"));
+    check_budget(session_spend(transcript, spend_cache), orchestration)
+        .err()
+        .map(|violation| ("budget", violation))
+}
+
+/// The first rule `tool` breaks, with its kind for the log: the delegation,
+/// or the sub-agent's tool rules and scope.
+fn judge(
+    v: &Value,
+    tool: &str,
+    caller: Option<&str>,
+    cwd: &str,
+    root: &Path,
+    project: &ProjectConfig,
+    orchestration: &OrchestrationConfig,
+) -> Option<(&'static str, PolicyViolation)> {
+    if tool == "Agent" || tool == "Task" {
+        // `.and_then`, not `?`: an absent `tool_input` must be judged exactly
+        // like `tool_input: null`, or the shape of the call decides again —
+        // the very asymmetry the implicit-target fix was meant to close.
+        let target = v
+            .get("tool_input")
+            .and_then(|i| i.get("subagent_type"))
+            .and_then(Value::as_str)
+            .filter(|s| !s.is_empty())
+            .unwrap_or(IMPLICIT_SUBAGENT);
+        return check_delegation(caller, target, orchestration)
+            .err()
+            .map(|violation| ("delegation", violation));
+    }
+    // Tool rules and scopes bind sub-agents; the main thread is the user's.
+    let agent = caller?;
+    if let Err(violation) = check_tool(agent, tool, orchestration) {
+        return Some(("tool", violation));
+    }
+    if tool == "Bash" {
+        return None;
+    }
+    let file = v
+        .get("tool_input")
+        .and_then(|i| i.get("file_path"))
+        .and_then(Value::as_str)?;
+    let scope = agent_scope(agent, root, project);
+    let Some(path) = project_relative(Path::new(file), root, Path::new(cwd)) else {
+        // Outside the project: no scope entry covers it, not even `**`.
+        return (!scope.is_empty()).then(|| {
+            let reason = format!(
+                "{file} is outside the project, so outside {agent}'s scope [{}]",
+                scope.join(", ")
+            );
+            (
+                "path",
+                PolicyViolation {
+                    target: file.to_string(),
+                    allowed: scope,
+                    reason,
+                },
+            )
+        });
+    };
+    check_path(agent, &scope, &path, orchestration)
+        .err()
+        .map(|violation| ("path", violation))
+}
+
+/// The `scope` of the armadai agent a sub-agent was generated from; empty
+/// (no restriction) when the name resolves to no armadai agent.
+fn agent_scope(agent: &str, root: &Path, project: &ProjectConfig) -> Vec<String> {
+    let fragments = armadai_core::agent_source::project_fragments(root);
+    armadai_core::agent_source::load_agent_by_name(agent, project, root, &fragments)
+        .map(|(a, _)| a.metadata.scope)
+        .unwrap_or_default()
+}
+
+/// `file` relative to the project root, `/`-separated, after lexical
+/// normalization (`.` dropped, `..` popped) so `src/../docs/x.md` is judged
+/// as `docs/x.md`. A path outside the root (a worktree) is taken relative to
+/// the session's `cwd` instead; one outside both — including any `..` that
+/// climbs above them — is `None`, which no scope covers.
+fn project_relative(file: &Path, root: &Path, cwd: &Path) -> Option<String> {
+    let absolute = normalize(&cwd.join(file));
+    let relative = absolute
+        .strip_prefix(normalize(root))
+        .or_else(|_| absolute.strip_prefix(normalize(cwd)))
+        .ok()?;
+    Some(
+        relative
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/"),
+    )
+}
+
+/// `path` with `.` components dropped and each `..` popping its parent,
+/// without touching the filesystem (the file may not exist yet).
+fn normalize(path: &Path) -> std::path::PathBuf {
+    use std::path::Component;
+    let mut out = std::path::PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                out.pop();
+            }
+            other => out.push(other),
+        }
+    }
+    out
+}
+
+/// Where the gate keeps what it already counted of each transcript: next to
+/// the policy log it writes.
+fn spend_cache_dir(log: &Path) -> std::path::PathBuf {
+    log.with_file_name("policy-spend")
+}
 
-    match check_delegation(caller, target, orchestration) {
-        Ok(()) => None,
-        Err(violation) => Some(
-            serde_json::json!({
-                "hookSpecificOutput": {
-                    "hookEventName": "PreToolUse",
-                    "permissionDecision": "deny",
-                    "permissionDecisionReason": violation.reason,
-                }
-            })
-            .to_string(),
-        ),
+/// What [`session_spend`] has already read of one transcript: the byte
+/// offset of the first line not yet counted, a digest of the bytes just
+/// before it, the running total, and the message ids counted so far.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct SpendCache {
+    offset: u64,
+    #[serde(default)]
+    tail: String,
+    total: f64,
+    seen: HashSet<String>,
+}
+
+/// How many bytes before the cached offset [`tail_digest`] covers.
+const TAIL_LEN: u64 = 4096;
+
+/// Digest of the (up to) [`TAIL_LEN`] bytes of `file` ending at `offset`,
+/// which tells an append apart from a rewrite of the same length or longer.
+fn tail_digest(file: &mut std::fs::File, offset: u64) -> Option<String> {
+    use std::io::{Seek, SeekFrom};
+    let start = offset.saturating_sub(TAIL_LEN);
+    file.seek(SeekFrom::Start(start)).ok()?;
+    let mut tail = vec![0; (offset - start) as usize];
+    file.read_exact(&mut tail).ok()?;
+    Some(
+        Sha256::digest(&tail)
+            .iter()
+            .map(|b| format!("{b:02x}"))
+            .collect(),
+    )
+}
+
+/// What the session recorded in `transcript` has cost so far, in USD: the
+/// `costUSD` Claude Code records when it does, else an estimate from each
+/// assistant message's token usage at the provider's list prices. Streaming
+/// writes one message over several lines, so each message id counts once.
+///
+/// The gate runs on every judged call and transcripts reach hundreds of MB,
+/// so only the lines appended since the previous call are read: the offset,
+/// total and ids are cached per transcript under `cache_dir`. A transcript
+/// shorter than the cached offset, or whose bytes before it changed, was
+/// rewritten and is read again from the start. An unreadable transcript has cost nothing — no opinion, as
+/// everywhere here.
+pub fn session_spend(transcript: &Path, cache_dir: &Path) -> f64 {
+    use std::io::{Seek, SeekFrom};
+    let Ok(mut file) = std::fs::File::open(transcript) else {
+        return 0.0;
+    };
+    let len = file.metadata().map(|m| m.len()).unwrap_or(0);
+    let key = Sha256::digest(transcript.to_string_lossy().as_bytes());
+    let cache_path = cache_dir.join(format!(
+        "{}.json",
+        key[..16]
+            .iter()
+            .map(|b| format!("{b:02x}"))
+            .collect::<String>()
+    ));
+    let mut cache: SpendCache = std::fs::read(&cache_path)
+        .ok()
+        .and_then(|raw| serde_json::from_slice(&raw).ok())
+        .filter(|c: &SpendCache| c.offset <= len)
+        .filter(|c| tail_digest(&mut file, c.offset).is_some_and(|tail| tail == c.tail))
+        .unwrap_or_default();
+    let start = cache.offset;
+
+    let mut reader = std::io::BufReader::new(file);
+    if reader.seek(SeekFrom::Start(cache.offset)).is_err() {
+        return cache.total;
+    }
+    let mut line = String::new();
+    loop {
+        line.clear();
+        // A partially-written last line is left for the next call.
+        match reader.read_line(&mut line) {
+            Ok(n) if n > 0 && line.ends_with('\n') => cache.offset += n as u64,
+            _ => break,
+        }
+        let Ok(v) = serde_json::from_str::<Value>(&line) else {
+            continue;
+        };
+        if str_at(&v, "type") != "assistant" {
+            continue;
+        }
+        let Some(message) = v.get("message") else {
+            continue;
+        };
+        let id = str_at(message, "id");
+        if !id.is_empty() && !cache.seen.insert(id.to_string()) {
+            continue;
+        }
+        cache.total += match v.get("costUSD").and_then(Value::as_f64) {
+            Some(cost) => cost,
+            None => estimate_cost(message),
+        };
+    }
+
+    if cache.offset != start {
+        cache.tail = tail_digest(reader.get_mut(), cache.offset).unwrap_or_default();
+        // Best-effort, through a temporary so a concurrent gate never reads
+        // half a cache; losing an update only means re-reading those lines.
+        let tmp = cache_path.with_extension(format!("{}.tmp", std::process::id()));
+        let written = std::fs::create_dir_all(cache_dir)
+            .and_then(|()| std::fs::write(&tmp, serde_json::to_vec(&cache).unwrap_or_default()))
+            .and_then(|()| std::fs::rename(&tmp, &cache_path));
+        if let Err(e) = written {
+            let _ = std::fs::remove_file(&tmp);
+            tracing::warn!("failed to cache session spend: {e}");
+        }
     }
+    cache.total
+}
+
+/// List price of one assistant message, from its model and usage, priced
+/// like the Anthropic API provider prices its own completions.
+fn estimate_cost(message: &Value) -> f64 {
+    let tokens = |k: &str| {
+        message
+            .pointer(&format!("/usage/{k}"))
+            .and_then(Value::as_u64)
+            .unwrap_or(0)
+    };
+    armadai_providers::pricing::anthropic_cost(
+        str_at(message, "model"),
+        &armadai_providers::pricing::AnthropicUsage {
+            input_tokens: tokens("input_tokens"),
+            cache_creation_input_tokens: tokens("cache_creation_input_tokens"),
+            cache_read_input_tokens: tokens("cache_read_input_tokens"),
+            output_tokens: tokens("output_tokens"),
+        },
+    )
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    /// The gate as the hook runs it, logging into a throwaway file rather
+    /// than the user's real policy log.
+    fn decide(raw: &str) -> Option<String> {
+        let dir = tempfile::tempdir().unwrap();
+        decide_logging_to(raw, &dir.path().join("policy.jsonl"))
+    }
+
     /// Payload shaped like the ones captured from a real session during the
     /// feasibility spike — not invented.
     fn payload(agent_type: &str, subagent_type: &str, cwd: &str) -> String {
@@ -298,8 +591,9 @@ mod tests {
         assert!(decide(&raw).is_some(), "Task must be policed like Agent");
     }
 
-    /// A tool that is not a delegation carries no topology decision — the
-    /// guard that keeps a matcher-less hook from denying unrelated tools.
+    /// A main-thread call that is not a delegation carries no topology
+    /// decision, and a tool outside `JUDGED_TOOLS` none at all — the guard
+    /// that keeps a matcher-less hook from denying unrelated tools.
     #[test]
     fn a_non_delegation_tool_is_never_judged() {
         let dir = project_with_strict_policy();
@@ -310,6 +604,14 @@ mod tests {
         })
         .to_string();
         assert!(decide(&raw).is_none());
+        let read = serde_json::json!({
+            "cwd": dir.path().to_string_lossy(),
+            "tool_name": "Read",
+            "agent_type": "qa-specialist",
+            "tool_input": { "file_path": "x" },
+        })
+        .to_string();
+        assert!(decide(&read).is_none());
     }
 
     #[test]
@@ -385,4 +687,226 @@ mod tests {
             "switching to off must take effect on the next call"
         );
     }
+
+    /// A project whose `qa-specialist` may run Bash but not edit, and whose
+    /// `core-specialist` is confined to `src/`.
+    fn project_with_tool_rules(policy: &str) -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join(".armadai/agents")).unwrap();
+        std::fs::write(
+            dir.path().join(".armadai/config.yaml"),
+            format!(
+                "orchestration:\n  policy: {policy}\n  agent_tools:\n    \
+                 qa-specialist:\n      allow: [Bash]\n"
+            ),
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join(".armadai/agents/core-specialist.md"),
+            "# core-specialist\n\n## Metadata\n- provider: claude\n- scope: [src/]\n\n\
+             ## System Prompt\n\nHi\n",
+        )
+        .unwrap();
+        dir
+    }
+
+    fn tool_payload(dir: &Path, agent: &str, tool: &str, input: Value) -> String {
+        serde_json::json!({
+            "session_id": "s1",
+            "cwd": dir.to_string_lossy(),
+            "hook_event_name": "PreToolUse",
+            "tool_name": tool,
+            "agent_type": agent,
+            "tool_input": input,
+        })
+        .to_string()
+    }
+
+    #[test]
+    fn a_sub_agent_is_held_to_its_tool_rules() {
+        let dir = project_with_tool_rules("strict");
+        let edit = serde_json::json!({ "file_path": "src/lib.rs" });
+        assert!(
+            decide(&tool_payload(
+                dir.path(),
+                "qa-specialist",
+                "Bash",
+                serde_json::json!({})
+            ))
+            .is_none()
+        );
+        let out = decide(&tool_payload(
+            dir.path(),
+            "qa-specialist",
+            "MultiEdit",
+            edit.clone(),
+        ))
+        .expect("an edit outside the allow-list is refused");
+        assert!(out.contains("qa-specialist may only use [Bash]"), "{out}");
+        assert!(
+            decide(&tool_payload(dir.path(), "", "Edit", edit)).is_none(),
+            "the main thread is not held to sub-agent rules"
+        );
+    }
+
+    #[test]
+    fn writes_outside_the_agent_scope_are_refused() {
+        let dir = project_with_tool_rules("strict");
+        let inside = dir.path().join("src/main.rs");
+        assert!(
+            decide(&tool_payload(
+                dir.path(),
+                "core-specialist",
+                "Write",
+                serde_json::json!({ "file_path": inside.to_string_lossy() }),
+            ))
+            .is_none()
+        );
+        let out = decide(&tool_payload(
+            dir.path(),
+            "core-specialist",
+            "Edit",
+            serde_json::json!({ "file_path": "docs/guide.md" }),
+        ))
+        .expect("a path outside the scope is refused");
+        assert!(
+            out.contains("docs/guide.md is outside core-specialist's scope"),
+            "{out}"
+        );
+    }
+
+    #[test]
+    fn warn_mode_logs_without_refusing() {
+        let dir = project_with_tool_rules("warn");
+        let log = dir.path().join("policy.jsonl");
+        let raw = tool_payload(
+            dir.path(),
+            "qa-specialist",
+            "Write",
+            serde_json::json!({ "file_path": "a.txt" }),
+        );
+        assert!(decide_logging_to(&raw, &log).is_none());
+        let record: policy_log::ViolationRecord =
+            serde_json::from_str(std::fs::read_to_string(&log).unwrap().trim()).unwrap();
+        assert_eq!(record.session_id, "s1");
+        assert_eq!(record.kind, "tool");
+        assert_eq!(record.agent, "qa-specialist");
+        assert_eq!(record.target, "Write");
+        assert!(!record.refused);
+    }
+
+    #[test]
+    fn the_session_budget_refuses_once_spent() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join(".armadai")).unwrap();
+        std::fs::write(
+            dir.path().join(".armadai/config.yaml"),
+            "orchestration:\n  policy: strict\n  session_budget: 1.0\n",
+        )
+        .unwrap();
+        let transcript = dir.path().join("t.jsonl");
+        let line = |id: &str| {
+            format!(
+                r#"{{"type":"assistant","message":{{"id":"{id}","model":"claude-opus-4","usage":{{"input_tokens":10000,"output_tokens":5000}}}}}}"#
+            ) + "\n"
+        };
+        // 0.15 + 0.375 per opus message; the repeated id is one message.
+        std::fs::write(&transcript, line("m1") + &line("m1")).unwrap();
+        let raw = serde_json::json!({
+            "cwd": dir.path().to_string_lossy(),
+            "transcript_path": transcript.to_string_lossy(),
+            "tool_name": "Bash",
+            "agent_type": "",
+            "tool_input": { "command": "ls" },
+        })
+        .to_string();
+        // One log for both calls, so the second one goes through the cache
+        // the first one wrote.
+        let log = dir.path().join("logs/policy.jsonl");
+        assert!(
+            decide_logging_to(&raw, &log).is_none(),
+            "$0.53 counted twice would be over"
+        );
+        // Rewritten at the same length: read again, not served from the cache.
+        std::fs::write(&transcript, line("m1") + &line("m2")).unwrap();
+        let out =
+            decide_logging_to(&raw, &log).expect("over budget, even the main thread is refused");
+        assert!(out.contains("$1.05, over its $1.00 budget"), "{out}");
+    }
+
+    #[test]
+    fn recorded_costs_win_over_estimates() {
+        let dir = tempfile::tempdir().unwrap();
+        let transcript = dir.path().join("t.jsonl");
+        std::fs::write(
+            &transcript,
+            r#"{"type":"assistant","costUSD":0.5,"message":{"id":"a","model":"claude-opus-4","usage":{"input_tokens":1000000}}}
+{"type":"assistant","message":{"id":"b","model":"claude-haiku","usage":{"cache_read_input_tokens":1000000}}}
+{"type":"user","message":{"content":"hi"}}
+"#,
+        )
+        .unwrap();
+        let cache = dir.path().join("policy-spend");
+        let spent = session_spend(&transcript, &cache);
+        assert!((spent - 0.58).abs() < 1e-9, "{spent}");
+        assert_eq!(
+            session_spend(&dir.path().join("missing.jsonl"), &cache),
+            0.0
+        );
+    }
+
+    #[test]
+    fn spend_is_read_incrementally_from_the_cached_offset() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = dir.path().join("policy-spend");
+        let transcript = dir.path().join("t.jsonl");
+        let line = |id: &str, cost: f64| {
+            format!(r#"{{"type":"assistant","costUSD":{cost},"message":{{"id":"{id}"}}}}"#) + "\n"
+        };
+        std::fs::write(&transcript, line("a", 0.25)).unwrap();
+        assert_eq!(session_spend(&transcript, &cache), 0.25);
+        assert_eq!(std::fs::read_dir(&cache).unwrap().count(), 1);
+
+        // Appended lines are added to the cached total; a repeated id and a
+        // half-written line are not (yet).
+        let mut more = std::fs::OpenOptions::new()
+            .append(true)
+            .open(&transcript)
+            .unwrap();
+        use std::io::Write;
+        write!(more, "{}{}{{\"type\"", line("a", 0.25), line("b", 0.5)).unwrap();
+        assert_eq!(session_spend(&transcript, &cache), 0.75);
+
+        // A rewritten, shorter transcript is counted again from the start.
+        std::fs::write(&transcript, line("c", 0.125)).unwrap();
+        assert_eq!(session_spend(&transcript, &cache), 0.125);
+
+        // So is one rewritten at the same length.
+        std::fs::write(&transcript, line("d", 0.375)).unwrap();
+        assert_eq!(session_spend(&transcript, &cache), 0.375);
+    }
+
+    #[test]
+    fn dot_dot_cannot_escape_the_agent_scope() {
+        let dir = project_with_tool_rules("strict");
+        let edit = |file: String| {
+            decide(&tool_payload(
+                dir.path(),
+                "core-specialist",
+                "Edit",
+                serde_json::json!({ "file_path": file }),
+            ))
+        };
+        assert!(edit("src/./a/../lib.rs".into()).is_none());
+        let out = edit("src/../docs/x.md".into()).expect("normalized to docs/x.md");
+        assert!(
+            out.contains("docs/x.md is outside core-specialist's scope"),
+            "{out}"
+        );
+        let ci = dir.path().join("src/../.github/workflows/ci.yml");
+        assert!(edit(ci.to_string_lossy().into_owned()).is_some());
+        let out = edit("src/../../elsewhere/src/x.rs".into())
+            .expect("climbing above the root is out of scope");
+        assert!(out.contains("is outside the project"), "{out}");
+    }
 }
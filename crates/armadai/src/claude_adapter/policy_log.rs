@@ -0,0 +1,77 @@
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+/// One policy violation seen by `armadai __claude-policy-gate`, refused
+/// (`strict`) or only reported (`warn`). Appended by the gate and followed by
+/// `armadai watch`, which shows it in the Workroom.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ViolationRecord {
+    pub timestamp: String,
+    #[serde(default)]
+    pub session_id: String,
+    /// `warn` or `strict`.
+    pub mode: String,
+    /// `delegation`, `tool`, `path` or `budget`.
+    pub kind: String,
+    /// The agent at fault; empty for the main thread.
+    #[serde(default)]
+    pub agent: String,
+    /// What it reached for: an agent, a tool, a path, or `session_budget`.
+    pub target: String,
+    pub reason: String,
+    /// Whether the call was refused.
+    pub refused: bool,
+}
+
+/// Resolved path of the violation log (override with `ARMADAI_POLICY_LOG`).
+pub fn log_path() -> PathBuf {
+    if let Ok(p) = std::env::var("ARMADAI_POLICY_LOG") {
+        return PathBuf::from(p);
+    }
+    armadai_core::config::config_dir().join("policy-violations.jsonl")
+}
+
+/// Append one record to the log at `path` (creating parent dirs as needed).
+pub fn append(path: &Path, record: &ViolationRecord) -> anyhow::Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let line = serde_json::to_string(record)?;
+    use std::io::Write;
+    let mut f = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?;
+    writeln!(f, "{line}")?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn append_writes_one_line_per_record() {
+        let dir = tempfile::tempdir().unwrap();
+        let p = dir.path().join("nested/log.jsonl");
+        let record = ViolationRecord {
+            timestamp: "t".into(),
+            session_id: "s1".into(),
+            mode: "warn".into(),
+            kind: "tool".into(),
+            agent: "qa".into(),
+            target: "Bash".into(),
+            reason: "r".into(),
+            refused: false,
+        };
+        append(&p, &record).unwrap();
+        append(&p, &record).unwrap();
+        let raw = std::fs::read_to_string(&p).unwrap();
+        let back: Vec<ViolationRecord> = raw
+            .lines()
+            .map(|l| serde_json::from_str(l).unwrap())
+            .collect();
+        assert_eq!(back, vec![record.clone(), record]);
+    }
+}
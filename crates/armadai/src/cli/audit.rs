@@ -2,8 +2,10 @@ use std::path::PathBuf;
 
 use crate::audit::{
     deep::{DeepOutcome, available_cli, run_deep},
-    import_surfaces,
+    export::ReportFormat,
+    fix, import_surfaces,
     proposal::generate_proposal,
+    report::AuditReport,
     rules::{AuditSettings, Severity},
     run_audit,
 };
@@ -11,6 +13,15 @@ use armadai_core::agent::{Agent, AgentMetadata};
 use armadai_core::provider::{ChatMessage, CompletionRequest};
 use armadai_providers::factory::create_provider;
 
+fn render(audit: &AuditReport, format: ReportFormat) -> String {
+    match format {
+        ReportFormat::Markdown => audit.to_markdown(),
+        ReportFormat::Html => audit.to_html(),
+        ReportFormat::Json => audit.to_json(),
+        ReportFormat::Sarif => audit.to_sarif(),
+    }
+}
+
 pub(crate) fn min_severity_from(flag: &str, quiet: bool) -> Severity {
     if quiet {
         return Severity::Warning;
@@ -42,6 +53,7 @@ fn build_deep_auditor(cli: &str) -> Agent {
             tags: vec![],
             stacks: vec![],
             scope: vec![],
+            skills: vec![],
             model_fallback: vec![],
             cost_limit: None,
             rate_limit: None,
@@ -90,7 +102,7 @@ fn call_deep_auditor(agent: &Agent, prompt: &str) -> anyhow::Result<String> {
 /// callers (and tests) can inject the detection result instead of mutating
 /// the process environment.
 async fn apply_deep_pass(
-    audit: &mut crate::audit::report::AuditReport,
+    audit: &mut AuditReport,
     root: &std::path::Path,
     settings: &AuditSettings,
     cli: Option<&str>,
@@ -125,14 +137,103 @@ async fn apply_deep_pass(
     Ok(())
 }
 
+/// What `--fix` does with the edits findings carry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FixMode {
+    Apply,
+    DryRun,
+}
+
+/// Preview or apply the findings' edits. Returns the audit to report:
+/// re-run after applying, so the report and the exit code describe the
+/// fixed files.
+fn fix_findings(
+    root: &std::path::Path,
+    audit: AuditReport,
+    mode: FixMode,
+    reaudit: impl FnOnce() -> AuditReport,
+) -> anyhow::Result<AuditReport> {
+    let plan = fix::plan(&audit.findings);
+    let h = crate::cli::style::header();
+    let m = crate::cli::style::muted();
+    let w = crate::cli::style::warn();
+    anstream::println!("{h}armadai audit --fix - {}{h:#}", root.display());
+    for (f, reason) in &plan.skipped {
+        anstream::println!(
+            "  {w}skipped {} {}: {reason}{w:#}",
+            f.rule,
+            fix::relative(root, &f.file).display()
+        );
+    }
+    if plan.is_empty() {
+        anstream::println!("  {m}No finding has a mechanical fix.{m:#}\n");
+        return Ok(audit);
+    }
+    let (r, ad) = (crate::cli::style::err(), crate::cli::style::ok());
+    for patch in &plan.patches {
+        let name = fix::relative(root, &patch.file);
+        for line in patch.unified_diff(&name.to_string_lossy()).lines() {
+            match line.chars().next() {
+                Some('@') | None => anstream::println!("  {m}{line}{m:#}"),
+                _ if line.starts_with("---") || line.starts_with("+++") => {
+                    anstream::println!("  {h}{line}{h:#}")
+                }
+                Some('-') => anstream::println!("  {r}{line}{r:#}"),
+                Some('+') => anstream::println!("  {ad}{line}{ad:#}"),
+                _ => println!("  {line}"),
+            }
+        }
+    }
+    if mode == FixMode::DryRun {
+        anstream::println!(
+            "\n  {m}Dry run: {} finding(s) fixable in {} file(s); nothing written.{m:#}\n",
+            plan.fixed.len(),
+            plan.patches.len()
+        );
+        return Ok(audit);
+    }
+    let backup = fix::apply(root, &plan)?;
+    let after = reaudit();
+    let remaining: Vec<_> = plan
+        .fixed
+        .iter()
+        .filter(|f| {
+            after
+                .findings
+                .iter()
+                .any(|g| g.rule == f.rule && g.file == f.file && g.message == f.message)
+        })
+        .collect();
+    let o = crate::cli::style::ok();
+    anstream::println!(
+        "\n  {o}{} finding(s) fixed in {} file(s){o:#} {m}(originals in {}){m:#}",
+        plan.fixed.len() - remaining.len(),
+        plan.patches.len(),
+        fix::relative(root, &backup).display()
+    );
+    for f in remaining {
+        anstream::println!(
+            "  {w}still reported after the fix: {} {} {}{w:#}",
+            f.rule,
+            fix::relative(root, &f.file).display(),
+            f.message
+        );
+    }
+    println!();
+    Ok(after)
+}
+
+#[allow(clippy::too_many_arguments)]
 pub async fn execute(
     path: Option<PathBuf>,
     report: Option<PathBuf>,
+    format: Option<ReportFormat>,
     min_severity: String,
     quiet: bool,
     propose: bool,
     deep: bool,
     no_usage: bool,
+    fix: Option<FixMode>,
 ) -> anyhow::Result<()> {
     let root = match path {
         Some(p) => p,
@@ -141,7 +242,13 @@ pub async fn execute(
     if !root.is_dir() {
         anyhow::bail!("not a directory: {}", root.display());
     }
-    let settings = AuditSettings::from_project(&root);
+    if propose && report.is_none() && format.is_some() {
+        anyhow::bail!("--propose prints to stdout; pair --format with --report to use both");
+    }
+    if fix.is_some() && report.is_none() && format.is_some() {
+        anyhow::bail!("--fix prints diffs to stdout; pair --format with --report to use both");
+    }
+    let settings = AuditSettings::from_project(&root)?;
     // Detect first: on the "nothing here" path, there is nothing to audit
     // and nothing to propose, so the (potentially hundreds-of-megabytes)
     // transcript scan below must never run for it. `config` is kept around
@@ -149,6 +256,21 @@ pub async fn execute(
     // second time.
     let (detected, config) = import_surfaces(&root);
     if detected.is_empty() {
+        // A pipeline reading `--format` output still gets a document.
+        if let (None, Some(format)) = (&report, format) {
+            let empty = AuditReport {
+                root,
+                detected,
+                agent_count: 0,
+                skill_count: 0,
+                findings: Vec::new(),
+                deep_raw: None,
+                usage: Vec::new(),
+                custom_rules: settings.rules.iter().map(|r| r.info()).collect(),
+            };
+            print!("{}", render(&empty, format));
+            return Ok(());
+        }
         let o = crate::cli::style::ok();
         let m = crate::cli::style::muted();
         anstream::println!(
@@ -163,35 +285,34 @@ pub async fn execute(
     // Scanned at most once here (only when something was detected and usage
     // measurement is enabled) and bound for the rest of the command —
     // transcripts can run into the hundreds of megabytes.
-    let usage = usage_enabled.then(|| crate::audit::usage::scan(&root));
-    let usage = usage.filter(|o| !o.is_empty());
-    let mut audit = run_audit(&root, &settings, usage.as_ref());
+    let usage = if usage_enabled {
+        crate::audit::usage::scan_all(&root)
+    } else {
+        Vec::new()
+    };
+    let mut audit = run_audit(&root, &settings, &usage);
     if deep {
         apply_deep_pass(&mut audit, &root, &settings, available_cli()).await?;
     }
-    audit.print_terminal(min_severity_from(&min_severity, quiet));
-    if let Some(out) = report {
-        let is_html = out
-            .extension()
-            .and_then(|e| e.to_str())
-            .map(|e| e.eq_ignore_ascii_case("html") || e.eq_ignore_ascii_case("htm"))
-            .unwrap_or(false);
-        if is_html {
-            std::fs::write(&out, audit.to_html())?;
-            let o = crate::cli::style::ok();
-            let m = crate::cli::style::muted();
-            anstream::println!(
-                "\n  {o}HTML report written to{o:#} {m}{}{m:#}",
-                out.display()
-            );
-        } else {
-            std::fs::write(&out, audit.to_markdown())?;
-            let o = crate::cli::style::ok();
-            let m = crate::cli::style::muted();
-            anstream::println!(
-                "\n  {o}Markdown report written to{o:#} {m}{}{m:#}",
-                out.display()
-            );
+    if let Some(mode) = fix {
+        audit = fix_findings(&root, audit, mode, || run_audit(&root, &settings, &usage))?;
+    }
+    match (report, format) {
+        // `--format` alone: the report replaces the terminal output.
+        (None, Some(format)) => print!("{}", render(&audit, format)),
+        (report, format) => {
+            audit.print_terminal(min_severity_from(&min_severity, quiet));
+            if let Some(out) = report {
+                let format = format.unwrap_or_else(|| ReportFormat::from_extension(&out));
+                std::fs::write(&out, render(&audit, format))?;
+                let o = crate::cli::style::ok();
+                let m = crate::cli::style::muted();
+                anstream::println!(
+                    "\n  {o}{} report written to{o:#} {m}{}{m:#}",
+                    format.label(),
+                    out.display()
+                );
+            }
         }
     }
     if propose {
@@ -235,11 +356,12 @@ mod tests {
     use super::*;
 
     /// Points `ARMADAI_CLAUDE_PROJECTS_DIR` at a fresh, empty tempdir so
-    /// `execute()`'s unconditional `usage::scan(&root)` call never reads the
-    /// real machine's `~/.claude/projects` — non-deterministic across
-    /// machines, and on a machine with a real transcript corpus, actively
-    /// wrong for tests that assert on a clean, controlled fixture. Mirrors
-    /// `discovery`'s own `ProjectsDirGuard`.
+    /// `execute()`'s unconditional `usage::scan_all(&root)` call never reads
+    /// the real machine's `~/.claude/projects` (nor its Gemini/Codex
+    /// sessions) — non-deterministic across machines, and on a machine with
+    /// a real transcript corpus, actively wrong for tests that assert on a
+    /// clean, controlled fixture. Mirrors `discovery`'s own
+    /// `ProjectsDirGuard`.
     ///
     /// The `MutexGuard` is a struct field, not a bare local binding, which is
     /// what keeps clippy's `await_holding_lock` from firing when this guard
@@ -255,8 +377,7 @@ mod tests {
         fn empty() -> Self {
             let lock = armadai_core::config::ENV_MUTEX.lock().unwrap();
             let dir = tempfile::tempdir().unwrap();
-            // SAFETY: modifies the global environment; serialised via ENV_MUTEX.
-            unsafe { std::env::set_var("ARMADAI_CLAUDE_PROJECTS_DIR", dir.path()) }
+            set_session_dirs(Some(dir.path()));
             Self {
                 _dir: dir,
                 _lock: lock,
@@ -268,8 +389,7 @@ mod tests {
         /// fresh empty one.
         fn at(dir: tempfile::TempDir) -> Self {
             let lock = armadai_core::config::ENV_MUTEX.lock().unwrap();
-            // SAFETY: modifies the global environment; serialised via ENV_MUTEX.
-            unsafe { std::env::set_var("ARMADAI_CLAUDE_PROJECTS_DIR", dir.path()) }
+            set_session_dirs(Some(dir.path()));
             Self {
                 _dir: dir,
                 _lock: lock,
@@ -279,8 +399,26 @@ mod tests {
 
     impl Drop for ProjectsDirGuard {
         fn drop(&mut self) {
-            // SAFETY: restoring env state at end of test scope.
-            unsafe { std::env::remove_var("ARMADAI_CLAUDE_PROJECTS_DIR") }
+            set_session_dirs(None);
+        }
+    }
+
+    /// Points (or, with `None`, resets) the session roots of every CLI whose
+    /// usage is scanned. Gemini and Codex share the Claude directory: their
+    /// own layouts never match a Claude transcript tree.
+    fn set_session_dirs(dir: Option<&std::path::Path>) {
+        for var in [
+            "ARMADAI_CLAUDE_PROJECTS_DIR",
+            "ARMADAI_GEMINI_TMP_DIR",
+            "ARMADAI_CODEX_SESSIONS_DIR",
+        ] {
+            // SAFETY: modifies the global environment; callers hold ENV_MUTEX.
+            unsafe {
+                match dir {
+                    Some(dir) => std::env::set_var(var, dir),
+                    None => std::env::remove_var(var),
+                }
+            }
         }
     }
 
@@ -332,11 +470,13 @@ mod tests {
         let result = execute(
             Some(PathBuf::from("/nonexistent/xyz")),
             None,
+            None,
             "info".to_string(),
             false,
             false,
             false,
             false,
+            None,
         )
         .await;
         assert!(result.is_err());
@@ -352,11 +492,13 @@ mod tests {
         let result = execute(
             Some(dir.path().to_path_buf()),
             None,
+            None,
             "info".to_string(),
             false,
             false,
             false,
             false,
+            None,
         )
         .await;
         assert!(result.is_err()); // A01 critical -> non-zero exit
@@ -377,11 +519,13 @@ mod tests {
         let result = execute(
             Some(dir.path().to_path_buf()),
             Some(report_path.clone()),
+            None,
             "info".to_string(),
             false,
             false,
             false,
             false,
+            None,
         )
         .await;
         assert!(result.is_ok());
@@ -389,6 +533,81 @@ mod tests {
         assert!(md.contains("# armadai audit"));
     }
 
+    #[tokio::test]
+    async fn execute_writes_sarif_with_a_line_for_a_deprecated_model() {
+        let _env = ProjectsDirGuard::empty();
+        let dir = tempfile::tempdir().unwrap();
+        let agents = dir.path().join(".claude/agents");
+        std::fs::create_dir_all(&agents).unwrap();
+        std::fs::write(
+            agents.join("old.md"),
+            "---\nname: old\ndescription: Old agent\nmodel: gpt-4-turbo\ntools: Read\n---\nShort prompt.",
+        )
+        .unwrap();
+        let report_path = dir.path().join("audit.out");
+        let result = execute(
+            Some(dir.path().to_path_buf()),
+            Some(report_path.clone()),
+            Some(ReportFormat::Sarif),
+            "info".to_string(),
+            false,
+            false,
+            false,
+            false,
+            None,
+        )
+        .await;
+        assert!(result.is_err(), "a deprecated model is critical");
+        let log: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(report_path).unwrap()).unwrap();
+        let results = log["runs"][0]["results"].as_array().unwrap();
+        let a03 = results.iter().find(|r| r["ruleId"] == "A03").unwrap();
+        let location = &a03["locations"][0]["physicalLocation"];
+        assert_eq!(location["artifactLocation"]["uri"], ".claude/agents/old.md");
+        assert_eq!(location["region"]["startLine"], 4);
+    }
+
+    #[tokio::test]
+    async fn execute_fix_replaces_a_deprecated_model_then_audits_again() {
+        let _env = ProjectsDirGuard::empty();
+        let dir = tempfile::tempdir().unwrap();
+        let agents = dir.path().join(".claude/agents");
+        std::fs::create_dir_all(&agents).unwrap();
+        let text = "---\nname: old\ndescription: Old agent\nmodel: gpt-4-turbo\ntools: Read\n---\nShort prompt.";
+        std::fs::write(agents.join("old.md"), text).unwrap();
+        let run = |mode| {
+            execute(
+                Some(dir.path().to_path_buf()),
+                None,
+                None,
+                "info".to_string(),
+                false,
+                false,
+                false,
+                true,
+                Some(mode),
+            )
+        };
+
+        assert!(run(FixMode::DryRun).await.is_err(), "nothing was fixed");
+        assert_eq!(
+            std::fs::read_to_string(agents.join("old.md")).unwrap(),
+            text
+        );
+
+        assert!(run(FixMode::Apply).await.is_ok(), "the re-audit is clean");
+        assert_eq!(
+            std::fs::read_to_string(agents.join("old.md")).unwrap(),
+            text.replace("gpt-4-turbo", "gpt-4o")
+        );
+        let backups: Vec<_> = std::fs::read_dir(dir.path().join(".armadai/backups"))
+            .unwrap()
+            .filter_map(|e| e.ok())
+            .filter(|e| e.path().is_dir())
+            .collect();
+        assert_eq!(backups.len(), 1);
+    }
+
     #[tokio::test]
     async fn execute_writes_html_when_extension_is_html() {
         let _env = ProjectsDirGuard::empty();
@@ -404,11 +623,13 @@ mod tests {
         let result = execute(
             Some(dir.path().to_path_buf()),
             Some(report_path.clone()),
+            None,
             "info".to_string(),
             false,
             false,
             false,
             false,
+            None,
         )
         .await;
         assert!(result.is_ok());
@@ -430,11 +651,13 @@ mod tests {
         let result = execute(
             Some(dir.path().to_path_buf()),
             None,
+            None,
             "info".to_string(),
             false,
             true,
             false,
             false,
+            None,
         )
         .await;
         assert!(result.is_ok());
@@ -454,11 +677,13 @@ mod tests {
         let result = execute(
             Some(project.path().to_path_buf()),
             Some(report_path.clone()),
+            None,
             "info".to_string(),
             false,
             false,
             false,
             false, // no_usage
+            None,
         )
         .await;
         assert!(result.is_ok());
@@ -476,11 +701,13 @@ mod tests {
         let result = execute(
             Some(project.path().to_path_buf()),
             Some(report_path.clone()),
+            None,
             "info".to_string(),
             false,
             false,
             false,
             true, // no_usage
+            None,
         )
         .await;
         assert!(result.is_ok());
@@ -504,11 +731,13 @@ mod tests {
         let result = execute(
             Some(project.path().to_path_buf()),
             Some(report_path.clone()),
+            None,
             "info".to_string(),
             false,
             false,
             false,
             false, // no_usage: flag absent
+            None,
         )
         .await;
         assert!(result.is_ok());
@@ -532,11 +761,13 @@ mod tests {
         let result = execute(
             Some(project.path().to_path_buf()),
             Some(report_path.clone()),
+            None,
             "info".to_string(),
             false,
             false,
             false,
             true, // no_usage: the flag must win
+            None,
         )
         .await;
         assert!(result.is_ok());
@@ -551,8 +782,8 @@ mod tests {
         let agents = dir.path().join(".claude/agents");
         std::fs::create_dir_all(&agents).unwrap();
         std::fs::write(agents.join("a.md"), "---\nname: a\ndescription: d\n---\nP.").unwrap();
-        let settings = AuditSettings::from_project(dir.path());
-        let mut audit = run_audit(dir.path(), &settings, None);
+        let settings = AuditSettings::from_project(dir.path()).unwrap();
+        let mut audit = run_audit(dir.path(), &settings, &[]);
         let err = apply_deep_pass(&mut audit, dir.path(), &settings, None)
             .await
             .unwrap_err();
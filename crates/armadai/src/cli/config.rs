@@ -11,6 +11,7 @@ pub enum ConfigAction {
     /// Initialize or manage secrets (SOPS + age)
     #[command(long_about = "Initialize or manage secrets (SOPS + age).\n\n\
             Uses age for key generation and SOPS for encrypting provider API keys. \
+            Both are built in: the sops and age binaries are not required. \
             Secrets are stored in config/providers.sops.yaml.")]
     Secrets {
         #[command(subcommand)]
@@ -200,35 +201,25 @@ providers:
         let m = crate::cli::style::muted();
         anstream::println!("{m}Template created at: {}{m:#}", sops_path.display());
 
-        // Try to encrypt it with sops
-        let encrypt = std::process::Command::new("sops")
-            .args(["--encrypt", "--in-place", &sops_path.display().to_string()])
-            .output();
-
-        match encrypt {
-            Ok(output) if output.status.success() => {
+        let recipient =
+            armadai_secrets::keys::public_key(&config_dir.join(armadai_secrets::keys::KEY_FILE))?;
+        let data: serde_yaml_ng::Value = serde_yaml_ng::from_str(template)?;
+        match armadai_secrets::sops::encrypt_file(&sops_path, &data, &[recipient]) {
+            Ok(()) => {
                 let o = crate::cli::style::ok();
                 anstream::println!("{o}File encrypted successfully.{o:#}");
                 let m = crate::cli::style::muted();
-                anstream::println!("{m}\nEdit your secrets with:{m:#}");
-                anstream::println!("{m}  sops config/providers.sops.yaml{m:#}");
+                anstream::println!("{m}\nSet your keys with:{m:#}");
+                anstream::println!("{m}  armadai secrets set <provider>{m:#}");
             }
-            Ok(output) => {
-                let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(e) => {
                 let w = crate::cli::style::warn();
                 anstream::println!(
-                    "{w}\nWarning: could not encrypt file: {stderr}\n\
+                    "{w}\nWarning: could not encrypt file: {e}\n\
                      Make sure SOPS_AGE_KEY_FILE is set, then encrypt manually:\n  \
                      sops --encrypt --in-place config/providers.sops.yaml{w:#}"
                 );
             }
-            Err(_) => {
-                let w = crate::cli::style::warn();
-                anstream::println!(
-                    "{w}\nWarning: sops not found. Install it and encrypt manually:\n  \
-                     sops --encrypt --in-place config/providers.sops.yaml{w:#}"
-                );
-            }
         }
     } else {
         let m = crate::cli::style::muted();
@@ -265,7 +256,7 @@ async fn secrets_rotate() -> anyhow::Result<()> {
     // 1. Decrypt current secrets
     let m = crate::cli::style::muted();
     anstream::println!("{m}Decrypting current secrets...{m:#}");
-    let secrets = armadai_secrets::sops::decrypt_file(&sops_path)?;
+    let secrets = armadai_secrets::sops::decrypt_value(&sops_path)?;
 
     // 2. Backup old key
     let backup_path = config_dir.join("age-key.txt.bak");
@@ -275,29 +266,18 @@ async fn secrets_rotate() -> anyhow::Result<()> {
 
     // 3. Generate new key
     std::fs::remove_file(&key_path)?;
-    let output = std::process::Command::new("age-keygen")
-        .args(["-o", &key_path.display().to_string()])
-        .output()?;
-
-    if !output.status.success() {
-        // Restore backup
-        std::fs::copy(&backup_path, &key_path)?;
-        anyhow::bail!(
-            "age-keygen failed: {}",
-            String::from_utf8_lossy(&output.stderr)
-        );
-    }
+    let public_key = match armadai_secrets::keys::generate_key_file(&key_path) {
+        Ok(public_key) => public_key,
+        Err(e) => {
+            // Restore backup
+            std::fs::copy(&backup_path, &key_path)?;
+            anyhow::bail!("age key generation failed: {e}");
+        }
+    };
     let o = crate::cli::style::ok();
     anstream::println!("{o}New age key generated.{o:#}");
 
     // 4. Update .sops.yaml with new public key
-    let key_content = std::fs::read_to_string(&key_path)?;
-    let public_key = key_content
-        .lines()
-        .find(|l| l.starts_with("# public key:"))
-        .and_then(|l| l.strip_prefix("# public key: "))
-        .ok_or_else(|| anyhow::anyhow!("Could not extract public key"))?;
-
     let sops_config = format!(
         r#"creation_rules:
   - path_regex: \.sops\.yaml$
@@ -310,19 +290,8 @@ async fn secrets_rotate() -> anyhow::Result<()> {
     anstream::println!("{m}Updated .sops.yaml with new public key.{m:#}");
 
     // 5. Re-encrypt secrets with new key
-    let yaml = serde_yaml_ng::to_string(&secrets)?;
-    std::fs::write(&sops_path, yaml)?;
-
-    let encrypt = std::process::Command::new("sops")
-        .args(["--encrypt", "--in-place", &sops_path.display().to_string()])
-        .output()?;
-
-    if !encrypt.status.success() {
-        anyhow::bail!(
-            "Failed to re-encrypt with new key: {}",
-            String::from_utf8_lossy(&encrypt.stderr)
-        );
-    }
+    armadai_secrets::sops::encrypt_file(&sops_path, &secrets, &[public_key])
+        .map_err(|e| anyhow::anyhow!("Failed to re-encrypt with new key: {e}"))?;
 
     let o = crate::cli::style::ok();
     anstream::println!("{o}Secrets re-encrypted with new key.{o:#}");
@@ -0,0 +1,214 @@
+//! `armadai convert`: bring a CrewAI crew or an AutoGen team into the
+//! project as ArmadAI agents plus an `orchestration:` config (see
+//! `crate::frameworks`).
+
+use std::path::{Path, PathBuf};
+
+use clap::Args;
+
+use super::import::{Destination, destination, fragment_body};
+use crate::frameworks::{self, ConvertedAgent, Framework};
+use crate::linker::import::{append_declared, rewrite_agent_md, yaml_list, yaml_scalar};
+use crate::linker::slugify;
+use armadai_core::agent_source;
+use armadai_core::project;
+use armadai_core::prompt::Prompt;
+
+#[derive(Args, Debug)]
+pub struct ConvertArgs {
+    /// Framework the definitions come from
+    #[arg(long, short, value_enum)]
+    pub from: Framework,
+    /// CrewAI project directory or agents.yaml; AutoGen JSON file
+    pub path: PathBuf,
+    /// Where agents go: agents.yaml (default) or an agents directory
+    #[arg(long)]
+    pub into: Option<PathBuf>,
+    /// Only convert the agents, leave the orchestration config alone
+    #[arg(long)]
+    pub no_orchestration: bool,
+    /// Show what would be written without writing anything
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
+pub async fn execute(args: ConvertArgs) -> anyhow::Result<()> {
+    let (root, config) = project::find_project_config().ok_or_else(|| {
+        anyhow::anyhow!(
+            "No project config found (.armadai/config.yaml or armadai.yaml). \
+             Run `armadai init --project` to create one."
+        )
+    })?;
+    let destination = destination(&root, args.into)?;
+    let conversion = frameworks::convert(args.from, &args.path)?;
+
+    let fragments = agent_source::project_fragments(&root);
+    let (library, _) = agent_source::load_all_agents(&config, &root, &fragments);
+
+    let h = crate::cli::style::header();
+    let m = crate::cli::style::muted();
+    let o = crate::cli::style::ok();
+    let w = crate::cli::style::warn();
+    anstream::println!(
+        "{h}Converting {} definitions{h:#} from {} ({} agent(s))",
+        args.from.label(),
+        args.path.display(),
+        conversion.agents.len()
+    );
+
+    let mut written = Vec::new();
+    for agent in &conversion.agents {
+        let model = agent.view.model.as_deref().unwrap_or_default();
+        if library.iter().any(|a| slugify(&a.name) == agent.name) {
+            anstream::println!("  {m}= {} already in the library, skipped{m:#}", agent.name);
+            continue;
+        }
+        if args.dry_run {
+            anstream::println!("  {o}+{o:#} {} ({}, {model})", agent.name, agent.provider);
+            continue;
+        }
+        match write_agent(&root, &destination, agent, &fragments) {
+            Ok(paths) => {
+                let paths: Vec<String> = paths
+                    .iter()
+                    .map(|p| p.strip_prefix(&root).unwrap_or(p).display().to_string())
+                    .collect();
+                anstream::println!(
+                    "  {o}+{o:#} {} ({}, {model}) {m}→ {}{m:#}",
+                    agent.name,
+                    agent.provider,
+                    paths.join(", ")
+                );
+                written.push(agent.name.clone());
+            }
+            Err(e) => anstream::eprintln!("  {w}skip: {} — {e}{w:#}", agent.name),
+        }
+    }
+    if let Destination::Directory(dir) = &destination
+        && !written.is_empty()
+    {
+        let rel = dir.strip_prefix(&root).unwrap_or(dir);
+        anstream::println!(
+            "{m}  list the new files of `{}` under `agents:` in the project config to include them{m:#}",
+            rel.display()
+        );
+    }
+
+    if let Some(topology) = conversion
+        .topology
+        .as_ref()
+        .filter(|_| !args.no_orchestration)
+    {
+        let yaml = topology.to_yaml();
+        let config_file = project::config_file(&root);
+        match config_file {
+            Some(file) if config.orchestration.is_none() && !args.dry_run => {
+                let mut text = std::fs::read_to_string(&file)?;
+                if !text.is_empty() && !text.ends_with('\n') {
+                    text.push('\n');
+                }
+                text.push('\n');
+                text.push_str(&yaml);
+                std::fs::write(&file, text)?;
+                let rel = file.strip_prefix(&root).unwrap_or(&file);
+                anstream::println!("\n{o}Orchestration{o:#} added to {}:", rel.display());
+            }
+            _ if args.dry_run => anstream::println!("\n{h}Orchestration{h:#} (dry run):"),
+            _ => anstream::println!(
+                "\n{w}The project config already has an orchestration section;{w:#} \
+                 merge this one by hand:"
+            ),
+        }
+        for line in yaml.lines() {
+            anstream::println!("  {m}{line}{m:#}");
+        }
+    }
+
+    if !conversion.unmapped.is_empty() {
+        anstream::println!(
+            "\n{w}Not converted{w:#} ({} setting(s) with no ArmadAI equivalent):",
+            conversion.unmapped.len()
+        );
+        for note in &conversion.unmapped {
+            anstream::println!("  - {note}");
+        }
+    }
+    Ok(())
+}
+
+/// Write one converted agent; returns the files written.
+fn write_agent(
+    root: &Path,
+    destination: &Destination,
+    agent: &ConvertedAgent,
+    fragments: &[Prompt],
+) -> anyhow::Result<Vec<PathBuf>> {
+    let slug = &agent.name;
+    match destination {
+        Destination::Directory(dir) => {
+            let path = dir.join(format!("{slug}.md"));
+            if path.exists() {
+                anyhow::bail!("{} already exists", path.display());
+            }
+            let mut header = format!("# {slug}\n\n## Metadata\n- provider: {}\n", agent.provider);
+            if let Some(t) = agent.temperature {
+                header.push_str(&format!("- temperature: {t}\n"));
+            }
+            if let Some(n) = agent.max_tokens {
+                header.push_str(&format!("- max_tokens: {n}\n"));
+            }
+            if let Some(s) = agent.timeout {
+                header.push_str(&format!("- timeout: {s}\n"));
+            }
+            std::fs::create_dir_all(dir)?;
+            std::fs::write(&path, rewrite_agent_md(&header, &agent.view))?;
+            Ok(vec![path])
+        }
+        Destination::Declarations(decls_path) => {
+            // A declaration is dropped when a library file has its name.
+            for dir in project::library_dirs(root) {
+                let file = dir.join(format!("{slug}.md"));
+                if file.is_file() {
+                    anyhow::bail!("{} would shadow the declaration", file.display());
+                }
+            }
+            let fragment_path = project::prompt_dirs(root)[0].join(format!("{slug}.md"));
+            if fragment_path.exists() || fragments.iter().any(|f| f.name == *slug) {
+                anyhow::bail!("a prompt fragment named '{slug}' already exists");
+            }
+            let body = fragment_body(&agent.view)?;
+
+            let mut fields: Vec<(&str, String)> = vec![("name", slug.to_string())];
+            if let Some(description) = agent.view.description.as_deref() {
+                fields.push(("description", yaml_scalar(description)));
+            }
+            fields.push(("provider", agent.provider.clone()));
+            if let Some(model) = &agent.view.model {
+                fields.push(("model", yaml_scalar(model)));
+            }
+            if let Some(t) = agent.temperature {
+                fields.push(("temperature", t.to_string()));
+            }
+            if let Some(n) = agent.max_tokens {
+                fields.push(("max_tokens", n.to_string()));
+            }
+            if let Some(s) = agent.timeout {
+                fields.push(("timeout", s.to_string()));
+            }
+            fields.push(("prompt", yaml_list(std::slice::from_ref(slug))));
+            let yaml = std::fs::read_to_string(decls_path).unwrap_or_default();
+            let yaml = append_declared(&yaml, &fields);
+
+            std::fs::create_dir_all(fragment_path.parent().unwrap_or(root))?;
+            std::fs::write(
+                &fragment_path,
+                format!("---\nname: {}\n---\n\n{body}\n", yaml_scalar(slug)),
+            )?;
+            if let Some(parent) = decls_path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::write(decls_path, yaml)?;
+            Ok(vec![fragment_path, decls_path.clone()])
+        }
+    }
+}
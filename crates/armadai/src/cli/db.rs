@@ -0,0 +1,128 @@
+//! Database maintenance commands — prune old history, reclaim space, inspect
+//! table sizes.
+//!
+//! `db prune` deletes runs (event log + projections) older than a given age,
+//! optionally folding their costs into daily rollups first so `armadai costs`
+//! totals are preserved. The same pruning runs automatically on startup when
+//! `storage.retention.older_than` is set in config.yaml.
+
+use clap::Subcommand;
+
+#[derive(Subcommand)]
+pub enum DbAction {
+    /// Delete runs older than a given age
+    Prune {
+        /// Maximum age to keep, e.g. 90d, 12w, 48h
+        #[arg(long, value_name = "AGE")]
+        older_than: String,
+        /// Keep per-day cost aggregates of the pruned runs
+        #[arg(long)]
+        keep_costs: bool,
+    },
+    /// Reclaim unused space in the database file
+    Vacuum,
+    /// Show row counts and sizes per table, and runs per project
+    Stats,
+}
+
+/// Execute the `armadai db` command dispatcher.
+pub async fn execute(action: DbAction) -> anyhow::Result<()> {
+    #[cfg(feature = "storage")]
+    {
+        use armadai_storage::maintenance;
+
+        let db = crate::db::init_db()?;
+        match action {
+            DbAction::Prune {
+                older_than,
+                keep_costs,
+            } => {
+                let secs = crate::db::parse_age(&older_than)?;
+                let report = maintenance::prune_older_than(&db, secs, keep_costs)?;
+                let plural = if report.runs == 1 { "run" } else { "runs" };
+                println!(
+                    "{} {plural} older than {older_than} pruned ({} events, {} projection rows)",
+                    report.runs, report.events, report.projection_rows
+                );
+                if keep_costs && report.rolled_up > 0 {
+                    println!("{} run costs kept in daily rollups", report.rolled_up);
+                }
+                if report.runs > 0 {
+                    println!("Run `armadai db vacuum` to reclaim the freed space.");
+                }
+            }
+            DbAction::Vacuum => {
+                let before = maintenance::stats(&db)?.file_bytes;
+                maintenance::vacuum(&db)?;
+                let after = maintenance::stats(&db)?.file_bytes;
+                println!(
+                    "Database vacuumed: {} -> {}",
+                    format_bytes(before),
+                    format_bytes(after)
+                );
+            }
+            DbAction::Stats => print_stats(&maintenance::stats(&db)?),
+        }
+        Ok(())
+    }
+
+    #[cfg(not(feature = "storage"))]
+    {
+        let _ = action;
+        anyhow::bail!(
+            "Database commands require the 'storage' feature. Build with: cargo build --features storage"
+        )
+    }
+}
+
+#[cfg(feature = "storage")]
+fn print_stats(stats: &armadai_storage::maintenance::DbStats) {
+    println!(
+        "Database size: {} ({} free)",
+        format_bytes(stats.file_bytes),
+        format_bytes(stats.free_bytes)
+    );
+    println!();
+    println!("{:<22} {:>10} {:>12}", "TABLE", "ROWS", "SIZE");
+    println!("{}", "-".repeat(46));
+    for t in &stats.tables {
+        let size = t.bytes.map(format_bytes).unwrap_or_else(|| "-".to_string());
+        println!("{:<22} {:>10} {:>12}", t.name, t.rows, size);
+    }
+
+    if stats.projects.is_empty() {
+        return;
+    }
+    println!();
+    println!("{:<50} {:>8} {:>12}", "PROJECT", "RUNS", "COST");
+    println!("{}", "-".repeat(72));
+    for p in &stats.projects {
+        let name = p.project.as_deref().unwrap_or("(no project)");
+        println!("{:<50} {:>8} {:>12.6}", name, p.runs, p.cost);
+    }
+}
+
+#[cfg(feature = "storage")]
+fn format_bytes(bytes: i64) -> String {
+    const KIB: f64 = 1024.0;
+    let b = bytes as f64;
+    if b >= KIB * KIB {
+        format!("{:.1} MiB", b / (KIB * KIB))
+    } else if b >= KIB {
+        format!("{:.1} KiB", b / KIB)
+    } else {
+        format!("{bytes} B")
+    }
+}
+
+#[cfg(all(test, feature = "storage"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_bytes_picks_unit() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(4096), "4.0 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024 / 2), "1.5 MiB");
+    }
+}
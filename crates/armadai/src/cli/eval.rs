@@ -0,0 +1,246 @@
+//! `armadai eval` — run a golden suite, score it, compare to a baseline.
+//!
+//! Scored results are stored (storage feature) so the next eval of the same
+//! suite compares against them; `--baseline` pins a specific eval instead.
+//! The command exits non-zero when any case fails, so it can gate CI.
+
+use std::path::PathBuf;
+
+use crate::eval::{self, CaseResult, Runner, Suite};
+
+pub async fn execute(
+    suite_path: PathBuf,
+    junit: Option<PathBuf>,
+    baseline: Option<String>,
+    no_store: bool,
+) -> anyhow::Result<()> {
+    let suite = Suite::load(&suite_path)?;
+    let runner = Runner::new(suite.fake.as_deref())?;
+
+    let h = crate::cli::style::header();
+    let m = crate::cli::style::muted();
+    let mode = match &suite.fake {
+        Some(p) => format!("fake: {}", p.display()),
+        None => "live providers".to_string(),
+    };
+    anstream::println!(
+        "{h}Eval '{}'{h:#} {m}({} cases, {mode}){m:#}",
+        suite.name,
+        suite.cases.len()
+    );
+
+    let results = runner.run_suite(&suite, print_case).await?;
+
+    let passed = results.iter().filter(|r| r.passed()).count();
+    let score = eval::suite_score(&results);
+    let cost: f64 = results.iter().map(|r| r.cost).sum();
+    let style = if passed == results.len() {
+        crate::cli::style::ok()
+    } else {
+        crate::cli::style::err()
+    };
+    anstream::println!(
+        "\n{style}{passed}/{} passed{style:#}  score {:.1}%  cost ${cost:.4}",
+        results.len(),
+        score * 100.0
+    );
+
+    #[cfg(feature = "storage")]
+    store_and_compare(&suite, &results, baseline.as_deref(), no_store)?;
+    #[cfg(not(feature = "storage"))]
+    {
+        let _ = no_store;
+        if baseline.is_some() {
+            anyhow::bail!(
+                "Baselines require the 'storage' feature. Build with: cargo build --features storage"
+            );
+        }
+    }
+
+    if let Some(path) = junit {
+        std::fs::write(&path, eval::junit::render(&suite.name, &results))?;
+        anstream::println!("{m}JUnit report written to {}{m:#}", path.display());
+    }
+
+    if passed < results.len() {
+        anyhow::bail!(
+            "{} of {} cases failed",
+            results.len() - passed,
+            results.len()
+        );
+    }
+    Ok(())
+}
+
+fn print_case(r: &CaseResult) {
+    let m = crate::cli::style::muted();
+    let stats = format!(
+        "{:.0}%  ${:.4}  {}ms",
+        r.score * 100.0,
+        r.cost,
+        r.duration_ms
+    );
+    if r.passed() {
+        let o = crate::cli::style::ok();
+        anstream::println!("  {o}PASS{o:#} {}  {m}{stats}{m:#}", r.name);
+    } else {
+        let e = crate::cli::style::err();
+        anstream::println!("  {e}FAIL{e:#} {}  {m}{stats}{m:#}", r.name);
+        for f in &r.failures {
+            anstream::println!("       {m}- {f}{m:#}");
+        }
+    }
+}
+
+#[cfg(feature = "storage")]
+fn store_and_compare(
+    suite: &Suite,
+    results: &[CaseResult],
+    baseline: Option<&str>,
+    no_store: bool,
+) -> anyhow::Result<()> {
+    use armadai_storage::queries::{self, EvalCaseRecord, EvalRunRecord};
+
+    let db = crate::db::init_db()?;
+    let record = EvalRunRecord {
+        id: uuid::Uuid::new_v4().to_string(),
+        suite: suite.name.clone(),
+        passed: results.iter().filter(|r| r.passed()).count() as i64,
+        total: results.len() as i64,
+        score: eval::suite_score(results),
+        cost: results.iter().map(|r| r.cost).sum(),
+    };
+    let cases: Vec<EvalCaseRecord> = results
+        .iter()
+        .map(|r| EvalCaseRecord {
+            case_name: r.name.clone(),
+            passed: r.passed(),
+            score: r.score,
+            cost: r.cost,
+            tokens_in: r.output.tokens_in as i64,
+            tokens_out: r.output.tokens_out as i64,
+            duration_ms: r.duration_ms as i64,
+            run_id: r.output.run_id.clone(),
+            failures: r.failures.clone(),
+        })
+        .collect();
+
+    let base = match baseline {
+        Some(id) => Some(
+            queries::get_eval_run(&db, id)?
+                .ok_or_else(|| anyhow::anyhow!("Baseline eval '{id}' not found"))?,
+        ),
+        None => queries::latest_eval_run(&db, &suite.name)?,
+    };
+    if !no_store {
+        queries::insert_eval_run(&db, &record, &cases)?;
+        let m = crate::cli::style::muted();
+        anstream::println!("{m}Eval stored as {}{m:#}", record.id);
+    }
+
+    let Some(base) = base else {
+        return Ok(());
+    };
+    let base_cases = queries::get_eval_results(&db, &base.id)?;
+    print_comparison(&base, &compare(&cases, &base_cases), record.score);
+    Ok(())
+}
+
+/// Per-case changes between a baseline eval and the current one.
+#[cfg(feature = "storage")]
+#[derive(Debug, Default, PartialEq)]
+struct Comparison {
+    /// Passed in the baseline, failing now.
+    regressions: Vec<String>,
+    /// Failing in the baseline, passing now.
+    fixes: Vec<String>,
+    /// Cases absent from the baseline.
+    new_cases: Vec<String>,
+}
+
+#[cfg(feature = "storage")]
+fn compare(
+    current: &[armadai_storage::queries::EvalCaseRecord],
+    baseline: &[armadai_storage::queries::EvalCaseRecord],
+) -> Comparison {
+    let mut cmp = Comparison::default();
+    for c in current {
+        match baseline.iter().find(|b| b.case_name == c.case_name) {
+            None => cmp.new_cases.push(c.case_name.clone()),
+            Some(b) if b.passed && !c.passed => cmp.regressions.push(c.case_name.clone()),
+            Some(b) if !b.passed && c.passed => cmp.fixes.push(c.case_name.clone()),
+            Some(_) => {}
+        }
+    }
+    cmp
+}
+
+#[cfg(feature = "storage")]
+fn print_comparison(base: &armadai_storage::queries::EvalRunRecord, cmp: &Comparison, score: f64) {
+    let h = crate::cli::style::header();
+    let m = crate::cli::style::muted();
+    anstream::println!(
+        "\n{h}vs baseline{h:#} {m}{} ({}/{} passed, score {:.1}%){m:#}",
+        base.id,
+        base.passed,
+        base.total,
+        base.score * 100.0
+    );
+    let delta = (score - base.score) * 100.0;
+    let style = if delta < 0.0 {
+        crate::cli::style::err()
+    } else {
+        crate::cli::style::ok()
+    };
+    anstream::println!("  score {style}{delta:+.1} pts{style:#}");
+    let e = crate::cli::style::err();
+    for name in &cmp.regressions {
+        anstream::println!("  {e}regressed{e:#} {name}");
+    }
+    let o = crate::cli::style::ok();
+    for name in &cmp.fixes {
+        anstream::println!("  {o}fixed{o:#} {name}");
+    }
+    for name in &cmp.new_cases {
+        anstream::println!("  {m}new{m:#} {name}");
+    }
+}
+
+#[cfg(all(test, feature = "storage"))]
+mod tests {
+    use super::*;
+    use armadai_storage::queries::EvalCaseRecord;
+
+    fn case(name: &str, passed: bool) -> EvalCaseRecord {
+        EvalCaseRecord {
+            case_name: name.to_string(),
+            passed,
+            score: if passed { 1.0 } else { 0.0 },
+            cost: 0.0,
+            tokens_in: 0,
+            tokens_out: 0,
+            duration_ms: 0,
+            run_id: None,
+            failures: vec![],
+        }
+    }
+
+    #[test]
+    fn compare_classifies_cases() {
+        let baseline = [case("a", true), case("b", false), case("c", true)];
+        let current = [
+            case("a", false),
+            case("b", true),
+            case("c", true),
+            case("d", true),
+        ];
+        assert_eq!(
+            compare(&current, &baseline),
+            Comparison {
+                regressions: vec!["a".into()],
+                fixes: vec!["b".into()],
+                new_cases: vec!["d".into()],
+            }
+        );
+    }
+}
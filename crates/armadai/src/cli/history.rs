@@ -1,4 +1,4 @@
-pub async fn execute(agent: Option<String>) -> anyhow::Result<()> {
+pub async fn execute(agent: Option<String>, search: Option<String>) -> anyhow::Result<()> {
     #[cfg(feature = "storage")]
     {
         use crate::db::init_db;
@@ -6,10 +6,22 @@ pub async fn execute(agent: Option<String>) -> anyhow::Result<()> {
 
         let db = init_db()?;
 
-        let records = queries::get_history(&db, agent.as_deref(), 50)?;
+        let (records, snippets): (Vec<_>, Vec<_>) = match search.as_deref() {
+            Some(query) => queries::search_history(&db, query, agent.as_deref(), 50)?
+                .into_iter()
+                .map(|hit| (hit.run, Some(hit.snippet)))
+                .unzip(),
+            None => queries::get_history(&db, agent.as_deref(), 50)?
+                .into_iter()
+                .map(|r| (r, None))
+                .unzip(),
+        };
 
         if records.is_empty() {
-            println!("No execution records found.");
+            match search {
+                Some(query) => println!("No execution records match '{query}'."),
+                None => println!("No execution records found."),
+            }
             return Ok(());
         }
 
@@ -19,7 +31,7 @@ pub async fn execute(agent: Option<String>) -> anyhow::Result<()> {
         );
         println!("{}", "-".repeat(89));
 
-        for r in &records {
+        for (r, snippet) in records.iter().zip(&snippets) {
             let model_short = if r.model.len() > 18 {
                 format!("{}...", &r.model[..17])
             } else {
@@ -29,6 +41,9 @@ pub async fn execute(agent: Option<String>) -> anyhow::Result<()> {
                 "{:<20} {:<15} {:<20} {:>6} {:>6} {:>10.6} {:>8}",
                 r.agent, r.provider, model_short, r.tokens_in, r.tokens_out, r.cost, r.duration_ms
             );
+            if let Some(snippet) = snippet {
+                println!("    {}", snippet.replace('\n', " "));
+            }
         }
 
         Ok(())
@@ -36,7 +51,7 @@ pub async fn execute(agent: Option<String>) -> anyhow::Result<()> {
 
     #[cfg(not(feature = "storage"))]
     {
-        let _ = agent;
+        let _ = (agent, search);
         anyhow::bail!(
             "History requires the 'storage' feature. Build with: cargo build --features storage"
         )
@@ -0,0 +1,452 @@
+use std::collections::BTreeMap;
+use std::io::IsTerminal;
+use std::path::{Path, PathBuf};
+
+use crate::audit::proposal::native_provider;
+use crate::audit::reverse::ImportedAgent;
+use crate::linker::import::{
+    self, AgentView, ImportBase, SyncStatus, append_declared, set_declared_field, yaml_list,
+    yaml_scalar,
+};
+use crate::linker::{LinkTarget, slugify};
+use armadai_core::agent::Agent;
+use armadai_core::agent_decl::{self, PromptStep};
+use armadai_core::agent_source::{self, declarations_path};
+use armadai_core::project;
+use armadai_core::prompt::Prompt;
+
+/// Where agents the library does not have yet are written.
+pub(super) enum Destination {
+    /// `.armadai/agents.yaml`, with one prompt fragment per agent.
+    Declarations(PathBuf),
+    /// A directory of ArmadAI agent files.
+    Directory(PathBuf),
+}
+
+/// What `--yes`, `--force` and `--dry-run` allow.
+#[derive(Debug, Clone, Copy)]
+pub struct ImportOptions {
+    pub yes: bool,
+    pub force: bool,
+    pub dry_run: bool,
+}
+
+pub async fn execute(
+    from: LinkTarget,
+    into: Option<PathBuf>,
+    agents_filter: Option<Vec<String>>,
+    options: ImportOptions,
+) -> anyhow::Result<()> {
+    let (root, config) = project::find_project_config().ok_or_else(|| {
+        anyhow::anyhow!(
+            "No project config found (.armadai/config.yaml or armadai.yaml). \
+             Run `armadai init --project` to create one."
+        )
+    })?;
+    let destination = destination(&root, into)?;
+
+    let reverse = crate::audit::reverse_linker(from.as_str())
+        .ok_or_else(|| anyhow::anyhow!("No importer for '{from}'"))?;
+    if !reverse.detect(&root) {
+        anyhow::bail!("No {from} configuration found in {}", root.display());
+    }
+    let mut natives = reverse.parse(&root).agents;
+    if let Some(ref filter) = agents_filter {
+        let wanted: Vec<String> = filter.iter().map(|n| slugify(n)).collect();
+        natives.retain(|a| wanted.contains(&slugify(&a.name)));
+        if natives.is_empty() {
+            anyhow::bail!(
+                "No {from} agents match the given filter: {}",
+                filter.join(", ")
+            );
+        }
+    }
+    if natives.is_empty() {
+        let m = crate::cli::style::muted();
+        anstream::println!("{m}No {from} agents to import.{m:#}");
+        return Ok(());
+    }
+
+    let fragments = agent_source::project_fragments(&root);
+    let (library, warnings) = agent_source::load_all_agents(&config, &root, &fragments);
+    for w in &warnings {
+        let s = crate::cli::style::warn();
+        anstream::eprintln!("{s}  warn: {}{s:#}", w.message());
+    }
+    let mut base = ImportBase::load(&root)?;
+    let interactive = std::io::stdin().is_terminal() && !options.yes && !options.dry_run;
+
+    let h = crate::cli::style::header();
+    anstream::println!("{h}Importing from {from}{h:#} ({} agent(s))", natives.len());
+
+    let (mut imported, mut pending, mut failed) = (0, 0, 0);
+    let mut base_changed = false;
+    'agents: for native in &natives {
+        let slug = slugify(&native.name);
+        let key = ImportBase::key(from.as_str(), &slug);
+        if !native.issues.is_empty() {
+            let w = crate::cli::style::warn();
+            for issue in &native.issues {
+                anstream::eprintln!("{w}  skip: {slug} — {}{w:#}", issue.message);
+            }
+            failed += 1;
+            continue;
+        }
+
+        let existing = library.iter().find(|a| slugify(&a.name) == slug);
+        let library_view = existing.map(AgentView::from_library);
+        let mut view = AgentView::from_native(native, from.as_str());
+        match &library_view {
+            Some(lib) => view.inherit_unset(lib),
+            None => {
+                view.model
+                    .get_or_insert_with(|| crate::audit::proposal::portable_model(None));
+            }
+        }
+        let native_text = view.canonical();
+        let library_text = library_view.as_ref().map(AgentView::canonical);
+        let base_text = base.agents.get(&key).cloned();
+        let status = import::classify(base_text.as_deref(), library_text.as_deref(), &native_text);
+
+        print_status(&slug, status);
+        match status {
+            SyncStatus::InSync => {
+                if base_text.as_deref() != Some(native_text.as_str()) {
+                    base.agents.insert(key, native_text);
+                    base_changed = true;
+                }
+                continue;
+            }
+            SyncStatus::LibraryChanged => {
+                let m = crate::cli::style::muted();
+                anstream::println!(
+                    "{m}    the library is ahead; `armadai link --target {from}` carries it over{m:#}"
+                );
+                continue;
+            }
+            SyncStatus::Conflict => {
+                let base_text = base_text.as_deref().unwrap_or_default();
+                let library_text = library_text.as_deref().unwrap_or_default();
+                print_diff(
+                    "library changes since the last import",
+                    base_text,
+                    library_text,
+                );
+                print_diff(
+                    "native changes since the last import",
+                    base_text,
+                    &native_text,
+                );
+            }
+            _ => print_diff(
+                "library -> native",
+                library_text.as_deref().unwrap_or_default(),
+                &native_text,
+            ),
+        }
+
+        let accept = if options.dry_run {
+            false
+        } else if interactive {
+            let theme = dialoguer::theme::ColorfulTheme::default();
+            let choice = dialoguer::Select::with_theme(&theme)
+                .with_prompt(format!("Import {slug}?"))
+                .items(["accept", "skip", "quit"])
+                .default(if status == SyncStatus::Conflict { 1 } else { 0 })
+                .interact()?;
+            match choice {
+                0 => true,
+                1 => false,
+                _ => break 'agents,
+            }
+        } else {
+            options.yes && (status.auto_accept() || options.force)
+        };
+        if !accept {
+            pending += 1;
+            continue;
+        }
+
+        let result = match (existing, &library_view) {
+            (Some(agent), Some(lib)) => update_existing(&root, agent, lib, &view, &fragments),
+            _ => add_new(&root, &destination, native, &slug, &view, &fragments),
+        };
+        match result {
+            Ok(written) => {
+                let o = crate::cli::style::ok();
+                for path in written {
+                    let rel = path.strip_prefix(&root).unwrap_or(&path);
+                    anstream::println!("    {o}imported{o:#} -> {}", rel.display());
+                }
+                base.agents.insert(key, native_text);
+                base_changed = true;
+                imported += 1;
+            }
+            Err(e) => {
+                let w = crate::cli::style::warn();
+                anstream::eprintln!("{w}  skip: {slug} — {e}{w:#}");
+                failed += 1;
+            }
+        }
+    }
+
+    if base_changed && !options.dry_run {
+        base.save(&root)?;
+    }
+
+    let m = crate::cli::style::muted();
+    println!();
+    anstream::println!("{m}{imported} imported, {pending} left as is, {failed} skipped{m:#}");
+    if pending > 0 && !options.dry_run && !interactive {
+        let hint = if options.yes {
+            "Conflicts need --force, or run in a terminal to choose per agent."
+        } else {
+            "Re-run with --yes to accept them, or in a terminal to choose per agent."
+        };
+        anstream::println!("{m}{hint}{m:#}");
+    }
+    if imported > 0 {
+        anstream::println!(
+            "{m}Run `armadai link --target {from}` to regenerate the linked files.{m:#}"
+        );
+    }
+    if failed > 0 {
+        anyhow::bail!("{failed} agent(s) could not be imported");
+    }
+    Ok(())
+}
+
+/// Resolve `--into`: the declarations file by default, else a directory.
+pub(super) fn destination(root: &Path, into: Option<PathBuf>) -> anyhow::Result<Destination> {
+    let declarations = declarations_path(root);
+    let Some(into) = into else {
+        return Ok(Destination::Declarations(declarations));
+    };
+    let path = if into.is_absolute() {
+        into
+    } else {
+        root.join(into)
+    };
+    let is_yaml = path
+        .extension()
+        .is_some_and(|ext| ext == "yaml" || ext == "yml");
+    if !is_yaml {
+        return Ok(Destination::Directory(path));
+    }
+    // `agents.yaml` alone means the project's declarations file.
+    if path == declarations || path == root.join("agents.yaml") {
+        return Ok(Destination::Declarations(declarations));
+    }
+    anyhow::bail!(
+        "--into {} is not read by ArmadAI; use {} or an agents directory",
+        path.display(),
+        declarations.display()
+    )
+}
+
+fn print_status(slug: &str, status: SyncStatus) {
+    let style = match status {
+        SyncStatus::InSync | SyncStatus::LibraryChanged => crate::cli::style::muted(),
+        SyncStatus::Conflict => crate::cli::style::err(),
+        SyncStatus::New => crate::cli::style::ok(),
+        SyncStatus::NativeChanged | SyncStatus::Differs => crate::cli::style::warn(),
+    };
+    let a = crate::cli::style::accent();
+    anstream::println!("  {a}{slug}{a:#}  {style}{}{style:#}", status.label());
+}
+
+pub(super) fn print_diff(title: &str, a: &str, b: &str) {
+    use armadai_core::orchestration::es::diff::{DiffLine, line_diff};
+
+    let m = crate::cli::style::muted();
+    let (r, ad) = (crate::cli::style::err(), crate::cli::style::ok());
+    anstream::println!("    {m}{title}{m:#}");
+    for line in line_diff(a, b) {
+        match line {
+            DiffLine::Same(t) => println!("      {t}"),
+            DiffLine::Removed(t) => anstream::println!("    {r}- {t}{r:#}"),
+            DiffLine::Added(t) => anstream::println!("    {ad}+ {t}{ad:#}"),
+        }
+    }
+}
+
+/// Bring a library agent in line with the native view, where it lives.
+fn update_existing(
+    root: &Path,
+    agent: &Agent,
+    library: &AgentView,
+    view: &AgentView,
+    fragments: &[Prompt],
+) -> anyhow::Result<Vec<PathBuf>> {
+    // Model and scope are only written when they moved, so a concrete
+    // model pinned in the library survives a native file that resolved it.
+    let model = view
+        .model
+        .clone()
+        .filter(|m| library.model.as_ref() != Some(m));
+    let scope = view
+        .scope
+        .clone()
+        .filter(|s| library.scope.as_ref() != Some(s));
+
+    if agent.source != declarations_path(root) {
+        let text = std::fs::read_to_string(&agent.source)?;
+        let changes = AgentView {
+            model,
+            scope,
+            ..view.clone()
+        };
+        std::fs::write(&agent.source, import::rewrite_agent_md(&text, &changes))?;
+        return Ok(vec![agent.source.clone()]);
+    }
+
+    // A declared agent: its prompt is one fragment this project owns.
+    let decls_path = declarations_path(root);
+    let decls = agent_decl::load(&decls_path)?;
+    let decl = decls
+        .agents
+        .iter()
+        .find(|d| d.name == agent.name)
+        .ok_or_else(|| anyhow::anyhow!("'{}' is not in {}", agent.name, decls_path.display()))?;
+    let [PromptStep::Plain(fragment)] = decl.prompt.as_slice() else {
+        anyhow::bail!("its prompt is composed from several fragments; merge by hand");
+    };
+    let shared = decls
+        .agents
+        .iter()
+        .filter(|d| {
+            d.prompt
+                .iter()
+                .any(|s| matches!(s, PromptStep::Plain(f) if f == fragment))
+        })
+        .count();
+    if shared > 1 {
+        anyhow::bail!("fragment '{fragment}' is shared with other agents; merge by hand");
+    }
+    let frag = fragments
+        .iter()
+        .find(|f| &f.name == fragment)
+        .filter(|f| f.source.starts_with(root))
+        .ok_or_else(|| anyhow::anyhow!("fragment '{fragment}' is not in this project"))?;
+    let body = fragment_body(view)?;
+
+    let mut yaml = std::fs::read_to_string(&decls_path)?;
+    if let Some(model) = &model {
+        yaml = set_declared_field(&yaml, &decl.name, "model", model)
+            .ok_or_else(|| anyhow::anyhow!("'{}' not found in the YAML text", decl.name))?;
+    }
+    if let Some(scope) = &scope {
+        yaml = set_declared_field(&yaml, &decl.name, "scope", &yaml_list(scope))
+            .ok_or_else(|| anyhow::anyhow!("'{}' not found in the YAML text", decl.name))?;
+    }
+
+    let text = std::fs::read_to_string(&frag.source)?;
+    let (_, old_body) = armadai_core::parser::frontmatter::extract_frontmatter(&text);
+    let head = text.strip_suffix(old_body).unwrap_or_default();
+    let separator = if head.is_empty() { "" } else { "\n" };
+    std::fs::write(&frag.source, format!("{head}{separator}{body}\n"))?;
+    let mut written = vec![frag.source.clone()];
+    if model.is_some() || scope.is_some() {
+        std::fs::write(&decls_path, yaml)?;
+        written.push(decls_path);
+    }
+    Ok(written)
+}
+
+/// Write an agent the library does not have yet.
+fn add_new(
+    root: &Path,
+    destination: &Destination,
+    native: &ImportedAgent,
+    slug: &str,
+    view: &AgentView,
+    fragments: &[Prompt],
+) -> anyhow::Result<Vec<PathBuf>> {
+    let provider = native_provider(native);
+    match destination {
+        Destination::Directory(dir) => {
+            let path = dir.join(format!("{slug}.md"));
+            if path.exists() {
+                anyhow::bail!("{} already exists", path.display());
+            }
+            std::fs::create_dir_all(dir)?;
+            std::fs::write(&path, import::new_agent_md(slug, provider, view))?;
+            let rel = path.strip_prefix(root).unwrap_or(&path);
+            let m = crate::cli::style::muted();
+            anstream::println!(
+                "{m}    list `{}` under `agents:` in the project config to include it{m:#}",
+                rel.display()
+            );
+            Ok(vec![path])
+        }
+        Destination::Declarations(decls_path) => {
+            // A declaration is dropped when a library file has its name.
+            for dir in project::library_dirs(root) {
+                let file = dir.join(format!("{slug}.md"));
+                if file.is_file() {
+                    anyhow::bail!(
+                        "{} would shadow the declaration; use --into {}",
+                        file.display(),
+                        dir.display()
+                    );
+                }
+            }
+            let fragment_path = project::prompt_dirs(root)[0].join(format!("{slug}.md"));
+            if fragment_path.exists() || fragments.iter().any(|f| f.name == slug) {
+                anyhow::bail!("a prompt fragment named '{slug}' already exists");
+            }
+            let body = fragment_body(view)?;
+            let description = native
+                .metadata
+                .description
+                .clone()
+                .or_else(|| {
+                    view.system_prompt
+                        .lines()
+                        .find(|l| !l.trim().is_empty())
+                        .map(str::to_string)
+                })
+                .unwrap_or_default();
+
+            let mut fields: Vec<(&str, String)> = vec![("name", slug.to_string())];
+            if !description.trim().is_empty() {
+                fields.push(("description", yaml_scalar(description.trim())));
+            }
+            fields.push(("provider", provider.to_string()));
+            if let Some(model) = &view.model {
+                fields.push(("model", yaml_scalar(model)));
+            }
+            if let Some(scope) = view.scope.as_ref().filter(|s| !s.is_empty()) {
+                fields.push(("scope", yaml_list(scope)));
+            }
+            fields.push(("prompt", yaml_list(&[slug.to_string()])));
+            let yaml = std::fs::read_to_string(decls_path).unwrap_or_default();
+            let yaml = append_declared(&yaml, &fields);
+
+            std::fs::create_dir_all(fragment_path.parent().unwrap_or(root))?;
+            std::fs::write(
+                &fragment_path,
+                format!("---\nname: {}\n---\n\n{body}\n", yaml_scalar(slug)),
+            )?;
+            if let Some(parent) = decls_path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::write(decls_path, yaml)?;
+            Ok(vec![fragment_path, decls_path.clone()])
+        }
+    }
+}
+
+/// A fragment body for `view`. Fragments are templates, so a native prompt
+/// that itself contains `{{...}}` cannot be stored as one verbatim.
+pub(super) fn fragment_body(view: &AgentView) -> anyhow::Result<String> {
+    let body = view.linked_body();
+    let (_, placeholders) = armadai_core::template::render_lenient(&body, &BTreeMap::new());
+    if !placeholders.is_empty() {
+        anyhow::bail!(
+            "its prompt contains template placeholders ({}); use --into with an agents directory",
+            placeholders.join(", ")
+        );
+    }
+    Ok(body)
+}
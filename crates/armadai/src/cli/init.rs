@@ -1,3 +1,7 @@
+use std::collections::{BTreeMap, HashSet, VecDeque};
+use std::io::IsTerminal;
+use std::path::PathBuf;
+
 use armadai_core::config;
 use armadai_core::starter::{StarterPack, find_pack_dir, list_available_packs};
 
@@ -129,15 +133,166 @@ fn install_pack(name: &str, force: bool) -> anyhow::Result<StarterPack> {
     };
 
     let pack = StarterPack::load(&pack_dir)?;
+    for (dep, dir) in resolve_dependencies(&pack, find_dependency)? {
+        install_from_dir(&dep, &dir, force)?;
+    }
+    install_from_dir(&pack, &pack_dir, force)?;
+    Ok(pack)
+}
+
+/// A range one pack asks of another.
+struct Requirement {
+    from: String,
+    range: String,
+    req: semver::VersionReq,
+}
+
+/// The packs `pack` depends on, directly or not, each at the highest
+/// version `find` has that matches every range asked of it, in install
+/// order (dependencies first). Nothing is installed until the whole graph
+/// resolves: a cycle, or ranges no single version satisfies, fail first.
+fn resolve_dependencies(
+    pack: &StarterPack,
+    mut find: impl FnMut(&str, &semver::VersionReq) -> Option<PathBuf>,
+) -> anyhow::Result<Vec<(StarterPack, PathBuf)>> {
+    let mut wanted: BTreeMap<String, Vec<Requirement>> = BTreeMap::new();
+    let mut chosen: BTreeMap<String, (StarterPack, PathBuf)> = BTreeMap::new();
+    let mut queue = VecDeque::new();
+    add_requirements(pack, &mut wanted, &mut queue)?;
+    while let Some(name) = queue.pop_front() {
+        let reqs = &wanted[&name];
+        if chosen
+            .get(&name)
+            .is_some_and(|(dep, _)| reqs.iter().all(|r| dep.satisfies(&r.req)))
+        {
+            continue;
+        }
+        let all = semver::VersionReq {
+            comparators: reqs
+                .iter()
+                .flat_map(|r| r.req.comparators.clone())
+                .collect(),
+        };
+        let Some(dir) = find(&name, &all) else {
+            let asks: Vec<String> = reqs
+                .iter()
+                .map(|r| format!("'{}' requires {}", r.from, r.range))
+                .collect();
+            if reqs.len() > 1 {
+                anyhow::bail!(
+                    "conflicting requirements for starter pack '{name}': {}; no version satisfies them all",
+                    asks.join(", ")
+                );
+            }
+            anyhow::bail!(
+                "pack '{}' requires '{name}' {}, but no matching version is available",
+                reqs[0].from,
+                reqs[0].range
+            );
+        };
+        let dep = StarterPack::load(&dir)?;
+        add_requirements(&dep, &mut wanted, &mut queue)?;
+        chosen.insert(name, (dep, dir));
+    }
+
+    let mut order = Vec::new();
+    let mut done = HashSet::new();
+    let mut chain = vec![pack.name.clone()];
+    for name in pack.dependencies.keys() {
+        visit(name, &mut chosen, &mut chain, &mut done, &mut order)?;
+    }
+    Ok(order)
+}
+
+/// Queue the ranges `pack` asks of its dependencies.
+fn add_requirements(
+    pack: &StarterPack,
+    wanted: &mut BTreeMap<String, Vec<Requirement>>,
+    queue: &mut VecDeque<String>,
+) -> anyhow::Result<()> {
+    for (name, range) in &pack.dependencies {
+        let req = semver::VersionReq::parse(range).map_err(|e| {
+            anyhow::anyhow!(
+                "pack '{}': invalid range '{range}' for '{name}': {e}",
+                pack.name
+            )
+        })?;
+        wanted.entry(name.clone()).or_default().push(Requirement {
+            from: pack.name.clone(),
+            range: range.clone(),
+            req,
+        });
+        queue.push_back(name.clone());
+    }
+    Ok(())
+}
+
+/// Append `name` to `order` after its own dependencies. `chain` holds the
+/// packs being visited, to report cycles.
+fn visit(
+    name: &str,
+    chosen: &mut BTreeMap<String, (StarterPack, PathBuf)>,
+    chain: &mut Vec<String>,
+    done: &mut HashSet<String>,
+    order: &mut Vec<(StarterPack, PathBuf)>,
+) -> anyhow::Result<()> {
+    if chain.iter().any(|c| c == name) {
+        anyhow::bail!(
+            "dependency cycle between starter packs: {} -> {name}",
+            chain.join(" -> ")
+        );
+    }
+    if done.contains(name) {
+        return Ok(());
+    }
+    let deps: Vec<String> = chosen[name].0.dependencies.keys().cloned().collect();
+    chain.push(name.to_string());
+    for dep in &deps {
+        visit(dep, chosen, chain, done, order)?;
+    }
+    chain.pop();
+    done.insert(name.to_string());
+    if let Some(entry) = chosen.remove(name) {
+        order.push(entry);
+    }
+    Ok(())
+}
+
+/// A pack matching `req`, syncing the remote starter sources once on a miss
+/// (as [`resolve_pack_dir`] does).
+fn find_dependency(name: &str, req: &semver::VersionReq) -> Option<PathBuf> {
+    if let Some(dir) = armadai_core::starter::find_pack_matching(name, req) {
+        return Some(dir);
+    }
+    let sources = crate::cli::registry::effective_starter_sources();
+    if sources.is_empty() {
+        return None;
+    }
+    let _ = crate::starters_registry::sync_starters(&sources);
+    armadai_core::starter::find_pack_matching(name, req)
+}
+
+fn install_from_dir(
+    pack: &StarterPack,
+    pack_dir: &std::path::Path,
+    force: bool,
+) -> anyhow::Result<()> {
     let r = crate::cli::style::running();
     let a = crate::cli::style::accent();
+    let version = pack
+        .version
+        .as_deref()
+        .map(|v| format!(" {v}"))
+        .unwrap_or_default();
     anstream::println!(
-        "\n{r}Installing starter pack:{r:#} {a}{}{a:#} — {}",
+        "\n{r}Installing starter pack:{r:#} {a}{}{version}{a:#} — {}",
         pack.name,
         pack.description
     );
 
-    let (agents, prompts, skills) = pack.install(&pack_dir, force)?;
+    let (agents, prompts, skills) = pack.install(pack_dir, force)?;
+    pin_remote_pack(pack, pack_dir);
+    crate::pack_ledger::record_or_warn(pack, pack_dir);
     let o = crate::cli::style::ok();
     let m = crate::cli::style::muted();
     anstream::println!(
@@ -147,8 +302,140 @@ fn install_pack(name: &str, force: bool) -> anyhow::Result<StarterPack> {
         prompts,
         skills
     );
+    Ok(())
+}
 
-    Ok(pack)
+/// `armadai init --uninstall-pack`: remove the files an installed pack put
+/// in the library, except those modified since.
+pub fn uninstall_pack(name: &str) -> anyhow::Result<()> {
+    let report = crate::pack_ledger::uninstall(&config::config_dir(), name)?;
+    print_report(&report);
+    let o = crate::cli::style::ok();
+    let a = crate::cli::style::accent();
+    anstream::println!(
+        "\n{o}Pack{o:#} {a}'{name}'{a:#} {o}uninstalled:{o:#} {} file(s) removed, {} kept",
+        report.removed.len(),
+        report.kept.len()
+    );
+    Ok(())
+}
+
+/// `armadai init --upgrade-pack`: move an installed pack to the highest
+/// version available after syncing the remote starter sources, falling back
+/// to the directory it was installed from. Moving to an older version than
+/// the installed one is confirmed first, or needs `force`.
+pub fn upgrade_pack(name: &str, force: bool) -> anyhow::Result<()> {
+    let root = config::config_dir();
+    let ledger = crate::pack_ledger::Ledger::load(&root)?;
+    let Some(installed) = ledger.packs.get(name) else {
+        anyhow::bail!(
+            "starter pack '{name}' is not installed (install it with `armadai init --pack {name}`)"
+        );
+    };
+    let sources = crate::cli::registry::effective_starter_sources();
+    if !sources.is_empty() {
+        crate::starters_registry::sync_starters(&sources);
+    }
+    let recorded = std::path::PathBuf::from(&installed.source);
+    let Some(pack_dir) = armadai_core::starter::find_pack_matching(name, &semver::VersionReq::STAR)
+        .or_else(|| recorded.join("pack.yaml").is_file().then_some(recorded))
+    else {
+        anyhow::bail!("starter pack '{name}' is no longer available from any starter source");
+    };
+    let pack = StarterPack::load(&pack_dir)?;
+    let from = installed
+        .version
+        .as_deref()
+        .unwrap_or("unversioned")
+        .to_string();
+    let to = pack.version.as_deref().unwrap_or("unversioned").to_string();
+    if is_downgrade(installed.version.as_deref(), &pack)? && !force {
+        let confirmed = std::io::stdin().is_terminal()
+            && dialoguer::Confirm::new()
+                .with_prompt(format!(
+                    "Pack '{name}' {from} is installed; the newest available is {to}. Downgrade?"
+                ))
+                .default(false)
+                .interact()?;
+        if !confirmed {
+            anyhow::bail!(
+                "starter pack '{name}' {from} is newer than the available {to}; \
+                 re-run with --force to downgrade"
+            );
+        }
+    }
+
+    let report = crate::pack_ledger::upgrade(&root, &pack, &pack_dir)?;
+    pin_remote_pack(&pack, &pack_dir);
+    print_report(&report);
+    let o = crate::cli::style::ok();
+    let a = crate::cli::style::accent();
+    anstream::println!("\n{o}Pack{o:#} {a}'{name}'{a:#} {o}upgraded:{o:#} {from} -> {to}");
+    if !report.conflicts.is_empty() {
+        let w = crate::cli::style::warn();
+        anstream::println!(
+            "{w}{} file(s) have conflict markers to resolve.{w:#}",
+            report.conflicts.len()
+        );
+    }
+    Ok(())
+}
+
+/// Whether installing `pack` over the `installed` version goes back: to a
+/// lower version, or to an unversioned pack over a versioned one.
+fn is_downgrade(installed: Option<&str>, pack: &StarterPack) -> anyhow::Result<bool> {
+    let Some(installed) = installed.and_then(|v| semver::Version::parse(v).ok()) else {
+        return Ok(false);
+    };
+    Ok(pack.semver()?.is_none_or(|v| v < installed))
+}
+
+fn print_report(report: &crate::pack_ledger::Report) {
+    let m = crate::cli::style::muted();
+    let w = crate::cli::style::warn();
+    let root = config::config_dir();
+    let groups = [
+        ("added", &report.added),
+        ("updated", &report.updated),
+        ("merged", &report.merged),
+        ("removed", &report.removed),
+    ];
+    for (label, files) in groups {
+        for file in files {
+            anstream::println!("  {m}{label:<9} {}{m:#}", root.join(file).display());
+        }
+    }
+    for file in &report.conflicts {
+        anstream::println!("  {w}conflict  {}{w:#}", root.join(file).display());
+    }
+    for file in &report.kept {
+        anstream::println!(
+            "  {w}kept      {}{w:#} {m}(changed locally){m:#}",
+            root.join(file).display()
+        );
+    }
+}
+
+/// Pin a pack installed from a remote starter source in `armadai.lock`;
+/// built-in and local packs are not pinned.
+fn pin_remote_pack(pack: &StarterPack, pack_dir: &std::path::Path) {
+    let Some(source) = crate::cli::registry::effective_starter_sources()
+        .into_iter()
+        .find(|s| pack_dir.starts_with(crate::starters_registry::source_cache_dir(&s.url)))
+    else {
+        return;
+    };
+    let repo = crate::starters_registry::source_cache_dir(&source.url);
+    match crate::lockfile::pin(
+        crate::lockfile::AssetKind::Starter,
+        &pack.name,
+        &source.url,
+        &repo,
+        pack_dir,
+    ) {
+        Ok(asset) => crate::lockfile::record_or_warn(asset),
+        Err(e) => tracing::warn!("not pinned in {}: {e}", crate::lockfile::LOCK_FILE),
+    }
 }
 
 /// Generate the YAML content for an empty armadai project configuration.
@@ -435,6 +722,129 @@ fn write_if_missing_or_force(
 mod tests {
     use super::*;
 
+    /// A test pack: `(name, version, [(dependency, range)])`.
+    type PackSpec<'a> = (&'a str, &'a str, &'a [(&'a str, &'a str)]);
+
+    /// Write a pack per spec under `root`, returning their directories for
+    /// a [`finder`].
+    fn packs(root: &std::path::Path, specs: &[PackSpec]) -> Vec<PathBuf> {
+        let mut dirs = Vec::new();
+        for (name, version, deps) in specs {
+            let dir = root.join(format!("{name}-{version}"));
+            std::fs::create_dir_all(&dir).unwrap();
+            let deps: String = if deps.is_empty() {
+                " {}\n".to_string()
+            } else {
+                std::iter::once("\n".to_string())
+                    .chain(deps.iter().map(|(n, r)| format!("  {n}: \"{r}\"\n")))
+                    .collect()
+            };
+            std::fs::write(
+                dir.join("pack.yaml"),
+                format!("name: {name}\ndescription: d\nversion: {version}\ndependencies:{deps}"),
+            )
+            .unwrap();
+            dirs.push(dir);
+        }
+        dirs
+    }
+
+    /// Picks the highest version among `dirs` matching the range.
+    fn finder(dirs: Vec<PathBuf>) -> impl FnMut(&str, &semver::VersionReq) -> Option<PathBuf> {
+        move |name, req| {
+            dirs.iter()
+                .filter_map(|d| StarterPack::load(d).ok().map(|p| (p, d)))
+                .filter(|(p, _)| p.name == name && p.satisfies(req))
+                .max_by_key(|(p, _)| p.semver().ok().flatten())
+                .map(|(_, d)| d.clone())
+        }
+    }
+
+    fn root_pack(deps: &[(&str, &str)]) -> StarterPack {
+        StarterPack {
+            name: "root".into(),
+            description: "d".into(),
+            version: None,
+            agents: vec![],
+            prompts: vec![],
+            skills: vec![],
+            checksums: Default::default(),
+            dependencies: deps
+                .iter()
+                .map(|(n, r)| (n.to_string(), r.to_string()))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn dependencies_resolve_to_one_version_installed_first() {
+        let dir = tempfile::tempdir().unwrap();
+        let find = finder(packs(
+            dir.path(),
+            &[
+                ("a", "1.0.0", &[("base", "^1")]),
+                ("c", "1.0.0", &[("base", ">=1.1")]),
+                ("base", "1.0.0", &[]),
+                ("base", "1.2.0", &[]),
+                ("base", "2.0.0", &[]),
+            ],
+        ));
+        let order = resolve_dependencies(&root_pack(&[("a", "*"), ("c", "^1")]), find).unwrap();
+        let names: Vec<String> = order
+            .iter()
+            .map(|(p, _)| format!("{}@{}", p.name, p.version.as_deref().unwrap()))
+            .collect();
+        assert_eq!(names, ["base@1.2.0", "a@1.0.0", "c@1.0.0"]);
+    }
+
+    #[test]
+    fn conflicting_ranges_fail_before_anything_installs() {
+        let dir = tempfile::tempdir().unwrap();
+        let find = finder(packs(
+            dir.path(),
+            &[
+                ("a", "1.0.0", &[("base", "^1")]),
+                ("c", "1.0.0", &[("base", "^2")]),
+                ("base", "1.0.0", &[]),
+                ("base", "2.0.0", &[]),
+            ],
+        ));
+        let err = resolve_dependencies(&root_pack(&[("a", "*"), ("c", "*")]), find)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("conflicting requirements"), "{err}");
+        assert!(
+            err.contains("'a' requires ^1") && err.contains("'c' requires ^2"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn dependency_cycles_are_reported() {
+        let dir = tempfile::tempdir().unwrap();
+        let find = finder(packs(
+            dir.path(),
+            &[("a", "1.0.0", &[("b", "*")]), ("b", "1.0.0", &[("a", "*")])],
+        ));
+        let err = resolve_dependencies(&root_pack(&[("a", "*")]), find)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("root -> a -> b -> a"), "{err}");
+    }
+
+    #[test]
+    fn downgrades_are_detected_against_the_installed_version() {
+        let versioned = |v: Option<&str>| StarterPack {
+            version: v.map(str::to_string),
+            ..root_pack(&[])
+        };
+        assert!(is_downgrade(Some("1.2.0"), &versioned(Some("1.1.9"))).unwrap());
+        assert!(is_downgrade(Some("1.2.0"), &versioned(None)).unwrap());
+        assert!(!is_downgrade(Some("1.2.0"), &versioned(Some("1.2.0"))).unwrap());
+        assert!(!is_downgrade(Some("1.2.0"), &versioned(Some("2.0.0"))).unwrap());
+        assert!(!is_downgrade(None, &versioned(Some("0.1.0"))).unwrap());
+    }
+
     #[test]
     fn resolve_pack_dir_accepts_local_path() {
         let dir = tempfile::tempdir().unwrap();
@@ -1,16 +1,30 @@
 use std::io::IsTerminal;
 use std::path::{Path, PathBuf};
 
+use crate::linker::manifest::{self, FileStatus, LinkManifest, ManifestEntry, Planned};
 use crate::linker::model_resolution::{self, TargetKind};
 use crate::linker::{self, LinkAgent};
+use armadai_core::agent::Agent;
 use armadai_core::project;
 
+/// What `link` does with the generated files.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LinkMode {
+    Write {
+        force: bool,
+    },
+    DryRun,
+    /// Compare generated files against the manifest and disk; write nothing.
+    Check,
+    /// Pull hand edits of drifted files back into their source agents.
+    Adopt,
+}
+
 pub async fn execute(
     target: Option<crate::linker::LinkTarget>,
     model_flag: Option<String>,
     coordinator_flag: Option<String>,
-    dry_run: bool,
-    force: bool,
+    mode: LinkMode,
     output: Option<PathBuf>,
     agents_filter: Option<Vec<String>>,
 ) -> anyhow::Result<()> {
@@ -122,10 +136,21 @@ pub async fn execute(
         .ok_or_else(|| {
             anyhow::anyhow!(
                 "No link target specified. Use --target or set link.target in armadai.yaml.\n\
-                 Supported targets: claude, codex, copilot, gemini, opencode"
+                 Supported targets: claude, codex, copilot, cursor, gemini, opencode"
             )
         })?;
 
+    // `--check`/`--adopt` must regenerate exactly what the last link wrote,
+    // so they reuse its model instead of prompting for one.
+    let mut manifest = LinkManifest::load(&root)?;
+    let read_only = matches!(mode, LinkMode::Check | LinkMode::Adopt);
+    let model_flag = model_flag.or_else(|| {
+        read_only
+            .then(|| manifest.targets.get(&target_name)?.model.clone())
+            .flatten()
+    });
+    let mut chosen_model = model_flag.clone();
+
     // 4b. Model resolution: remap agent models based on target kind
     let target_kind = model_resolution::classify_target(&target_name);
     match target_kind {
@@ -152,6 +177,7 @@ pub async fn execute(
                 }
             }
         }
+        TargetKind::ModelAgnostic => {}
         TargetKind::Orchestrator => {
             if let Some(ref model) = model_flag {
                 model_resolution::remap_models_for_orchestrator(&mut link_agents, model);
@@ -161,7 +187,7 @@ pub async fn execute(
                         model,
                     );
                 }
-            } else if std::io::stdin().is_terminal() {
+            } else if std::io::stdin().is_terminal() && !read_only {
                 #[cfg(feature = "providers-api")]
                 let model = model_resolution::prompt_model_interactive().await?;
                 #[cfg(not(feature = "providers-api"))]
@@ -173,6 +199,7 @@ pub async fn execute(
                         &model,
                     );
                 }
+                chosen_model = Some(model);
             } else {
                 // Non-interactive without --model: resolve latest:* placeholders
                 // using each agent's own provider
@@ -210,6 +237,7 @@ pub async fn execute(
     }
 
     // 8. Resolve output paths relative to project root
+    let mut sources_by_path: Vec<Option<String>> = Vec::new();
     let output_files: Vec<_> = files
         .into_iter()
         .map(|f| {
@@ -217,6 +245,7 @@ pub async fn execute(
             let default_dir = PathBuf::from(linker.default_output_dir());
             let relative = f.path.strip_prefix(&default_dir).unwrap_or(&f.path);
             let final_path = root.join(&output_dir).join(relative);
+            sources_by_path.push(f.source);
             (final_path, f.content)
         })
         .collect();
@@ -268,8 +297,29 @@ pub async fn execute(
         }
     }
 
-    // 9. Dry run or write
-    if dry_run {
+    let planned: Vec<Planned> = output_files
+        .iter()
+        .zip(&sources_by_path)
+        .map(|((path, content), source)| Planned {
+            path,
+            content,
+            source: source.as_deref(),
+        })
+        .chain(extra_files.iter().map(|(path, content)| Planned {
+            path,
+            content,
+            source: None,
+        }))
+        .collect();
+
+    // 9. Check, adopt, dry run or write
+    if mode == LinkMode::Check {
+        return print_check(&manifest, &root, &target_name, &planned);
+    }
+    if mode == LinkMode::Adopt {
+        return adopt_edits(&mut manifest, &root, &target_name, &planned, &agents);
+    }
+    if mode == LinkMode::DryRun {
         let h = crate::cli::style::header();
         let a = crate::cli::style::accent();
         let m = crate::cli::style::muted();
@@ -290,29 +340,70 @@ pub async fn execute(
         return Ok(());
     }
 
+    let force = matches!(mode, LinkMode::Write { force: true });
     let mut written = 0;
     let mut skipped = 0;
+    let mut planned_keys = Vec::new();
 
-    for (path, content) in output_files.iter().chain(extra_files.iter()) {
-        if path.exists() && !force {
+    for p in &planned {
+        let key = manifest::relative_key(&root, p.path);
+        planned_keys.push(key.clone());
+        // A file `link` wrote and nobody touched since (or one that already
+        // has the exact content) is ours to replace; anything else on disk
+        // needs --force.
+        let disk = std::fs::read_to_string(p.path).ok();
+        let untouched = match (&disk, manifest.entry(&key)) {
+            (Some(d), _) if d == p.content => true,
+            (Some(d), Some(e)) => e.hash == manifest::content_hash(d),
+            _ => false,
+        };
+        if disk.is_some() && !untouched && !force {
             let w = crate::cli::style::warn();
-            anstream::eprintln!(
-                "{w}  skip: {} already exists (use --force to overwrite){w:#}",
-                path.display()
-            );
+            if manifest.entry(&key).is_some() {
+                anstream::eprintln!(
+                    "{w}  skip: {} was edited since the last link (use --adopt to keep the edits, --force to discard them){w:#}",
+                    p.path.display()
+                );
+            } else {
+                anstream::eprintln!(
+                    "{w}  skip: {} already exists (use --force to overwrite){w:#}",
+                    p.path.display()
+                );
+            }
             skipped += 1;
             continue;
         }
 
-        if let Some(parent) = path.parent() {
-            std::fs::create_dir_all(parent)?;
+        if disk.as_deref() != Some(p.content) {
+            if let Some(parent) = p.path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::write(p.path, p.content)?;
+            let m = crate::cli::style::muted();
+            anstream::println!("{m}  wrote {}{m:#}", p.path.display());
         }
-        std::fs::write(path, content)?;
-        let m = crate::cli::style::muted();
-        anstream::println!("{m}  wrote {}{m:#}", path.display());
+        manifest.record(ManifestEntry {
+            path: key,
+            hash: manifest::content_hash(p.content),
+            source: p.source.map(str::to_string),
+            target: target_name.clone(),
+        });
         written += 1;
     }
 
+    manifest.prune_deleted(&root, &target_name, &planned_keys);
+    manifest
+        .targets
+        .entry(target_name.clone())
+        .or_default()
+        .model = chosen_model;
+    manifest.save(&root)?;
+    let orphaned = manifest
+        .check(&root, &target_name, &planned)
+        .iter()
+        .filter(|c| c.status == FileStatus::Orphaned)
+        .count();
+
     let mut summary = format!("Linked {} agent(s)", link_agents.len());
     if skill_count > 0 {
         summary.push_str(&format!(", {} skill(s)", skill_count));
@@ -330,10 +421,155 @@ pub async fn execute(
         written,
         skipped
     );
+    if orphaned > 0 {
+        let w = crate::cli::style::warn();
+        anstream::println!(
+            "{w}{orphaned} file(s) from a previous link are no longer generated — see `armadai link --check`.{w:#}"
+        );
+    }
+
+    Ok(())
+}
+
+/// `link --check`: report drifted, stale, missing and orphaned files.
+fn print_check(
+    manifest: &LinkManifest,
+    root: &Path,
+    target: &str,
+    planned: &[Planned],
+) -> anyhow::Result<()> {
+    let checks = manifest.check(root, target, planned);
+    let m = crate::cli::style::muted();
+    let mut failures = 0;
+    for c in checks.iter().filter(|c| c.status != FileStatus::Clean) {
+        let style = if c.status.is_failure() {
+            failures += 1;
+            crate::cli::style::err()
+        } else {
+            crate::cli::style::warn()
+        };
+        let source = c
+            .source
+            .as_deref()
+            .map(|s| format!(" (agent {s})"))
+            .unwrap_or_default();
+        anstream::println!(
+            "  {style}{:<14}{style:#}{}{m}{source}{m:#}",
+            c.status.label(),
+            c.path
+        );
+    }
+    let clean = checks.len()
+        - checks
+            .iter()
+            .filter(|c| c.status != FileStatus::Clean)
+            .count();
+    if failures == 0 {
+        let o = crate::cli::style::ok();
+        anstream::println!("{o}Link '{target}' is in sync{o:#} {m}({clean} file(s) clean){m:#}");
+        return Ok(());
+    }
+    anstream::println!(
+        "\n{m}Fix with `armadai link` (stale/missing), `armadai link --adopt` (drifted) \
+         or `armadai unlink` (orphaned).{m:#}"
+    );
+    anyhow::bail!("{failures} generated file(s) out of sync for '{target}'")
+}
 
+/// `link --adopt`: copy hand edits of drifted files back into the source
+/// agent, then record the edited files as generated.
+fn adopt_edits(
+    manifest: &mut LinkManifest,
+    root: &Path,
+    target: &str,
+    planned: &[Planned],
+    agents: &[Agent],
+) -> anyhow::Result<()> {
+    let checks = manifest.check(root, target, planned);
+    let mut adopted = 0;
+    let mut failed = 0;
+    for (c, p) in checks.iter().zip(planned) {
+        if c.status
+            != (FileStatus::Drifted {
+                source_changed: false,
+            })
+        {
+            if c.status
+                == (FileStatus::Drifted {
+                    source_changed: true,
+                })
+            {
+                let w = crate::cli::style::warn();
+                anstream::eprintln!(
+                    "{w}  skip: {} — its source agent changed too; merge by hand{w:#}",
+                    c.path
+                );
+                failed += 1;
+            }
+            continue;
+        }
+        let result = adopt_one(p, agents);
+        match result {
+            Ok(source_file) => {
+                let edited = std::fs::read_to_string(p.path)?;
+                manifest.record(ManifestEntry {
+                    path: c.path.clone(),
+                    hash: manifest::content_hash(&edited),
+                    source: c.source.clone(),
+                    target: target.to_string(),
+                });
+                let o = crate::cli::style::ok();
+                anstream::println!("  {o}adopted{o:#} {} -> {}", c.path, source_file.display());
+                adopted += 1;
+            }
+            Err(e) => {
+                let w = crate::cli::style::warn();
+                anstream::eprintln!("{w}  skip: {} — {e}{w:#}", c.path);
+                failed += 1;
+            }
+        }
+    }
+    if adopted > 0 {
+        manifest.save(root)?;
+        let m = crate::cli::style::muted();
+        anstream::println!(
+            "{m}{adopted} edit(s) adopted. Run `armadai link` to regenerate the linked files.{m:#}"
+        );
+    } else if failed == 0 {
+        let m = crate::cli::style::muted();
+        anstream::println!("{m}No drifted files to adopt.{m:#}");
+    }
+    if failed > 0 {
+        anyhow::bail!("{failed} drifted file(s) could not be adopted");
+    }
     Ok(())
 }
 
+/// Splice one drifted file's edit into its source agent's file.
+fn adopt_one(p: &Planned, agents: &[Agent]) -> anyhow::Result<PathBuf> {
+    let name = p
+        .source
+        .ok_or_else(|| anyhow::anyhow!("not generated from a single agent"))?;
+    let agent = agents
+        .iter()
+        .find(|a| a.name == name)
+        .ok_or_else(|| anyhow::anyhow!("source agent '{name}' not found"))?;
+    let sections: Vec<&str> = [
+        Some(agent.system_prompt.as_str()),
+        agent.instructions.as_deref(),
+        agent.output_format.as_deref(),
+        agent.context.as_deref(),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+    let edited = std::fs::read_to_string(p.path)?;
+    let (idx, new_text) =
+        manifest::locate_edit(p.content, &edited, &sections).map_err(|e| anyhow::anyhow!(e))?;
+    manifest::splice_into_source(&agent.source, sections[idx], &new_text)?;
+    Ok(agent.source.clone())
+}
+
 /// Collect all files from a directory recursively as (relative_path, content) pairs.
 /// Only includes text files (valid UTF-8).
 fn collect_dir_files(dir: &Path) -> anyhow::Result<Vec<(PathBuf, String)>> {
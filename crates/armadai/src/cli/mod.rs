@@ -1,23 +1,32 @@
 mod audit;
 mod config;
+mod convert;
 mod costs;
+mod db;
+mod eval;
 mod extract;
 mod history;
+mod import;
 pub mod init;
 mod inspect;
 mod link;
 mod list;
 mod models;
 pub(crate) mod new;
+mod pack;
 mod projections;
 mod prompts;
-mod registry;
+pub(crate) mod registry;
 mod run;
 mod run_es_record;
 mod run_replay;
+pub(crate) mod runs;
+mod search;
+mod secrets;
 pub(crate) mod setup;
 mod skills;
 pub(crate) mod style;
+mod sync;
 mod unlink;
 mod up;
 mod update;
@@ -181,20 +190,37 @@ pub enum Command {
         path: Option<std::path::PathBuf>,
     },
     /// Audit native agentic configs (Claude Code) and report issues
-    #[command(long_about = "Audit native agentic configs and report issues.\n\n\
+    #[command(
+        long_about = "Audit native agentic configs and report issues.\n\n\
             Scans .claude/agents/, .claude/skills/ and CLAUDE.md (no ArmadAI setup \
             required), runs static rules (deprecated models, oversized prompts, \
             duplicated blocks, broken references, plaintext secrets...) and prints \
             an actionable report. It also reads this project's Claude Code transcripts \
             under ~/.claude/projects/ to measure observed usage (rules U01-U04) — that \
             data never leaves this machine; pass --no-usage or set `audit.usage: false` \
-            in the project config to skip it. Exits non-zero if critical findings exist.")]
+            in the project config to skip it. Exits non-zero if critical findings exist.\n\n\
+            --format json|sarif emits a machine-readable report (SARIF 2.1.0 for \
+            code-scanning uploads) on stdout, or into the --report file.\n\n\
+            --fix applies the mechanical fixes some findings carry (deprecated model \
+            ids, misspelled @mentions, plaintext keys), shows them as a unified diff, \
+            backs up the originals under .armadai/backups/ and audits again.",
+        after_help = "Examples:\n  \
+            armadai audit\n  \
+            armadai audit --report audit.html\n  \
+            armadai audit --format sarif > armadai.sarif\n  \
+            armadai audit --format json --report audit.json\n  \
+            armadai audit --fix --dry-run\n  \
+            armadai audit --fix"
+    )]
     Audit {
         /// Project directory to audit (defaults to current directory)
         path: Option<std::path::PathBuf>,
-        /// Write a report to this file (markdown, or HTML if the extension is .html)
+        /// Write a report to this file (format from --format, else from the extension: .html, .json, .sarif, otherwise markdown)
         #[arg(long)]
         report: Option<std::path::PathBuf>,
+        /// Report format; without --report the report is printed to stdout instead of the terminal summary
+        #[arg(long, value_enum)]
+        format: Option<crate::audit::export::ReportFormat>,
         /// Only display findings at or above this severity (exit code still counts everything)
         #[arg(long, value_parser = ["crit", "warn", "info"], default_value = "info")]
         min_severity: String,
@@ -210,6 +236,12 @@ pub enum Command {
         /// Skip scanning Claude Code transcripts for observed usage (overrides `audit.usage` in project config)
         #[arg(long)]
         no_usage: bool,
+        /// Apply the fixes findings carry to the native files, then audit again
+        #[arg(long, conflicts_with_all = ["propose", "deep"])]
+        fix: bool,
+        /// With --fix: print the diff without writing anything
+        #[arg(long, requires = "fix")]
+        dry_run: bool,
     },
     /// Extract agents, prompts, and skills with dependency resolution
     #[command(
@@ -227,11 +259,16 @@ pub enum Command {
     /// View execution history
     #[command(after_help = "Examples:\n  \
         armadai history\n  \
-        armadai history --agent code-reviewer")]
+        armadai history --agent code-reviewer\n  \
+        armadai history --search \"sql injection\"\n  \
+        armadai history --search migrat* --agent reviewer")]
     History {
         /// Filter by agent name
         #[arg(long)]
         agent: Option<String>,
+        /// Full-text search over run inputs/outputs, board entries and ring contributions
+        #[arg(long, value_name = "QUERY")]
+        search: Option<String>,
     },
     /// View cost tracking
     #[command(after_help = "Examples:\n  \
@@ -259,6 +296,65 @@ pub enum Command {
             armadai projections rebuild --run <run-id>"
     )]
     Projections(projections::ProjectionsAction),
+    /// Database maintenance: prune old history, vacuum, stats
+    #[command(
+        subcommand,
+        long_about = "Database maintenance: prune old history, vacuum, stats.\n\n\
+            `prune` deletes runs older than the given age (event log and projections). \
+            With --keep-costs, their costs are folded into daily rollups first so \
+            `armadai costs` totals are unchanged. Set storage.retention.older_than in \
+            config.yaml to prune automatically on startup.",
+        after_help = "Examples:\n  \
+            armadai db prune --older-than 90d --keep-costs\n  \
+            armadai db vacuum\n  \
+            armadai db stats"
+    )]
+    Db(db::DbAction),
+    /// Export, import and compare runs
+    #[command(
+        subcommand,
+        long_about = "Export, import and compare runs.\n\n\
+            A bundle (.armadai.jsonl) carries a run's full event log, its config snapshot \
+            and the agent definitions it used, so a run can be shared with a teammate or \
+            attached to a bug report. Importing it makes --replay, history and the web \
+            trace viewer work on it. Use --redact to mask API keys and tokens.\n\n\
+            `diff` aligns two runs by agent and round/lap and shows output diffs, \
+            token/cost deltas, changed board entry kinds or votes, and the final result.",
+        after_help = "Examples:\n  \
+            armadai runs export <run-id> -o run.armadai.jsonl\n  \
+            armadai runs export <run-id> -o run.armadai.jsonl --redact\n  \
+            armadai runs import run.armadai.jsonl\n  \
+            armadai runs import run.armadai.jsonl --as <new-id> --agents-dir ./imported\n  \
+            armadai runs diff <run-a> <run-b>\n  \
+            armadai runs diff <run-a> <run-b> --json"
+    )]
+    Runs(runs::RunsAction),
+    /// Run a golden test suite against agents
+    #[command(
+        long_about = "Run a golden test suite against agents.\n\n\
+            A suite YAML lists inputs plus assertions on the output: contains, not_contains, \
+            regex, json_schema, judge (an LLM rubric graded PASS/FAIL by a judge agent) and \
+            max_cost. Cases run through `armadai run`, against real providers or, with \
+            `fake:`, an armadai-fake scenario. Scored results are stored and compared to the \
+            previous eval of the same suite. Exits non-zero when a case fails.",
+        after_help = "Examples:\n  \
+            armadai eval evals/summarizer.yaml\n  \
+            armadai eval evals/summarizer.yaml --junit target/eval.xml\n  \
+            armadai eval evals/summarizer.yaml --baseline <eval-id> --no-store"
+    )]
+    Eval {
+        /// Suite file (YAML)
+        suite: std::path::PathBuf,
+        /// Write a JUnit XML report to this file
+        #[arg(long, value_name = "FILE")]
+        junit: Option<std::path::PathBuf>,
+        /// Compare against this eval instead of the suite's previous one
+        #[arg(long, value_name = "EVAL_ID")]
+        baseline: Option<String>,
+        /// Don't store this eval's results
+        #[arg(long)]
+        no_store: bool,
+    },
     /// Manage providers and secrets
     #[command(
         long_about = "Manage providers and secrets.\n\n\
@@ -272,6 +368,66 @@ pub enum Command {
         #[command(subcommand)]
         action: config::ConfigAction,
     },
+    /// Store, read, list and remove provider secrets
+    #[command(
+        subcommand,
+        long_about = "Store, read, list and remove provider secrets.\n\n\
+            Each provider has an api_key, an optional org_id and any other field it needs \
+            (base_url, region, headers…). Values are read from a hidden prompt, or from \
+            stdin when it is not a terminal, and stored encrypted with the age key from \
+            `armadai config secrets init`. With --project they go to the project's \
+            .armadai/ directory instead and override the user's fields of the same name.",
+        after_help = "Examples:\n  \
+            armadai secrets set anthropic\n  \
+            echo https://proxy.internal | armadai secrets set anthropic --field base_url --project\n  \
+            armadai secrets list\n  \
+            armadai secrets get anthropic --field base_url\n  \
+            armadai secrets remove anthropic --field base_url --project"
+    )]
+    Secrets(secrets::SecretsCommand),
+    /// Install the registry assets pinned in armadai.lock
+    #[command(
+        long_about = "Install the registry assets pinned in armadai.lock.\n\n\
+            Every agent, skill and starter imported from a registry is recorded in \
+            armadai.lock (at the project root, or in the config directory outside a \
+            project) with its source, git revision and a sha256 hash of its content. \
+            `sync` installs the assets that are missing; with --locked it reinstalls \
+            every asset at its exact locked revision and fails if any content no longer \
+            matches its hash.",
+        after_help = "Examples:\n  \
+            armadai sync\n  \
+            armadai sync --locked"
+    )]
+    Sync {
+        /// Reinstall every asset exactly as locked, verifying hashes
+        #[arg(long)]
+        locked: bool,
+    },
+    /// Show locked assets whose upstream content changed
+    #[command(
+        long_about = "Show locked assets whose upstream content changed.\n\n\
+            Fetches the upstream of each git-sourced asset in armadai.lock and lists those \
+            whose content differs from the locked hash. Starters installed from archives \
+            have no revision and are not checked.",
+        after_help = "Examples:\n  armadai outdated"
+    )]
+    Outdated,
+    /// Show upstream changes to locked assets and apply them
+    #[command(
+        long_about = "Show upstream changes to locked assets and apply them.\n\n\
+            Prints a diff of every outdated asset (or only the named ones), then installs \
+            the upstream version and updates armadai.lock once confirmed.",
+        after_help = "Examples:\n  \
+            armadai upgrade\n  \
+            armadai upgrade code-reviewer --yes"
+    )]
+    Upgrade {
+        /// Only upgrade these assets (by name)
+        names: Vec<String>,
+        /// Apply without asking
+        #[arg(long, short)]
+        yes: bool,
+    },
     /// Launch the interactive shell
     #[cfg(feature = "tui")]
     #[command(long_about = "Launch the interactive shell.\n\n\
@@ -342,12 +498,18 @@ pub enum Command {
         long_about = "Generate native config files for AI assistants.\n\n\
             Reads project config (.armadai/config.yaml or armadai.yaml) and generates \
             target-specific configuration files (e.g. .claude/agents/*.md for Claude Code, \
-            .github/agents/*.agent.md for GitHub Copilot). One source format, any target.",
+            .github/agents/*.agent.md for GitHub Copilot). One source format, any target.\n\n\
+            Every link records what it wrote in .armadai/link-manifest.json. Files edited \
+            by hand since are left alone (--force discards the edits); --check reports \
+            drifted, stale and orphaned files for CI, and --adopt copies hand edits back \
+            into the source agent.",
         after_help = "Examples:\n  \
             armadai link --target claude\n  \
             armadai link --target copilot --dry-run\n  \
             armadai link --target claude --agents code-reviewer test-writer\n  \
-            armadai link --target claude --output .claude/agents --force"
+            armadai link --target claude --output .claude/agents --force\n  \
+            armadai link --target claude --check\n  \
+            armadai link --target claude --adopt"
     )]
     Link {
         /// Target AI assistant
@@ -365,6 +527,12 @@ pub enum Command {
         /// Overwrite existing files without confirmation
         #[arg(long)]
         force: bool,
+        /// Report drifted, stale, missing and orphaned files; exit non-zero if any
+        #[arg(long, conflicts_with_all = ["dry_run", "force", "adopt"])]
+        check: bool,
+        /// Copy hand edits of generated files back into their source agents
+        #[arg(long, conflicts_with_all = ["dry_run", "force"])]
+        adopt: bool,
         /// Output directory (overrides config and defaults)
         #[arg(long, short)]
         output: Option<std::path::PathBuf>,
@@ -403,22 +571,91 @@ pub enum Command {
         #[arg(long, num_args = 1..)]
         agents: Option<Vec<String>>,
     },
+    /// Import agents edited in a native CLI's files back into the library
+    #[command(
+        long_about = "Import agents edited in a native CLI's files back into the library.\n\n\
+            Reads the native agents of the chosen CLI with the same parsers as `armadai audit`, \
+            maps their models to portable tiers and compares each one with the library agent \
+            of the same name. The canonical text accepted at the last import \
+            (.armadai/import-base.json) is the base of a three-way comparison, so a native edit, \
+            a library edit and a conflict are told apart.\n\n\
+            Existing agents are updated where they live (agent file, or the prompt fragment of a \
+            declared agent). New agents are declared in .armadai/agents.yaml with one prompt \
+            fragment each, or written as agent files when --into names a directory. In a terminal \
+            each change is accepted or skipped per agent; elsewhere --yes accepts new and \
+            natively changed agents, and --force also takes the native side of conflicts.",
+        after_help = "Examples:\n  \
+            armadai import --from claude\n  \
+            armadai import --from gemini --dry-run\n  \
+            armadai import --from copilot --into .armadai/agents --yes\n  \
+            armadai import --from codex --agents reviewer --yes --force"
+    )]
+    Import {
+        /// Native CLI to import from
+        #[arg(long, short, value_enum)]
+        from: crate::linker::LinkTarget,
+        /// Where new agents go: agents.yaml (default) or an agents directory
+        #[arg(long)]
+        into: Option<std::path::PathBuf>,
+        /// Only import specific agents (by name)
+        #[arg(long, num_args = 1..)]
+        agents: Option<Vec<String>>,
+        /// Accept new and natively changed agents without asking
+        #[arg(long, short)]
+        yes: bool,
+        /// With --yes, also take the native side of conflicts
+        #[arg(long, requires = "yes")]
+        force: bool,
+        /// Show the comparison without writing anything
+        #[arg(long, conflicts_with_all = ["yes", "force"])]
+        dry_run: bool,
+    },
+    /// Convert CrewAI or AutoGen definitions into ArmadAI agents
+    #[command(
+        long_about = "Convert CrewAI or AutoGen definitions into ArmadAI agents.\n\n\
+            CrewAI: reads config/agents.yaml and config/tasks.yaml of a crew (give the project \
+            directory or agents.yaml). Role and backstory become the system prompt, goal and \
+            tasks the instructions, `llm` the provider and model. A hierarchical crew becomes a \
+            coordinator over one team, a sequential crew a ring in task order.\n\n\
+            AutoGen: reads a team or agent JSON file (`dump_component()` of AgentChat, or an \
+            AutoGen Studio 0.2 workflow). A round-robin chat becomes a ring; selector, \
+            Magentic-One and group chats a hierarchy under a generated coordinator; a swarm a \
+            hierarchy under its first participant.\n\n\
+            Agents are declared in .armadai/agents.yaml with one prompt fragment each, or \
+            written as agent files when --into names a directory; agents already in the \
+            library are skipped. The orchestration section is added to the project config \
+            unless it has one. Models without a portable tier use the `proxy` provider. \
+            Tools, termination conditions, human agents and other settings with no ArmadAI \
+            equivalent are listed at the end.",
+        after_help = "Examples:\n  \
+            armadai convert --from crewai .\n  \
+            armadai convert --from crewai src/my_crew/config/agents.yaml --dry-run\n  \
+            armadai convert --from autogen team.json --into .armadai/agents\n  \
+            armadai convert --from autogen workflow.json --no-orchestration"
+    )]
+    Convert(convert::ConvertArgs),
     /// Initialize ArmadAI configuration
     #[command(
         long_about = "Initialize ArmadAI configuration.\n\n\
             Creates ~/.config/armadai/ with default config.yaml, providers.yaml, \
             and subdirectories (agents/, prompts/, skills/, registry/).\n\n\
             Use --project to create a .armadai/ directory with config.yaml and \
-            subdirectories (agents/, prompts/, skills/, starters/).",
+            subdirectories (agents/, prompts/, skills/, starters/).\n\n\
+            Installed packs are recorded in installed-packs.yaml. --uninstall-pack removes \
+            the files of a pack that were not modified since install; --upgrade-pack \
+            replaces them with the latest available version and three-way merges the \
+            ones edited locally, leaving conflict markers where both sides changed.",
         after_help = "Examples:\n  \
             armadai init\n  \
             armadai init --force\n  \
             armadai init --project\n  \
             armadai init --pack rust-dev\n  \
-            armadai init --pack fullstack --force"
+            armadai init --pack fullstack --force\n  \
+            armadai init --upgrade-pack rust-dev\n  \
+            armadai init --uninstall-pack rust-dev"
     )]
     Init {
-        /// Overwrite existing config files
+        /// Overwrite existing config files (with --upgrade-pack: allow a downgrade)
         #[arg(long)]
         force: bool,
         /// Create a project-local .armadai/ directory with config.yaml
@@ -427,6 +664,12 @@ pub enum Command {
         /// Starter pack name, or path to a directory containing pack.yaml
         #[arg(long)]
         pack: Option<String>,
+        /// Remove the unmodified files of an installed starter pack
+        #[arg(long, value_name = "NAME", conflicts_with_all = ["pack", "project", "upgrade_pack"])]
+        uninstall_pack: Option<String>,
+        /// Upgrade an installed starter pack, merging local edits
+        #[arg(long, value_name = "NAME", conflicts_with_all = ["pack", "project"])]
+        upgrade_pack: Option<String>,
     },
     /// Browse and import agents from the community registry
     #[command(
@@ -439,9 +682,37 @@ pub enum Command {
             armadai registry search \"security review\"\n  \
             armadai registry list --category official\n  \
             armadai registry add official/security\n  \
-            armadai registry info official/security"
+            armadai registry info official/security\n  \
+            armadai registry sources add starters https://example.com/packs.tar.gz --public-key RWQ… --require-signature"
     )]
     Registry(registry::RegistryAction),
+    /// Search agents, skills and starter packs across all registries
+    #[command(
+        long_about = "Search agents, skills and starter packs across all registries.\n\n\
+            Results are ranked with BM25 over name, tags, description and content, from \
+            an index of the synced registries kept in the registry cache: searching works \
+            offline. Query terms tolerate typos and match longer words they start. The \
+            index is rebuilt by `registry sync` and `skills sync`, or with --rebuild.",
+        after_help = "Examples:\n  \
+            armadai search \"security review\"\n  \
+            armadai search testing --kind skill\n  \
+            armadai search reviewer --stack rust --category official\n  \
+            armadai search docker --source anthropics --rebuild"
+    )]
+    Search(search::SearchArgs),
+    /// Build starter packs into versioned archives
+    #[command(
+        subcommand,
+        long_about = "Build starter packs into versioned archives.\n\n\
+            `pack build` validates a pack directory, bundles the prompts its agents pull \
+            in through apply_to, stamps the version and a sha256 checksum of every file \
+            into pack.yaml, and writes a reproducible <name>-<version>.tar.gz. Publish the \
+            archive (and its minisign signature) at a URL and add it as a starter source.",
+        after_help = "Examples:\n  \
+            armadai pack build ./starters/review --set-version 1.2.0\n  \
+            armadai pack build ./starters/review --out dist"
+    )]
+    Pack(pack::PackAction),
     /// Manage composable prompts
     #[command(
         subcommand,
@@ -494,15 +765,19 @@ pub enum Command {
     /// delegation topology. Hidden from help.
     #[command(hide = true, name = "__claude-policy-gate")]
     ClaudePolicyGate,
-    /// Watch a Claude Code session live in the Workroom (via the armadai plugin).
+    /// Watch an assistant session live in the Workroom (Claude Code via the
+    /// armadai plugin, Gemini CLI or Codex from their session files).
     #[cfg(feature = "tui")]
     Watch {
-        /// Attach to the most recently registered session.
+        /// Attach to the most recent session.
         #[arg(long)]
         last: bool,
         /// Attach to a specific session id.
         #[arg(long)]
         session: Option<String>,
+        /// CLI whose sessions to follow.
+        #[arg(long, value_parser = ["claude", "gemini", "codex"], default_value = "claude")]
+        source: String,
         /// Emit reconstructed RunEvents as JSONL to stdout instead of the TUI.
         #[arg(long)]
         json: bool,
@@ -644,16 +919,49 @@ pub async fn handle(cli: Cli) -> anyhow::Result<()> {
         Command::Audit {
             path,
             report,
+            format,
             min_severity,
             quiet,
             propose,
             deep,
             no_usage,
-        } => audit::execute(path, report, min_severity, quiet, propose, deep, no_usage).await,
-        Command::History { agent } => history::execute(agent).await,
+            fix,
+            dry_run,
+        } => {
+            let fix = fix.then_some(if dry_run {
+                audit::FixMode::DryRun
+            } else {
+                audit::FixMode::Apply
+            });
+            audit::execute(
+                path,
+                report,
+                format,
+                min_severity,
+                quiet,
+                propose,
+                deep,
+                no_usage,
+                fix,
+            )
+            .await
+        }
+        Command::History { agent, search } => history::execute(agent, search).await,
         Command::Costs { agent, from } => costs::execute(agent, from).await,
         Command::Projections(action) => projections::execute(action).await,
+        Command::Db(action) => db::execute(action).await,
+        Command::Runs(action) => runs::execute(action).await,
+        Command::Eval {
+            suite,
+            junit,
+            baseline,
+            no_store,
+        } => eval::execute(suite, junit, baseline, no_store).await,
         Command::Config { action } => config::execute(action).await,
+        Command::Secrets(action) => secrets::execute(action).await,
+        Command::Sync { locked } => sync::execute_sync(locked).await,
+        Command::Outdated => sync::execute_outdated().await,
+        Command::Upgrade { names, yes } => sync::execute_upgrade(names, yes).await,
         #[cfg(feature = "tui")]
         Command::Shell { ascii } => crate::shell::app::run_shell(ascii).await,
         #[cfg(feature = "tui")]
@@ -669,6 +977,9 @@ pub async fn handle(cli: Cli) -> anyhow::Result<()> {
         Command::Models(action) => models::execute(action).await,
         Command::Extract(args) => extract::execute(args).await,
         Command::Registry(action) => registry::execute(action).await,
+        Command::Search(args) => search::execute(args).await,
+        Command::Convert(args) => convert::execute(args).await,
+        Command::Pack(action) => pack::execute(action).await,
         Command::Prompts(action) => prompts::execute(action).await,
         Command::Skills(action) => skills::execute(action).await,
         Command::Link {
@@ -677,9 +988,22 @@ pub async fn handle(cli: Cli) -> anyhow::Result<()> {
             coordinator,
             dry_run,
             force,
+            check,
+            adopt,
             output,
             agents,
-        } => link::execute(target, model, coordinator, dry_run, force, output, agents).await,
+        } => {
+            let mode = if check {
+                link::LinkMode::Check
+            } else if adopt {
+                link::LinkMode::Adopt
+            } else if dry_run {
+                link::LinkMode::DryRun
+            } else {
+                link::LinkMode::Write { force }
+            };
+            link::execute(target, model, coordinator, mode, output, agents).await
+        }
         Command::Unlink {
             target,
             coordinator,
@@ -688,10 +1012,35 @@ pub async fn handle(cli: Cli) -> anyhow::Result<()> {
             output,
             agents,
         } => unlink::execute(target, coordinator, dry_run, with_config, output, agents).await,
+        Command::Import {
+            from,
+            into,
+            agents,
+            yes,
+            force,
+            dry_run,
+        } => {
+            let options = import::ImportOptions {
+                yes,
+                force,
+                dry_run,
+            };
+            import::execute(from, into, agents, options).await
+        }
+        Command::Init {
+            uninstall_pack: Some(name),
+            ..
+        } => init::uninstall_pack(&name),
+        Command::Init {
+            upgrade_pack: Some(name),
+            force,
+            ..
+        } => init::upgrade_pack(&name, force),
         Command::Init {
             force,
             project,
             pack,
+            ..
         } => init::execute(force, project, pack).await,
         Command::Update => update::execute().await,
         Command::Up => up::start().await,
@@ -712,8 +1061,9 @@ pub async fn handle(cli: Cli) -> anyhow::Result<()> {
         Command::Watch {
             last,
             session,
+            source,
             json,
-        } => watch::execute(last, session, json).await,
+        } => watch::execute(last, session, json, &source).await,
     }
 }
 
@@ -0,0 +1,284 @@
+//! `armadai pack build` — turn a starter pack directory into a versioned,
+//! reproducible `.tar.gz` that can be published as an archive starter
+//! source.
+//!
+//! The pack is validated, the prompts its agents pull in through
+//! `apply_to` are bundled, and `version` and `checksums` are stamped into
+//! the archived `pack.yaml` (the source directory is left untouched).
+
+use std::path::{Path, PathBuf};
+
+use clap::Subcommand;
+use serde_yaml_ng::{Mapping, Value};
+
+use armadai_core::dependency_resolver::resolve_dependencies;
+use armadai_core::pack_validation::{Severity, validate_pack};
+use armadai_core::prompt::load_all_prompts;
+use armadai_core::starter::StarterPack;
+
+use crate::lockfile::{self, Staging};
+use crate::starters_registry::verify::PACK_SIGNATURE;
+
+#[derive(Subcommand)]
+pub enum PackAction {
+    /// Build a starter pack directory into a versioned .tar.gz
+    Build {
+        /// Directory containing pack.yaml
+        dir: PathBuf,
+        /// Version to stamp (default: `version` from pack.yaml)
+        #[arg(long = "set-version")]
+        version: Option<String>,
+        /// Directory to write the archive to
+        #[arg(long, short, default_value = ".")]
+        out: PathBuf,
+    },
+}
+
+pub async fn execute(action: PackAction) -> anyhow::Result<()> {
+    match action {
+        PackAction::Build { dir, version, out } => {
+            let archive = build(&dir, version.as_deref(), &out)?;
+            let o = crate::cli::style::ok();
+            let m = crate::cli::style::muted();
+            anstream::println!("{o}Built{o:#} {}", archive.display());
+            anstream::println!("{m}  {}{m:#}", lockfile::integrity(&archive)?);
+            anstream::println!(
+                "{m}  Sign it with `minisign -Sm {}` and publish both files.{m:#}",
+                archive.display()
+            );
+            Ok(())
+        }
+    }
+}
+
+/// Build `dir` into `<out>/<name>-<version>.tar.gz` and return its path.
+pub fn build(dir: &Path, version: Option<&str>, out: &Path) -> anyhow::Result<PathBuf> {
+    report_issues(dir, "pack")?;
+    let pack = StarterPack::load(dir)?;
+    let version = match version.or(pack.version.as_deref()) {
+        Some(v) => {
+            semver::Version::parse(v).map_err(|e| anyhow::anyhow!("invalid version '{v}': {e}"))?
+        }
+        None => anyhow::bail!(
+            "pack '{}' has no version: set `version:` in pack.yaml or pass --set-version",
+            pack.name
+        ),
+    };
+
+    let staging = Staging::new()?;
+    let staged = staging.path().join(&pack.name);
+    for (rel, file) in lockfile::files_under(dir)? {
+        // A signature of the source pack.yaml does not match the stamped one.
+        if rel == PACK_SIGNATURE {
+            continue;
+        }
+        let dest = staged.join(&rel);
+        if let Some(parent) = dest.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::copy(&file, &dest)?;
+    }
+
+    let prompts = bundle_prompts(&pack, &staged)?;
+
+    let mut manifest: Mapping =
+        serde_yaml_ng::from_str(&std::fs::read_to_string(staged.join("pack.yaml"))?)?;
+    manifest.insert("version".into(), version.to_string().into());
+    manifest.insert(
+        "prompts".into(),
+        Value::Sequence(prompts.into_iter().map(Value::from).collect()),
+    );
+    manifest.remove("checksums");
+    let mut checksums = Mapping::new();
+    for (rel, file) in lockfile::files_under(&staged)? {
+        if rel != "pack.yaml" {
+            checksums.insert(rel.into(), lockfile::integrity(&file)?.into());
+        }
+    }
+    manifest.insert("checksums".into(), Value::Mapping(checksums));
+    std::fs::write(
+        staged.join("pack.yaml"),
+        serde_yaml_ng::to_string(&manifest)?,
+    )?;
+    report_issues(&staged, "built pack")?;
+
+    std::fs::create_dir_all(out)?;
+    let archive = out.join(format!("{}-{version}.tar.gz", pack.name));
+    write_archive(&staged, &pack.name, &archive)?;
+    Ok(archive)
+}
+
+/// Print validation warnings; fail on errors.
+fn report_issues(dir: &Path, what: &str) -> anyhow::Result<()> {
+    let issues = validate_pack(dir);
+    let w = crate::cli::style::warn();
+    let e = crate::cli::style::err();
+    for issue in &issues {
+        match issue.severity {
+            Severity::Warning => {
+                anstream::println!("  {w}warning{w:#} {}: {}", issue.location, issue.message)
+            }
+            Severity::Error => {
+                anstream::println!("  {e}error{e:#} {}: {}", issue.location, issue.message)
+            }
+        }
+    }
+    let errors = issues
+        .iter()
+        .filter(|i| i.severity == Severity::Error)
+        .count();
+    if errors > 0 {
+        anyhow::bail!("{what} has {errors} validation error(s)");
+    }
+    Ok(())
+}
+
+/// The pack's full prompt list: those it names, and those of its
+/// `prompts/` directory that apply to its agents. Library prompts are never
+/// copied in (they may hold anything); the ones that would apply are named
+/// in a warning, to move into the pack by hand.
+fn bundle_prompts(pack: &StarterPack, staged: &Path) -> anyhow::Result<Vec<String>> {
+    let prompts_dir = staged.join("prompts");
+    let agents: Vec<_> = pack
+        .agents
+        .iter()
+        .filter_map(|name| {
+            let file = staged
+                .join("agents")
+                .join(format!("{}.md", name.trim_end_matches(".md")));
+            armadai_core::parser::parse_agent_file(&file).ok()
+        })
+        .collect();
+
+    let mut names: Vec<String> = pack
+        .prompts
+        .iter()
+        .map(|p| p.trim_end_matches(".md").to_string())
+        .collect();
+    let m = crate::cli::style::muted();
+    for prompt in resolve_dependencies(&agents, &load_all_prompts(&prompts_dir)).prompts {
+        let Some(stem) = prompt.source.file_stem().and_then(|s| s.to_str()) else {
+            continue;
+        };
+        if names.iter().any(|n| n == stem) {
+            continue;
+        }
+        anstream::println!(
+            "  {m}+ prompt {stem} (apply_to: {}){m:#}",
+            prompt.apply_to.join(", ")
+        );
+        names.push(stem.to_string());
+    }
+
+    let w = crate::cli::style::warn();
+    let library = load_all_prompts(&armadai_core::config::user_prompts_dir());
+    for prompt in resolve_dependencies(&agents, &library).prompts {
+        let Some(stem) = prompt.source.file_stem().and_then(|s| s.to_str()) else {
+            continue;
+        };
+        if !names.iter().any(|n| n == stem) {
+            anstream::println!(
+                "  {w}warning{w:#} library prompt '{stem}' applies to pack agents but is not \
+                 bundled; copy {} to prompts/ to include it",
+                prompt.source.display()
+            );
+        }
+    }
+    Ok(names)
+}
+
+/// Write `dir` as `<root>/...` into a gzipped tarball that only depends on
+/// file paths and contents: entries sorted, times, owners and modes fixed.
+fn write_archive(dir: &Path, root: &str, archive: &Path) -> anyhow::Result<()> {
+    let file = std::fs::File::create(archive)?;
+    let gz = flate2::GzBuilder::new()
+        .mtime(0)
+        .write(file, flate2::Compression::default());
+    let mut tar = tar::Builder::new(gz);
+    for (rel, path) in lockfile::files_under(dir)? {
+        let data = std::fs::read(&path)?;
+        let mut header = tar::Header::new_gnu();
+        header.set_size(data.len() as u64);
+        header.set_mode(0o644);
+        header.set_mtime(0);
+        header.set_uid(0);
+        header.set_gid(0);
+        header.set_entry_type(tar::EntryType::Regular);
+        tar.append_data(&mut header, format!("{root}/{rel}"), data.as_slice())?;
+    }
+    tar.into_inner()?.finish()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write(path: &Path, content: &str) {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, content).unwrap();
+    }
+
+    #[test]
+    fn build_stamps_version_and_checksums_reproducibly() {
+        let _guard = armadai_core::config::ENV_MUTEX.lock().unwrap();
+        let orig = std::env::var("ARMADAI_CONFIG_DIR").ok();
+        let tmp = tempfile::tempdir().unwrap();
+        // SAFETY: serialised via ENV_MUTEX; restored at end of test.
+        unsafe {
+            std::env::set_var("ARMADAI_CONFIG_DIR", tmp.path().join("config"));
+        }
+
+        let src = tmp.path().join("src");
+        write(
+            &src.join("pack.yaml"),
+            "name: review\ndescription: Review agents\nagents: [reviewer]\n",
+        );
+        write(
+            &src.join("agents/reviewer.md"),
+            "# Reviewer\n\n## Metadata\n- provider: anthropic\n\n## System Prompt\n\nReview.\n",
+        );
+        write(
+            &src.join("prompts/style.md"),
+            "---\napply_to: [reviewer]\n---\nBe terse.\n",
+        );
+
+        write(
+            &tmp.path().join("config/prompts/private.md"),
+            "---\napply_to: [reviewer]\n---\nInternal notes.\n",
+        );
+
+        let err = build(&src, None, &tmp.path().join("out")).unwrap_err();
+        assert!(err.to_string().contains("no version"), "{err}");
+
+        let first = build(&src, Some("1.2.0"), &tmp.path().join("a")).unwrap();
+        let second = build(&src, Some("1.2.0"), &tmp.path().join("b")).unwrap();
+        assert_eq!(first.file_name().unwrap(), "review-1.2.0.tar.gz");
+        assert_eq!(
+            std::fs::read(&first).unwrap(),
+            std::fs::read(&second).unwrap()
+        );
+
+        let unpacked = tmp.path().join("unpacked");
+        let gz = flate2::read::GzDecoder::new(std::fs::File::open(&first).unwrap());
+        tar::Archive::new(gz).unpack(&unpacked).unwrap();
+        let pack = StarterPack::load(&unpacked.join("review")).unwrap();
+        assert_eq!(pack.version.as_deref(), Some("1.2.0"));
+        assert_eq!(pack.prompts, ["style"]);
+        assert_eq!(
+            pack.checksums.keys().collect::<Vec<_>>(),
+            ["agents/reviewer.md", "prompts/style.md"]
+        );
+        // The source directory is left untouched.
+        assert!(
+            !std::fs::read_to_string(src.join("pack.yaml"))
+                .unwrap()
+                .contains("version")
+        );
+
+        match orig {
+            Some(v) => unsafe { std::env::set_var("ARMADAI_CONFIG_DIR", v) },
+            None => unsafe { std::env::remove_var("ARMADAI_CONFIG_DIR") },
+        }
+    }
+}
@@ -137,7 +137,7 @@ mod tests {
 
         // Persist a log + project once.
         let run_id = "run-y";
-        let mut log = SqliteLog::new(db.clone());
+        let mut log = SqliteLog::with_redactor(db.clone(), crate::redact::Redactor::off());
         for e in sample_blackboard_events(run_id) {
             log.append(run_id, &e).unwrap();
         }
@@ -174,7 +174,7 @@ mod tests {
         let db = open_in_memory().unwrap();
 
         // Persist two runs.
-        let mut log = SqliteLog::new(db.clone());
+        let mut log = SqliteLog::with_redactor(db.clone(), crate::redact::Redactor::off());
         for e in sample_blackboard_events("run-a") {
             log.append("run-a", &e).unwrap();
         }
@@ -1,6 +1,7 @@
 use clap::{Subcommand, ValueEnum};
 
 use crate::registry::{cache, convert, search, sync};
+use crate::search_index::{self, Filter, Kind};
 use armadai_core::config::registries_config_path;
 use armadai_core::project::find_project_config;
 use armadai_core::registries::{
@@ -13,7 +14,7 @@ pub enum RegistryAction {
     Sync,
     /// Search agents by keyword
     Search {
-        /// Search query (keywords, AND logic)
+        /// Search query (keywords, every one must match; typos tolerated)
         query: String,
         /// Filter by category
         #[arg(long)]
@@ -56,6 +57,12 @@ pub enum SourcesAction {
         kind: SourceKind,
         /// Registry source URL
         url: String,
+        /// Minisign public key the source's signatures must verify against (starters only)
+        #[arg(long)]
+        public_key: Option<String>,
+        /// Refuse the source unless its content is signed (starters only)
+        #[arg(long, requires = "public_key")]
+        require_signature: bool,
     },
     /// Remove a custom registry source from user config
     Remove {
@@ -99,7 +106,12 @@ pub async fn execute(action: RegistryAction) -> anyhow::Result<()> {
         RegistryAction::Info { agent } => cmd_info(&agent).await,
         RegistryAction::Sources { action } => match action {
             SourcesAction::List => sources_list().await,
-            SourcesAction::Add { kind, url } => sources_add(kind, &url).await,
+            SourcesAction::Add {
+                kind,
+                url,
+                public_key,
+                require_signature,
+            } => sources_add(kind, &url, public_key, require_signature).await,
             SourcesAction::Remove { kind, url } => sources_remove(kind, &url).await,
         },
     }
@@ -128,6 +140,7 @@ async fn cmd_sync() -> anyhow::Result<()> {
     if !starter_sources.is_empty() {
         crate::starters_registry::sync_starters(&starter_sources);
     }
+    search_index::rebuild_or_warn();
 
     Ok(())
 }
@@ -156,18 +169,13 @@ pub(crate) fn effective_starter_sources() -> Vec<RegistrySource> {
 
 async fn cmd_search(query: &str, category: Option<&str>) -> anyhow::Result<()> {
     check_staleness();
-    let sources = sync::effective_sources();
-    let index = cache::load_or_build_index(&sources)?;
-
-    let entries = match category {
-        Some(cat) => {
-            let filtered = search::filter_by_category(&index.entries, cat);
-            filtered.into_iter().cloned().collect::<Vec<_>>()
-        }
-        None => index.entries.clone(),
+    let index = search_index::load_or_rebuild()?;
+    let filter = Filter {
+        kind: Some(Kind::Agent),
+        category,
+        ..Default::default()
     };
-
-    let results = search::search(&entries, query);
+    let results = index.search(query, &filter);
 
     if results.is_empty() {
         let m = crate::cli::style::muted();
@@ -178,7 +186,7 @@ async fn cmd_search(query: &str, category: Option<&str>) -> anyhow::Result<()> {
     // Compute column widths
     let name_w = results
         .iter()
-        .map(|r| r.entry.name.len())
+        .map(|r| r.doc.name.len())
         .max()
         .unwrap_or(4)
         .max(4);
@@ -192,11 +200,11 @@ async fn cmd_search(query: &str, category: Option<&str>) -> anyhow::Result<()> {
     );
 
     for r in &results {
-        let desc = r.entry.description.as_deref().unwrap_or("-");
+        let desc = r.doc.description.as_deref().unwrap_or("-");
         let a = crate::cli::style::accent();
         anstream::println!(
-            "  {a}{:<name_w$}{a:#}  {:>5}  {}",
-            r.entry.name,
+            "  {a}{:<name_w$}{a:#}  {:>5.1}  {}",
+            r.doc.name,
             r.score,
             desc
         );
@@ -293,6 +301,20 @@ async fn cmd_add(agent: &str, force: bool) -> anyhow::Result<()> {
     let a = crate::cli::style::accent();
     anstream::println!("{r}Converting {r:#}{a}{}{a:#} ...", entry.name);
     let dst = convert::import_to_library(&entry.source, &entry.path, force)?;
+    if let Some(url) = sources.iter().find(|u| sync::source_key(u) == entry.source) {
+        let repo = sync::dir_for_key(&entry.source);
+        let pinned = crate::lockfile::pin(
+            crate::lockfile::AssetKind::Agent,
+            &entry.name,
+            url,
+            &repo,
+            &repo.join(&entry.path),
+        );
+        match pinned {
+            Ok(asset) => crate::lockfile::record_or_warn(asset),
+            Err(e) => tracing::warn!("not pinned in {}: {e}", crate::lockfile::LOCK_FILE),
+        }
+    }
     let o = crate::cli::style::ok();
     let m = crate::cli::style::muted();
     anstream::println!("{o}Installed:{o:#} {m}{}{m:#}", dst.display());
@@ -459,22 +481,39 @@ async fn sources_list() -> anyhow::Result<()> {
     anstream::println!("\n{h}Starters:{h:#}");
     for source in &user.starters {
         let m = crate::cli::style::muted();
-        anstream::println!("{m}  [user]    {}{m:#}", source.url);
+        anstream::println!("{m}  [user]    {}{}{m:#}", source.url, trust_label(source));
     }
     if let Some(ref proj) = project {
         for source in &proj.starters {
             let m = crate::cli::style::muted();
-            anstream::println!("{m}  [project] {}{m:#}", source.url);
+            anstream::println!("{m}  [project] {}{}{m:#}", source.url, trust_label(source));
         }
     }
 
     Ok(())
 }
 
-/// Add a custom registry source to user config (idempotent).
-async fn sources_add(kind: SourceKind, url: &str) -> anyhow::Result<()> {
+/// Add a custom registry source to user config (idempotent). Signature
+/// settings given for a source already registered replace its current ones.
+async fn sources_add(
+    kind: SourceKind,
+    url: &str,
+    public_key: Option<String>,
+    require_signature: bool,
+) -> anyhow::Result<()> {
     let mut config = load_user_registries();
     let registry_kind: RegistryKind = kind.into();
+    if public_key.is_some() && registry_kind != RegistryKind::Starters {
+        anyhow::bail!("--public-key is only supported for starter sources");
+    }
+    let source = RegistrySource {
+        url: url.to_string(),
+        public_key,
+        require_signature,
+        ..Default::default()
+    };
+    // Reject a malformed key before it is saved.
+    crate::starters_registry::verify::Trust::of(&source)?;
 
     let sources = match registry_kind {
         RegistryKind::Agents => &mut config.agents,
@@ -484,22 +523,32 @@ async fn sources_add(kind: SourceKind, url: &str) -> anyhow::Result<()> {
     };
 
     // Check if already present (idempotent)
-    if sources.iter().any(|s| s.url == url) {
-        let m = crate::cli::style::muted();
-        anstream::println!("{m}Source already registered: {url}{m:#}");
+    if let Some(existing) = sources.iter_mut().find(|s| s.url == url) {
+        if source.public_key.is_none() {
+            let m = crate::cli::style::muted();
+            anstream::println!("{m}Source already registered: {url}{m:#}");
+            return Ok(());
+        }
+        existing.public_key = source.public_key;
+        existing.require_signature = source.require_signature;
+        save_registries_config(&config)?;
+        let o = crate::cli::style::ok();
+        anstream::println!(
+            "{o}Updated signature settings of {} registry source: {url}{o:#}",
+            kind_name(registry_kind)
+        );
         return Ok(());
     }
 
-    sources.push(RegistrySource {
-        url: url.to_string(),
-        kind: None,
-    });
+    let signed = source.public_key.is_some();
+    sources.push(source);
 
     save_registries_config(&config)?;
     let o = crate::cli::style::ok();
     anstream::println!(
-        "{o}Added {} registry source: {url}{o:#}",
-        kind_name(registry_kind)
+        "{o}Added {} registry source: {url}{o:#}{}",
+        kind_name(registry_kind),
+        if signed { " (signed)" } else { "" }
     );
     let m = crate::cli::style::muted();
     anstream::println!("{m}  Saved to {}{m:#}", registries_config_path().display());
@@ -540,6 +589,15 @@ async fn sources_remove(kind: SourceKind, url: &str) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// How a starter source's content is verified, for `sources list`.
+fn trust_label(source: &RegistrySource) -> &'static str {
+    match (&source.public_key, source.require_signature) {
+        (Some(_), true) => "  (signature required)",
+        (Some(_), false) => "  (signed)",
+        (None, _) => "",
+    }
+}
+
 /// Save the registries config to disk, creating parent directory if needed.
 fn save_registries_config(config: &RegistriesConfig) -> anyhow::Result<()> {
     let path = registries_config_path();
@@ -620,7 +678,9 @@ mod tests {
         }
 
         let url = "https://custom.example.com/agents.git";
-        sources_add(SourceKind::Agents, url).await.unwrap();
+        sources_add(SourceKind::Agents, url, None, false)
+            .await
+            .unwrap();
 
         let loaded = load_user_registries();
         assert_eq!(loaded.agents.len(), 1);
@@ -647,7 +707,9 @@ mod tests {
         }
 
         let url = "https://custom.example.com/agents.git";
-        sources_add(SourceKind::Agents, url).await.unwrap();
+        sources_add(SourceKind::Agents, url, None, false)
+            .await
+            .unwrap();
         sources_remove(SourceKind::Agents, url).await.unwrap();
 
         let loaded = load_user_registries();
@@ -674,8 +736,12 @@ mod tests {
         }
 
         let url = "https://custom.example.com/agents.git";
-        sources_add(SourceKind::Agents, url).await.unwrap();
-        sources_add(SourceKind::Agents, url).await.unwrap();
+        sources_add(SourceKind::Agents, url, None, false)
+            .await
+            .unwrap();
+        sources_add(SourceKind::Agents, url, None, false)
+            .await
+            .unwrap();
 
         let loaded = load_user_registries();
         assert_eq!(loaded.agents.len(), 1, "duplicate add should be idempotent");
@@ -10,7 +10,7 @@ use armadai_core::orchestration::es::event::ExecutionEvent;
 use armadai_core::orchestration::es::log::{EventLog, InMemoryLog};
 use armadai_core::orchestration::es::state::ExecutionState;
 use armadai_core::project::{self, AgentRef, ProjectConfig, ProjectDefaults};
-use armadai_core::provider::{ChatMessage, CompletionRequest};
+use armadai_core::provider::{ChatMessage, CompletionRequest, Provider};
 use armadai_providers::factory::create_provider;
 
 const GUIDED_MODE_INSTRUCTION: &str = "\
@@ -21,6 +21,12 @@ significantly change your approach, ask 2-3 targeted clarifying questions first.
 Only proceed with your complete response once you have enough context to deliver \
 accurate, relevant output.";
 
+/// `create_provider`, with the agent's skill scripts callable as tools when
+/// it runs in API mode (see `crate::skill_tools`).
+fn create_run_provider(agent: &Agent) -> anyhow::Result<Box<dyn Provider>> {
+    crate::skill_tools::wrap(agent, create_provider(agent)?)
+}
+
 /// Execute a run command. Parameters are independent CLI options that map directly to
 /// configuration flags; grouping into a struct would obscure the caller's argument binding.
 #[allow(clippy::too_many_arguments)]
@@ -482,7 +488,7 @@ async fn resume_run(
         if is_orchestrated_pattern(&pattern) {
             apply_orchestrated_timeout(&mut agent, timeout_overrides.agent_timeout_secs);
         }
-        let provider = create_provider(&agent)?;
+        let provider = create_run_provider(&agent)?;
         providers_map.insert(name.clone(), Arc::from(provider));
         agents_map.insert(name.clone(), agent);
     }
@@ -976,7 +982,7 @@ async fn run_single_agent(
     }
 
     // 2. Create provider
-    let provider = create_provider(&agent)?;
+    let provider = create_run_provider(&agent)?;
 
     // 4. Resolve effective mode and build system prompt
     let effective_mode = agent
@@ -1358,7 +1364,8 @@ async fn run_single_agent_es(
 
     // 2. Create provider (step 2).
     let provider_name = agent.metadata.provider.clone();
-    let provider: Arc<dyn armadai_core::provider::Provider> = Arc::from(create_provider(&agent)?);
+    let provider: Arc<dyn armadai_core::provider::Provider> =
+        Arc::from(create_run_provider(&agent)?);
 
     // 4. Guided-mode system-prompt augmentation (step 4).
     let effective_mode = agent
@@ -1444,10 +1451,13 @@ fn record_run(metrics: &RunMetrics, input: &str, output: &str, project: Option<&
         }
     };
 
+    let redactor = crate::redact::Redactor::global();
     let record = queries::RunRecord {
         agent: metrics.agent.clone(),
-        input: input.to_string(),
-        output: output.to_string(),
+        input: redactor.redact(input).unwrap_or_else(|| input.to_string()),
+        output: redactor
+            .redact(output)
+            .unwrap_or_else(|| output.to_string()),
         provider: metrics.provider_name.clone(),
         model: metrics.model.clone(),
         tokens_in: metrics.tokens_in,
@@ -1673,7 +1683,7 @@ async fn run_orchestrated(
 
         apply_orchestrated_timeout(&mut agent, timeout_overrides.agent_timeout_secs);
 
-        let provider = create_provider(&agent)?;
+        let provider = create_run_provider(&agent)?;
         providers.push(Arc::from(provider));
         agents.push(agent);
     }
@@ -2554,10 +2564,13 @@ pub(crate) fn record_hierarchical_into(
     use armadai_storage::queries;
 
     // 1. Parent run record.
+    let redactor = crate::redact::Redactor::global();
     let parent = queries::RunRecord {
         agent: "orchestration:hierarchical".to_string(),
-        input: input.to_string(),
-        output: result.content.clone(),
+        input: redactor.redact(input).unwrap_or_else(|| input.to_string()),
+        output: redactor
+            .redact(&result.content)
+            .unwrap_or_else(|| result.content.clone()),
         provider: "orchestration".to_string(),
         model: String::new(),
         tokens_in: result.total_tokens_in as i64,
@@ -2590,7 +2603,9 @@ pub(crate) fn record_hierarchical_into(
             seq: seq as i64,
             from_agent: ev.from.clone(),
             to_agent: ev.to.clone(),
-            message: ev.message.clone(),
+            message: redactor
+                .redact(&ev.message)
+                .unwrap_or_else(|| ev.message.clone()),
             depth: ev.depth as i64,
         };
         if let Err(e) = queries::insert_delegation_event(db, rec) {
@@ -2731,6 +2746,7 @@ mod tests {
                 tags: vec![],
                 stacks: vec![],
                 scope: vec![],
+                skills: vec![],
                 model_fallback: vec![],
                 cost_limit: None,
                 rate_limit: None,
@@ -2855,6 +2871,7 @@ mod selection_tests {
                 tags: tags.iter().map(|s| s.to_string()).collect(),
                 stacks: stacks.iter().map(|s| s.to_string()).collect(),
                 scope: vec![],
+                skills: vec![],
                 model_fallback: vec![],
                 cost_limit: None,
                 rate_limit: None,
@@ -3154,6 +3171,7 @@ mod es_switch_tests {
                 tags: vec![],
                 stacks: vec![],
                 scope: vec![],
+                skills: vec![],
                 model_fallback: vec![],
                 cost_limit: None,
                 rate_limit: None,
@@ -3731,7 +3749,7 @@ mod es_switch_tests {
         let (_capture, sink) = capture_sink();
         let filtered_sink = quiet_max_content_sink(&sink, false, None);
         let mut log = SinkProjectingLog::with_meta(
-            SqliteLog::new(db),
+            SqliteLog::with_redactor(db, crate::redact::Redactor::off()),
             &filtered_sink,
             agent_meta_from_roster(&agents),
         );
@@ -3785,7 +3803,7 @@ mod es_switch_tests {
         let (_capture, sink) = capture_sink();
         let filtered_sink = quiet_max_content_sink(&sink, false, None);
         let mut log = SinkProjectingLog::with_meta(
-            SqliteLog::new(db),
+            SqliteLog::with_redactor(db, crate::redact::Redactor::off()),
             &filtered_sink,
             agent_meta_from_roster(&agents),
         );
@@ -3833,7 +3851,7 @@ mod es_switch_tests {
         let (_capture, sink) = capture_sink();
         let filtered_sink = quiet_max_content_sink(&sink, false, None);
         let mut log = SinkProjectingLog::with_meta(
-            SqliteLog::new(db),
+            SqliteLog::with_redactor(db, crate::redact::Redactor::off()),
             &filtered_sink,
             agent_meta_from_roster(&agents),
         );
@@ -3884,7 +3902,7 @@ mod es_switch_tests {
         let (_capture, sink) = capture_sink();
         let filtered_sink = quiet_max_content_sink(&sink, false, None);
         let mut log = SinkProjectingLog::with_meta(
-            SqliteLog::new(db.clone()),
+            SqliteLog::with_redactor(db.clone(), crate::redact::Redactor::off()),
             &filtered_sink,
             agent_meta_from_roster(&agents),
         );
@@ -4318,7 +4336,11 @@ mod es_switch_tests {
             "solo".to_string(),
             ("anthropic".to_string(), "concrete-model".to_string()),
         );
-        let mut log = SinkProjectingLog::with_meta(SqliteLog::new(db), sink.as_ref(), agent_meta);
+        let mut log = SinkProjectingLog::with_meta(
+            SqliteLog::with_redactor(db, crate::redact::Redactor::off()),
+            sink.as_ref(),
+            agent_meta,
+        );
 
         let mut agents = BTreeMap::new();
         agents.insert("solo".to_string(), test_agent("solo"));
@@ -4352,7 +4374,8 @@ mod es_switch_tests {
         let (live_capture, live_sink) = capture_sink();
         run_direct_against_sqlite_log(db.clone(), &run_id, &live_sink).await;
 
-        let replay_log = crate::es_log::SqliteLog::new(db);
+        let replay_log =
+            crate::es_log::SqliteLog::with_redactor(db, crate::redact::Redactor::off());
         let (replay_capture, replay_sink) = capture_sink();
         crate::cli::run_replay::replay_from_log(&replay_log, &run_id, &replay_sink, false).unwrap();
 
@@ -4424,7 +4447,8 @@ mod es_switch_tests {
         let (_live_capture, live_sink) = capture_sink();
         run_direct_against_sqlite_log(db.clone(), &run_id, &live_sink).await;
 
-        let replay_log = crate::es_log::SqliteLog::new(db);
+        let replay_log =
+            crate::es_log::SqliteLog::with_redactor(db, crate::redact::Redactor::off());
         let (replay_capture, replay_sink) = capture_sink();
         crate::cli::run_replay::replay_from_log(&replay_log, &run_id, &replay_sink, false).unwrap();
 
@@ -4479,7 +4503,8 @@ mod es_switch_tests {
         let (_live_capture, live_sink) = capture_sink();
         run_direct_against_sqlite_log(db.clone(), &run_id, &live_sink).await;
 
-        let replay_log = crate::es_log::SqliteLog::new(db);
+        let replay_log =
+            crate::es_log::SqliteLog::with_redactor(db, crate::redact::Redactor::off());
         let (replay_capture, replay_sink) = capture_sink();
         crate::cli::run_replay::replay_from_log(&replay_log, &run_id, &replay_sink, false).unwrap();
 
@@ -4524,7 +4549,7 @@ mod es_switch_tests {
         let db = open_in_memory().unwrap();
         let run_id = uuid::Uuid::new_v4().to_string();
 
-        let mut log = SqliteLog::new(db.clone());
+        let mut log = SqliteLog::with_redactor(db.clone(), crate::redact::Redactor::off());
         let events = [
             ExecutionEvent::RunStarted {
                 run_id: run_id.clone(),
@@ -4570,7 +4595,7 @@ mod es_switch_tests {
             log.append(&run_id, event).unwrap();
         }
 
-        let replay_log = SqliteLog::new(db);
+        let replay_log = SqliteLog::with_redactor(db, crate::redact::Redactor::off());
         let (capture, sink) = capture_sink();
         crate::cli::run_replay::replay_from_log(&replay_log, &run_id, &sink, false).unwrap();
 
@@ -4594,7 +4619,8 @@ mod es_switch_tests {
         use crate::es_log::SqliteLog;
         use armadai_storage::open_in_memory;
 
-        let log = SqliteLog::new(open_in_memory().unwrap());
+        let log =
+            SqliteLog::with_redactor(open_in_memory().unwrap(), crate::redact::Redactor::off());
         let (_capture, sink) = capture_sink();
         let err = crate::cli::run_replay::replay_from_log(&log, "does-not-exist", &sink, false)
             .unwrap_err();
@@ -4654,7 +4680,7 @@ mod es_switch_tests {
         // process died before the single agent was invoked) — this folds to
         // `RunStatus::Running`, exactly what `resume_run` requires to
         // proceed with a resume.
-        let mut log = SqliteLog::new(db);
+        let mut log = SqliteLog::with_redactor(db, crate::redact::Redactor::off());
         log.append(
             &run_id,
             &ExecutionEvent::RunStarted {
@@ -808,7 +808,8 @@ mod storage_tests {
         let db = open_in_memory().unwrap();
 
         // Persist a minimal blackboard log via SqliteLog.
-        let mut log = crate::es_log::SqliteLog::new(db.clone());
+        let mut log =
+            crate::es_log::SqliteLog::with_redactor(db.clone(), crate::redact::Redactor::off());
         for e in sample_blackboard_events("run-x") {
             use armadai_core::orchestration::es::log::EventLog;
             log.append("run-x", &e).unwrap();
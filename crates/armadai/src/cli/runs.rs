@@ -0,0 +1,643 @@
+//! Run bundles — move a run's event log between databases.
+//!
+//! `runs export` writes a `.armadai.jsonl` bundle: a header line, one line
+//! per agent definition the run used (raw Markdown, when found locally), then
+//! every `execution_events` row verbatim (the `config` snapshot included).
+//! `runs import` inserts those rows into the local event log under the same
+//! `run_id` (or `--as <id>`) and rebuilds the projections, so `run --replay`,
+//! `history` and the web trace viewer work on the imported run.
+//!
+//! `runs diff` aligns two event logs step by step
+//! (`armadai_core::orchestration::es::diff`); the TUI's Orchestration tab
+//! shows the same diff side by side (`m` to mark a run, `d` to compare).
+//!
+//! Direct runs have no orchestration projection (see
+//! `run_es_record::project_run`): once imported they replay, but only
+//! orchestrated runs show up in the trace viewer.
+
+use std::path::PathBuf;
+
+use clap::Subcommand;
+
+#[derive(Subcommand)]
+pub enum RunsAction {
+    /// Export a run (event log + agent definitions) as a bundle
+    Export {
+        /// Run to export
+        run_id: String,
+        /// Output file (default: stdout)
+        #[arg(short, long, value_name = "FILE")]
+        output: Option<PathBuf>,
+        /// Replace API keys and tokens with [REDACTED]
+        #[arg(long)]
+        redact: bool,
+    },
+    /// Import a run bundle into the local database
+    Import {
+        /// Bundle file written by `armadai runs export`
+        file: PathBuf,
+        /// Import under a different run id
+        #[arg(long = "as", value_name = "RUN_ID")]
+        as_id: Option<String>,
+        /// Also write the bundled agent definitions into this directory
+        #[arg(long, value_name = "DIR")]
+        agents_dir: Option<PathBuf>,
+    },
+    /// Compare two runs step by step
+    Diff {
+        /// Baseline run
+        a: String,
+        /// Run to compare against the baseline
+        b: String,
+        /// Print the diff as JSON
+        #[arg(long)]
+        json: bool,
+        /// Also list steps that did not change
+        #[arg(long)]
+        all: bool,
+    },
+}
+
+/// Value of the header's `format` field; bumped on incompatible changes.
+#[cfg(feature = "storage")]
+const BUNDLE_FORMAT: &str = "armadai-run/1";
+
+/// One line of a bundle.
+#[cfg(feature = "storage")]
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+enum BundleLine {
+    Header {
+        format: String,
+        run_id: String,
+        exported_at: String,
+        armadai_version: String,
+        redacted: bool,
+    },
+    Agent {
+        name: String,
+        file_name: String,
+        content: String,
+    },
+    Event {
+        seq: i64,
+        ts: String,
+        event: serde_json::Value,
+    },
+}
+
+/// An agent definition carried by a bundle.
+#[cfg(feature = "storage")]
+#[derive(Debug, Clone, PartialEq)]
+struct BundledAgent {
+    name: String,
+    file_name: String,
+    content: String,
+}
+
+/// What `import_bundle` inserted.
+#[cfg(feature = "storage")]
+#[derive(Debug)]
+struct ImportedRun {
+    run_id: String,
+    events: usize,
+    agents: Vec<BundledAgent>,
+}
+
+/// Execute the `armadai runs` command dispatcher.
+pub async fn execute(action: RunsAction) -> anyhow::Result<()> {
+    #[cfg(feature = "storage")]
+    {
+        let db = crate::db::init_db()?;
+        match action {
+            RunsAction::Export {
+                run_id,
+                output,
+                redact,
+            } => {
+                let rows = armadai_storage::queries::get_event_rows(&db, &run_id)?;
+                let mut agents = Vec::new();
+                for name in run_agents(&rows) {
+                    match locate_agent(&name) {
+                        Some(agent) => agents.push(agent),
+                        None => {
+                            let s = crate::cli::style::warn();
+                            anstream::eprintln!(
+                                "{s}  warn: agent '{name}' not found locally; its definition is not included{s:#}"
+                            );
+                        }
+                    }
+                }
+                let redactor = redact.then(|| crate::redact::Redactor::global().masking());
+                let bundle = export_bundle(&run_id, &rows, &agents, redactor.as_ref())?;
+                match output {
+                    Some(path) => {
+                        std::fs::write(&path, bundle)?;
+                        println!(
+                            "Run {run_id} exported to {} ({} events, {} agents{})",
+                            path.display(),
+                            rows.len(),
+                            agents.len(),
+                            if redact { ", redacted" } else { "" }
+                        );
+                    }
+                    None => print!("{bundle}"),
+                }
+            }
+            RunsAction::Import {
+                file,
+                as_id,
+                agents_dir,
+            } => {
+                let text = std::fs::read_to_string(&file)?;
+                let imported = import_bundle(
+                    &db,
+                    &text,
+                    as_id.as_deref(),
+                    crate::redact::Redactor::global(),
+                )?;
+                println!(
+                    "Run {} imported ({} events)",
+                    imported.run_id, imported.events
+                );
+                if let Some(dir) = agents_dir {
+                    std::fs::create_dir_all(&dir)?;
+                    for agent in &imported.agents {
+                        let path = dir.join(&agent.file_name);
+                        if path.exists() {
+                            println!("  skipped {} (already exists)", path.display());
+                            continue;
+                        }
+                        std::fs::write(&path, &agent.content)?;
+                        println!("  wrote {}", path.display());
+                    }
+                }
+                println!("Replay it with: armadai run --replay {}", imported.run_id);
+            }
+            RunsAction::Diff { a, b, json, all } => {
+                let diff = load_diff(&db, &a, &b)?;
+                if json {
+                    println!("{}", serde_json::to_string_pretty(&diff)?);
+                } else {
+                    print_diff(&diff, all);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[cfg(not(feature = "storage"))]
+    {
+        let _ = action;
+        anyhow::bail!(
+            "Run bundles require the 'storage' feature. Build with: cargo build --features storage"
+        )
+    }
+}
+
+/// Read both event logs and diff them.
+#[cfg(feature = "storage")]
+pub(crate) fn load_diff(
+    db: &armadai_storage::Database,
+    a: &str,
+    b: &str,
+) -> anyhow::Result<armadai_core::orchestration::es::diff::RunDiff> {
+    use armadai_core::orchestration::es::log::EventLog;
+
+    let log = crate::es_log::SqliteLog::new(db.clone());
+    let a_events = log.events(a)?;
+    let b_events = log.events(b)?;
+    for (id, events) in [(a, &a_events), (b, &b_events)] {
+        if events.is_empty() {
+            anyhow::bail!("run '{id}' not found in the event log");
+        }
+    }
+    Ok(armadai_core::orchestration::es::diff::diff_runs(
+        a, &a_events, b, &b_events,
+    ))
+}
+
+#[cfg(feature = "storage")]
+fn print_diff(diff: &armadai_core::orchestration::es::diff::RunDiff, all: bool) {
+    use crate::cli::style;
+    use armadai_core::orchestration::es::diff::DiffLine;
+
+    let h = style::header();
+    let m = style::muted();
+    anstream::println!("{h}A{h:#} {} ({})", diff.a.run_id, diff.a.pattern);
+    anstream::println!("{h}B{h:#} {} ({})", diff.b.run_id, diff.b.pattern);
+    anstream::println!(
+        "{m}tokens in {} -> {} ({:+}), out {} -> {} ({:+}), cost ${:.6} -> ${:.6} ({:+.6}){m:#}",
+        diff.a.tokens_in,
+        diff.b.tokens_in,
+        diff.b.tokens_in as i64 - diff.a.tokens_in as i64,
+        diff.a.tokens_out,
+        diff.b.tokens_out,
+        diff.b.tokens_out as i64 - diff.a.tokens_out as i64,
+        diff.a.cost,
+        diff.b.cost,
+        diff.b.cost - diff.a.cost
+    );
+
+    let print_lines = |lines: &[DiffLine]| {
+        let (r, a) = (style::err(), style::ok());
+        for line in lines {
+            match line {
+                DiffLine::Same(t) => println!("    {t}"),
+                DiffLine::Removed(t) => anstream::println!("  {r}- {t}{r:#}"),
+                DiffLine::Added(t) => anstream::println!("  {a}+ {t}{a:#}"),
+            }
+        }
+    };
+
+    let mut unchanged = 0;
+    for step in &diff.steps {
+        if step.is_unchanged() {
+            unchanged += 1;
+            if all {
+                println!();
+                anstream::println!("{m}= {}{m:#}", step.label());
+            }
+            continue;
+        }
+        println!();
+        let (din, dout, dcost) = step.deltas();
+        let presence = match (&step.a, &step.b) {
+            (Some(_), None) => " (only in A)",
+            (None, Some(_)) => " (only in B)",
+            _ => "",
+        };
+        anstream::println!(
+            "{h}~ {}{presence}{h:#}  {m}tokens {din:+}/{dout:+}, cost {dcost:+.6}{m:#}",
+            step.label()
+        );
+        if let Some(change) = step.detail_change() {
+            let w = style::warn();
+            anstream::println!("  {w}{change}{w:#}");
+        }
+        print_lines(&step.output_diff);
+    }
+
+    println!();
+    if diff.final_changed {
+        anstream::println!("{h}~ final result{h:#}");
+        print_lines(&diff.final_diff);
+    } else {
+        anstream::println!("{m}= final result unchanged{m:#}");
+    }
+    if !all && unchanged > 0 {
+        anstream::println!("{m}{unchanged} unchanged step(s) hidden (use --all){m:#}");
+    }
+}
+
+/// Agent names from the run's first `RunStarted` event.
+#[cfg(feature = "storage")]
+fn run_agents(rows: &[armadai_storage::queries::EventRow]) -> Vec<String> {
+    rows.iter()
+        .filter(|r| r.kind == "run_started")
+        .find_map(|r| serde_json::from_str::<serde_json::Value>(&r.payload_json).ok())
+        .and_then(|v| {
+            v.get("agents")?.as_array().map(|a| {
+                a.iter()
+                    .filter_map(|n| n.as_str().map(str::to_string))
+                    .collect()
+            })
+        })
+        .unwrap_or_default()
+}
+
+/// Find an agent's Markdown file the way `armadai run` would: through the
+/// project config when there is one, else the default agents directory.
+/// Declared agents (`.armadai/agents.yaml`) have no file and are skipped.
+#[cfg(feature = "storage")]
+fn locate_agent(name: &str) -> Option<BundledAgent> {
+    use armadai_core::project::{self, AgentRef};
+
+    let path = match project::find_project_config() {
+        Some((root, _)) => project::resolve_agent(
+            &AgentRef::Named {
+                name: name.to_string(),
+            },
+            &root,
+        )
+        .ok()?,
+        None => armadai_core::agent::Agent::find_file(
+            &armadai_core::config::AppPaths::resolve().agents_dir,
+            name,
+        )?,
+    };
+    Some(BundledAgent {
+        name: name.to_string(),
+        file_name: path.file_name()?.to_string_lossy().into_owned(),
+        content: std::fs::read_to_string(&path).ok()?,
+    })
+}
+
+/// Serialize a run's event rows and agent definitions as a bundle, their
+/// text rewritten by `redactor` when there is one.
+#[cfg(feature = "storage")]
+fn export_bundle(
+    run_id: &str,
+    rows: &[armadai_storage::queries::EventRow],
+    agents: &[BundledAgent],
+    redactor: Option<&crate::redact::Redactor>,
+) -> anyhow::Result<String> {
+    if rows.is_empty() {
+        anyhow::bail!("run '{run_id}' not found in the event log");
+    }
+    let mut lines = vec![BundleLine::Header {
+        format: BUNDLE_FORMAT.to_string(),
+        run_id: run_id.to_string(),
+        exported_at: chrono::Utc::now().to_rfc3339(),
+        armadai_version: env!("CARGO_PKG_VERSION").to_string(),
+        redacted: redactor.is_some(),
+    }];
+    for agent in agents {
+        lines.push(BundleLine::Agent {
+            name: agent.name.clone(),
+            file_name: agent.file_name.clone(),
+            content: redactor
+                .and_then(|r| r.redact(&agent.content))
+                .unwrap_or_else(|| agent.content.clone()),
+        });
+    }
+    for row in rows {
+        let mut event: serde_json::Value = serde_json::from_str(&row.payload_json)?;
+        if let Some(redactor) = redactor {
+            redact_value(redactor, &mut event);
+        }
+        lines.push(BundleLine::Event {
+            seq: row.seq,
+            ts: row.ts.clone(),
+            event,
+        });
+    }
+
+    let mut out = String::new();
+    for line in &lines {
+        out.push_str(&serde_json::to_string(line)?);
+        out.push('\n');
+    }
+    Ok(out)
+}
+
+/// Parse a bundle, insert its events under `as_id` (default: the exported
+/// run id) and rebuild the run's projections. Events are redacted as
+/// `SqliteLog` redacts them: rewritten, and followed by a
+/// `Warned { code: "secret_redacted" }` event.
+#[cfg(feature = "storage")]
+fn import_bundle(
+    db: &armadai_storage::Database,
+    text: &str,
+    as_id: Option<&str>,
+    redactor: &crate::redact::Redactor,
+) -> anyhow::Result<ImportedRun> {
+    use armadai_core::orchestration::es::event::ExecutionEvent;
+    use armadai_storage::queries::{self, EventRow};
+
+    let mut lines = text
+        .lines()
+        .enumerate()
+        .filter(|(_, l)| !l.trim().is_empty());
+    let exported_id = match lines.next() {
+        Some((_, l)) => match serde_json::from_str(l) {
+            Ok(BundleLine::Header { format, run_id, .. }) if format == BUNDLE_FORMAT => run_id,
+            Ok(BundleLine::Header { format, .. }) => {
+                anyhow::bail!("unsupported bundle format '{format}' (expected {BUNDLE_FORMAT})")
+            }
+            _ => anyhow::bail!("not an armadai run bundle: missing header line"),
+        },
+        None => anyhow::bail!("empty bundle"),
+    };
+    let run_id = as_id.unwrap_or(&exported_id).to_string();
+
+    let mut events = Vec::new();
+    let mut agents = Vec::new();
+    let mut has_start = false;
+    // Warnings inserted so far, each shifting the events after it.
+    let mut shift = 0;
+    for (idx, line) in lines {
+        let parsed: BundleLine = serde_json::from_str(line)
+            .map_err(|e| anyhow::anyhow!("bundle line {}: {e}", idx + 1))?;
+        match parsed {
+            BundleLine::Header { .. } => {
+                anyhow::bail!("bundle line {}: unexpected second header", idx + 1)
+            }
+            BundleLine::Agent {
+                name,
+                file_name,
+                content,
+            } => {
+                // Written under `--agents-dir`: never let a bundle pick a path.
+                let file_name = std::path::Path::new(&file_name)
+                    .file_name()
+                    .map(|f| f.to_string_lossy().into_owned())
+                    .ok_or_else(|| {
+                        anyhow::anyhow!("bundle line {}: invalid agent file name", idx + 1)
+                    })?;
+                agents.push(BundledAgent {
+                    name,
+                    file_name,
+                    content,
+                });
+            }
+            BundleLine::Event { seq, ts, event } => {
+                let kind = event["t"].as_str().unwrap_or_default().to_string();
+                // Re-serialized from the typed event, exactly as `SqliteLog`
+                // writes it, so the imported payload is byte-identical.
+                let mut event: ExecutionEvent = serde_json::from_value(event)
+                    .map_err(|e| anyhow::anyhow!("bundle line {}: {e}", idx + 1))?;
+                if let ExecutionEvent::RunStarted { run_id: id, .. } = &mut event {
+                    has_start = true;
+                    id.clone_from(&run_id);
+                }
+                let redacted = redactor.redact_event(&event);
+                events.push(EventRow {
+                    seq: seq + shift,
+                    ts: ts.clone(),
+                    kind,
+                    payload_json: serde_json::to_string(redacted.as_ref().unwrap_or(&event))?,
+                });
+                if redacted.is_some() {
+                    shift += 1;
+                    let warned = ExecutionEvent::Warned {
+                        code: crate::redact::SECRET_REDACTED.to_string(),
+                    };
+                    events.push(EventRow {
+                        seq: seq + shift,
+                        ts,
+                        kind: "warned".to_string(),
+                        payload_json: serde_json::to_string(&warned)?,
+                    });
+                }
+            }
+        }
+    }
+    if !has_start {
+        anyhow::bail!("bundle has no run_started event");
+    }
+
+    // One transaction: a projection failure must not leave the events of a
+    // half-imported run behind (a retry would then hit "already exists").
+    queries::in_transaction(db, || {
+        queries::insert_event_rows(db, &run_id, &events)?;
+        crate::cli::run_es_record::project_run(db, &run_id)
+    })?;
+    Ok(ImportedRun {
+        run_id,
+        events: events.len(),
+        agents,
+    })
+}
+
+/// Every string in `value`, redacted.
+#[cfg(feature = "storage")]
+fn redact_value(redactor: &crate::redact::Redactor, value: &mut serde_json::Value) {
+    use serde_json::Value;
+    match value {
+        Value::String(s) => {
+            if let Some(redacted) = redactor.redact(s) {
+                *s = redacted;
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(|v| redact_value(redactor, v)),
+        Value::Object(map) => map.values_mut().for_each(|v| redact_value(redactor, v)),
+        _ => {}
+    }
+}
+
+#[cfg(all(test, feature = "storage"))]
+mod tests {
+    use super::*;
+    use crate::es_log::SqliteLog;
+    use crate::redact::Redactor;
+    use armadai_core::config::RedactionMode;
+    use armadai_core::orchestration::es::event::ExecutionEvent;
+    use armadai_core::orchestration::es::log::EventLog;
+    use armadai_storage::{open_in_memory, queries};
+
+    fn seed(db: &armadai_storage::Database, run_id: &str, input: &str) {
+        let mut log = SqliteLog::with_redactor(db.clone(), Redactor::off());
+        for e in [
+            ExecutionEvent::RunStarted {
+                run_id: run_id.to_string(),
+                pattern: "blackboard".to_string(),
+                agents: vec!["a".to_string()],
+                input: input.to_string(),
+                project: None,
+                roster: Default::default(),
+            },
+            ExecutionEvent::ConfigSnapshot {
+                config_json: r#"{"max_rounds":3}"#.to_string(),
+            },
+            ExecutionEvent::RoundStarted { round: 1 },
+            ExecutionEvent::Completed {
+                content: "done".to_string(),
+            },
+        ] {
+            log.append(run_id, &e).unwrap();
+        }
+    }
+
+    fn agent() -> BundledAgent {
+        BundledAgent {
+            name: "a".to_string(),
+            file_name: "a.md".to_string(),
+            content: "# A\n## Metadata\n- provider: anthropic\n".to_string(),
+        }
+    }
+
+    #[test]
+    fn export_then_import_round_trips_events_and_projections() {
+        let src = open_in_memory().unwrap();
+        seed(&src, "run-1", "review this");
+        let rows = queries::get_event_rows(&src, "run-1").unwrap();
+        let bundle = export_bundle("run-1", &rows, &[agent()], None).unwrap();
+        assert_eq!(bundle.lines().count(), 1 + 1 + rows.len());
+
+        let dst = open_in_memory().unwrap();
+        let imported = import_bundle(&dst, &bundle, None, Redactor::off()).unwrap();
+        assert_eq!(imported.run_id, "run-1");
+        assert_eq!(imported.events, rows.len());
+        assert_eq!(imported.agents, vec![agent()]);
+        assert_eq!(queries::get_event_rows(&dst, "run-1").unwrap(), rows);
+        assert!(
+            queries::get_orchestration_run(&dst, "run-1")
+                .unwrap()
+                .is_some()
+        );
+
+        // Same bundle again collides; `--as` imports it under a new id.
+        assert!(import_bundle(&dst, &bundle, None, Redactor::off()).is_err());
+        import_bundle(&dst, &bundle, Some("run-2"), Redactor::off()).unwrap();
+        let events = SqliteLog::with_redactor(dst.clone(), Redactor::off())
+            .events("run-2")
+            .unwrap();
+        assert!(matches!(
+            &events[0],
+            ExecutionEvent::RunStarted { run_id, .. } if run_id == "run-2"
+        ));
+    }
+
+    #[test]
+    fn export_redact_masks_secret_patterns_and_known_keys() {
+        let db = open_in_memory().unwrap();
+        let key = "sk-ant-REDACTED";
+        let own = "my-own-provider-key";
+        seed(&db, "run-1", &format!("use key {key} or {own}"));
+        let rows = queries::get_event_rows(&db, "run-1").unwrap();
+
+        // `storage.redaction: off` still masks patterns once `--redact` asks.
+        let redactor = Redactor::new(RedactionMode::Off, [own.to_string()]).masking();
+        let bundle = export_bundle("run-1", &rows, &[], Some(&redactor)).unwrap();
+        assert!(!bundle.contains(key) && !bundle.contains(own), "{bundle}");
+        assert!(bundle.contains("[REDACTED]"));
+        assert!(bundle.contains(r#""redacted":true"#));
+    }
+
+    #[test]
+    fn imported_events_are_redacted_and_flagged() {
+        let src = open_in_memory().unwrap();
+        let key = "sk-ant-REDACTED";
+        seed(&src, "run-1", &format!("use key {key}"));
+        let rows = queries::get_event_rows(&src, "run-1").unwrap();
+        let bundle = export_bundle("run-1", &rows, &[], None).unwrap();
+
+        let dst = open_in_memory().unwrap();
+        let redactor = Redactor::new(RedactionMode::Mask, []);
+        let imported = import_bundle(&dst, &bundle, None, &redactor).unwrap();
+        assert_eq!(imported.events, rows.len() + 1);
+        let stored = queries::get_event_rows(&dst, "run-1").unwrap();
+        assert!(stored.iter().all(|r| !r.payload_json.contains(key)));
+        assert_eq!(stored[1].kind, "warned");
+        assert!(
+            stored[1]
+                .payload_json
+                .contains(crate::redact::SECRET_REDACTED)
+        );
+        let seqs: Vec<i64> = stored.iter().map(|r| r.seq).collect();
+        assert_eq!(seqs, (0..stored.len() as i64).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn diff_loads_both_logs_and_rejects_unknown_runs() {
+        let db = open_in_memory().unwrap();
+        seed(&db, "run-1", "task");
+        seed(&db, "run-2", "task");
+        let diff = load_diff(&db, "run-1", "run-2").unwrap();
+        assert!(diff.is_identical());
+        assert_eq!(diff.a.pattern, "blackboard");
+
+        let err = load_diff(&db, "run-1", "nope").unwrap_err();
+        assert!(err.to_string().contains("'nope'"), "{err}");
+    }
+
+    #[test]
+    fn export_unknown_run_and_import_garbage_fail() {
+        assert!(export_bundle("nope", &[], &[], None).is_err());
+        let db = open_in_memory().unwrap();
+        let err = import_bundle(&db, "{\"kind\":\"event\"}\n", None, Redactor::off()).unwrap_err();
+        assert!(err.to_string().contains("header"), "{err}");
+        assert!(run_agents(&[]).is_empty());
+    }
+}
@@ -0,0 +1,107 @@
+//! `armadai search`: one ranked search across the agent, skill and starter
+//! registries, served offline from the persisted index (see
+//! `crate::search_index`).
+
+use clap::Args;
+
+use crate::search_index::{self, Filter, Kind};
+
+#[derive(Args, Debug)]
+pub struct SearchArgs {
+    /// Search query (keywords, every one must match; typos tolerated)
+    pub query: String,
+    /// Only search one kind of entry
+    #[arg(long, value_enum)]
+    pub kind: Option<Kind>,
+    /// Filter agents by registry category (e.g. official)
+    #[arg(long)]
+    pub category: Option<String>,
+    /// Filter by supported stack (e.g. rust)
+    #[arg(long)]
+    pub stack: Option<String>,
+    /// Filter by registry source (substring of the source)
+    #[arg(long)]
+    pub source: Option<String>,
+    /// Rebuild the index from the synced registries first
+    #[arg(long)]
+    pub rebuild: bool,
+    /// Maximum number of results to show
+    #[arg(long, short = 'n', default_value_t = 20)]
+    pub limit: usize,
+}
+
+pub async fn execute(args: SearchArgs) -> anyhow::Result<()> {
+    let index = if args.rebuild {
+        let index = search_index::rebuild()?;
+        let m = crate::cli::style::muted();
+        anstream::println!("{m}Indexed {} entries.{m:#}", index.docs.len());
+        index
+    } else {
+        search_index::load_or_rebuild()?
+    };
+
+    let m = crate::cli::style::muted();
+    if index.docs.is_empty() {
+        anstream::println!(
+            "{m}Nothing indexed. Run `armadai registry sync` and `armadai skills sync` first.{m:#}"
+        );
+        return Ok(());
+    }
+
+    let filter = Filter {
+        kind: args.kind,
+        category: args.category.as_deref(),
+        stack: args.stack.as_deref(),
+        source: args.source.as_deref(),
+    };
+    let results = index.search(&args.query, &filter);
+    if results.is_empty() {
+        anstream::println!("{m}Nothing matching '{}'.{m:#}", args.query);
+        return Ok(());
+    }
+
+    let shown = &results[..results.len().min(args.limit)];
+    let name_w = shown
+        .iter()
+        .map(|r| r.doc.name.len())
+        .max()
+        .unwrap_or(4)
+        .max(4);
+
+    let h = crate::cli::style::header();
+    anstream::println!(
+        "{h}  {:<7}  {:<name_w$}  SCORE  DESCRIPTION{h:#}",
+        "KIND",
+        "NAME"
+    );
+    anstream::println!(
+        "{m}  -------  {:<name_w$}  -----  -----------{m:#}",
+        "-".repeat(name_w),
+    );
+    let a = crate::cli::style::accent();
+    for r in shown {
+        let desc = r.doc.description.as_deref().unwrap_or("-");
+        let desc = if desc.chars().count() > 60 {
+            format!("{}...", desc.chars().take(57).collect::<String>())
+        } else {
+            desc.to_string()
+        };
+        anstream::println!(
+            "  {:<7}  {a}{:<name_w$}{a:#}  {:>5.1}  {desc}",
+            r.doc.kind.label(),
+            r.doc.name,
+            r.score,
+        );
+    }
+
+    if results.len() > shown.len() {
+        anstream::println!(
+            "\n{m}  {} of {} result(s); use --limit to see more.{m:#}",
+            shown.len(),
+            results.len()
+        );
+    } else {
+        anstream::println!("\n{m}  {} result(s).{m:#}", results.len());
+    }
+    Ok(())
+}
@@ -0,0 +1,263 @@
+//! Provider secrets commands — set, read, list and remove the API keys and
+//! other fields (`base_url`, `region`, headers…) providers are created with.
+//!
+//! Secrets live in two layers: the user's, in the config directory, and the
+//! current project's, in its `.armadai/` directory. Both are SOPS files
+//! encrypted to the user's age key; project fields override the user's ones
+//! field by field, the way `factory::get_api_key` reads them.
+
+use std::io::IsTerminal;
+use std::path::PathBuf;
+
+use clap::Subcommand;
+
+use armadai_secrets::{Layer, ProviderSecrets};
+
+#[derive(Subcommand)]
+pub enum SecretsCommand {
+    /// Store a secret field for a provider (read from a prompt or stdin)
+    Set {
+        /// Provider name (anthropic, openai, google, …)
+        provider: String,
+        /// Field to set
+        #[arg(long, default_value = "api_key")]
+        field: String,
+        /// Store it in the current project's layer
+        #[arg(long)]
+        project: bool,
+    },
+    /// Print the value a provider field resolves to
+    Get {
+        provider: String,
+        #[arg(long, default_value = "api_key")]
+        field: String,
+        /// Only look at the current project's layer
+        #[arg(long)]
+        project: bool,
+    },
+    /// List stored fields per provider, values masked
+    List {
+        /// Only list the current project's layer
+        #[arg(long)]
+        project: bool,
+    },
+    /// Remove a field, or a whole provider
+    Remove {
+        provider: String,
+        /// Field to remove (default: every field of the provider)
+        #[arg(long)]
+        field: Option<String>,
+        /// Remove it from the current project's layer
+        #[arg(long)]
+        project: bool,
+    },
+}
+
+pub async fn execute(action: SecretsCommand) -> anyhow::Result<()> {
+    match action {
+        SecretsCommand::Set {
+            provider,
+            field,
+            project,
+        } => set(&provider, &field, project),
+        SecretsCommand::Get {
+            provider,
+            field,
+            project,
+        } => get(&provider, &field, project),
+        SecretsCommand::List { project } => list(project),
+        SecretsCommand::Remove {
+            provider,
+            field,
+            project,
+        } => remove(&provider, field.as_deref(), project),
+    }
+}
+
+const NO_PROJECT: &str = "--project: no armadai project found (run 'armadai init --project')";
+
+/// The current project's `.armadai/` directory, if inside one.
+fn project_dir() -> Option<PathBuf> {
+    armadai_core::project::find_project_config()
+        .map(|(root, _)| armadai_core::project::project_dir(&root))
+}
+
+/// The directory the user's layer and age key live in: the global config
+/// directory, never one inside a checkout.
+fn user_dir() -> PathBuf {
+    armadai_core::config::config_dir()
+}
+
+/// The layer a write goes to: the project's with `--project`, else the user's.
+fn target_layer(project: bool) -> anyhow::Result<Layer> {
+    let config_dir = user_dir();
+    if !project {
+        return Ok(Layer::user(&config_dir));
+    }
+    let dir = project_dir().ok_or_else(|| anyhow::anyhow!(NO_PROJECT))?;
+    Ok(Layer::project(&dir, &config_dir))
+}
+
+fn read_value(provider: &str, field: &str) -> anyhow::Result<String> {
+    let value = if std::io::stdin().is_terminal() {
+        dialoguer::Password::new()
+            .with_prompt(format!("{provider} {field}"))
+            .interact()?
+    } else {
+        let mut line = String::new();
+        std::io::stdin().read_line(&mut line)?;
+        line
+    };
+    let value = value.trim().to_string();
+    if value.is_empty() {
+        anyhow::bail!("empty value: nothing stored");
+    }
+    Ok(value)
+}
+
+fn set(provider: &str, field: &str, project: bool) -> anyhow::Result<()> {
+    let layer = target_layer(project)?;
+    let mut secrets = armadai_secrets::load_layer(&layer)?;
+    let value = read_value(provider, field)?;
+    secrets
+        .providers
+        .entry(provider.to_string())
+        .or_default()
+        .set(field, &value);
+    let path = armadai_secrets::save_layer(&layer, &secrets)?;
+    let o = crate::cli::style::ok();
+    let m = crate::cli::style::muted();
+    anstream::println!(
+        "{o}✓{o:#} {provider}.{field} stored {m}({}){m:#}",
+        path.display()
+    );
+    Ok(())
+}
+
+fn get(provider: &str, field: &str, project: bool) -> anyhow::Result<()> {
+    let secrets = if project {
+        armadai_secrets::load_layer(&target_layer(true)?)?
+    } else {
+        armadai_secrets::load_layered(
+            &armadai_core::config::config_dir(),
+            &armadai_core::config::secrets_overlay_dirs(),
+        )
+    };
+    let value = secrets
+        .providers
+        .get(provider)
+        .and_then(|c| c.get(field))
+        .ok_or_else(|| anyhow::anyhow!("no {field} stored for '{provider}'"))?;
+    println!("{value}");
+    Ok(())
+}
+
+/// `value` with only its ends showing, enough to tell two keys apart.
+fn mask(value: &str) -> String {
+    let chars: Vec<char> = value.chars().collect();
+    if chars.len() < 12 {
+        return "••••".to_string();
+    }
+    let head: String = chars[..4].iter().collect();
+    let tail: String = chars[chars.len() - 4..].iter().collect();
+    format!("{head}…{tail}")
+}
+
+fn list(project: bool) -> anyhow::Result<()> {
+    let config_dir = user_dir();
+    let mut layers: Vec<(&str, ProviderSecrets)> = Vec::new();
+    if !project {
+        layers.push((
+            "user",
+            armadai_secrets::load_layer(&Layer::user(&config_dir))?,
+        ));
+    }
+    match project_dir() {
+        // `ARMADAI_CONFIG_DIR` can point at the project's `.armadai/` itself.
+        Some(dir) if !project && armadai_secrets::same_dir(&dir, &config_dir) => {}
+        Some(dir) => layers.push((
+            "project",
+            armadai_secrets::load_layer(&Layer::project(&dir, &config_dir))?,
+        )),
+        None if project => anyhow::bail!(NO_PROJECT),
+        None => {}
+    }
+
+    let h = crate::cli::style::header();
+    let m = crate::cli::style::muted();
+    let w = crate::cli::style::warn();
+    // What the project layer changes once layered: endpoint fields without
+    // a key beside them do not override the user's.
+    let overrides = layers
+        .iter()
+        .find(|(s, _)| *s == "project")
+        .map(|(_, p)| ProviderSecrets::default().layered(p.clone()))
+        .unwrap_or_default();
+    let mut any = false;
+    for (source, secrets) in &layers {
+        for (provider, creds) in &secrets.providers {
+            anstream::println!("{h}{provider}{h:#} {m}({source}){m:#}");
+            for (field, value) in creds.fields() {
+                // A project field hides the user's one when both are listed.
+                let shadowed = *source == "user"
+                    && overrides
+                        .providers
+                        .get(provider)
+                        .is_some_and(|c| c.get(&field).is_some());
+                if shadowed {
+                    anstream::println!(
+                        "  {field:<12} {}  {w}overridden by project{w:#}",
+                        mask(&value)
+                    );
+                } else {
+                    anstream::println!("  {field:<12} {}", mask(&value));
+                }
+                any = true;
+            }
+        }
+    }
+    if !any {
+        anstream::println!(
+            "{m}No secrets stored. Add one with: armadai secrets set <provider>{m:#}"
+        );
+    }
+    Ok(())
+}
+
+fn remove(provider: &str, field: Option<&str>, project: bool) -> anyhow::Result<()> {
+    let layer = target_layer(project)?;
+    let mut secrets = armadai_secrets::load_layer(&layer)?;
+    let Some(creds) = secrets.providers.get_mut(provider) else {
+        anyhow::bail!("no secrets stored for '{provider}'");
+    };
+    let what = match field {
+        Some(field) => {
+            if !creds.remove(field) {
+                anyhow::bail!("no {field} stored for '{provider}'");
+            }
+            if creds.is_empty() {
+                secrets.providers.remove(provider);
+            }
+            format!("{provider}.{field}")
+        }
+        None => {
+            secrets.providers.remove(provider);
+            provider.to_string()
+        }
+    };
+    armadai_secrets::save_layer(&layer, &secrets)?;
+    let o = crate::cli::style::ok();
+    anstream::println!("{o}✓{o:#} {what} removed");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mask_shows_only_the_ends_of_long_values() {
+        assert_eq!(mask("sk-ant-0123456789abcd"), "sk-a…abcd");
+        assert_eq!(mask("short"), "••••");
+    }
+}
@@ -1,6 +1,7 @@
 use clap::Subcommand;
 
-use crate::skills_registry::{cache, search, sync};
+use crate::search_index::{self, Filter, Kind};
+use crate::skills_registry::{cache, sync};
 use armadai_core::config::user_skills_dir;
 use armadai_core::project;
 use armadai_core::skill::{Skill, load_all_skills};
@@ -18,7 +19,7 @@ pub enum SkillsAction {
     Sync,
     /// Search skills in the registry
     Search {
-        /// Search query (keywords, AND logic)
+        /// Search query (keywords, every one must match; typos tolerated)
         query: String,
     },
     /// Add a skill from a GitHub repo (owner/repo or owner/repo/skill-name)
@@ -197,21 +198,25 @@ async fn cmd_sync() -> anyhow::Result<()> {
     let index = cache::build_index(&sources)?;
     let o = crate::cli::style::ok();
     anstream::println!("{o}Indexed {} skill(s).{o:#}", index.entries.len());
+    search_index::rebuild_or_warn();
     Ok(())
 }
 
 async fn cmd_search(query: &str) -> anyhow::Result<()> {
     check_staleness();
-    let sources = cache::effective_sources();
-    let index = cache::load_or_build_index(&sources)?;
+    let index = search_index::load_or_rebuild()?;
 
-    if index.entries.is_empty() {
+    if !index.docs.iter().any(|d| d.kind == Kind::Skill) {
         let m = crate::cli::style::muted();
         anstream::println!("{m}No skills indexed. Run `armadai skills sync` first.{m:#}");
         return Ok(());
     }
 
-    let results = search::search(&index.entries, query);
+    let filter = Filter {
+        kind: Some(Kind::Skill),
+        ..Default::default()
+    };
+    let results = index.search(query, &filter);
 
     if results.is_empty() {
         let m = crate::cli::style::muted();
@@ -222,13 +227,13 @@ async fn cmd_search(query: &str) -> anyhow::Result<()> {
     // Compute column widths
     let name_w = results
         .iter()
-        .map(|r| r.entry.name.len())
+        .map(|r| r.doc.name.len())
         .max()
         .unwrap_or(4)
         .max(4);
     let repo_w = results
         .iter()
-        .map(|r| r.entry.source_repo.len())
+        .map(|r| r.doc.source.len())
         .max()
         .unwrap_or(6)
         .max(6);
@@ -249,7 +254,7 @@ async fn cmd_search(query: &str) -> anyhow::Result<()> {
     );
 
     for r in &results {
-        let desc = r.entry.description.as_deref().unwrap_or("-");
+        let desc = r.doc.description.as_deref().unwrap_or("-");
         let desc_display = if desc.len() > 50 {
             format!("{}...", &desc[..47])
         } else {
@@ -257,10 +262,10 @@ async fn cmd_search(query: &str) -> anyhow::Result<()> {
         };
         let a = crate::cli::style::accent();
         anstream::println!(
-            "  {a}{:<name_w$}{a:#}  {:>5}  {:<repo_w$}  {}",
-            r.entry.name,
+            "  {a}{:<name_w$}{a:#}  {:>5.1}  {:<repo_w$}  {}",
+            r.doc.name,
             r.score,
-            r.entry.source_repo,
+            r.doc.source,
             desc_display,
         );
     }
@@ -343,6 +348,16 @@ async fn cmd_add(source: &str, force: bool) -> anyhow::Result<()> {
 
     // Copy entire skill directory
     copy_dir_recursive(&skill_dir, &dest)?;
+    match crate::lockfile::pin(
+        crate::lockfile::AssetKind::Skill,
+        &skill.name,
+        &format!("https://github.com/{repo_slug}"),
+        &repo_path,
+        &skill_dir,
+    ) {
+        Ok(asset) => crate::lockfile::record_or_warn(asset),
+        Err(e) => tracing::warn!("not pinned in {}: {e}", crate::lockfile::LOCK_FILE),
+    }
 
     let o = crate::cli::style::ok();
     let a = crate::cli::style::accent();
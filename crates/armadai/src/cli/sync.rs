@@ -0,0 +1,221 @@
+//! `armadai sync`, `outdated` and `upgrade`: reproduce and move the assets
+//! pinned in `armadai.lock` (see `crate::lockfile`).
+
+use std::io::IsTerminal;
+use std::path::Path;
+
+use crate::lockfile::{self, LockedAsset, Lockfile, Staging};
+
+/// Install every locked asset at its locked revision. Without `locked`,
+/// assets already in the library are left alone; with it, every asset is
+/// overwritten with its exact locked content, and any asset whose content
+/// no longer matches its hash fails the command.
+pub async fn execute_sync(locked: bool) -> anyhow::Result<()> {
+    let path = Lockfile::path();
+    let lock = Lockfile::load(&path)?;
+    let m = crate::cli::style::muted();
+    if lock.assets.is_empty() {
+        anstream::println!("{m}Nothing to sync: no assets in {}.{m:#}", path.display());
+        return Ok(());
+    }
+
+    let o = crate::cli::style::ok();
+    let e = crate::cli::style::err();
+    let mut failed = 0;
+    for asset in &lock.assets {
+        let label = format!("{} {}", asset.kind.label(), asset.name);
+        if !locked && lockfile::installed_path(asset).is_some_and(|p| p.exists()) {
+            anstream::println!("  {m}{label}: installed{m:#}");
+            continue;
+        }
+        match sync_one(asset, locked) {
+            Ok(()) => anstream::println!(
+                "  {o}{label}{o:#} {m}@ {}{m:#}",
+                asset
+                    .revision
+                    .as_deref()
+                    .map(lockfile::short)
+                    .unwrap_or("archive")
+            ),
+            Err(err) => {
+                failed += 1;
+                anstream::println!("  {e}{label}: {err}{e:#}");
+            }
+        }
+    }
+    if failed > 0 {
+        anyhow::bail!("{failed} asset(s) could not be installed as locked");
+    }
+    Ok(())
+}
+
+fn sync_one(asset: &LockedAsset, force: bool) -> anyhow::Result<()> {
+    let staging = Staging::new()?;
+    let content = lockfile::locked_content(asset, staging.path())?;
+    lockfile::install(asset, &content, force)
+}
+
+/// A locked asset whose upstream content changed.
+struct Outdated {
+    index: usize,
+    upstream: String,
+    /// Keeps the upstream content alive until the upgrade is applied.
+    _staging: Staging,
+    /// Upstream content, under the staging directory.
+    content: std::path::PathBuf,
+}
+
+/// Fetch each git-sourced asset's upstream and keep those whose content
+/// changed. Archive sources have no revision to compare and are skipped.
+fn find_outdated(lock: &Lockfile, names: &[String]) -> anyhow::Result<Vec<Outdated>> {
+    let mut out = Vec::new();
+    for (index, asset) in lock.assets.iter().enumerate() {
+        if !names.is_empty() && !names.contains(&asset.name) {
+            continue;
+        }
+        let Some(rev) = &asset.revision else {
+            continue;
+        };
+        let repo = lockfile::ensure_source(asset)?;
+        let upstream = lockfile::fetch_upstream(&repo)?;
+        if &upstream == rev {
+            continue;
+        }
+        let staging = Staging::new()?;
+        let content = lockfile::materialize(&repo, &upstream, &asset.path, staging.path())?;
+        if lockfile::integrity(&content)? != asset.integrity {
+            out.push(Outdated {
+                index,
+                upstream,
+                _staging: staging,
+                content,
+            });
+        }
+    }
+    Ok(out)
+}
+
+pub async fn execute_outdated() -> anyhow::Result<()> {
+    let lock = Lockfile::load(&Lockfile::path())?;
+    let outdated = find_outdated(&lock, &[])?;
+    let m = crate::cli::style::muted();
+    if outdated.is_empty() {
+        anstream::println!(
+            "{m}All {} locked asset(s) are up to date.{m:#}",
+            lock.assets.len()
+        );
+        return Ok(());
+    }
+    let h = crate::cli::style::header();
+    anstream::println!("{h}  KIND     NAME                      LOCKED        UPSTREAM{h:#}");
+    for o in &outdated {
+        let asset = &lock.assets[o.index];
+        let a = crate::cli::style::accent();
+        anstream::println!(
+            "  {:<8} {a}{:<25}{a:#} {:<13} {}",
+            asset.kind.label(),
+            asset.name,
+            asset
+                .revision
+                .as_deref()
+                .map(lockfile::short)
+                .unwrap_or("-"),
+            lockfile::short(&o.upstream)
+        );
+    }
+    anstream::println!("\n{m}Run `armadai upgrade` to see the changes and apply them.{m:#}");
+    Ok(())
+}
+
+pub async fn execute_upgrade(names: Vec<String>, yes: bool) -> anyhow::Result<()> {
+    let path = Lockfile::path();
+    let mut lock = Lockfile::load(&path)?;
+    let outdated = find_outdated(&lock, &names)?;
+    let m = crate::cli::style::muted();
+    if outdated.is_empty() {
+        anstream::println!("{m}Nothing to upgrade.{m:#}");
+        return Ok(());
+    }
+
+    for o in &outdated {
+        let asset = &lock.assets[o.index];
+        let h = crate::cli::style::header();
+        anstream::println!(
+            "{h}{} {}{h:#} {m}{} -> {}{m:#}",
+            asset.kind.label(),
+            asset.name,
+            asset
+                .revision
+                .as_deref()
+                .map(lockfile::short)
+                .unwrap_or("-"),
+            lockfile::short(&o.upstream)
+        );
+        let staging = Staging::new()?;
+        let before = lockfile::locked_content(asset, staging.path())?;
+        print_changes(&before, &o.content)?;
+    }
+
+    let apply = if yes {
+        true
+    } else if std::io::stdin().is_terminal() {
+        dialoguer::Confirm::new()
+            .with_prompt(format!("Upgrade {} asset(s)?", outdated.len()))
+            .default(false)
+            .interact()?
+    } else {
+        anstream::println!("\n{m}Re-run with --yes to apply.{m:#}");
+        false
+    };
+    if !apply {
+        return Ok(());
+    }
+
+    let o_style = crate::cli::style::ok();
+    for o in &outdated {
+        let asset = &mut lock.assets[o.index];
+        lockfile::install(asset, &o.content, true)?;
+        asset.revision = Some(o.upstream.clone());
+        asset.integrity = lockfile::integrity(&o.content)?;
+        anstream::println!(
+            "{o_style}Upgraded{o_style:#} {} {} to {}",
+            asset.kind.label(),
+            asset.name,
+            lockfile::short(&o.upstream)
+        );
+    }
+    lock.save(&path)?;
+    Ok(())
+}
+
+/// Line diff of every file that differs between two versions of an asset
+/// (a file, or a directory compared file by file).
+fn print_changes(before: &Path, after: &Path) -> anyhow::Result<()> {
+    let files = |p: &Path| -> anyhow::Result<Vec<(String, std::path::PathBuf)>> {
+        if p.is_file() {
+            let name = p
+                .file_name()
+                .unwrap_or_default()
+                .to_string_lossy()
+                .into_owned();
+            Ok(vec![(name, p.to_path_buf())])
+        } else {
+            lockfile::files_under(p)
+        }
+    };
+    let old: std::collections::BTreeMap<_, _> = files(before)?.into_iter().collect();
+    let new: std::collections::BTreeMap<_, _> = files(after)?.into_iter().collect();
+    let read = |p: Option<&std::path::PathBuf>| {
+        p.and_then(|p| std::fs::read(p).ok())
+            .map(|b| String::from_utf8_lossy(&b).into_owned())
+            .unwrap_or_default()
+    };
+    let names: std::collections::BTreeSet<&String> = old.keys().chain(new.keys()).collect();
+    for name in names {
+        let (a, b) = (read(old.get(name)), read(new.get(name)));
+        if a != b {
+            super::import::print_diff(name, &a, &b);
+        }
+    }
+    Ok(())
+}
@@ -74,7 +74,7 @@ pub async fn execute(
         .ok_or_else(|| {
             anyhow::anyhow!(
                 "No link target specified. Use --target or set link.target in armadai.yaml.\n\
-                 Supported targets: claude, copilot, gemini, opencode"
+                 Supported targets: claude, codex, copilot, cursor, gemini, opencode"
             )
         })?;
 
@@ -139,6 +139,18 @@ pub async fn execute(
         }
     }
 
+    // 8d. Include files a previous link recorded that the config no longer
+    // generates (orphans), unless only some agents are being unlinked
+    let mut manifest = linker::manifest::LinkManifest::load(&root)?;
+    if agents_filter.is_none() {
+        for entry in manifest.files.iter().filter(|e| e.target == target_name) {
+            let path = root.join(&entry.path);
+            if !targets.contains(&path) {
+                targets.push(path);
+            }
+        }
+    }
+
     // 9. Optionally include the project config file itself
     if with_config {
         // Detect which config file is active
@@ -196,6 +208,14 @@ pub async fn execute(
         }
     }
 
+    // 11b. Forget the removed files in the link manifest
+    if !manifest.files.is_empty() {
+        for path in &targets {
+            manifest.forget(&linker::manifest::relative_key(&root, path));
+        }
+        manifest.save(&root)?;
+    }
+
     // 12. Clean up empty ancestor directories
     let stop_at = &root;
     for path in &targets {
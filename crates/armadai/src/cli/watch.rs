@@ -1,27 +1,35 @@
-use crate::claude_adapter::{drive_session, session_index};
+use crate::claude_adapter::session_index;
+use crate::session_adapter;
 
 /// Minimal synthetic project config so the Workroom seeds the transcript's
-/// root agent ("claude") as the Coordinator — the delegated subagents (added
-/// dynamically as role Agent, see Workroom::ensure_agent) then indent beneath
-/// it in the hierarchical tree. A watched Claude Code session has no
-/// armadai.yaml, so we synthesize the minimum init_from_config needs.
-const WATCH_ROOT_CONFIG: &str = "coordinator: claude\n";
+/// root agent (`claude`, `gemini` or `codex`) as the Coordinator — the
+/// delegated subagents (added dynamically as role Agent, see
+/// Workroom::ensure_agent) then indent beneath it in the hierarchical tree. A
+/// watched session has no armadai.yaml, so we synthesize the minimum
+/// init_from_config needs.
+fn watch_root_config(root: &str) -> String {
+    format!("coordinator: {root}\n")
+}
 
-/// `armadai watch` — attach the Workroom to a Claude Code session (from the
-/// index the plugin populates) and stream reconstructed RunEvents.
+/// `armadai watch` — attach the Workroom to a session of `source` (Claude
+/// Code from the index the plugin populates, Gemini CLI or Codex from their
+/// own session files) and stream reconstructed RunEvents.
 ///
 /// `_last` (`--last`) is accepted for CLI compatibility/documentation but no
 /// longer changes resolution: an explicit `--session <id>` always wins (it
 /// used to be silently overridden by `--last`, see M1) and, when no
 /// `--session` is given, the most-recent session is picked regardless —
 /// which was already the default with no flags at all.
-pub async fn execute(_last: bool, session: Option<String>, json: bool) -> anyhow::Result<()> {
-    let sessions = session_index::load()?;
+pub async fn execute(
+    _last: bool,
+    session: Option<String>,
+    json: bool,
+    source: &str,
+) -> anyhow::Result<()> {
+    let adapter = session_adapter::adapter(source)?;
+    let sessions = adapter.sessions()?;
     if sessions.is_empty() {
-        anyhow::bail!(
-            "no Claude Code sessions registered — install the armadai-workroom plugin \
-             (see crates/armadai/assets/claude-plugin) and start a Claude Code session"
-        );
+        anyhow::bail!("{}", adapter.no_sessions_hint());
     }
     // Explicit `--session <id>` always wins over `--last`. Only fall back to
     // "most recent" when no session id was given at all.
@@ -31,17 +39,20 @@ pub async fn execute(_last: bool, session: Option<String>, json: bool) -> anyhow
         session_index::resolve(&sessions, true, None)
     }
     .ok_or_else(|| anyhow::anyhow!("no matching session (use --last or --session <id>)"))?;
+    let tail = adapter.tail(&picked);
 
     if json {
         // Headless: replay to JSONL on stdout (no TUI).
         let sink = armadai_core::events::make_sink(true);
-        return drive_session(picked, sink, false).await;
+        return session_adapter::drive(&picked, tail, sink, false, session_adapter::POLLING).await;
     }
 
     // Live Workroom TUI, fed by the transcript adapter. `follow=true` tails.
     let (_run_id, _content) = crate::shell::run_view::run_orchestration_tui(
-        move |sink| async move { drive_session(picked, sink, true).await },
-        Some(WATCH_ROOT_CONFIG.to_string()),
+        move |sink| async move {
+            session_adapter::drive(&picked, tail, sink, true, session_adapter::POLLING).await
+        },
+        Some(watch_root_config(adapter.name())),
         None,
     )
     .await?;
@@ -56,7 +67,7 @@ mod tests {
     fn root_agent_is_seeded_as_coordinator() {
         use crate::shell::workroom::{AgentRole, Workroom};
         let mut wr = Workroom::new();
-        wr.init_from_config(WATCH_ROOT_CONFIG);
+        wr.init_from_config(&watch_root_config("claude"));
         let claude = wr
             .agents_for_test()
             .iter()
@@ -120,7 +131,7 @@ mod tests {
         )
         .unwrap();
         // json=true → no TUI; must resolve --last and complete without error.
-        let r = execute(true, None, true).await;
+        let r = execute(true, None, true, "claude").await;
         assert!(r.is_ok());
     }
 
@@ -129,7 +140,7 @@ mod tests {
         let dir = tempfile::tempdir().unwrap();
         let idx = dir.path().join("empty.jsonl");
         let _env = SessionIndexEnvGuard::set(&idx);
-        let r = execute(false, Some("does-not-exist".into()), true).await;
+        let r = execute(false, Some("does-not-exist".into()), true, "claude").await;
         assert!(r.is_err());
     }
 
@@ -151,7 +162,7 @@ mod tests {
             },
         )
         .unwrap();
-        let r = execute(false, Some("zzz".into()), true).await;
+        let r = execute(false, Some("zzz".into()), true, "claude").await;
         assert!(
             r.is_err(),
             "a non-empty index with an unmatched --session id must still error"
@@ -189,7 +200,7 @@ mod tests {
         // Before the fix: last=true short-circuits resolve() and silently returns
         // "b", so this would be Ok. After the fix: the explicit (unmatched)
         // --session must win and this must error.
-        let r = execute(true, Some("does-not-exist".into()), true).await;
+        let r = execute(true, Some("does-not-exist".into()), true, "claude").await;
         assert!(
             r.is_err(),
             "--session must take precedence over --last, even to the point of \
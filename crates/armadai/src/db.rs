@@ -49,7 +49,50 @@ pub fn init_db() -> anyhow::Result<Database> {
         );
     }
 
-    armadai_storage::open(&path)
+    let db = armadai_storage::open(&path)?;
+    apply_retention(&db, &config.storage.retention);
+    Ok(db)
+}
+
+/// Parse a retention age like `90d`, `12w`, `48h` (or `30m`) into seconds.
+pub fn parse_age(s: &str) -> anyhow::Result<u64> {
+    let s = s.trim();
+    let split = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+    let (digits, unit) = s.split_at(split);
+    let n: u64 = digits
+        .parse()
+        .map_err(|_| anyhow::anyhow!("invalid age '{s}': expected e.g. 90d, 12w, 48h"))?;
+    let unit_secs = match unit {
+        "m" => 60,
+        "h" => 3_600,
+        "d" => 86_400,
+        "w" => 7 * 86_400,
+        _ => anyhow::bail!("invalid age '{s}': unit must be one of m, h, d, w"),
+    };
+    n.checked_mul(unit_secs)
+        .ok_or_else(|| anyhow::anyhow!("invalid age '{s}': too large"))
+}
+
+/// Apply `storage.retention` once per process. Best-effort: a bad
+/// `older_than` or a failed prune is logged, never fatal to the command.
+fn apply_retention(db: &Database, retention: &armadai_core::config::RetentionConfig) {
+    let Some(older_than) = retention.older_than.as_deref() else {
+        return;
+    };
+    static APPLIED: std::sync::Once = std::sync::Once::new();
+    APPLIED.call_once(|| {
+        let result = parse_age(older_than).and_then(|secs| {
+            armadai_storage::maintenance::prune_older_than(db, secs, retention.keep_costs)
+        });
+        match result {
+            Ok(report) if report.runs > 0 => tracing::info!(
+                "storage.retention: pruned {} run(s) older than {older_than}",
+                report.runs
+            ),
+            Ok(_) => {}
+            Err(e) => tracing::warn!("storage.retention ({older_than}) not applied: {e:#}"),
+        }
+    });
 }
 
 #[cfg(test)]
@@ -73,4 +116,17 @@ mod tests {
         assert!(resolved.starts_with(armadai_core::config::data_dir()));
         assert!(resolved.ends_with("data/armadai.sqlite"));
     }
+
+    #[test]
+    fn parse_age_units() {
+        assert_eq!(parse_age("90d").unwrap(), 90 * 86_400);
+        assert_eq!(parse_age("2w").unwrap(), 14 * 86_400);
+        assert_eq!(parse_age("48h").unwrap(), 48 * 3_600);
+        assert_eq!(parse_age(" 30m ").unwrap(), 1_800);
+        assert!(parse_age("90").is_err());
+        assert!(parse_age("d").is_err());
+        assert!(parse_age("3y").is_err());
+        assert!(parse_age("99999999999999999999d").is_err());
+        assert!(parse_age("18446744073709551615w").is_err());
+    }
 }
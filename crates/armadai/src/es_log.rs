@@ -5,10 +5,16 @@
 //! depends on `rusqlite` and `armadai_storage::Database` — `core` only owns
 //! the storage-agnostic `EventLog` trait and the always-on `InMemoryLog`
 //! (see `armadai_core::orchestration::es::log`).
+//!
+//! Content is redacted (`crate::redact`) before it is written: an event that
+//! carried a secret is stored rewritten and followed by a
+//! `Warned { code: "secret_redacted" }` event.
 
 use armadai_core::orchestration::es::event::ExecutionEvent;
 use armadai_core::orchestration::es::log::EventLog;
 
+use crate::redact::{Redactor, SECRET_REDACTED};
+
 /// Extract the internal serde tag (`t`, e.g. `"run_started"`) from an
 /// `ExecutionEvent`'s serialized form, used as the `kind` column value.
 fn event_kind(event: &ExecutionEvent) -> anyhow::Result<String> {
@@ -24,17 +30,22 @@ fn event_kind(event: &ExecutionEvent) -> anyhow::Result<String> {
 /// (schema v3).
 pub struct SqliteLog {
     db: armadai_storage::Database,
+    redactor: &'static Redactor,
 }
 
 impl SqliteLog {
-    /// Wrap an existing storage handle.
+    /// Wrap an existing storage handle, redacting with the configured
+    /// [`Redactor::global`].
     pub fn new(db: armadai_storage::Database) -> Self {
-        Self { db }
+        Self::with_redactor(db, Redactor::global())
     }
-}
 
-impl EventLog for SqliteLog {
-    fn append(&mut self, run_id: &str, event: &ExecutionEvent) -> anyhow::Result<()> {
+    /// Wrap `db`, redacting with `redactor`; tests pass [`Redactor::off`].
+    pub fn with_redactor(db: armadai_storage::Database, redactor: &'static Redactor) -> Self {
+        Self { db, redactor }
+    }
+
+    fn insert(&self, run_id: &str, event: &ExecutionEvent) -> anyhow::Result<()> {
         let kind = event_kind(event)?;
         let payload_json = serde_json::to_string(event)?;
         let conn = self
@@ -52,6 +63,23 @@ impl EventLog for SqliteLog {
         )?;
         Ok(())
     }
+}
+
+impl EventLog for SqliteLog {
+    fn append(&mut self, run_id: &str, event: &ExecutionEvent) -> anyhow::Result<()> {
+        match self.redactor.redact_event(event) {
+            Some(redacted) => {
+                self.insert(run_id, &redacted)?;
+                self.insert(
+                    run_id,
+                    &ExecutionEvent::Warned {
+                        code: SECRET_REDACTED.to_string(),
+                    },
+                )
+            }
+            None => self.insert(run_id, event),
+        }
+    }
 
     fn events(&self, run_id: &str) -> anyhow::Result<Vec<ExecutionEvent>> {
         let conn = self
@@ -102,7 +130,7 @@ mod tests {
     #[test]
     fn sqlite_log_roundtrip() {
         let db = armadai_storage::open_in_memory().unwrap();
-        let mut log = SqliteLog::new(db);
+        let mut log = SqliteLog::with_redactor(db, Redactor::off());
         for e in sample() {
             log.append("r1", &e).unwrap();
         }
@@ -116,6 +144,27 @@ mod tests {
         assert!(matches!(got[2], E::Completed { .. }));
     }
 
+    #[test]
+    fn sqlite_log_stores_secrets_redacted_with_a_warning() {
+        static REDACTOR: std::sync::LazyLock<Redactor> = std::sync::LazyLock::new(|| {
+            Redactor::new(
+                armadai_core::config::RedactionMode::Mask,
+                ["tok-known-123456".to_string()],
+            )
+        });
+        let db = armadai_storage::open_in_memory().unwrap();
+        let mut log = SqliteLog::with_redactor(db, &REDACTOR);
+        log.append("r1", &marker("uses tok-known-123456 here", 0))
+            .unwrap();
+        log.append("r1", &marker("clean", 1)).unwrap();
+
+        let got = log.events("r1").unwrap();
+        assert_eq!(got.len(), 3);
+        assert_eq!(observed_content(&got[0]), "uses [REDACTED] here-0");
+        assert!(matches!(&got[1], E::Warned { code } if code == SECRET_REDACTED));
+        assert_eq!(observed_content(&got[2]), "clean-1");
+    }
+
     /// Extract the `content` field of an `AgentObserved` event, panicking on
     /// any other variant. Used to check per-event identity/order in the
     /// multi-`run_id` isolation test below.
@@ -151,7 +200,7 @@ mod tests {
     #[test]
     fn sqlite_multi_run_id_seq_isolation() {
         let db = armadai_storage::open_in_memory().unwrap();
-        let mut log = SqliteLog::new(db);
+        let mut log = SqliteLog::with_redactor(db, Redactor::off());
         let run_ids = ["rA", "rB", "rA", "rB", "rA"];
         for (idx, run_id) in run_ids.iter().enumerate() {
             log.append(run_id, &marker(run_id, idx)).unwrap();
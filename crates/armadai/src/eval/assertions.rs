@@ -0,0 +1,277 @@
+//! Suite assertions and the JSON-schema subset they validate against.
+
+use serde::Deserialize;
+use serde_json::Value;
+
+use super::{Case, RunOutput};
+
+/// One check on a case's output. Written in YAML as a single-key map, e.g.
+/// `- contains: "## Summary"`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(try_from = "RawAssertion")]
+pub enum Assertion {
+    Contains(String),
+    NotContains(String),
+    Regex(String),
+    /// Output must parse as JSON (optionally inside a ```json fence) and
+    /// match the schema. Supports `type`, `properties`, `required`, `items`
+    /// and `enum`.
+    JsonSchema(Value),
+    /// Rubric graded by the judge agent, which must answer PASS or FAIL on
+    /// its first line.
+    Judge(String),
+    /// Upper bound on the case run's cost in USD.
+    MaxCost(f64),
+}
+
+/// YAML shape of an assertion: exactly one of these keys is set.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawAssertion {
+    contains: Option<String>,
+    not_contains: Option<String>,
+    regex: Option<String>,
+    json_schema: Option<Value>,
+    judge: Option<String>,
+    max_cost: Option<f64>,
+}
+
+impl TryFrom<RawAssertion> for Assertion {
+    type Error = String;
+
+    fn try_from(raw: RawAssertion) -> Result<Self, Self::Error> {
+        let mut set = [
+            raw.contains.map(Assertion::Contains),
+            raw.not_contains.map(Assertion::NotContains),
+            raw.regex.map(Assertion::Regex),
+            raw.json_schema.map(Assertion::JsonSchema),
+            raw.judge.map(Assertion::Judge),
+            raw.max_cost.map(Assertion::MaxCost),
+        ]
+        .into_iter()
+        .flatten();
+        match (set.next(), set.next()) {
+            (Some(a), None) => Ok(a),
+            (None, _) => Err("empty assertion".to_string()),
+            (Some(_), Some(_)) => {
+                Err("each assertion must have exactly one key; use separate list items".to_string())
+            }
+        }
+    }
+}
+
+impl Assertion {
+    /// Reject assertions that can never be evaluated, at load time.
+    pub fn validate(&self) -> anyhow::Result<()> {
+        match self {
+            Assertion::Regex(re) => {
+                regex::Regex::new(re).map_err(|e| anyhow::anyhow!("Invalid regex '{re}': {e}"))?;
+            }
+            Assertion::JsonSchema(schema) if !schema.is_object() => {
+                anyhow::bail!("json_schema must be a mapping");
+            }
+            Assertion::MaxCost(c) if *c < 0.0 => anyhow::bail!("max_cost must be >= 0"),
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Check the assertion against a run's output. `Judge` needs a second
+    /// run and is handled by the runner; here it always fails.
+    pub fn check(&self, out: &RunOutput) -> Result<(), String> {
+        match self {
+            Assertion::Contains(s) if out.content.contains(s.as_str()) => Ok(()),
+            Assertion::Contains(s) => Err(format!("output does not contain {s:?}")),
+            Assertion::NotContains(s) if !out.content.contains(s.as_str()) => Ok(()),
+            Assertion::NotContains(s) => Err(format!("output contains {s:?}")),
+            Assertion::Regex(re) => match regex::Regex::new(re) {
+                Ok(r) if r.is_match(&out.content) => Ok(()),
+                Ok(_) => Err(format!("output does not match /{re}/")),
+                Err(e) => Err(format!("invalid regex /{re}/: {e}")),
+            },
+            Assertion::JsonSchema(schema) => {
+                let json: Value = serde_json::from_str(strip_fence(&out.content))
+                    .map_err(|e| format!("output is not valid JSON: {e}"))?;
+                validate_schema(schema, &json, "$")
+            }
+            Assertion::Judge(_) => Err("judge assertions need a judge run".to_string()),
+            Assertion::MaxCost(max) if out.cost <= *max => Ok(()),
+            Assertion::MaxCost(max) => Err(format!("cost ${:.4} exceeds ${max:.4}", out.cost)),
+        }
+    }
+}
+
+/// Prompt sent to the judge agent for a rubric.
+pub fn judge_prompt(rubric: &str, case: &Case, out: &RunOutput) -> String {
+    format!(
+        "You are grading an AI agent's answer against a rubric.\n\
+         Reply with PASS or FAIL alone on the first line, then one sentence explaining why.\n\n\
+         ## Rubric\n{rubric}\n\n## Input\n{}\n\n## Answer\n{}\n",
+        case.input, out.content
+    )
+}
+
+/// Interpret the judge's reply.
+pub fn check_verdict(verdict: &RunOutput) -> Result<(), String> {
+    if let Some(err) = &verdict.error {
+        return Err(format!("judge run failed: {err}"));
+    }
+    let mut lines = verdict.content.lines().filter(|l| !l.trim().is_empty());
+    let first = lines.next().unwrap_or_default().trim();
+    let reason = lines.next().unwrap_or_default().trim();
+    let word = first
+        .trim_matches(|c: char| !c.is_ascii_alphabetic())
+        .to_ascii_uppercase();
+    if word.starts_with("PASS") {
+        Ok(())
+    } else if word.starts_with("FAIL") {
+        Err(format!(
+            "judge: {}",
+            if reason.is_empty() { first } else { reason }
+        ))
+    } else {
+        Err(format!("judge gave no PASS/FAIL verdict: {first:?}"))
+    }
+}
+
+/// The JSON inside a single ```/```json fence, or the whole text.
+fn strip_fence(text: &str) -> &str {
+    let t = text.trim();
+    let Some(rest) = t.strip_prefix("```") else {
+        return t;
+    };
+    let body = rest.split_once('\n').map(|(_, b)| b).unwrap_or_default();
+    body.strip_suffix("```").unwrap_or(body).trim()
+}
+
+fn validate_schema(schema: &Value, value: &Value, path: &str) -> Result<(), String> {
+    if let Some(ty) = schema.get("type").and_then(Value::as_str) {
+        let ok = match ty {
+            "object" => value.is_object(),
+            "array" => value.is_array(),
+            "string" => value.is_string(),
+            "number" => value.is_number(),
+            "integer" => value.is_i64() || value.is_u64(),
+            "boolean" => value.is_boolean(),
+            "null" => value.is_null(),
+            other => return Err(format!("{path}: unsupported schema type {other:?}")),
+        };
+        if !ok {
+            return Err(format!("{path}: expected {ty}"));
+        }
+    }
+    if let Some(allowed) = schema.get("enum").and_then(Value::as_array)
+        && !allowed.contains(value)
+    {
+        return Err(format!(
+            "{path}: {value} is not one of {}",
+            Value::from(allowed.clone())
+        ));
+    }
+    if let Some(obj) = value.as_object() {
+        for key in schema
+            .get("required")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_str)
+        {
+            if !obj.contains_key(key) {
+                return Err(format!("{path}: missing required property {key:?}"));
+            }
+        }
+        if let Some(props) = schema.get("properties").and_then(Value::as_object) {
+            for (key, sub) in props {
+                if let Some(v) = obj.get(key) {
+                    validate_schema(sub, v, &format!("{path}.{key}"))?;
+                }
+            }
+        }
+    }
+    if let (Some(items), Some(arr)) = (schema.get("items"), value.as_array()) {
+        for (i, v) in arr.iter().enumerate() {
+            validate_schema(items, v, &format!("{path}[{i}]"))?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn out(content: &str, cost: f64) -> RunOutput {
+        RunOutput {
+            content: content.to_string(),
+            cost,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn assertions_parse_from_single_key_maps() {
+        let parsed: Vec<Assertion> = serde_yaml_ng::from_str(
+            "- contains: a\n- not_contains: b\n- regex: '^x'\n\
+             - json_schema: {type: object}\n- judge: concise\n- max_cost: 0.1\n",
+        )
+        .unwrap();
+        assert_eq!(parsed[0], Assertion::Contains("a".into()));
+        assert_eq!(parsed[5], Assertion::MaxCost(0.1));
+        assert!(matches!(parsed[3], Assertion::JsonSchema(_)));
+        assert!(Assertion::Regex("(".into()).validate().is_err());
+        assert!(serde_yaml_ng::from_str::<Assertion>("{contains: a, regex: b}").is_err());
+        assert!(serde_yaml_ng::from_str::<Assertion>("{equals: a}").is_err());
+    }
+
+    #[test]
+    fn text_and_cost_assertions() {
+        let o = out("## Summary\n- one", 0.02);
+        assert!(Assertion::Contains("Summary".into()).check(&o).is_ok());
+        assert!(Assertion::NotContains("Summary".into()).check(&o).is_err());
+        assert!(Assertion::Regex("(?m)^- ".into()).check(&o).is_ok());
+        assert!(Assertion::MaxCost(0.05).check(&o).is_ok());
+        let err = Assertion::MaxCost(0.01).check(&o).unwrap_err();
+        assert!(err.contains("exceeds"), "{err}");
+    }
+
+    #[test]
+    fn json_schema_subset() {
+        let schema: Value = serde_json::json!({
+            "type": "object",
+            "required": ["title", "tags"],
+            "properties": {
+                "title": {"type": "string"},
+                "tags": {"type": "array", "items": {"enum": ["a", "b"]}},
+            }
+        });
+        let check = |content: &str| Assertion::JsonSchema(schema.clone()).check(&out(content, 0.0));
+        assert!(check("```json\n{\"title\":\"t\",\"tags\":[\"a\"]}\n```").is_ok());
+        assert!(check("{\"title\":\"t\"}").unwrap_err().contains("tags"));
+        assert!(
+            check("{\"title\":1,\"tags\":[]}")
+                .unwrap_err()
+                .contains("$.title")
+        );
+        assert!(
+            check("{\"title\":\"t\",\"tags\":[\"c\"]}")
+                .unwrap_err()
+                .contains("$.tags[0]")
+        );
+        assert!(check("not json").unwrap_err().contains("not valid JSON"));
+    }
+
+    #[test]
+    fn judge_verdicts() {
+        assert!(check_verdict(&out("PASS\nfine", 0.0)).is_ok());
+        assert!(check_verdict(&out("**Pass**", 0.0)).is_ok());
+        assert_eq!(
+            check_verdict(&out("FAIL\nmisses the second change", 0.0)).unwrap_err(),
+            "judge: misses the second change"
+        );
+        assert!(
+            check_verdict(&out("maybe", 0.0))
+                .unwrap_err()
+                .contains("no PASS/FAIL")
+        );
+    }
+}
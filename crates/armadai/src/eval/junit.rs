@@ -0,0 +1,82 @@
+//! JUnit XML report for CI systems.
+
+use super::CaseResult;
+
+/// Render `results` as a single-suite JUnit XML document.
+pub fn render(suite: &str, results: &[CaseResult]) -> String {
+    let failures = results.iter().filter(|r| !r.passed()).count();
+    let total_secs: f64 = results.iter().map(|r| r.duration_ms as f64 / 1000.0).sum();
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    xml.push_str(&format!(
+        "<testsuites>\n  <testsuite name=\"{}\" tests=\"{}\" failures=\"{failures}\" errors=\"0\" time=\"{total_secs:.3}\">\n",
+        escape(suite),
+        results.len()
+    ));
+    for r in results {
+        xml.push_str(&format!(
+            "    <testcase name=\"{}\" classname=\"{}\" time=\"{:.3}\"",
+            escape(&r.name),
+            escape(suite),
+            r.duration_ms as f64 / 1000.0
+        ));
+        if r.passed() {
+            xml.push_str("/>\n");
+            continue;
+        }
+        xml.push_str(">\n");
+        xml.push_str(&format!(
+            "      <failure message=\"{}\">{}</failure>\n",
+            escape(&r.failures[0]),
+            escape(&r.failures.join("\n"))
+        ));
+        xml.push_str("    </testcase>\n");
+    }
+    xml.push_str("  </testsuite>\n</testsuites>\n");
+    xml
+}
+
+fn escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            // Control characters other than tab/newline are invalid in XML 1.0.
+            c if c.is_control() && c != '\n' && c != '\t' && c != '\r' => {}
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eval::RunOutput;
+
+    #[test]
+    fn renders_passes_and_escaped_failures() {
+        let case = |name: &str, failures: Vec<String>| CaseResult {
+            name: name.to_string(),
+            output: RunOutput::default(),
+            cost: 0.0,
+            duration_ms: 1500,
+            score: 0.0,
+            failures,
+        };
+        let xml = render(
+            "smoke",
+            &[
+                case("ok", vec![]),
+                case("bad <one>", vec!["output does not contain \"a&b\"".into()]),
+            ],
+        );
+        assert!(xml.contains("tests=\"2\" failures=\"1\""));
+        assert!(xml.contains("<testcase name=\"ok\" classname=\"smoke\" time=\"1.500\"/>"));
+        assert!(xml.contains("name=\"bad &lt;one&gt;\""));
+        assert!(xml.contains("message=\"output does not contain &quot;a&amp;b&quot;\""));
+    }
+}
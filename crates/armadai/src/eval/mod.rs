@@ -0,0 +1,434 @@
+//! Evaluation harness — golden test suites for agents.
+//!
+//! A suite is a YAML file listing inputs for an agent plus assertions on its
+//! output. Each case is executed through `armadai run --json --headless` in a
+//! child process, so it goes through exactly the same provider, history and
+//! event-log path as a normal run. With `fake:` set, the child runs against an
+//! `armadai-fake` scenario instead of a real provider.
+//!
+//! ```yaml
+//! name: summarizer
+//! agent: summarizer
+//! fake: scenarios/summarizer.yaml   # optional, relative to the suite file
+//! judge: strict-reviewer            # optional, agent used by `judge` assertions
+//! cases:
+//!   - name: release-notes
+//!     input: "Summarize: ..."
+//!     assert:
+//!       - contains: "## Summary"
+//!       - regex: "(?m)^- "
+//!       - json_schema: { type: object, required: [title] }
+//!       - judge: "Mentions every breaking change"
+//!       - max_cost: 0.02
+//! ```
+
+pub mod assertions;
+pub mod junit;
+
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use anyhow::Context;
+use serde::Deserialize;
+
+pub use assertions::Assertion;
+
+/// A parsed suite file.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Suite {
+    pub name: String,
+    /// Default agent for cases that don't name one.
+    #[serde(default)]
+    pub agent: Option<String>,
+    /// `armadai-fake` scenario to run the cases against instead of a real
+    /// provider. Relative paths resolve against the suite file.
+    #[serde(default)]
+    pub fake: Option<PathBuf>,
+    /// Agent that grades `judge` assertions. Defaults to the case agent.
+    #[serde(default)]
+    pub judge: Option<String>,
+    pub cases: Vec<Case>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Case {
+    pub name: String,
+    pub input: String,
+    #[serde(default)]
+    pub agent: Option<String>,
+    #[serde(default, rename = "assert")]
+    pub assertions: Vec<Assertion>,
+}
+
+impl Suite {
+    /// Load a suite and resolve `fake` against the suite's directory.
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let text = std::fs::read_to_string(path)
+            .with_context(|| format!("Cannot read suite {}", path.display()))?;
+        let mut suite: Suite = serde_yaml_ng::from_str(&text)
+            .with_context(|| format!("Invalid suite {}", path.display()))?;
+        if let Some(fake) = &suite.fake
+            && fake.is_relative()
+        {
+            let base = path.parent().unwrap_or(Path::new("."));
+            suite.fake = Some(base.join(fake));
+        }
+        suite.validate()?;
+        Ok(suite)
+    }
+
+    fn validate(&self) -> anyhow::Result<()> {
+        if self.cases.is_empty() {
+            anyhow::bail!("Suite '{}' has no cases", self.name);
+        }
+        let mut seen = std::collections::HashSet::new();
+        for case in &self.cases {
+            if !seen.insert(case.name.as_str()) {
+                anyhow::bail!("Duplicate case name '{}'", case.name);
+            }
+            if case.agent.is_none() && self.agent.is_none() {
+                anyhow::bail!(
+                    "Case '{}' has no agent and the suite sets no default `agent:`",
+                    case.name
+                );
+            }
+            for a in &case.assertions {
+                a.validate()
+                    .with_context(|| format!("Case '{}'", case.name))?;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn case_agent<'a>(&'a self, case: &'a Case) -> &'a str {
+        case.agent
+            .as_deref()
+            .or(self.agent.as_deref())
+            .unwrap_or_default()
+    }
+}
+
+/// Env var `fake-claude` reads its scenario from. Mirrors
+/// `armadai_fake::SCENARIO_ENV`, which is only linked with `e2e-fake`.
+const FAKE_SCENARIO_ENV: &str = "ARMADAI_FAKE_SCENARIO";
+
+/// What one `armadai run --json` child produced.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RunOutput {
+    pub run_id: Option<String>,
+    pub content: String,
+    pub tokens_in: u64,
+    pub tokens_out: u64,
+    pub cost: f64,
+    /// Set when the run emitted an `error` event or no `result`.
+    pub error: Option<String>,
+}
+
+impl RunOutput {
+    /// Fold the RunEvent JSONL stream of `armadai run --json`.
+    pub fn from_jsonl(stdout: &str) -> Self {
+        let mut out = RunOutput::default();
+        let mut got_result = false;
+        for line in stdout.lines() {
+            let Ok(ev) = serde_json::from_str::<serde_json::Value>(line) else {
+                continue;
+            };
+            let str_field = |k: &str| ev.get(k).and_then(|v| v.as_str()).map(str::to_string);
+            match ev.get("t").and_then(|t| t.as_str()) {
+                Some("run_start") => out.run_id = str_field("run_id"),
+                Some("result") => {
+                    got_result = true;
+                    out.content = str_field("content").unwrap_or_default();
+                    out.tokens_in = ev.get("tin").and_then(|v| v.as_u64()).unwrap_or(0);
+                    out.tokens_out = ev.get("tout").and_then(|v| v.as_u64()).unwrap_or(0);
+                    out.cost = ev.get("cost").and_then(|v| v.as_f64()).unwrap_or(0.0);
+                }
+                Some("error") => {
+                    out.error = Some(format!(
+                        "{}: {}",
+                        str_field("code").unwrap_or_default(),
+                        str_field("msg").unwrap_or_default()
+                    ));
+                }
+                _ => {}
+            }
+        }
+        if !got_result && out.error.is_none() {
+            out.error = Some("run produced no result".to_string());
+        }
+        out
+    }
+}
+
+/// The scored outcome of one case.
+#[derive(Debug, Clone)]
+pub struct CaseResult {
+    pub name: String,
+    pub output: RunOutput,
+    /// Cost of the case run plus any judge runs.
+    pub cost: f64,
+    pub duration_ms: u64,
+    /// Fraction of assertions that held, `0.0..=1.0`.
+    pub score: f64,
+    pub failures: Vec<String>,
+}
+
+impl CaseResult {
+    pub fn passed(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+/// Runs agents in `armadai run` child processes.
+pub struct Runner {
+    exe: PathBuf,
+    /// Env for fake mode: `ARMADAI_FAKE_SCENARIO` + a PATH that resolves
+    /// `claude` to `fake-claude`.
+    env: Vec<(String, std::ffi::OsString)>,
+    fake_bin_dir: Option<PathBuf>,
+}
+
+impl Runner {
+    pub fn new(fake: Option<&Path>) -> anyhow::Result<Self> {
+        let exe = std::env::current_exe().context("Cannot locate the armadai executable")?;
+        let Some(scenario) = fake else {
+            return Ok(Self {
+                exe,
+                env: Vec::new(),
+                fake_bin_dir: None,
+            });
+        };
+
+        let scenario = std::fs::canonicalize(scenario)
+            .with_context(|| format!("Fake scenario not found: {}", scenario.display()))?;
+        let fake_claude =
+            exe.with_file_name(format!("fake-claude{}", std::env::consts::EXE_SUFFIX));
+        if !fake_claude.is_file() {
+            anyhow::bail!(
+                "Suites with `fake:` need the fake-claude binary next to armadai ({}). \
+                 Build with: cargo build --features e2e-fake",
+                fake_claude.display()
+            );
+        }
+        let bin_dir = std::env::temp_dir().join(format!("armadai-eval-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&bin_dir)?;
+        std::fs::copy(
+            &fake_claude,
+            bin_dir.join(format!("claude{}", std::env::consts::EXE_SUFFIX)),
+        )?;
+        let mut paths = vec![bin_dir.clone()];
+        if let Some(path) = std::env::var_os("PATH") {
+            paths.extend(std::env::split_paths(&path));
+        }
+        let env = vec![
+            (FAKE_SCENARIO_ENV.to_string(), scenario.into_os_string()),
+            ("PATH".to_string(), std::env::join_paths(paths)?),
+        ];
+        Ok(Self {
+            exe,
+            env,
+            fake_bin_dir: Some(bin_dir),
+        })
+    }
+
+    /// Run `agent` on `input` and collect its output.
+    pub async fn run(&self, agent: &str, input: &str) -> anyhow::Result<RunOutput> {
+        let output = tokio::process::Command::new(&self.exe)
+            .args(run_args(agent, input))
+            .envs(self.env.iter().map(|(k, v)| (k, v)))
+            .stdin(std::process::Stdio::null())
+            .output()
+            .await
+            .with_context(|| format!("Failed to spawn {}", self.exe.display()))?;
+        let mut run = RunOutput::from_jsonl(&String::from_utf8_lossy(&output.stdout));
+        if run.error.is_some() && !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            if let Some(last) = stderr.lines().rev().find(|l| !l.trim().is_empty()) {
+                run.error = Some(format!(
+                    "{} ({})",
+                    run.error.unwrap_or_default(),
+                    last.trim()
+                ));
+            }
+        }
+        Ok(run)
+    }
+
+    /// Run every case of `suite`, reporting each result to `on_case` as it
+    /// completes.
+    pub async fn run_suite(
+        &self,
+        suite: &Suite,
+        mut on_case: impl FnMut(&CaseResult),
+    ) -> anyhow::Result<Vec<CaseResult>> {
+        let mut results = Vec::with_capacity(suite.cases.len());
+        for case in &suite.cases {
+            let agent = suite.case_agent(case);
+            let started = Instant::now();
+            let output = self.run(agent, &case.input).await?;
+            let mut cost = output.cost;
+            let mut failures = Vec::new();
+            let mut held = 0usize;
+
+            if let Some(err) = &output.error {
+                failures.push(format!("run failed: {err}"));
+            } else {
+                let judge_agent = suite.judge.as_deref().unwrap_or(agent);
+                for assertion in &case.assertions {
+                    let outcome = match assertion {
+                        Assertion::Judge(rubric) => {
+                            let verdict = self
+                                .run(
+                                    judge_agent,
+                                    &assertions::judge_prompt(rubric, case, &output),
+                                )
+                                .await?;
+                            cost += verdict.cost;
+                            assertions::check_verdict(&verdict)
+                        }
+                        other => other.check(&output),
+                    };
+                    match outcome {
+                        Ok(()) => held += 1,
+                        Err(msg) => failures.push(msg),
+                    }
+                }
+            }
+
+            let total = case.assertions.len();
+            let score = if output.error.is_some() {
+                0.0
+            } else if total == 0 {
+                1.0
+            } else {
+                held as f64 / total as f64
+            };
+            let result = CaseResult {
+                name: case.name.clone(),
+                output,
+                cost,
+                duration_ms: started.elapsed().as_millis() as u64,
+                score,
+                failures,
+            };
+            on_case(&result);
+            results.push(result);
+        }
+        Ok(results)
+    }
+}
+
+impl Drop for Runner {
+    fn drop(&mut self) {
+        if let Some(dir) = &self.fake_bin_dir {
+            let _ = std::fs::remove_dir_all(dir);
+        }
+    }
+}
+
+/// Mean case score.
+pub fn suite_score(results: &[CaseResult]) -> f64 {
+    if results.is_empty() {
+        return 0.0;
+    }
+    results.iter().map(|r| r.score).sum::<f64>() / results.len() as f64
+}
+
+/// `armadai` arguments running `agent` on `input`; options first, so an
+/// input starting with `-` is not taken for a flag.
+fn run_args<'a>(agent: &'a str, input: &'a str) -> [&'a str; 6] {
+    ["run", "--json", "--headless", "--", agent, input]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn run_args_pass_a_dash_input_as_the_input() {
+        use clap::Parser;
+        let cli = crate::cli::Cli::try_parse_from(
+            std::iter::once("armadai").chain(run_args("-reviewer", "--help")),
+        )
+        .unwrap();
+        let Some(crate::cli::Command::Run {
+            agent,
+            input,
+            json,
+            headless,
+            ..
+        }) = cli.command
+        else {
+            panic!("not a run command");
+        };
+        assert_eq!(agent.as_deref(), Some("-reviewer"));
+        assert_eq!(input.as_deref(), Some("--help"));
+        assert!(json && headless);
+    }
+
+    #[test]
+    fn suite_parses_and_resolves_fake_path() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("suite.yaml");
+        std::fs::write(
+            &path,
+            "name: smoke\nagent: writer\nfake: scenario.yaml\ncases:\n  \
+             - name: one\n    input: hi\n    assert:\n      - contains: hello\n      \
+             - max_cost: 0.5\n  - name: two\n    input: yo\n    agent: other\n",
+        )
+        .unwrap();
+        let suite = Suite::load(&path).unwrap();
+        assert_eq!(
+            suite.fake.as_deref(),
+            Some(dir.path().join("scenario.yaml").as_path())
+        );
+        assert_eq!(suite.cases[0].assertions.len(), 2);
+        assert_eq!(suite.case_agent(&suite.cases[0]), "writer");
+        assert_eq!(suite.case_agent(&suite.cases[1]), "other");
+    }
+
+    #[test]
+    fn suite_rejects_duplicate_cases_and_missing_agent() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("suite.yaml");
+        std::fs::write(
+            &path,
+            "name: s\nagent: a\ncases:\n  - {name: x, input: i}\n  - {name: x, input: j}\n",
+        )
+        .unwrap();
+        assert!(
+            Suite::load(&path)
+                .unwrap_err()
+                .to_string()
+                .contains("Duplicate")
+        );
+
+        std::fs::write(&path, "name: s\ncases:\n  - {name: x, input: i}\n").unwrap();
+        assert!(
+            Suite::load(&path)
+                .unwrap_err()
+                .to_string()
+                .contains("no agent")
+        );
+    }
+
+    #[test]
+    fn run_output_folds_jsonl() {
+        let out = RunOutput::from_jsonl(
+            "{\"t\":\"run_start\",\"run_id\":\"r1\",\"v\":1}\n\
+             not json\n\
+             {\"t\":\"result\",\"content\":\"done\",\"tin\":3,\"tout\":4,\"cost\":0.5,\"agents\":1}\n",
+        );
+        assert_eq!(out.run_id.as_deref(), Some("r1"));
+        assert_eq!(out.content, "done");
+        assert_eq!((out.tokens_in, out.tokens_out), (3, 4));
+        assert!(out.error.is_none());
+
+        let failed =
+            RunOutput::from_jsonl("{\"t\":\"error\",\"code\":\"provider\",\"msg\":\"boom\"}");
+        assert_eq!(failed.error.as_deref(), Some("provider: boom"));
+        assert!(RunOutput::from_jsonl("").error.is_some());
+    }
+}
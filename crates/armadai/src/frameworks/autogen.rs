@@ -0,0 +1,527 @@
+//! AutoGen JSON: AgentChat component configs (`team.dump_component()`,
+//! 0.4 and later) and AutoGen Studio 0.2 workflow/agent exports.
+//!
+//! Assistant agents become ArmadAI agents. A round-robin chat maps to a
+//! ring; selector, Magentic-One and 0.2 group chats to a hierarchy under a
+//! generated coordinator that stands in for the speaker selection; a swarm
+//! to a hierarchy under its first participant. Human and code-executor
+//! agents have no ArmadAI equivalent and are reported.
+
+use std::path::Path;
+
+use serde_json::{Map, Value};
+
+use super::{Conversion, ConvertedAgent, Topology, agent, provider_and_model};
+use crate::linker::import::AgentView;
+use crate::linker::slugify;
+
+const AGENT_KEYS: &[&str] = &["name", "description", "system_message", "model_client"];
+const AGENT_KEYS_V02: &[&str] = &["name", "description", "system_message", "llm_config"];
+const MODEL_KEYS: &[&str] = &["model", "temperature", "max_tokens", "timeout"];
+/// Settings that do not change what an agent does.
+const IGNORED_KEYS: &[&str] = &[
+    "model_client_stream",
+    "tool_call_summary_format",
+    "api_key",
+    "cache_seed",
+    "model_info",
+    "human_input_mode",
+];
+
+const COORDINATOR_PROMPT: &str = "You coordinate a team of agents. For each step of the \
+    task, pick the team member best placed to do it, delegate to them, and combine their \
+    results into the final answer.";
+
+pub(super) fn convert(path: &Path) -> anyhow::Result<Conversion> {
+    if path.is_dir() {
+        anyhow::bail!(
+            "{} is a directory; pass the AutoGen JSON file (e.g. from `team.dump_component()`)",
+            path.display()
+        );
+    }
+    let text = std::fs::read_to_string(path)
+        .map_err(|e| anyhow::anyhow!("reading {}: {e}", path.display()))?;
+    let value: Value = serde_json::from_str(&text)
+        .map_err(|e| anyhow::anyhow!("parsing {}: {e}", path.display()))?;
+
+    let mut conv = Conversion::default();
+    if value.get("provider").is_some() {
+        component(&value, &mut conv)?;
+    } else if value.get("receiver").is_some() {
+        workflow(&value, &mut conv);
+    } else if let Some(agents) = value.as_array() {
+        for spec in agents {
+            agent_v02(spec, &mut conv);
+        }
+    } else {
+        agent_v02(&value, &mut conv);
+    }
+    if conv.agents.is_empty() {
+        anyhow::bail!("no convertible agents in {}", path.display());
+    }
+    Ok(conv)
+}
+
+/// Last segment of a component's `provider` class path.
+fn class(component: &Value) -> &str {
+    let provider = component
+        .get("provider")
+        .and_then(Value::as_str)
+        .unwrap_or_default();
+    provider.rsplit('.').next().unwrap_or(provider)
+}
+
+fn config(component: &Value) -> Map<String, Value> {
+    component
+        .get("config")
+        .and_then(Value::as_object)
+        .cloned()
+        .unwrap_or_default()
+}
+
+/// An AgentChat component: a team or a single agent.
+fn component(value: &Value, conv: &mut Conversion) -> anyhow::Result<()> {
+    let kind = value.get("component_type").and_then(Value::as_str);
+    if kind == Some("agent") || class(value).ends_with("Agent") {
+        agent_v04(value, conv);
+        return Ok(());
+    }
+    if kind.is_some_and(|k| k != "team") {
+        anyhow::bail!("a '{}' component is not an agent or a team", class(value));
+    }
+
+    let team = config(value);
+    let mut names = Vec::new();
+    for participant in team
+        .get("participants")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+    {
+        if participant.get("component_type").and_then(Value::as_str) == Some("team") {
+            conv.note(format!(
+                "nested team '{}': not converted; convert it on its own",
+                class(participant)
+            ));
+            continue;
+        }
+        if let Some(name) = agent_v04(participant, conv) {
+            names.push(name);
+        }
+    }
+
+    let class = class(value);
+    let mut known = vec!["participants"];
+    conv.topology = match class {
+        "RoundRobinGroupChat" => {
+            known.push("max_turns");
+            let turns = team.get("max_turns").and_then(Value::as_u64);
+            Some(Topology::Ring {
+                max_laps: turns.map(|t| t.div_ceil(names.len().max(1) as u64) as u32),
+                agents: names,
+            })
+        }
+        "SelectorGroupChat" | "MagenticOneGroupChat" => {
+            known.push("model_client");
+            let name = if class == "SelectorGroupChat" {
+                "selector"
+            } else {
+                "orchestrator"
+            };
+            let coordinator = coordinator(name, team.get("model_client"), conv);
+            let coordinator_name = coordinator.name.clone();
+            conv.agents.push(coordinator);
+            Some(Topology::Hierarchical {
+                coordinator: coordinator_name,
+                agents: names,
+            })
+        }
+        "Swarm" if !names.is_empty() => {
+            conv.note("swarm: handoffs become delegation from the first participant");
+            let coordinator = names.remove(0);
+            Some(Topology::Hierarchical {
+                coordinator,
+                agents: names,
+            })
+        }
+        other => {
+            conv.note(format!("team type '{other}': no orchestration pattern"));
+            None
+        }
+    };
+    report_leftovers(conv, "team", &team, &known);
+    Ok(())
+}
+
+/// A 0.4 agent component; `None` when it is not converted.
+fn agent_v04(value: &Value, conv: &mut Conversion) -> Option<String> {
+    let spec = config(value);
+    let name = text(&spec, "name").unwrap_or_else(|| "agent".to_string());
+    let who = format!("agent '{name}'");
+    match class(value) {
+        "UserProxyAgent" => {
+            conv.note(format!(
+                "{who}: human in the loop; ArmadAI agents run unattended, skipped"
+            ));
+            return None;
+        }
+        "CodeExecutorAgent" => {
+            conv.note(format!(
+                "{who}: executes code without a model; give an agent a tool instead, skipped"
+            ));
+            return None;
+        }
+        "AssistantAgent" => {}
+        other => conv.note(format!("{who}: converted as an assistant ({other})")),
+    }
+    report_leftovers(conv, &who, &spec, AGENT_KEYS);
+    let client = spec.get("model_client").map(config).unwrap_or_default();
+    Some(push_agent(&name, &spec, &client, conv))
+}
+
+/// An AutoGen Studio 0.2 workflow: a sender and a receiver that may be a
+/// group chat manager.
+fn workflow(value: &Value, conv: &mut Conversion) {
+    for side in ["sender", "receiver"] {
+        let Some(spec) = value.get(side) else {
+            continue;
+        };
+        let Some(group) = spec.get("groupchat_config").and_then(Value::as_object) else {
+            agent_v02(spec, conv);
+            continue;
+        };
+        let mut names = Vec::new();
+        for participant in group
+            .get("agents")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+        {
+            if let Some(name) = agent_v02(participant, conv) {
+                names.push(name);
+            }
+        }
+        let method = group
+            .get("speaker_selection_method")
+            .and_then(Value::as_str)
+            .unwrap_or("auto");
+        let mut known = vec![
+            "agents",
+            "speaker_selection_method",
+            "admin_name",
+            "messages",
+        ];
+        conv.topology = Some(if method == "round_robin" {
+            known.push("max_round");
+            let rounds = group.get("max_round").and_then(Value::as_u64);
+            Topology::Ring {
+                max_laps: rounds.map(|r| r.div_ceil(names.len().max(1) as u64) as u32),
+                agents: names,
+            }
+        } else {
+            if method != "auto" {
+                conv.note(format!(
+                    "group chat: speaker_selection_method '{method}' becomes coordinator delegation"
+                ));
+            }
+            let manager = config(spec);
+            let llm = manager.get("llm_config");
+            let mut coordinator = coordinator(
+                &text(&manager, "name").unwrap_or_else(|| "chat-manager".to_string()),
+                None,
+                conv,
+            );
+            if let Some(model) = llm.and_then(model_v02) {
+                let (provider, model) = provider_and_model(&model, "group chat manager", conv);
+                coordinator.provider = provider;
+                coordinator.view.model = Some(model);
+            }
+            let name = coordinator.name.clone();
+            conv.agents.push(coordinator);
+            Topology::Hierarchical {
+                coordinator: name,
+                agents: names,
+            }
+        });
+        report_leftovers(conv, "group chat", group, &known);
+    }
+}
+
+/// A 0.2 agent (`{type, config}` or a bare config); `None` when it is not
+/// converted.
+fn agent_v02(value: &Value, conv: &mut Conversion) -> Option<String> {
+    let spec = match value.get("config") {
+        Some(_) => config(value),
+        None => value.as_object().cloned().unwrap_or_default(),
+    };
+    let name = text(&spec, "name").unwrap_or_else(|| "agent".to_string());
+    let who = format!("agent '{name}'");
+    let llm = spec.get("llm_config").filter(|l| l.is_object());
+    let kind = value
+        .get("type")
+        .and_then(Value::as_str)
+        .unwrap_or_default();
+    if kind == "userproxy" || llm.is_none() {
+        conv.note(format!(
+            "{who}: no LLM (user proxy or code executor); ArmadAI agents run unattended, skipped"
+        ));
+        return None;
+    }
+    report_leftovers(conv, &who, &spec, AGENT_KEYS_V02);
+
+    let llm = llm.and_then(Value::as_object).cloned().unwrap_or_default();
+    let mut client: Map<String, Value> = llm
+        .iter()
+        .filter(|(k, _)| *k != "config_list")
+        .map(|(k, v)| (k.clone(), v.clone()))
+        .collect();
+    if let Some(model) = model_v02(&Value::Object(llm)) {
+        client.insert("model".to_string(), model.into());
+    }
+    Some(push_agent(&name, &spec, &client, conv))
+}
+
+/// The model of a 0.2 `llm_config`: the first entry of its config list.
+fn model_v02(llm: &Value) -> Option<String> {
+    llm.get("config_list")
+        .and_then(Value::as_array)
+        .and_then(|list| list.first())
+        .or(Some(llm))
+        .and_then(|c| c.get("model"))
+        .and_then(Value::as_str)
+        .map(str::to_string)
+}
+
+/// Convert an agent from its config and model client config, and return
+/// its ArmadAI name.
+fn push_agent(
+    name: &str,
+    spec: &Map<String, Value>,
+    client: &Map<String, Value>,
+    conv: &mut Conversion,
+) -> String {
+    let who = format!("agent '{name}'");
+    report_leftovers(conv, &format!("{who} model"), client, MODEL_KEYS);
+
+    let (provider, model) = match text(client, "model") {
+        Some(model) => provider_and_model(&model, &who, conv),
+        None => {
+            conv.note(format!("{who}: no model; using the default tier"));
+            ("claude".to_string(), "latest:pro".to_string())
+        }
+    };
+    let system_prompt = text(spec, "system_message")
+        .unwrap_or_else(|| "You are a helpful AI assistant.".to_string());
+    let view = AgentView {
+        description: text(spec, "description").or_else(|| {
+            system_prompt
+                .lines()
+                .find(|l| !l.trim().is_empty())
+                .map(|l| l.trim().to_string())
+        }),
+        system_prompt,
+        ..AgentView::default()
+    };
+    let mut converted = agent(conv.unique_name(&slugify(name)), provider, model, view);
+    let number = |k: &str| client.get(k).and_then(Value::as_f64);
+    converted.temperature = number("temperature").map(|t| t as f32);
+    converted.max_tokens = number("max_tokens").map(|t| t as u32);
+    converted.timeout = number("timeout").map(|t| t.ceil() as u64);
+    let slug = converted.name.clone();
+    conv.agents.push(converted);
+    slug
+}
+
+/// The coordinator generated for a chat whose next speaker AutoGen picks
+/// with a model.
+fn coordinator(name: &str, client: Option<&Value>, conv: &mut Conversion) -> ConvertedAgent {
+    let model = client.map(config).and_then(|c| text(&c, "model"));
+    let (provider, model) = match model {
+        Some(model) => provider_and_model(&model, "coordinator", conv),
+        None => ("claude".to_string(), "latest:pro".to_string()),
+    };
+    let view = AgentView {
+        description: Some("Team coordinator".to_string()),
+        system_prompt: COORDINATOR_PROMPT.to_string(),
+        ..AgentView::default()
+    };
+    agent(conv.unique_name(&slugify(name)), provider, model, view)
+}
+
+fn text(map: &Map<String, Value>, key: &str) -> Option<String> {
+    map.get(key)
+        .and_then(Value::as_str)
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+}
+
+/// Record the keys of `map` outside `known` that are set to something.
+fn report_leftovers(conv: &mut Conversion, who: &str, map: &Map<String, Value>, known: &[&str]) {
+    for (key, value) in map {
+        let unset = match value {
+            Value::Null | Value::Bool(false) => true,
+            Value::String(s) => s.is_empty(),
+            Value::Array(a) => a.is_empty(),
+            Value::Object(o) => o.is_empty(),
+            Value::Bool(true) | Value::Number(_) => false,
+        };
+        if unset || known.contains(&key.as_str()) || IGNORED_KEYS.contains(&key.as_str()) {
+            continue;
+        }
+        conv.note(format!("{who}: {key} ({})", summary(value)));
+    }
+}
+
+/// A short rendering of a leftover value: component classes and names
+/// rather than their full configs.
+fn summary(value: &Value) -> String {
+    match value {
+        Value::Array(items) => items.iter().map(summary).collect::<Vec<_>>().join(", "),
+        Value::Object(o) => o
+            .get("config")
+            .and_then(|c| c.get("name"))
+            .and_then(Value::as_str)
+            .map(str::to_string)
+            .or_else(|| o.get("provider").map(|_| class(value).to_string()))
+            .unwrap_or_else(|| format!("{} setting(s)", o.len())),
+        Value::String(s) if s.chars().count() > 40 => {
+            format!("{}...", s.chars().take(37).collect::<String>())
+        }
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn convert_json(json: &str) -> Conversion {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("team.json");
+        std::fs::write(&path, json).unwrap();
+        convert(&path).unwrap()
+    }
+
+    const ASSISTANT: &str = r#"{
+        "provider": "autogen_agentchat.agents.AssistantAgent",
+        "component_type": "agent",
+        "config": {
+            "name": "primary",
+            "system_message": "You are a helpful assistant.",
+            "model_client": {
+                "provider": "autogen_ext.models.openai.OpenAIChatCompletionClient",
+                "config": {"model": "gpt-4o", "temperature": 0.3}
+            },
+            "tools": [{"provider": "autogen_core.tools.FunctionTool", "config": {"name": "search"}}],
+            "reflect_on_tool_use": false,
+            "model_client_stream": false
+        }
+    }"#;
+
+    #[test]
+    fn round_robin_team_becomes_a_ring() {
+        let json = format!(
+            r#"{{
+                "provider": "autogen_agentchat.teams.RoundRobinGroupChat",
+                "component_type": "team",
+                "config": {{
+                    "participants": [
+                        {ASSISTANT},
+                        {{"provider": "autogen_agentchat.agents.AssistantAgent",
+                          "config": {{"name": "critic", "system_message": "Critique.",
+                                      "model_client": {{"config": {{"model": "gpt-4o"}}}}}}}},
+                        {{"provider": "autogen_agentchat.agents.UserProxyAgent",
+                          "config": {{"name": "user"}}}}
+                    ],
+                    "termination_condition": {{"provider": "autogen_agentchat.conditions.TextMentionTermination",
+                                               "config": {{"text": "APPROVE"}}}},
+                    "max_turns": 5
+                }}
+            }}"#
+        );
+        let conv = convert_json(&json);
+        let names: Vec<_> = conv.agents.iter().map(|a| a.name.as_str()).collect();
+        assert_eq!(names, ["primary", "critic"]);
+        assert_eq!(conv.agents[0].provider, "gpt");
+        assert_eq!(conv.agents[0].temperature, Some(0.3));
+        assert_eq!(
+            conv.topology,
+            Some(Topology::Ring {
+                agents: vec!["primary".into(), "critic".into()],
+                max_laps: Some(3),
+            })
+        );
+        let report = conv.unmapped.join("\n");
+        assert!(
+            report.contains("agent 'primary': tools (search)"),
+            "{report}"
+        );
+        assert!(report.contains("agent 'user': human in the loop"));
+        assert!(report.contains("team: termination_condition (TextMentionTermination)"));
+        assert!(!report.contains("reflect_on_tool_use"));
+    }
+
+    #[test]
+    fn selector_team_gets_a_generated_coordinator() {
+        let json = format!(
+            r#"{{
+                "provider": "autogen_agentchat.teams.SelectorGroupChat",
+                "component_type": "team",
+                "config": {{
+                    "participants": [{ASSISTANT}],
+                    "model_client": {{"config": {{"model": "claude-sonnet-4-5"}}}},
+                    "selector_prompt": "Select an agent to perform task. {{roles}}"
+                }}
+            }}"#
+        );
+        let conv = convert_json(&json);
+        let selector = conv.agents.last().unwrap();
+        assert_eq!(selector.name, "selector");
+        assert_eq!(selector.provider, "claude");
+        assert_eq!(
+            conv.topology,
+            Some(Topology::Hierarchical {
+                coordinator: "selector".into(),
+                agents: vec!["primary".into()],
+            })
+        );
+        assert!(conv.unmapped.iter().any(|n| n.contains("selector_prompt")));
+    }
+
+    #[test]
+    fn studio_group_chat_workflow() {
+        let conv = convert_json(
+            r#"{
+                "type": "groupchat",
+                "sender": {"type": "userproxy", "config": {"name": "userproxy", "llm_config": false}},
+                "receiver": {
+                    "type": "groupchat",
+                    "config": {"name": "group_chat_manager",
+                               "llm_config": {"config_list": [{"model": "gpt-4-turbo"}]}},
+                    "groupchat_config": {
+                        "agents": [
+                            {"type": "assistant", "config": {"name": "planner",
+                             "system_message": "Plan.",
+                             "llm_config": {"config_list": [{"model": "gpt-4o"}], "max_tokens": 800}}}
+                        ],
+                        "admin_name": "Admin",
+                        "max_round": 10,
+                        "speaker_selection_method": "auto"
+                    }
+                }
+            }"#,
+        );
+        let names: Vec<_> = conv.agents.iter().map(|a| a.name.as_str()).collect();
+        assert_eq!(names, ["planner", "group-chat-manager"]);
+        assert_eq!(conv.agents[0].max_tokens, Some(800));
+        assert_eq!(conv.agents[1].provider, "gpt");
+        assert!(matches!(
+            conv.topology,
+            Some(Topology::Hierarchical { ref coordinator, .. }) if coordinator == "group-chat-manager"
+        ));
+        let report = conv.unmapped.join("\n");
+        assert!(report.contains("agent 'userproxy': no LLM"));
+        assert!(report.contains("group chat: max_round (10)"));
+    }
+}
@@ -0,0 +1,585 @@
+//! CrewAI projects: `config/agents.yaml` and `config/tasks.yaml`, and the
+//! `Process` the crew module (`crew.py`) builds the crew with.
+//!
+//! An agent's role and backstory become its system prompt, its goal and
+//! tasks its instructions. A hierarchical crew maps to a coordinator
+//! (the crew's `manager_agent`, else a generated manager) over one team; a
+//! sequential crew to a ring in task order.
+
+use std::path::{Path, PathBuf};
+
+use serde_yaml_ng::{Mapping, Value};
+
+use super::{Conversion, ConvertedAgent, Topology, agent, provider_and_model};
+use crate::linker::import::AgentView;
+use crate::linker::slugify;
+
+/// CrewAI falls back to this model when an agent sets no `llm`.
+const DEFAULT_MODEL: &str = "gpt-4o-mini";
+
+const AGENT_KEYS: &[&str] = &["role", "goal", "backstory", "llm", "max_execution_time"];
+const TASK_KEYS: &[&str] = &["description", "expected_output", "agent"];
+/// Keys that only affect CrewAI's own logging.
+const IGNORED_KEYS: &[&str] = &["verbose"];
+
+/// The files of a CrewAI project.
+struct Files {
+    agents: PathBuf,
+    tasks: Option<PathBuf>,
+    /// Directory of the crew's Python module.
+    module_dir: PathBuf,
+}
+
+#[derive(Debug, PartialEq)]
+enum Process {
+    Sequential,
+    Hierarchical {
+        manager_agent: Option<String>,
+        manager_llm: Option<String>,
+    },
+}
+
+pub(super) fn convert(path: &Path) -> anyhow::Result<Conversion> {
+    let files = locate(path)?;
+    let agents = load(&files.agents)?;
+    let tasks = match &files.tasks {
+        Some(tasks) => load(tasks)?,
+        None => Mapping::new(),
+    };
+    let process = detect_process(&files.module_dir);
+    let hierarchical = matches!(process, Process::Hierarchical { .. });
+    let mut conv = Conversion::default();
+
+    // Tasks per agent key, in task order.
+    let mut assigned: Vec<(String, Vec<(String, &Mapping)>)> = Vec::new();
+    let mut unassigned: Vec<(String, &Mapping)> = Vec::new();
+    for (name, task) in &tasks {
+        let name = key_str(name);
+        let Some(task) = task.as_mapping() else {
+            conv.note(format!("task '{name}': not a mapping, skipped"));
+            continue;
+        };
+        report_leftovers(&mut conv, &format!("task '{name}'"), task, TASK_KEYS);
+        match task.get("agent").and_then(Value::as_str).map(str::trim) {
+            Some(key) if agents.contains_key(key) => {
+                match assigned.iter_mut().find(|(k, _)| k == key) {
+                    Some((_, list)) => list.push((name, task)),
+                    None => assigned.push((key.to_string(), vec![(name, task)])),
+                }
+            }
+            Some(key) => conv.note(format!(
+                "task '{name}': agent '{key}' is not defined in agents.yaml, skipped"
+            )),
+            None => unassigned.push((name, task)),
+        }
+    }
+
+    let mut known = AGENT_KEYS.to_vec();
+    if hierarchical {
+        // Delegation is what the coordinator does anyway.
+        known.push("allow_delegation");
+    }
+    let mut keys = Vec::new();
+    for (key, spec) in &agents {
+        let key = key_str(key);
+        let Some(spec) = spec.as_mapping() else {
+            conv.note(format!("agent '{key}': not a mapping, skipped"));
+            continue;
+        };
+        let tasks = assigned
+            .iter()
+            .find(|(k, _)| *k == key)
+            .map(|(_, list)| list.as_slice())
+            .unwrap_or_default();
+        let converted = convert_agent(&key, spec, tasks, &known, &mut conv);
+        keys.push((key, converted.name.clone()));
+        conv.agents.push(converted);
+    }
+    if conv.agents.is_empty() {
+        anyhow::bail!("no agents in {}", files.agents.display());
+    }
+
+    match process {
+        Process::Sequential => {
+            for (name, _) in &unassigned {
+                conv.note(format!(
+                    "task '{name}': no agent, which a sequential crew requires; skipped"
+                ));
+            }
+            // Turn order follows the tasks; agents without tasks come last.
+            let mut order: Vec<String> = assigned
+                .iter()
+                .filter_map(|(key, _)| keys.iter().find(|(k, _)| k == key))
+                .map(|(_, slug)| slug.clone())
+                .collect();
+            for agent in &conv.agents {
+                if !order.contains(&agent.name) {
+                    order.push(agent.name.clone());
+                }
+            }
+            if order.len() > 1 {
+                conv.topology = Some(Topology::Ring {
+                    agents: order,
+                    max_laps: Some(1),
+                });
+            }
+        }
+        Process::Hierarchical {
+            manager_agent,
+            manager_llm,
+        } => {
+            let coordinator = match manager_agent
+                .and_then(|m| keys.iter().find(|(k, _)| *k == m).map(|(_, s)| s.clone()))
+            {
+                Some(slug) => slug,
+                None => {
+                    let manager = manager(manager_llm.as_deref(), &mut conv);
+                    let name = manager.name.clone();
+                    conv.agents.push(manager);
+                    name
+                }
+            };
+            if !unassigned.is_empty() {
+                let tasks = task_instructions(&unassigned);
+                let agent = conv
+                    .agents
+                    .iter_mut()
+                    .find(|a| a.name == coordinator)
+                    .expect("coordinator was just converted");
+                agent.view.instructions = Some(match agent.view.instructions.take() {
+                    Some(own) => format!("{own}\n\n{tasks}"),
+                    None => tasks,
+                });
+            }
+            let agents = conv
+                .agents
+                .iter()
+                .map(|a| a.name.clone())
+                .filter(|n| *n != coordinator)
+                .collect();
+            conv.topology = Some(Topology::Hierarchical {
+                coordinator,
+                agents,
+            });
+        }
+    }
+    Ok(conv)
+}
+
+fn convert_agent(
+    key: &str,
+    spec: &Mapping,
+    tasks: &[(String, &Mapping)],
+    known: &[&str],
+    conv: &mut Conversion,
+) -> ConvertedAgent {
+    let who = format!("agent '{key}'");
+    report_leftovers(conv, &who, spec, known);
+
+    let role = text(spec, "role").unwrap_or_else(|| key.to_string());
+    let mut system_prompt = format!("You are {role}.");
+    if let Some(backstory) = text(spec, "backstory") {
+        system_prompt.push(' ');
+        system_prompt.push_str(&backstory);
+    }
+    if let Some(goal) = text(spec, "goal") {
+        system_prompt.push_str(&format!("\n\nYour personal goal is: {goal}"));
+    }
+
+    let (instructions, output_format) = match tasks {
+        [] => (None, None),
+        [(_, task)] => (text(task, "description"), text(task, "expected_output")),
+        _ => (Some(task_instructions(tasks)), None),
+    };
+
+    let placeholders = placeholders(
+        [Some(&system_prompt), instructions.as_ref()]
+            .into_iter()
+            .flatten(),
+    );
+    if !placeholders.is_empty() {
+        conv.note(format!(
+            "{who}: {} are filled by `kickoff(inputs=...)` in CrewAI; \
+             pass those values in the run input instead",
+            placeholders.join(", ")
+        ));
+    }
+
+    let (model, temperature, max_tokens) = match spec.get("llm") {
+        Some(Value::String(model)) => (model.clone(), None, None),
+        Some(Value::Mapping(llm)) => {
+            let number = |k: &str| llm.get(k).and_then(Value::as_f64);
+            let leftovers: Mapping = llm
+                .iter()
+                .filter(|(k, _)| {
+                    !["model", "temperature", "max_tokens"].contains(&key_str(k).as_str())
+                })
+                .map(|(k, v)| (k.clone(), v.clone()))
+                .collect();
+            report_leftovers(conv, &format!("{who} llm"), &leftovers, &[]);
+            (
+                text(llm, "model").unwrap_or_else(|| DEFAULT_MODEL.to_string()),
+                number("temperature").map(|t| t as f32),
+                number("max_tokens").map(|t| t as u32),
+            )
+        }
+        _ => (DEFAULT_MODEL.to_string(), None, None),
+    };
+    let (provider, model) = provider_and_model(&model, &who, conv);
+
+    let view = AgentView {
+        description: Some(role.split_whitespace().collect::<Vec<_>>().join(" ")),
+        system_prompt,
+        instructions,
+        output_format,
+        ..AgentView::default()
+    };
+    let mut converted = agent(conv.unique_name(&slugify(key)), provider, model, view);
+    converted.temperature = temperature;
+    converted.max_tokens = max_tokens;
+    converted.timeout = spec
+        .get("max_execution_time")
+        .and_then(Value::as_u64)
+        .filter(|t| *t > 0);
+    converted
+}
+
+/// The manager CrewAI creates for a hierarchical crew without a
+/// `manager_agent`.
+fn manager(llm: Option<&str>, conv: &mut Conversion) -> ConvertedAgent {
+    let (provider, model) = provider_and_model(llm.unwrap_or(DEFAULT_MODEL), "manager", conv);
+    let view = AgentView {
+        description: Some("Crew Manager".to_string()),
+        system_prompt: "You are Crew Manager. You are a seasoned manager with a knack for \
+            getting the best out of your team. You are also known for your ability to \
+            delegate work to the right people, and to ask the right questions to get the \
+            best out of your team.\n\nYour personal goal is: Manage the team to complete \
+            the task in the best way possible."
+            .to_string(),
+        ..AgentView::default()
+    };
+    agent(conv.unique_name("manager"), provider, model, view)
+}
+
+/// Several tasks as one instructions section (no headings: they would end
+/// the section).
+fn task_instructions(tasks: &[(String, &Mapping)]) -> String {
+    tasks
+        .iter()
+        .map(|(name, task)| {
+            let mut out = format!("**{name}**");
+            if let Some(description) = text(task, "description") {
+                out.push_str(&format!("\n\n{description}"));
+            }
+            if let Some(expected) = text(task, "expected_output") {
+                out.push_str(&format!("\n\nExpected output: {expected}"));
+            }
+            out
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+/// Find `agents.yaml` from a file or project path, and `tasks.yaml` next
+/// to it.
+fn locate(path: &Path) -> anyhow::Result<Files> {
+    let agents = if path.is_file() {
+        path.to_path_buf()
+    } else {
+        find_agents_yaml(path, 4).ok_or_else(|| {
+            anyhow::anyhow!(
+                "No CrewAI agents.yaml found under {} (expected config/agents.yaml)",
+                path.display()
+            )
+        })?
+    };
+    let config_dir = agents.parent().unwrap_or(Path::new(".")).to_path_buf();
+    let tasks = ["tasks.yaml", "tasks.yml"]
+        .iter()
+        .map(|f| config_dir.join(f))
+        .find(|p| p.is_file());
+    let module_dir = if config_dir.file_name().is_some_and(|n| n == "config") {
+        config_dir.parent().unwrap_or(&config_dir).to_path_buf()
+    } else {
+        config_dir
+    };
+    Ok(Files {
+        agents,
+        tasks,
+        module_dir,
+    })
+}
+
+/// `agents.yaml` in `dir` or its `config/`, else in the closest
+/// subdirectory that has one (breadth-first, `depth` levels).
+fn find_agents_yaml(dir: &Path, depth: usize) -> Option<PathBuf> {
+    let here = [dir.to_path_buf(), dir.join("config")]
+        .into_iter()
+        .flat_map(|d| [d.join("agents.yaml"), d.join("agents.yml")])
+        .find(|p| p.is_file());
+    if here.is_some() || depth == 0 {
+        return here;
+    }
+    let mut subdirs: Vec<PathBuf> = std::fs::read_dir(dir)
+        .ok()?
+        .flatten()
+        .map(|e| e.path())
+        .filter(|p| {
+            p.is_dir()
+                && p.file_name()
+                    .and_then(|n| n.to_str())
+                    .is_some_and(|n| !n.starts_with('.') && !matches!(n, "node_modules" | "venv"))
+        })
+        .collect();
+    subdirs.sort();
+    subdirs.iter().find_map(|d| find_agents_yaml(d, depth - 1))
+}
+
+/// The crew's process, read from the Python files of its module.
+fn detect_process(module_dir: &Path) -> Process {
+    let Ok(entries) = std::fs::read_dir(module_dir) else {
+        return Process::Sequential;
+    };
+    let mut sources: Vec<PathBuf> = entries
+        .flatten()
+        .map(|e| e.path())
+        .filter(|p| p.extension().is_some_and(|e| e == "py"))
+        .collect();
+    sources.sort();
+    let source: String = sources
+        .iter()
+        .filter_map(|p| std::fs::read_to_string(p).ok())
+        .collect::<Vec<_>>()
+        .join("\n");
+    if !source.contains("Process.hierarchical") {
+        return Process::Sequential;
+    }
+    let capture = |pattern: &str| {
+        regex::Regex::new(pattern)
+            .ok()?
+            .captures(&source)
+            .map(|c| c[1].to_string())
+    };
+    Process::Hierarchical {
+        manager_agent: capture(r"manager_agent\s*=\s*(?:self\.)?([A-Za-z_][A-Za-z0-9_]*)"),
+        manager_llm: capture(r#"manager_llm\s*=\s*["']([^"']+)["']"#),
+    }
+}
+
+fn load(path: &Path) -> anyhow::Result<Mapping> {
+    let text = std::fs::read_to_string(path)
+        .map_err(|e| anyhow::anyhow!("reading {}: {e}", path.display()))?;
+    if text.trim().is_empty() {
+        return Ok(Mapping::new());
+    }
+    serde_yaml_ng::from_str(&text).map_err(|e| anyhow::anyhow!("parsing {}: {e}", path.display()))
+}
+
+fn key_str(key: &Value) -> String {
+    match key {
+        Value::String(s) => s.clone(),
+        other => serde_yaml_ng::to_string(other)
+            .map(|s| s.trim().to_string())
+            .unwrap_or_default(),
+    }
+}
+
+fn text(map: &Mapping, key: &str) -> Option<String> {
+    map.get(key)
+        .and_then(Value::as_str)
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+}
+
+/// Record the keys of `map` outside `known` that are set to something.
+fn report_leftovers(conv: &mut Conversion, who: &str, map: &Mapping, known: &[&str]) {
+    for (key, value) in map {
+        let key = key_str(key);
+        let unset = match value {
+            Value::Null | Value::Bool(false) => true,
+            Value::Sequence(s) => s.is_empty(),
+            Value::Mapping(m) => m.is_empty(),
+            _ => false,
+        };
+        if unset || known.contains(&key.as_str()) || IGNORED_KEYS.contains(&key.as_str()) {
+            continue;
+        }
+        conv.note(format!("{who}: {key} ({})", summary(value)));
+    }
+}
+
+/// A short rendering of a leftover value.
+fn summary(value: &Value) -> String {
+    match value {
+        Value::Sequence(items) => items
+            .iter()
+            .map(|i| match i {
+                Value::String(s) => s.clone(),
+                other => summary(other),
+            })
+            .collect::<Vec<_>>()
+            .join(", "),
+        Value::Mapping(m) => format!("{} setting(s)", m.len()),
+        Value::String(s) if s.chars().count() > 40 => {
+            format!("{}...", s.chars().take(37).collect::<String>())
+        }
+        other => key_str(other),
+    }
+}
+
+/// The `{name}` interpolation placeholders in `texts`, deduplicated.
+fn placeholders<'a>(texts: impl Iterator<Item = &'a String>) -> Vec<String> {
+    let re = regex::Regex::new(r"\{([A-Za-z_][A-Za-z0-9_]*)\}").expect("valid regex");
+    let mut found: Vec<String> = Vec::new();
+    for text in texts {
+        for cap in re.captures_iter(text) {
+            let name = format!("{{{}}}", &cap[1]);
+            if !found.contains(&name) {
+                found.push(name);
+            }
+        }
+    }
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write(path: &Path, content: &str) {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, content).unwrap();
+    }
+
+    const AGENTS: &str = "\
+researcher:
+  role: >
+    {topic} Senior Data Researcher
+  goal: Uncover cutting-edge developments in {topic}
+  backstory: You're a seasoned researcher.
+  llm: anthropic/claude-sonnet-4-5
+  tools: [SerperDevTool]
+  verbose: true
+reporting_analyst:
+  role: Reporting Analyst
+  goal: Create detailed reports
+  backstory: You're meticulous.
+  llm:
+    model: ollama/llama3
+    temperature: 0.2
+  max_execution_time: 300
+  allow_delegation: true
+";
+
+    const TASKS: &str = "\
+reporting_task:
+  description: Expand each topic into a full section.
+  expected_output: A markdown report.
+  agent: reporting_analyst
+research_task:
+  description: Research {topic}.
+  expected_output: A list of 10 bullet points.
+  agent: researcher
+  output_file: report.md
+";
+
+    #[test]
+    fn sequential_crew_becomes_a_ring_in_task_order() {
+        let tmp = tempfile::tempdir().unwrap();
+        let module = tmp.path().join("src/latest_ai");
+        write(&module.join("config/agents.yaml"), AGENTS);
+        write(&module.join("config/tasks.yaml"), TASKS);
+        write(
+            &module.join("crew.py"),
+            "return Crew(agents=self.agents, tasks=self.tasks, process=Process.sequential)\n",
+        );
+
+        let conv = convert(tmp.path()).unwrap();
+        assert_eq!(conv.agents.len(), 2);
+        let researcher = &conv.agents[0];
+        assert_eq!(researcher.name, "researcher");
+        assert_eq!(researcher.provider, "claude");
+        assert!(
+            researcher
+                .view
+                .system_prompt
+                .starts_with("You are {topic} Senior Data Researcher. You're a seasoned")
+        );
+        assert_eq!(
+            researcher.view.instructions.as_deref(),
+            Some("Research {topic}.")
+        );
+        assert_eq!(
+            researcher.view.output_format.as_deref(),
+            Some("A list of 10 bullet points.")
+        );
+
+        let analyst = &conv.agents[1];
+        assert_eq!(analyst.name, "reporting-analyst");
+        assert_eq!(analyst.provider, "proxy");
+        assert_eq!(analyst.view.model.as_deref(), Some("ollama/llama3"));
+        assert_eq!(analyst.temperature, Some(0.2));
+        assert_eq!(analyst.timeout, Some(300));
+
+        assert_eq!(
+            conv.topology,
+            Some(Topology::Ring {
+                agents: vec!["reporting-analyst".into(), "researcher".into()],
+                max_laps: Some(1),
+            })
+        );
+        let report = conv.unmapped.join("\n");
+        assert!(report.contains("agent 'researcher': tools (SerperDevTool)"));
+        assert!(report.contains("agent 'reporting_analyst': allow_delegation"));
+        assert!(report.contains("task 'research_task': output_file (report.md)"));
+        assert!(report.contains("{topic}"));
+        assert!(!report.contains("verbose"));
+    }
+
+    #[test]
+    fn hierarchical_crew_gets_a_coordinator() {
+        let tmp = tempfile::tempdir().unwrap();
+        write(&tmp.path().join("config/agents.yaml"), AGENTS);
+        write(
+            &tmp.path().join("config/tasks.yaml"),
+            "summary:\n  description: Summarise the findings.\n  expected_output: One paragraph.\n",
+        );
+        write(
+            &tmp.path().join("crew.py"),
+            "Crew(process=Process.hierarchical, manager_llm=\"gpt-4o\")\n",
+        );
+
+        let conv = convert(&tmp.path().join("config/agents.yaml")).unwrap();
+        let manager = conv.agents.last().unwrap();
+        assert_eq!(manager.name, "manager");
+        assert_eq!(manager.provider, "gpt");
+        // The unassigned task is the manager's to delegate.
+        assert!(
+            manager
+                .view
+                .instructions
+                .as_deref()
+                .unwrap()
+                .contains("**summary**")
+        );
+        assert_eq!(
+            conv.topology,
+            Some(Topology::Hierarchical {
+                coordinator: "manager".into(),
+                agents: vec!["researcher".into(), "reporting-analyst".into()],
+            })
+        );
+        assert!(!conv.unmapped.join("\n").contains("allow_delegation"));
+
+        write(
+            &tmp.path().join("crew.py"),
+            "Crew(process=Process.hierarchical, manager_agent=self.researcher())\n",
+        );
+        let conv = convert(tmp.path()).unwrap();
+        assert_eq!(conv.agents.len(), 2);
+        assert!(matches!(
+            conv.topology,
+            Some(Topology::Hierarchical { ref coordinator, .. }) if coordinator == "researcher"
+        ));
+    }
+}
@@ -0,0 +1,216 @@
+//! Conversion of other agent frameworks' definitions — CrewAI crews and
+//! AutoGen teams — into ArmadAI agents and an `orchestration:` config.
+//!
+//! Converters never drop a setting silently: whatever ArmadAI cannot
+//! express (tools, termination conditions, human-in-the-loop agents, ...)
+//! is listed in [`Conversion::unmapped`] for the user to carry over.
+
+mod autogen;
+mod crewai;
+
+use std::path::Path;
+
+use serde_yaml_ng::{Mapping, Value};
+
+use crate::audit::proposal::{demote_headings, model_family, portable_model};
+use crate::linker::import::AgentView;
+
+/// A framework `armadai convert` reads.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum)]
+pub enum Framework {
+    /// CrewAI project (config/agents.yaml, config/tasks.yaml)
+    Crewai,
+    /// AutoGen team or agent JSON (AgentChat components, Studio workflows)
+    Autogen,
+}
+
+impl Framework {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Crewai => "CrewAI",
+            Self::Autogen => "AutoGen",
+        }
+    }
+}
+
+/// One agent, ready to be written as an agent file or a declaration.
+#[derive(Debug, Clone)]
+pub struct ConvertedAgent {
+    /// Slug, also the name the orchestration config refers to.
+    pub name: String,
+    pub provider: String,
+    pub temperature: Option<f32>,
+    pub max_tokens: Option<u32>,
+    pub timeout: Option<u64>,
+    /// Description, model and prompt sections.
+    pub view: AgentView,
+}
+
+/// How the converted agents work together.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Topology {
+    /// A coordinator delegating to one team.
+    Hierarchical {
+        coordinator: String,
+        agents: Vec<String>,
+    },
+    /// Agents taking turns in a fixed order.
+    Ring {
+        agents: Vec<String>,
+        max_laps: Option<u32>,
+    },
+}
+
+impl Topology {
+    /// The `orchestration:` block of a project config.
+    pub fn to_yaml(&self) -> String {
+        let mut orch = Mapping::new();
+        orch.insert("enabled".into(), true.into());
+        let (pattern, coordinator, agents, max_laps) = match self {
+            Self::Hierarchical {
+                coordinator,
+                agents,
+            } => ("hierarchical", Some(coordinator), agents, None),
+            Self::Ring { agents, max_laps } => ("ring", None, agents, *max_laps),
+        };
+        orch.insert("pattern".into(), pattern.into());
+        if let Some(coordinator) = coordinator {
+            orch.insert("coordinator".into(), coordinator.as_str().into());
+        }
+        let mut team = Mapping::new();
+        team.insert(
+            "agents".into(),
+            Value::Sequence(agents.iter().map(|a| a.as_str().into()).collect()),
+        );
+        orch.insert("teams".into(), Value::Sequence(vec![Value::Mapping(team)]));
+        if let Some(laps) = max_laps {
+            orch.insert("max_laps".into(), laps.into());
+        }
+        let mut root = Mapping::new();
+        root.insert("orchestration".into(), Value::Mapping(orch));
+        serde_yaml_ng::to_string(&root).unwrap_or_default()
+    }
+}
+
+/// Result of converting one framework definition.
+#[derive(Debug, Default)]
+pub struct Conversion {
+    pub agents: Vec<ConvertedAgent>,
+    pub topology: Option<Topology>,
+    /// Settings with no ArmadAI equivalent, one line each.
+    pub unmapped: Vec<String>,
+}
+
+impl Conversion {
+    fn note(&mut self, message: impl Into<String>) {
+        self.unmapped.push(message.into());
+    }
+
+    /// `name` made unique among the agents converted so far.
+    fn unique_name(&self, name: &str) -> String {
+        let taken = |n: &str| self.agents.iter().any(|a| a.name == n);
+        if !taken(name) {
+            return name.to_string();
+        }
+        (2..)
+            .map(|i| format!("{name}-{i}"))
+            .find(|n| !taken(n))
+            .unwrap_or_default()
+    }
+}
+
+/// Convert the definition at `path` (a file or a project directory).
+pub fn convert(framework: Framework, path: &Path) -> anyhow::Result<Conversion> {
+    if !path.exists() {
+        anyhow::bail!("{} not found", path.display());
+    }
+    match framework {
+        Framework::Crewai => crewai::convert(path),
+        Framework::Autogen => autogen::convert(path),
+    }
+}
+
+/// Provider and ArmadAI model for a framework's model id (LiteLLM-style
+/// `provider/model` ids included). Models with a portable tier run on
+/// their family's unified provider; any other model keeps its id and goes
+/// through the `proxy` provider, which `conv` records.
+fn provider_and_model(model: &str, who: &str, conv: &mut Conversion) -> (String, String) {
+    let bare = model.rsplit('/').next().unwrap_or(model);
+    let portable = portable_model(Some(bare));
+    if portable.starts_with("latest:") {
+        return (model_family(bare).to_string(), portable);
+    }
+    conv.note(format!(
+        "{who}: model '{model}' has no ArmadAI tier; it runs through the `proxy` provider \
+         (an OpenAI-compatible gateway such as LiteLLM)"
+    ));
+    ("proxy".to_string(), model.to_string())
+}
+
+/// An agent with its prompt sections made safe for the agent format.
+fn agent(name: String, provider: String, model: String, view: AgentView) -> ConvertedAgent {
+    let demote = |s: Option<String>| s.map(|s| demote_headings(&s));
+    ConvertedAgent {
+        name,
+        provider,
+        temperature: None,
+        max_tokens: None,
+        timeout: None,
+        view: AgentView {
+            model: Some(model),
+            system_prompt: demote_headings(&view.system_prompt),
+            instructions: demote(view.instructions),
+            output_format: demote(view.output_format),
+            context: demote(view.context),
+            ..view
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use armadai_core::orchestration::{OrchestrationConfig, OrchestrationPattern, validate_config};
+
+    fn parse(topology: &Topology) -> OrchestrationConfig {
+        let root: Mapping = serde_yaml_ng::from_str(&topology.to_yaml()).unwrap();
+        serde_yaml_ng::from_value(root["orchestration"].clone()).unwrap()
+    }
+
+    #[test]
+    fn topologies_render_valid_orchestration_configs() {
+        let hierarchical = parse(&Topology::Hierarchical {
+            coordinator: "manager".into(),
+            agents: vec!["researcher".into(), "writer".into()],
+        });
+        assert!(hierarchical.enabled);
+        assert_eq!(hierarchical.pattern, OrchestrationPattern::Hierarchical);
+        assert_eq!(hierarchical.coordinator.as_deref(), Some("manager"));
+        assert_eq!(hierarchical.teams[0].agents, ["researcher", "writer"]);
+        assert!(validate_config(&hierarchical).is_ok());
+
+        let ring = parse(&Topology::Ring {
+            agents: vec!["a".into(), "b".into()],
+            max_laps: Some(3),
+        });
+        assert_eq!(ring.pattern, OrchestrationPattern::Ring);
+        assert_eq!(ring.max_laps, Some(3));
+        assert!(validate_config(&ring).is_ok());
+    }
+
+    #[test]
+    fn untiered_models_go_through_the_proxy() {
+        let mut conv = Conversion::default();
+        let (provider, model) = provider_and_model("ollama/llama3", "agent 'a'", &mut conv);
+        assert_eq!(
+            (provider.as_str(), model.as_str()),
+            ("proxy", "ollama/llama3")
+        );
+        assert_eq!(conv.unmapped.len(), 1);
+
+        let (provider, model) = provider_and_model("openai/gpt-4o", "agent 'b'", &mut conv);
+        assert_eq!(provider, "gpt");
+        assert!(model.starts_with("latest:"), "{model}");
+        assert_eq!(conv.unmapped.len(), 1);
+    }
+}
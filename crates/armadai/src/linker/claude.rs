@@ -91,7 +91,11 @@ fn generate_agent_file(agent: &LinkAgent) -> OutputFile {
         content.push('\n');
     }
 
-    OutputFile { path, content }
+    OutputFile {
+        path,
+        content,
+        source: Some(agent.name.clone()),
+    }
 }
 
 /// Generate `.claude/CLAUDE.md` with the coordinator's prompt and a team roster.
@@ -145,6 +149,7 @@ fn generate_claude_md(coordinator: &LinkAgent, agents: &[LinkAgent]) -> OutputFi
     OutputFile {
         path: PathBuf::from(".claude/CLAUDE.md"),
         content,
+        source: Some(coordinator.name.clone()),
     }
 }
 
@@ -81,7 +81,11 @@ fn generate_agent_file(agent: &LinkAgent) -> OutputFile {
         "developer_instructions = \"\"\"\n{instructions}\n\"\"\"\n"
     ));
 
-    OutputFile { path, content }
+    OutputFile {
+        path,
+        content,
+        source: Some(agent.name.clone()),
+    }
 }
 
 /// Generate `.codex/config.toml` with references to all agent configs.
@@ -106,6 +110,7 @@ fn generate_config_toml(agents: &[LinkAgent]) -> OutputFile {
     OutputFile {
         path: PathBuf::from(".codex/config.toml"),
         content,
+        source: None,
     }
 }
 
@@ -194,6 +199,7 @@ fn generate_agents_md(agents: &[LinkAgent], coordinator: Option<&LinkAgent>) ->
     OutputFile {
         path: PathBuf::from(".codex/AGENTS.md"),
         content,
+        source: coordinator.map(|c| c.name.clone()),
     }
 }
 
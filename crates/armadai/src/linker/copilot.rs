@@ -75,7 +75,11 @@ fn generate_agent_file(agent: &LinkAgent) -> OutputFile {
         content.push('\n');
     }
 
-    OutputFile { path, content }
+    OutputFile {
+        path,
+        content,
+        source: Some(agent.name.clone()),
+    }
 }
 
 /// Generate `.github/copilot-instructions.md` with coordinator prompt and team roster.
@@ -126,6 +130,7 @@ fn generate_copilot_instructions(coordinator: &LinkAgent, agents: &[LinkAgent])
     OutputFile {
         path: PathBuf::from(".github/copilot-instructions.md"),
         content,
+        source: Some(coordinator.name.clone()),
     }
 }
 
@@ -0,0 +1,212 @@
+use std::path::PathBuf;
+
+use super::{LinkAgent, Linker, OutputFile, armadai_protocol_block, slugify};
+
+/// Generates Cursor project rules (`.cursor/rules/{slug}.mdc`).
+///
+/// Each file has a frontmatter block with `description`, `globs` and
+/// `alwaysApply`, followed by the agent's prompt as the Markdown body.
+/// `globs` comes from the agent's `scope`: a scoped agent is auto-attached
+/// to matching files, an unscoped one is left for the model to request by
+/// its description.
+///
+/// When a coordinator is provided, its rule is `alwaysApply: true` and lists
+/// the other rules as the team roster.
+pub struct CursorLinker;
+
+impl Linker for CursorLinker {
+    fn name(&self) -> &str {
+        "cursor"
+    }
+
+    fn default_output_dir(&self) -> &str {
+        ".cursor"
+    }
+
+    fn generate(
+        &self,
+        agents: &[LinkAgent],
+        coordinator: Option<&LinkAgent>,
+        _sources: &[String],
+    ) -> Vec<OutputFile> {
+        let mut files: Vec<OutputFile> = agents.iter().map(generate_rule_file).collect();
+
+        if let Some(coord) = coordinator {
+            files.push(generate_coordinator_rule(coord, agents));
+        }
+
+        files
+    }
+}
+
+fn generate_rule_file(agent: &LinkAgent) -> OutputFile {
+    let slug = slugify(&agent.name);
+    let path = PathBuf::from(".cursor/rules").join(format!("{slug}.mdc"));
+
+    let mut content = frontmatter(&description(agent), &agent.scope, false);
+
+    content.push_str(&agent.system_prompt);
+
+    if let Some(ref instructions) = agent.instructions {
+        ensure_blank_line(&mut content);
+        content.push_str("## Instructions\n\n");
+        content.push_str(instructions);
+    }
+
+    if let Some(ref output_format) = agent.output_format {
+        ensure_blank_line(&mut content);
+        content.push_str("## Output Format\n\n");
+        content.push_str(output_format);
+    }
+
+    if let Some(ref context) = agent.context {
+        ensure_blank_line(&mut content);
+        content.push_str("## Context\n\n");
+        content.push_str(context);
+    }
+
+    if !content.ends_with('\n') {
+        content.push('\n');
+    }
+
+    OutputFile {
+        path,
+        content,
+        source: Some(agent.name.clone()),
+    }
+}
+
+/// Generate the coordinator's always-applied rule with a team roster.
+fn generate_coordinator_rule(coordinator: &LinkAgent, agents: &[LinkAgent]) -> OutputFile {
+    let slug = slugify(&coordinator.name);
+    let mut content = frontmatter(&description(coordinator), &[], true);
+
+    content.push_str(&coordinator.system_prompt);
+
+    if let Some(ref instructions) = coordinator.instructions {
+        ensure_blank_line(&mut content);
+        content.push_str(instructions);
+    }
+
+    if !agents.is_empty() {
+        ensure_blank_line(&mut content);
+        content.push_str("## Team\n\n");
+        content.push_str("| Rule | Description |\n");
+        content.push_str("|------|-------------|\n");
+        for agent in agents {
+            let desc = description(agent);
+            let desc_truncated = if desc.chars().count() > 80 {
+                format!("{}...", desc.chars().take(77).collect::<String>())
+            } else {
+                desc
+            };
+            content.push_str(&format!(
+                "| @{} | {} |\n",
+                slugify(&agent.name),
+                desc_truncated
+            ));
+        }
+        content.push_str(
+            "\nTo delegate to a specialized agent, mention its rule (e.g. `@rule-name`).\n",
+        );
+    }
+
+    content.push_str(armadai_protocol_block());
+
+    OutputFile {
+        path: PathBuf::from(".cursor/rules").join(format!("{slug}.mdc")),
+        content,
+        source: Some(coordinator.name.clone()),
+    }
+}
+
+fn description(agent: &LinkAgent) -> String {
+    agent
+        .description
+        .as_deref()
+        .or_else(|| agent.system_prompt.lines().find(|l| !l.trim().is_empty()))
+        .unwrap_or(&agent.name)
+        .to_string()
+}
+
+/// Cursor's frontmatter is not strict YAML: `globs` is a bare
+/// comma-separated list (a leading `*` would be a YAML alias), so values are
+/// written the way Cursor's own rule editor writes them.
+fn frontmatter(description: &str, scope: &[String], always_apply: bool) -> String {
+    let description = description.replace('\n', " ");
+    format!(
+        "---\ndescription: {}\nglobs: {}\nalwaysApply: {always_apply}\n---\n\n",
+        description.trim(),
+        scope.join(",")
+    )
+}
+
+/// Ensure there are two newlines (blank line) before a new section.
+fn ensure_blank_line(content: &mut String) {
+    if !content.ends_with("\n\n") {
+        if !content.ends_with('\n') {
+            content.push('\n');
+        }
+        content.push('\n');
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_agent(name: &str, system_prompt: &str, scope: &[&str]) -> LinkAgent {
+        LinkAgent {
+            name: name.to_string(),
+            system_prompt: system_prompt.to_string(),
+            instructions: None,
+            output_format: None,
+            context: None,
+            description: Some(system_prompt.lines().next().unwrap_or("").to_string()),
+            tags: vec![],
+            stacks: vec![],
+            scope: scope.iter().map(|s| s.to_string()).collect(),
+            model: None,
+            model_fallback: vec![],
+            temperature: 0.7,
+            provider: None,
+        }
+    }
+
+    #[test]
+    fn test_generate_scoped_rule() {
+        let agents = vec![make_agent(
+            "Rust Dev",
+            "You write Rust.",
+            &["src/**/*.rs", "Cargo.toml"],
+        )];
+        let files = CursorLinker.generate(&agents, None, &[]);
+
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].path, PathBuf::from(".cursor/rules/rust-dev.mdc"));
+        assert_eq!(
+            files[0].content,
+            "---\ndescription: You write Rust.\nglobs: src/**/*.rs,Cargo.toml\n\
+             alwaysApply: false\n---\n\nYou write Rust.\n"
+        );
+    }
+
+    #[test]
+    fn test_unscoped_rule_has_empty_globs() {
+        let files = CursorLinker.generate(&[make_agent("Helper", "Help.", &[])], None, &[]);
+        assert!(files[0].content.contains("globs: \nalwaysApply: false\n"));
+    }
+
+    #[test]
+    fn test_coordinator_always_applies_with_team() {
+        let agents = vec![make_agent("Rust Dev", "You write Rust.", &[])];
+        let coord = make_agent("Lead", "You coordinate.", &[]);
+        let files = CursorLinker.generate(&agents, Some(&coord), &[]);
+
+        assert_eq!(files.len(), 2);
+        assert_eq!(files[1].path, PathBuf::from(".cursor/rules/lead.mdc"));
+        assert!(files[1].content.contains("alwaysApply: true\n"));
+        assert!(files[1].content.contains("| @rust-dev | You write Rust. |"));
+        assert!(files[1].content.contains("ARMADAI_END"));
+    }
+}
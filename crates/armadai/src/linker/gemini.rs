@@ -99,7 +99,11 @@ fn generate_agent_file(agent: &LinkAgent) -> OutputFile {
         content.push('\n');
     }
 
-    OutputFile { path, content }
+    OutputFile {
+        path,
+        content,
+        source: Some(agent.name.clone()),
+    }
 }
 
 /// Generate the `GEMINI.md` coordinator context document.
@@ -197,6 +201,7 @@ fn generate_agents_md(agents: &[LinkAgent], coordinator: Option<&LinkAgent>) ->
     OutputFile {
         path: PathBuf::from(".gemini/GEMINI.md"),
         content,
+        source: coordinator.map(|c| c.name.clone()),
     }
 }
 
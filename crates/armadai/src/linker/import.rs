@@ -0,0 +1,663 @@
+//! `armadai import`: pull agents edited in a native CLI's files back into
+//! the ArmadAI library.
+//!
+//! Both sides are reduced to an [`AgentView`] (portable model, scope and
+//! the prompt sections a linker writes), so a native file generated by
+//! `armadai link` and the library agent it came from compare equal. The
+//! canonical text accepted at the last import is kept in
+//! `.armadai/import-base.json`, the base of the three-way comparison that
+//! tells a native edit from a library edit.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::audit::proposal::{demote_headings, portable_model};
+use crate::audit::reverse::ImportedAgent;
+use armadai_core::agent::Agent;
+
+/// Base store location, relative to the project root.
+pub const BASE_PATH: &str = ".armadai/import-base.json";
+
+const BASE_VERSION: u32 = 1;
+
+/// Section headings the linkers write after the system prompt, in order.
+const SECTIONS: [&str; 3] = ["Instructions", "Output Format", "Context"];
+
+/// The parts of an agent a native file carries.
+///
+/// `model` and `scope` are `None` when the native file does not say (a
+/// Copilot agent has no model, only Cursor rules carry globs); such a
+/// field is taken from the library side rather than reported as a change.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AgentView {
+    pub description: Option<String>,
+    pub model: Option<String>,
+    pub scope: Option<Vec<String>>,
+    pub system_prompt: String,
+    pub instructions: Option<String>,
+    pub output_format: Option<String>,
+    pub context: Option<String>,
+}
+
+impl AgentView {
+    /// View of an agent parsed from a native CLI's files.
+    pub fn from_native(agent: &ImportedAgent, from: &str) -> Self {
+        let mut body = agent.system_prompt.as_str();
+        if let Some(at) = body.find(super::armadai_protocol_block().trim()) {
+            body = body[..at].trim_end();
+        }
+        let body = if from == "copilot" {
+            strip_repeated_lead(body)
+        } else {
+            body.to_string()
+        };
+        let scope = agent.metadata.scope_globs();
+        Self {
+            description: agent.metadata.description.clone(),
+            model: agent
+                .metadata
+                .model
+                .as_deref()
+                .map(|m| portable_model(Some(m))),
+            scope: (!scope.is_empty()).then_some(scope),
+            ..Self::from_body(&body)
+        }
+    }
+
+    /// View of a library agent, file-backed or declared.
+    pub fn from_library(agent: &Agent) -> Self {
+        let sections = Self {
+            system_prompt: agent.system_prompt.clone(),
+            instructions: agent.instructions.clone(),
+            output_format: agent.output_format.clone(),
+            context: agent.context.clone(),
+            ..Self::default()
+        };
+        // A declared agent's fragments may carry the section headings in
+        // its prompt; re-splitting the linked body makes both shapes equal.
+        Self {
+            description: None,
+            model: Some(portable_model(agent.metadata.model.as_deref())),
+            scope: Some(agent.metadata.scope.clone()),
+            ..Self::from_body(&sections.linked_body())
+        }
+    }
+
+    /// Split a prompt as the linkers write it into its sections.
+    fn from_body(body: &str) -> Self {
+        let mut parts: [Vec<&str>; 4] = Default::default();
+        let mut current = 0;
+        let mut in_fence = false;
+        for line in body.lines() {
+            let trimmed = line.trim();
+            if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+                in_fence = !in_fence;
+            }
+            if !in_fence
+                && let Some(title) = trimmed.strip_prefix("## ")
+                && let Some(i) = SECTIONS.iter().position(|s| *s == title.trim())
+                && i + 1 > current
+            {
+                current = i + 1;
+                continue;
+            }
+            parts[current].push(line);
+        }
+        let text = |lines: &[&str]| lines.join("\n").trim().to_string();
+        let section = |lines: &[&str]| Some(text(lines)).filter(|t| !t.is_empty());
+        Self {
+            system_prompt: text(&parts[0]),
+            instructions: section(&parts[1]),
+            output_format: section(&parts[2]),
+            context: section(&parts[3]),
+            ..Self::default()
+        }
+    }
+
+    fn sections(&self) -> [Option<&str>; 3] {
+        [
+            self.instructions.as_deref(),
+            self.output_format.as_deref(),
+            self.context.as_deref(),
+        ]
+    }
+
+    /// The prompt as one text, sections under the headings a linker uses.
+    pub fn linked_body(&self) -> String {
+        let mut out = self.system_prompt.trim().to_string();
+        for (title, text) in SECTIONS.iter().zip(self.sections()) {
+            if let Some(text) = text {
+                out.push_str(&format!("\n\n## {title}\n\n{}", text.trim()));
+            }
+        }
+        out
+    }
+
+    /// Take `model` and `scope` from `library` where this view has none.
+    pub fn inherit_unset(&mut self, library: &AgentView) {
+        if self.model.is_none() {
+            self.model = library.model.clone();
+        }
+        if self.scope.is_none() {
+            self.scope = library.scope.clone();
+        }
+    }
+
+    /// Normalised text compared, diffed and stored as the import base.
+    /// Headings inside sections are demoted as they would be once written
+    /// into an agent file, so the round trip does not read as a change.
+    pub fn canonical(&self) -> String {
+        let demoted = Self {
+            system_prompt: demote_headings(&self.system_prompt),
+            instructions: self.instructions.as_deref().map(demote_headings),
+            output_format: self.output_format.as_deref().map(demote_headings),
+            context: self.context.as_deref().map(demote_headings),
+            ..Self::default()
+        };
+        let scope = self.scope.as_deref().unwrap_or_default();
+        format!(
+            "model: {}\nscope: {}\n\n{}\n",
+            self.model.as_deref().unwrap_or("-"),
+            if scope.is_empty() {
+                "-".to_string()
+            } else {
+                scope.join(", ")
+            },
+            demoted.linked_body()
+        )
+    }
+}
+
+/// The Copilot linker opens each agent file with its description, which
+/// defaults to the prompt's first line: drop that line when it repeats.
+fn strip_repeated_lead(body: &str) -> String {
+    let mut lines = body.lines().filter(|l| !l.trim().is_empty());
+    match (lines.next(), lines.next()) {
+        (Some(first), Some(second)) if first.trim() == second.trim() => {
+            let at = body.find(first).unwrap_or(0) + first.len();
+            body[at..].trim_start().to_string()
+        }
+        _ => body.to_string(),
+    }
+}
+
+/// Where an agent stands between the library, the native file and the base.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SyncStatus {
+    /// No library agent of that name.
+    New,
+    InSync,
+    /// Only the library changed since the last import; `link` will carry it.
+    LibraryChanged,
+    /// Only the native file changed since the last import.
+    NativeChanged,
+    /// Both changed since the last import.
+    Conflict,
+    /// They differ and no import recorded a base to tell which side moved.
+    Differs,
+}
+
+impl SyncStatus {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::New => "new",
+            Self::InSync => "in sync",
+            Self::LibraryChanged => "library changed",
+            Self::NativeChanged => "native changed",
+            Self::Conflict => "conflict",
+            Self::Differs => "differs",
+        }
+    }
+
+    /// Whether `--yes` accepts the native side without asking.
+    pub fn auto_accept(self) -> bool {
+        matches!(self, Self::New | Self::NativeChanged | Self::Differs)
+    }
+}
+
+/// Three-way classification of canonical texts.
+pub fn classify(base: Option<&str>, library: Option<&str>, native: &str) -> SyncStatus {
+    let Some(library) = library else {
+        return SyncStatus::New;
+    };
+    if library == native {
+        return SyncStatus::InSync;
+    }
+    match base {
+        None => SyncStatus::Differs,
+        Some(base) if base == library => SyncStatus::NativeChanged,
+        Some(base) if base == native => SyncStatus::LibraryChanged,
+        Some(_) => SyncStatus::Conflict,
+    }
+}
+
+/// Canonical texts accepted by previous imports, keyed `<cli>/<slug>`.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct ImportBase {
+    #[serde(default)]
+    pub version: u32,
+    #[serde(default)]
+    pub agents: BTreeMap<String, String>,
+}
+
+impl ImportBase {
+    /// Load the project's base store; a missing file is an empty store.
+    pub fn load(root: &Path) -> anyhow::Result<Self> {
+        let path = root.join(BASE_PATH);
+        match std::fs::read_to_string(&path) {
+            Ok(text) => serde_json::from_str(&text)
+                .map_err(|e| anyhow::anyhow!("Invalid import base {}: {e}", path.display())),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub fn save(&mut self, root: &Path) -> anyhow::Result<()> {
+        self.version = BASE_VERSION;
+        let path = root.join(BASE_PATH);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&path, serde_json::to_string_pretty(self)? + "\n")?;
+        Ok(())
+    }
+
+    pub fn key(from: &str, slug: &str) -> String {
+        format!("{from}/{slug}")
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Agent Markdown files
+// ---------------------------------------------------------------------------
+
+/// One `## ` section of an agent file; the preamble has no title.
+struct Block {
+    title: Option<String>,
+    lines: Vec<String>,
+}
+
+fn split_blocks(text: &str) -> Vec<Block> {
+    let mut blocks = vec![Block {
+        title: None,
+        lines: Vec::new(),
+    }];
+    let mut in_fence = false;
+    for line in text.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_fence = !in_fence;
+        }
+        if !in_fence && let Some(title) = line.strip_prefix("## ") {
+            blocks.push(Block {
+                title: Some(title.trim().to_string()),
+                lines: vec![line.to_string()],
+            });
+        } else if let Some(block) = blocks.last_mut() {
+            block.lines.push(line.to_string());
+        }
+    }
+    blocks
+}
+
+fn section_block(title: &str, text: &str) -> Block {
+    let mut lines = vec![format!("## {title}"), String::new()];
+    lines.extend(demote_headings(text.trim()).lines().map(str::to_string));
+    lines.push(String::new());
+    Block {
+        title: Some(title.to_string()),
+        lines,
+    }
+}
+
+/// Set or add a `- key: value` line of the Metadata section.
+fn set_metadata_line(lines: &mut Vec<String>, key: &str, value: &str) {
+    let entry = format!("- {key}: {value}");
+    let prefix = format!("- {key}:");
+    if let Some(line) = lines.iter_mut().find(|l| l.trim().starts_with(&prefix)) {
+        *line = entry;
+        return;
+    }
+    let at = lines
+        .iter()
+        .rposition(|l| l.trim_start().starts_with("- "))
+        .map_or(lines.len(), |i| i + 1);
+    lines.insert(at, entry);
+}
+
+/// Rewrite an ArmadAI agent file so its prompt sections, and the model and
+/// scope when `view` sets them, match `view`. Other sections (Pipeline,
+/// Triggers, ...) and metadata lines are kept as written.
+pub fn rewrite_agent_md(text: &str, view: &AgentView) -> String {
+    let mut blocks = split_blocks(text);
+
+    if let Some(meta) = blocks
+        .iter_mut()
+        .find(|b| b.title.as_deref() == Some("Metadata"))
+    {
+        if let Some(model) = &view.model {
+            set_metadata_line(&mut meta.lines, "model", model);
+        }
+        match view.scope.as_deref() {
+            Some([]) => meta
+                .lines
+                .retain(|l| !l.trim_start().starts_with("- scope:")),
+            Some(scope) => {
+                set_metadata_line(&mut meta.lines, "scope", &format!("[{}]", scope.join(", ")))
+            }
+            None => {}
+        }
+    }
+
+    let wanted: [(&str, Option<&str>); 4] = [
+        ("System Prompt", Some(view.system_prompt.as_str())),
+        (SECTIONS[0], view.instructions.as_deref()),
+        (SECTIONS[1], view.output_format.as_deref()),
+        (SECTIONS[2], view.context.as_deref()),
+    ];
+    let mut blocks: Vec<Block> = blocks
+        .into_iter()
+        .filter_map(
+            |b| match wanted.iter().find(|(t, _)| b.title.as_deref() == Some(t)) {
+                Some((title, Some(text))) => Some(section_block(title, text)),
+                Some((_, None)) => None,
+                None => Some(b),
+            },
+        )
+        .collect();
+    // Sections the file lacks go right after the previous prompt section,
+    // else after the metadata.
+    for (i, (title, text)) in wanted.iter().enumerate() {
+        let Some(text) = text else { continue };
+        if blocks.iter().any(|b| b.title.as_deref() == Some(title)) {
+            continue;
+        }
+        let anchor = wanted[..i]
+            .iter()
+            .rev()
+            .chain(std::iter::once(&("Metadata", None)))
+            .find_map(|(t, _)| blocks.iter().position(|b| b.title.as_deref() == Some(t)));
+        let at = anchor.map_or(blocks.len(), |p| p + 1);
+        blocks.insert(at, section_block(title, text));
+    }
+
+    let mut out = String::new();
+    for block in &blocks {
+        let mut body = block.lines.join("\n");
+        if block.title.is_some() || !body.trim().is_empty() {
+            body = body.trim_end().to_string();
+            out.push_str(&body);
+            out.push_str("\n\n");
+        }
+    }
+    out.truncate(out.trim_end().len());
+    out.push('\n');
+    out
+}
+
+/// A new agent file in the ArmadAI format.
+pub fn new_agent_md(name: &str, provider: &str, view: &AgentView) -> String {
+    rewrite_agent_md(
+        &format!("# {name}\n\n## Metadata\n- provider: {provider}\n"),
+        view,
+    )
+}
+
+// ---------------------------------------------------------------------------
+// `.armadai/agents.yaml`
+// ---------------------------------------------------------------------------
+
+/// A YAML scalar in its plainest valid spelling.
+pub fn yaml_scalar(value: &str) -> String {
+    serde_yaml_ng::to_string(value)
+        .map(|s| s.trim_end().to_string())
+        .unwrap_or_else(|_| format!("{value:?}"))
+}
+
+/// A one-line YAML flow list.
+pub fn yaml_list(items: &[String]) -> String {
+    let items: Vec<String> = items.iter().map(|s| yaml_scalar(s)).collect();
+    format!("[{}]", items.join(", "))
+}
+
+fn indent_of(line: &str) -> usize {
+    line.len() - line.trim_start().len()
+}
+
+fn is_item_of(line: &str, indent: usize) -> bool {
+    indent_of(line) == indent && line.trim_start().starts_with("- ")
+}
+
+/// Line range `[start, end)` of the top-level `agents:` block, and the
+/// indentation of its items. `None` when there is no such key.
+fn agents_block(lines: &[&str]) -> Option<(usize, usize, usize)> {
+    let start = lines.iter().position(|l| l.starts_with("agents:"))?;
+    let mut end = start + 1;
+    let mut item_indent = None;
+    while end < lines.len() {
+        let line = lines[end];
+        let content = line.trim();
+        if !content.is_empty() && !content.starts_with('#') {
+            if indent_of(line) == 0 && !content.starts_with("- ") {
+                break;
+            }
+            if item_indent.is_none() && content.starts_with("- ") {
+                item_indent = Some(indent_of(line));
+            }
+        }
+        end += 1;
+    }
+    // Trailing blank lines and comments belong to whatever follows.
+    while end > start + 1 && {
+        let c = lines[end - 1].trim();
+        c.is_empty() || c.starts_with('#')
+    } {
+        end -= 1;
+    }
+    Some((start, end, item_indent.unwrap_or(2)))
+}
+
+/// Append one agent entry (`key: value` lines, values already YAML) to the
+/// `agents:` list of a declarations file, keeping comments and layout.
+pub fn append_declared(yaml: &str, fields: &[(&str, String)]) -> String {
+    let lines: Vec<&str> = yaml.lines().collect();
+    let (mut out, insert_at, indent): (Vec<String>, usize, usize) = match agents_block(&lines) {
+        Some((start, end, indent)) => {
+            let mut out: Vec<String> = lines.iter().map(|l| l.to_string()).collect();
+            if out[start].trim_end() != "agents:" {
+                // `agents: []` or another inline value.
+                out[start] = "agents:".to_string();
+            }
+            (out, end, indent)
+        }
+        None => {
+            let mut out: Vec<String> = lines.iter().map(|l| l.to_string()).collect();
+            if out.last().is_some_and(|l| !l.trim().is_empty()) {
+                out.push(String::new());
+            }
+            out.push("agents:".to_string());
+            let at = out.len();
+            (out, at, 2)
+        }
+    };
+    let pad = " ".repeat(indent);
+    let entry: Vec<String> = fields
+        .iter()
+        .enumerate()
+        .map(|(i, (key, value))| {
+            let lead = if i == 0 { "- " } else { "  " };
+            format!("{pad}{lead}{key}: {value}")
+        })
+        .collect();
+    out.splice(insert_at..insert_at, entry);
+    out.join("\n") + "\n"
+}
+
+/// Set `key: value` (value already YAML) in the declared agent `name`,
+/// replacing a previous value written on one or more lines. `None` when
+/// the file declares no such agent.
+pub fn set_declared_field(yaml: &str, name: &str, key: &str, value: &str) -> Option<String> {
+    let lines: Vec<&str> = yaml.lines().collect();
+    let (start, end, indent) = agents_block(&lines)?;
+    let field_indent = indent + 2;
+    let is_name = |l: &str| {
+        l.trim_start()
+            .strip_prefix("- name:")
+            .is_some_and(|v| v.trim().trim_matches(|c| c == '"' || c == '\'') == name)
+    };
+    let item = (start + 1..end).find(|&i| is_item_of(lines[i], indent) && is_name(lines[i]))?;
+    let item_end = (item + 1..end)
+        .find(|&i| is_item_of(lines[i], indent))
+        .unwrap_or(end);
+
+    let mut out: Vec<String> = lines.iter().map(|l| l.to_string()).collect();
+    let entry = format!("{}{key}: {value}", " ".repeat(field_indent));
+    let prefix = format!("{key}:");
+    match (item + 1..item_end).find(|&i| {
+        indent_of(lines[i]) == field_indent && lines[i].trim_start().starts_with(&prefix)
+    }) {
+        Some(at) => {
+            // A block value continues on deeper-indented lines.
+            let value_end = (at + 1..item_end)
+                .find(|&i| {
+                    let l = lines[i];
+                    !l.trim().is_empty()
+                        && (indent_of(l) < field_indent
+                            || (indent_of(l) == field_indent && !l.trim_start().starts_with("- ")))
+                })
+                .unwrap_or(item_end);
+            out.splice(at..value_end, [entry]);
+        }
+        None => out.insert(item + 1, entry),
+    }
+    Some(out.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audit::reverse::PartialMetadata;
+
+    fn parse(text: &str) -> Agent {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("agent.md");
+        std::fs::write(&path, text).unwrap();
+        armadai_core::parser::parse_agent_file(&path).unwrap()
+    }
+
+    fn native(prompt: &str, model: Option<&str>) -> ImportedAgent {
+        ImportedAgent {
+            name: "reviewer".into(),
+            source_path: ".claude/agents/reviewer.md".into(),
+            metadata: PartialMetadata {
+                model: model.map(str::to_string),
+                ..PartialMetadata::default()
+            },
+            system_prompt: prompt.into(),
+            issues: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn linked_native_agent_round_trips_to_its_library_view() {
+        let agent = parse(
+            "# Reviewer\n\n## Metadata\n- provider: claude\n- model: latest:pro\n\n\
+             ## System Prompt\n\nReview code.\n\n## Instructions\n\nBe terse.\n",
+        );
+        let library = AgentView::from_library(&agent);
+        let mut imported = AgentView::from_native(
+            &native(
+                "Review code.\n\n## Instructions\n\nBe terse.",
+                Some("claude-sonnet-4-5"),
+            ),
+            "claude",
+        );
+        imported.inherit_unset(&library);
+        assert_eq!(imported.instructions.as_deref(), Some("Be terse."));
+        assert_eq!(imported.canonical(), library.canonical());
+
+        let copilot = AgentView::from_native(
+            &native(
+                "Review code.\n\nReview code.\n\n## Instructions\n\nBe terse.",
+                None,
+            ),
+            "copilot",
+        );
+        assert_eq!(copilot.system_prompt, "Review code.");
+        assert!(copilot.model.is_none());
+    }
+
+    #[test]
+    fn classify_uses_the_base_to_tell_sides_apart() {
+        assert_eq!(classify(None, None, "a"), SyncStatus::New);
+        assert_eq!(classify(None, Some("a"), "a"), SyncStatus::InSync);
+        assert_eq!(classify(None, Some("a"), "b"), SyncStatus::Differs);
+        assert_eq!(
+            classify(Some("a"), Some("a"), "b"),
+            SyncStatus::NativeChanged
+        );
+        assert_eq!(
+            classify(Some("b"), Some("a"), "b"),
+            SyncStatus::LibraryChanged
+        );
+        assert_eq!(classify(Some("c"), Some("a"), "b"), SyncStatus::Conflict);
+    }
+
+    #[test]
+    fn rewrite_replaces_prompt_sections_and_keeps_the_rest() {
+        let original = "# Reviewer\n\n## Metadata\n- provider: claude\n- model: latest:fast\n- tags: [review]\n\n\
+                        ## System Prompt\n\nOld prompt.\n\n## Output Format\n\nA list.\n\n\
+                        ## Pipeline\n- next: writer\n";
+        let view = AgentView {
+            model: Some("latest:pro".into()),
+            scope: Some(vec!["src/**".into()]),
+            system_prompt: "New prompt.\n\n# Rules\n\nNone.".into(),
+            instructions: Some("Be terse.".into()),
+            ..AgentView::default()
+        };
+        let out = rewrite_agent_md(original, &view);
+        assert_eq!(
+            out,
+            "# Reviewer\n\n## Metadata\n- provider: claude\n- model: latest:pro\n- tags: [review]\n\
+             - scope: [src/**]\n\n## System Prompt\n\nNew prompt.\n\n**Rules**\n\nNone.\n\n\
+             ## Instructions\n\nBe terse.\n\n## Pipeline\n- next: writer\n"
+        );
+        let reparsed = parse(&out);
+        assert_eq!(reparsed.metadata.scope, vec!["src/**"]);
+        assert!(reparsed.output_format.is_none());
+        assert!(reparsed.pipeline.is_some());
+    }
+
+    #[test]
+    fn declarations_are_appended_and_edited_in_place() {
+        let yaml = "# Team\ndefaults:\n  provider: claude\n\nagents:\n  - name: dev\n    model: latest:fast\n    scope:\n      - src/**\n    prompt: [dev]\n\n# trailing note\n";
+        let added = append_declared(
+            yaml,
+            &[
+                ("name", "reviewer".to_string()),
+                ("prompt", yaml_list(&["reviewer".to_string()])),
+            ],
+        );
+        assert!(added.contains(
+            "    prompt: [dev]\n  - name: reviewer\n    prompt: [reviewer]\n\n# trailing note"
+        ));
+        let decls: armadai_core::agent_decl::DeclaredAgents =
+            serde_yaml_ng::from_str(&added).unwrap();
+        assert_eq!(decls.agents.len(), 2);
+
+        let edited =
+            set_declared_field(&added, "dev", "scope", &yaml_list(&["docs/**".into()])).unwrap();
+        let edited = set_declared_field(&edited, "reviewer", "model", "latest:max").unwrap();
+        let decls: armadai_core::agent_decl::DeclaredAgents =
+            serde_yaml_ng::from_str(&edited).unwrap();
+        assert_eq!(decls.agents[0].scope, Some(vec!["docs/**".to_string()]));
+        assert_eq!(decls.agents[0].prompt.len(), 1);
+        assert_eq!(decls.agents[1].model.as_deref(), Some("latest:max"));
+        assert!(set_declared_field(&edited, "ghost", "model", "x").is_none());
+
+        let empty = append_declared("agents: []\n", &[("name", "a".to_string())]);
+        assert_eq!(empty, "agents:\n  - name: a\n");
+    }
+}
@@ -0,0 +1,411 @@
+//! Record of the files `armadai link` generated, used to detect drift.
+//!
+//! Stored at `.armadai/link-manifest.json`. Each entry holds the hash of the
+//! content `link` last wrote, so a later run can tell a generated file that
+//! was hand-edited (drifted) from one that is merely out of date with its
+//! source (stale), and spot files whose agent no longer exists (orphaned).
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// Manifest location, relative to the project root.
+pub const MANIFEST_PATH: &str = ".armadai/link-manifest.json";
+
+const MANIFEST_VERSION: u32 = 1;
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct LinkManifest {
+    #[serde(default)]
+    pub version: u32,
+    /// Per-target settings of the last link, keyed by target name.
+    #[serde(default)]
+    pub targets: BTreeMap<String, TargetRecord>,
+    #[serde(default)]
+    pub files: Vec<ManifestEntry>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct TargetRecord {
+    /// `--model` used (or picked interactively) for orchestrator targets, so
+    /// `--check` regenerates with the same model instead of prompting.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ManifestEntry {
+    /// Path relative to the project root, `/`-separated.
+    pub path: String,
+    /// `sha256:<hex>` of the content `link` wrote.
+    pub hash: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+    pub target: String,
+}
+
+/// A file the current config would generate.
+pub struct Planned<'a> {
+    pub path: &'a Path,
+    pub content: &'a str,
+    pub source: Option<&'a str>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileStatus {
+    /// On disk, identical to what `link` would write now.
+    Clean,
+    /// Hand-edited since the last link. `source_changed` is set when the
+    /// source agent changed too, so the edit cannot be adopted safely.
+    Drifted { source_changed: bool },
+    /// Untouched since the last link, but the source has changed since.
+    Stale,
+    /// Would be generated but is not on disk.
+    Missing,
+    /// Generated by a previous link, no longer produced by the config.
+    Orphaned,
+    /// On disk with different content, but never written by `link`.
+    Untracked,
+}
+
+impl FileStatus {
+    pub fn label(self) -> &'static str {
+        match self {
+            FileStatus::Clean => "clean",
+            FileStatus::Drifted {
+                source_changed: false,
+            } => "drifted",
+            FileStatus::Drifted {
+                source_changed: true,
+            } => "drifted+stale",
+            FileStatus::Stale => "stale",
+            FileStatus::Missing => "missing",
+            FileStatus::Orphaned => "orphaned",
+            FileStatus::Untracked => "untracked",
+        }
+    }
+
+    /// Whether `link --check` should fail on this status. Untracked files
+    /// predate the manifest and are only reported.
+    pub fn is_failure(self) -> bool {
+        !matches!(self, FileStatus::Clean | FileStatus::Untracked)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileCheck {
+    pub path: String,
+    pub status: FileStatus,
+    pub source: Option<String>,
+}
+
+/// `sha256:<hex>` of `content`.
+pub fn content_hash(content: &str) -> String {
+    let digest = Sha256::digest(content.as_bytes());
+    let hex: String = digest.iter().map(|b| format!("{b:02x}")).collect();
+    format!("sha256:{hex}")
+}
+
+/// `path` relative to `root`, `/`-separated, for manifest keys.
+pub fn relative_key(root: &Path, path: &Path) -> String {
+    let rel = path.strip_prefix(root).unwrap_or(path);
+    rel.components()
+        .map(|c| c.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+impl LinkManifest {
+    /// Load the project's manifest; a missing file is an empty manifest.
+    pub fn load(root: &Path) -> anyhow::Result<Self> {
+        let path = root.join(MANIFEST_PATH);
+        match std::fs::read_to_string(&path) {
+            Ok(text) => serde_json::from_str(&text)
+                .map_err(|e| anyhow::anyhow!("Invalid link manifest {}: {e}", path.display())),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub fn save(&mut self, root: &Path) -> anyhow::Result<()> {
+        self.version = MANIFEST_VERSION;
+        self.files.sort_by(|a, b| a.path.cmp(&b.path));
+        let path = root.join(MANIFEST_PATH);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&path, serde_json::to_string_pretty(self)? + "\n")?;
+        Ok(())
+    }
+
+    pub fn entry(&self, key: &str) -> Option<&ManifestEntry> {
+        self.files.iter().find(|e| e.path == key)
+    }
+
+    /// Insert or replace the entry for `entry.path`.
+    pub fn record(&mut self, entry: ManifestEntry) {
+        match self.files.iter_mut().find(|e| e.path == entry.path) {
+            Some(existing) => *existing = entry,
+            None => self.files.push(entry),
+        }
+    }
+
+    /// Drop the entry for `key`, if any.
+    pub fn forget(&mut self, key: &str) {
+        self.files.retain(|e| e.path != key);
+    }
+
+    /// Drop `target` entries whose file is neither planned nor on disk:
+    /// orphans that were already cleaned up by hand.
+    pub fn prune_deleted(&mut self, root: &Path, target: &str, planned: &[String]) {
+        self.files.retain(|e| {
+            e.target != target || planned.contains(&e.path) || root.join(&e.path).exists()
+        });
+    }
+
+    /// Classify every planned file and every manifest entry of `target`.
+    pub fn check(&self, root: &Path, target: &str, planned: &[Planned]) -> Vec<FileCheck> {
+        let mut checks = Vec::new();
+        let mut planned_keys = Vec::new();
+        for p in planned {
+            let key = relative_key(root, p.path);
+            let entry = self.entry(&key);
+            let status = match std::fs::read_to_string(p.path) {
+                Err(_) => FileStatus::Missing,
+                Ok(disk) if disk == p.content => FileStatus::Clean,
+                Ok(disk) => match entry {
+                    None => FileStatus::Untracked,
+                    Some(e) if e.hash == content_hash(&disk) => FileStatus::Stale,
+                    Some(e) => FileStatus::Drifted {
+                        source_changed: e.hash != content_hash(p.content),
+                    },
+                },
+            };
+            checks.push(FileCheck {
+                path: key.clone(),
+                status,
+                source: p.source.map(str::to_string),
+            });
+            planned_keys.push(key);
+        }
+        for e in &self.files {
+            if e.target == target && !planned_keys.contains(&e.path) && root.join(&e.path).exists()
+            {
+                checks.push(FileCheck {
+                    path: e.path.clone(),
+                    status: FileStatus::Orphaned,
+                    source: e.source.clone(),
+                });
+            }
+        }
+        checks
+    }
+}
+
+/// Locate a hand edit of `generated` (yielding `edited`) inside one of
+/// `sections`, the source texts the linker embedded verbatim.
+///
+/// Returns the index of the edited section and its new text. Fails when the
+/// edit touches generated scaffolding (frontmatter, headings, the protocol
+/// block) or spans more than one section.
+pub fn locate_edit(
+    generated: &str,
+    edited: &str,
+    sections: &[&str],
+) -> Result<(usize, String), String> {
+    let (g, e) = (generated.as_bytes(), edited.as_bytes());
+    let mut pre = g.iter().zip(e).take_while(|(a, b)| a == b).count();
+    while !generated.is_char_boundary(pre) {
+        pre -= 1;
+    }
+    let max_suf = g.len().min(e.len()) - pre;
+    let mut suf = g
+        .iter()
+        .rev()
+        .zip(e.iter().rev())
+        .take(max_suf)
+        .take_while(|(a, b)| a == b)
+        .count();
+    while !generated.is_char_boundary(g.len() - suf) {
+        suf -= 1;
+    }
+    let g_end = g.len() - suf;
+    if pre == g.len() && g.len() == e.len() {
+        return Err("no changes to adopt".to_string());
+    }
+
+    for (i, section) in sections.iter().enumerate() {
+        if section.is_empty() {
+            continue;
+        }
+        for (start, _) in generated.match_indices(section) {
+            let end = start + section.len();
+            if start <= pre && g_end <= end {
+                let new_end = e.len() - (g.len() - end);
+                return Ok((i, edited[start..new_end].to_string()));
+            }
+        }
+    }
+    Err(
+        "the edit is outside the agent's prompt sections (frontmatter, headings or generated text)"
+            .to_string(),
+    )
+}
+
+/// Replace the single verbatim occurrence of `old` in `source_file`.
+pub fn splice_into_source(source_file: &Path, old: &str, new: &str) -> anyhow::Result<()> {
+    let text = std::fs::read_to_string(source_file)?;
+    match text.matches(old).count() {
+        1 => {
+            std::fs::write(source_file, text.replacen(old, new, 1))?;
+            Ok(())
+        }
+        0 => anyhow::bail!(
+            "the edited section does not appear verbatim in {} (composed from fragments or escaped)",
+            source_file.display()
+        ),
+        _ => anyhow::bail!(
+            "the edited section appears more than once in {}",
+            source_file.display()
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(path: &str, content: &str) -> ManifestEntry {
+        ManifestEntry {
+            path: path.to_string(),
+            hash: content_hash(content),
+            source: Some("dev".to_string()),
+            target: "claude".to_string(),
+        }
+    }
+
+    #[test]
+    fn check_classifies_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        let write = |rel: &str, content: &str| {
+            let p = root.join(rel);
+            std::fs::create_dir_all(p.parent().unwrap()).unwrap();
+            std::fs::write(&p, content).unwrap();
+            p
+        };
+        let clean = write("a/clean.md", "same");
+        let drifted = write("a/drifted.md", "hand edit");
+        let stale = write("a/stale.md", "old");
+        let untracked = write("a/untracked.md", "mine");
+        write("a/orphan.md", "gone");
+        let missing = root.join("a/missing.md");
+
+        let mut manifest = LinkManifest::default();
+        for (p, c) in [
+            ("a/clean.md", "same"),
+            ("a/drifted.md", "gen"),
+            ("a/stale.md", "old"),
+            ("a/orphan.md", "gone"),
+            ("a/deleted.md", "x"),
+        ] {
+            manifest.record(entry(p, c));
+        }
+
+        let planned = [
+            (&clean, "same"),
+            (&drifted, "gen"),
+            (&stale, "new"),
+            (&untracked, "theirs"),
+            (&missing, "m"),
+        ];
+        let planned: Vec<Planned> = planned
+            .iter()
+            .map(|(p, c)| Planned {
+                path: p.as_path(),
+                content: c,
+                source: None,
+            })
+            .collect();
+        let status: Vec<_> = manifest
+            .check(root, "claude", &planned)
+            .into_iter()
+            .map(|c| (c.path, c.status))
+            .collect();
+        assert_eq!(
+            status,
+            vec![
+                ("a/clean.md".into(), FileStatus::Clean),
+                (
+                    "a/drifted.md".into(),
+                    FileStatus::Drifted {
+                        source_changed: false
+                    }
+                ),
+                ("a/stale.md".into(), FileStatus::Stale),
+                ("a/untracked.md".into(), FileStatus::Untracked),
+                ("a/missing.md".into(), FileStatus::Missing),
+                ("a/orphan.md".into(), FileStatus::Orphaned),
+            ]
+        );
+
+        manifest.prune_deleted(root, "claude", &[]);
+        assert!(manifest.entry("a/deleted.md").is_none());
+        assert!(manifest.entry("a/orphan.md").is_some());
+    }
+
+    #[test]
+    fn manifest_round_trips() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut manifest = LinkManifest::default();
+        manifest.record(entry("b.md", "b"));
+        manifest.record(entry("a.md", "a"));
+        manifest.record(entry("a.md", "a2"));
+        manifest.save(dir.path()).unwrap();
+        let loaded = LinkManifest::load(dir.path()).unwrap();
+        assert_eq!(loaded.version, MANIFEST_VERSION);
+        assert_eq!(loaded.files, vec![entry("a.md", "a2"), entry("b.md", "b")]);
+        assert!(
+            LinkManifest::load(&dir.path().join("none"))
+                .unwrap()
+                .files
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn locate_edit_finds_the_edited_section() {
+        let prompt = "You review code.\nBe terse.";
+        let instr = "Check tests.";
+        let generated = format!("---\nname: r\n---\n\n{prompt}\n\n## Instructions\n\n{instr}\n");
+        let edited = generated.replace("Be terse.", "Be terse and kind.");
+        assert_eq!(
+            locate_edit(&generated, &edited, &[prompt, instr]).unwrap(),
+            (0, "You review code.\nBe terse and kind.".to_string())
+        );
+
+        let edited = generated.replace("Check tests.", "Check tests and docs.");
+        assert_eq!(
+            locate_edit(&generated, &edited, &[prompt, instr])
+                .unwrap()
+                .0,
+            1
+        );
+
+        let edited = generated.replace("name: r", "name: x");
+        assert!(locate_edit(&generated, &edited, &[prompt, instr]).is_err());
+        assert!(locate_edit(&generated, &generated, &[prompt]).is_err());
+    }
+
+    #[test]
+    fn splice_requires_a_single_verbatim_match() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("a.md");
+        std::fs::write(&file, "# A\n\n## System Prompt\n\nold text\n").unwrap();
+        splice_into_source(&file, "old text", "new text").unwrap();
+        assert!(std::fs::read_to_string(&file).unwrap().contains("new text"));
+        assert!(splice_into_source(&file, "absent", "x").is_err());
+    }
+}
@@ -1,13 +1,17 @@
 mod claude;
 mod codex;
 mod copilot;
+mod cursor;
 mod gemini;
+pub mod import;
+pub mod manifest;
 pub mod model_resolution;
 mod opencode;
 
 pub use claude::ClaudeLinker;
 pub use codex::CodexLinker;
 pub use copilot::CopilotLinker;
+pub use cursor::CursorLinker;
 pub use gemini::GeminiLinker;
 pub use opencode::OpencodeLinker;
 
@@ -21,6 +25,7 @@ pub enum LinkTarget {
     Claude,
     Codex,
     Copilot,
+    Cursor,
     Gemini,
     Opencode,
 }
@@ -31,6 +36,7 @@ impl LinkTarget {
             Self::Claude => "claude",
             Self::Codex => "codex",
             Self::Copilot => "copilot",
+            Self::Cursor => "cursor",
             Self::Gemini => "gemini",
             Self::Opencode => "opencode",
         }
@@ -65,6 +71,10 @@ pub struct LinkAgent {
 pub struct OutputFile {
     pub path: PathBuf,
     pub content: String,
+    /// Agent whose prompt this file carries, recorded in the link manifest
+    /// so `link --adopt` knows where hand edits go. `None` for files that
+    /// only aggregate other agents (e.g. an index or config file).
+    pub source: Option<String>,
 }
 
 /// Trait for generating target-specific config files.
@@ -86,10 +96,11 @@ pub fn create_linker(target: &str) -> anyhow::Result<Box<dyn Linker>> {
         "claude" => Ok(Box::new(ClaudeLinker)),
         "codex" => Ok(Box::new(CodexLinker)),
         "copilot" => Ok(Box::new(CopilotLinker)),
+        "cursor" => Ok(Box::new(CursorLinker)),
         "gemini" => Ok(Box::new(GeminiLinker)),
         "opencode" => Ok(Box::new(OpencodeLinker)),
         _ => anyhow::bail!(
-            "Unknown link target: '{target}'. Supported targets: claude, codex, copilot, gemini, opencode"
+            "Unknown link target: '{target}'. Supported targets: claude, codex, copilot, cursor, gemini, opencode"
         ),
     }
 }
@@ -188,6 +199,11 @@ mod tests {
         assert!(create_linker("copilot").is_ok());
     }
 
+    #[test]
+    fn test_create_linker_cursor() {
+        assert!(create_linker("cursor").is_ok());
+    }
+
     #[test]
     fn test_create_linker_gemini() {
         assert!(create_linker("gemini").is_ok());
@@ -247,6 +263,7 @@ mod tests {
             tags: d_tags,
             stacks: d_stacks,
             scope: d_scope,
+            skills: d_skills,
             model_fallback: d_model_fallback,
             cost_limit: d_cost_limit,
             rate_limit: d_rate_limit,
@@ -267,6 +284,7 @@ mod tests {
             tags: w_tags,
             stacks: w_stacks,
             scope: w_scope,
+            skills: w_skills,
             model_fallback: w_model_fallback,
             cost_limit: w_cost_limit,
             rate_limit: w_rate_limit,
@@ -290,6 +308,7 @@ mod tests {
         assert_eq!(d_tags, w_tags, "metadata.tags diverged");
         assert_eq!(d_stacks, w_stacks, "metadata.stacks diverged");
         assert_eq!(d_scope, w_scope, "metadata.scope diverged");
+        assert_eq!(d_skills, w_skills, "metadata.skills diverged");
         assert_eq!(
             d_model_fallback, w_model_fallback,
             "metadata.model_fallback diverged"
@@ -355,7 +374,7 @@ mod tests {
     /// Run against **every** target, not just claude: a divergence that only
     /// shows in the codex projection is still a divergence.
     fn assert_projections_equal_across_targets(declared: &Agent, written: &Agent) {
-        for target in ["claude", "codex", "copilot", "gemini", "opencode"] {
+        for target in ["claude", "codex", "copilot", "cursor", "gemini", "opencode"] {
             let linker = create_linker(target).unwrap();
             let a = linker.generate(&[LinkAgent::from(declared)], None, &[]);
             let b = linker.generate(&[LinkAgent::from(written)], None, &[]);
@@ -442,8 +461,8 @@ mod tests {
                 dir.path().join(".armadai/agents.yaml"),
                 "defaults:\n  provider: claude\n  model: latest:pro\n  temperature: 0.3\n\
                  agents:\n  - name: core-specialist\n    description: Core domain\n    \
-                 tags: [rust, domain]\n    scope: [src/core/**]\n    max_tokens: 8192\n    \
-                 timeout: 45\n    model_fallback: [latest:fast]\n    prompt: [base]\n",
+                 tags: [rust, domain]\n    scope: [src/core/**]\n    skills: [repo-tools]\n    \
+                 max_tokens: 8192\n    timeout: 45\n    model_fallback: [latest:fast]\n    prompt: [base]\n",
             )
             .unwrap();
             // Loaded through `load_agent_by_name`, the actual production
@@ -468,8 +487,8 @@ mod tests {
                 &md,
                 "# core-specialist\n\n## Metadata\n\
                  - provider: claude\n- model: latest:pro\n- temperature: 0.3\n\
-                 - tags: [rust, domain]\n- scope: [src/core/**]\n- max_tokens: 8192\n\
-                 - timeout: 45\n- model_fallback: [latest:fast]\n\n## System Prompt\n\nYou own the core domain.\n",
+                 - tags: [rust, domain]\n- scope: [src/core/**]\n- skills: [repo-tools]\n\
+                 - max_tokens: 8192\n- timeout: 45\n- model_fallback: [latest:fast]\n\n## System Prompt\n\nYou own the core domain.\n",
             )
             .unwrap();
             let written = armadai_core::parser::parse_agent_file(&md).unwrap();
@@ -7,6 +7,8 @@ pub enum TargetKind {
     LlmEditor { provider: &'static str },
     /// Target is an orchestrator that can use any model (needs explicit --model).
     Orchestrator,
+    /// Target files carry no model at all (e.g. Cursor rules).
+    ModelAgnostic,
 }
 
 /// Classify a link target name into its kind.
@@ -17,6 +19,7 @@ pub fn classify_target(target: &str) -> TargetKind {
         },
         "gemini" => TargetKind::LlmEditor { provider: "google" },
         "codex" => TargetKind::LlmEditor { provider: "openai" },
+        "cursor" => TargetKind::ModelAgnostic,
         // copilot, opencode, etc.
         _ => TargetKind::Orchestrator,
     }
@@ -130,6 +133,7 @@ pub fn preview_model_resolution(agent_model: Option<&str>) -> Vec<(&'static str,
                 TargetKind::LlmEditor { provider } => {
                     resolve_model_for_tier(provider, tier.unwrap_or(ModelTier::Pro))
                 }
+                TargetKind::ModelAgnostic => "(not used)".to_string(),
                 TargetKind::Orchestrator => {
                     if let Some(t) = tier {
                         // Resolve against anthropic as default for preview
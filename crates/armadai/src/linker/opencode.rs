@@ -95,7 +95,11 @@ fn generate_agent_file(agent: &LinkAgent) -> OutputFile {
         content.push('\n');
     }
 
-    OutputFile { path, content }
+    OutputFile {
+        path,
+        content,
+        source: Some(agent.name.clone()),
+    }
 }
 
 /// Generate `.opencode/instructions.md` with the coordinator's prompt and a team roster.
@@ -140,6 +144,7 @@ fn generate_instructions_md(coordinator: &LinkAgent, agents: &[LinkAgent]) -> Ou
     OutputFile {
         path: PathBuf::from(".opencode/instructions.md"),
         content,
+        source: Some(coordinator.name.clone()),
     }
 }
 
@@ -0,0 +1,786 @@
+//! `armadai.lock`: what `registry add`, `skills add` and remote starter
+//! packs installed, and from exactly where.
+//!
+//! Each entry records the asset's source URL, its path in that source, the
+//! git revision it was taken at and a hash of its upstream content, so a
+//! teammate's `armadai sync --locked` reinstalls byte-for-byte the same
+//! files, and `armadai outdated` / `upgrade` can tell when upstream moved.
+//! Content at a locked revision is read straight from the cached clone's
+//! object store (`git ls-tree` + `git cat-file`), never by moving its
+//! checkout, so registry searches keep seeing the latest sync.
+//!
+//! The lockfile sits at the project root when inside a project (next to
+//! `.armadai/`, to be committed), else in the user config directory.
+
+use std::path::{Component, Path, PathBuf};
+use std::process::Command;
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use armadai_core::config::{config_dir, registry_cache_dir, user_agents_dir, user_skills_dir};
+use armadai_core::registries::RegistrySource;
+
+pub const LOCK_FILE: &str = "armadai.lock";
+
+const HEADER: &str = "# armadai.lock — generated by armadai, do not edit by hand.\n\
+                      # Reproduce with `armadai sync --locked`.\n";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AssetKind {
+    Agent,
+    Skill,
+    Starter,
+}
+
+impl AssetKind {
+    pub fn label(self) -> &'static str {
+        match self {
+            AssetKind::Agent => "agent",
+            AssetKind::Skill => "skill",
+            AssetKind::Starter => "starter",
+        }
+    }
+}
+
+/// One imported asset, pinned.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LockedAsset {
+    pub kind: AssetKind,
+    pub name: String,
+    /// Source URL (git repository or archive).
+    pub source: String,
+    /// Path of the asset inside the source: a file for agents, a directory
+    /// for skills and starter packs.
+    pub path: String,
+    /// Commit the asset was taken at; `None` for archive sources.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub revision: Option<String>,
+    /// [`integrity`] of the upstream content at `revision`.
+    pub integrity: String,
+}
+
+impl LockedAsset {
+    /// Refuse an entry whose fields would reach outside where it installs
+    /// or is read from: a lockfile comes with a project, from anyone.
+    pub fn validate(&self) -> anyhow::Result<()> {
+        let what = format!("{} '{}'", self.kind.label(), self.name);
+        if !is_single_component(&self.name) {
+            anyhow::bail!("{what}: name must be a single path component");
+        }
+        check_source(&self.source).map_err(|e| anyhow::anyhow!("{what}: {e}"))?;
+        if !is_relative_inside(&self.path) {
+            anyhow::bail!("{what}: path '{}' must stay inside its source", self.path);
+        }
+        if let Some(rev) = &self.revision {
+            check_revision(rev).map_err(|e| anyhow::anyhow!("{what}: {e}"))?;
+        }
+        Ok(())
+    }
+}
+
+/// `name` is one plain file or directory name.
+fn is_single_component(name: &str) -> bool {
+    let mut components = Path::new(name).components();
+    matches!(components.next(), Some(Component::Normal(_)))
+        && components.next().is_none()
+        && !name.contains('\\')
+}
+
+/// `source` is an `https://`, `ssh://` (or scp-like `user@host:path`) or
+/// `file://` URL: nothing `git clone` would read as an option or hand to
+/// another transport.
+fn check_source(source: &str) -> anyhow::Result<()> {
+    let url = ["https://", "ssh://", "file://"]
+        .iter()
+        .any(|scheme| source.starts_with(scheme))
+        || is_scp_like(source);
+    if source.starts_with('-') || source.chars().any(char::is_control) || !url {
+        anyhow::bail!("invalid source '{source}': expected an https, ssh or file URL");
+    }
+    Ok(())
+}
+
+/// `user@host:path`, git's short form of an ssh URL.
+fn is_scp_like(source: &str) -> bool {
+    let Some((user_host, path)) = source.split_once(':') else {
+        return false;
+    };
+    let Some((user, host)) = user_host.split_once('@') else {
+        return false;
+    };
+    let plain = |s: &str| {
+        !s.is_empty()
+            && !s.starts_with('-')
+            && s.bytes()
+                .all(|b| b.is_ascii_alphanumeric() || b"._-".contains(&b))
+    };
+    plain(user) && plain(host) && !path.is_empty() && !path.starts_with(':')
+}
+
+/// `path` is relative and never climbs out of where it starts (`.` is the
+/// source root itself).
+fn is_relative_inside(path: &str) -> bool {
+    !path.is_empty()
+        && !path.contains('\\')
+        && Path::new(path)
+            .components()
+            .all(|c| matches!(c, Component::Normal(_) | Component::CurDir))
+}
+
+/// A full git object id: 40 (SHA-1) or 64 (SHA-256) lowercase hex digits.
+fn check_revision(rev: &str) -> anyhow::Result<()> {
+    let hex = rev
+        .bytes()
+        .all(|b| b.is_ascii_digit() || (b'a'..=b'f').contains(&b));
+    if !hex || !(rev.len() == 40 || rev.len() == 64) {
+        anyhow::bail!("invalid revision '{rev}': expected a full commit id");
+    }
+    Ok(())
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Lockfile {
+    pub version: u32,
+    #[serde(default)]
+    pub assets: Vec<LockedAsset>,
+}
+
+impl Default for Lockfile {
+    fn default() -> Self {
+        Self {
+            version: 1,
+            assets: Vec::new(),
+        }
+    }
+}
+
+impl Lockfile {
+    /// The lockfile in use: `<project root>/armadai.lock` inside a project,
+    /// else `<config dir>/armadai.lock`.
+    pub fn path() -> PathBuf {
+        armadai_core::project::find_project_config()
+            .map(|(root, _)| root)
+            .unwrap_or_else(config_dir)
+            .join(LOCK_FILE)
+    }
+
+    /// Read `path`; an absent file is an empty lockfile.
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let text = std::fs::read_to_string(path)?;
+        let lock: Self = serde_yaml_ng::from_str(&text)
+            .map_err(|e| anyhow::anyhow!("invalid lockfile {}: {e}", path.display()))?;
+        for asset in &lock.assets {
+            asset
+                .validate()
+                .map_err(|e| anyhow::anyhow!("invalid lockfile {}: {e}", path.display()))?;
+        }
+        Ok(lock)
+    }
+
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let body = serde_yaml_ng::to_string(self)?;
+        std::fs::write(path, format!("{HEADER}{body}"))?;
+        Ok(())
+    }
+
+    /// Add `asset`, replacing the entry of the same kind and name. Entries
+    /// stay sorted so the file diffs cleanly.
+    pub fn upsert(&mut self, asset: LockedAsset) {
+        self.assets
+            .retain(|a| !(a.kind == asset.kind && a.name == asset.name));
+        self.assets.push(asset);
+        self.assets
+            .sort_by(|a, b| (a.kind, &a.name).cmp(&(b.kind, &b.name)));
+    }
+}
+
+/// Pin `asset` in the current lockfile. Returns the lockfile written.
+pub fn record(asset: LockedAsset) -> anyhow::Result<PathBuf> {
+    asset.validate()?;
+    let path = Lockfile::path();
+    let mut lock = Lockfile::load(&path)?;
+    lock.upsert(asset);
+    lock.save(&path)?;
+    Ok(path)
+}
+
+/// [`record`], reporting a failure as a warning: the install it follows
+/// already succeeded.
+pub fn record_or_warn(asset: LockedAsset) {
+    if let Err(e) = record(asset) {
+        let w = crate::cli::style::warn();
+        anstream::eprintln!("{w}warn: could not update {LOCK_FILE}: {e}{w:#}");
+    }
+}
+
+/// Pin entry for the content at `path` inside the source `repo` (cloned or
+/// extracted from `source`), at the clone's current commit.
+pub fn pin(
+    kind: AssetKind,
+    name: &str,
+    source: &str,
+    repo: &Path,
+    path: &Path,
+) -> anyhow::Result<LockedAsset> {
+    let rel = path
+        .strip_prefix(repo)
+        .unwrap_or(path)
+        .to_string_lossy()
+        .replace('\\', "/");
+    Ok(LockedAsset {
+        kind,
+        name: name.to_string(),
+        source: source.to_string(),
+        // A pack at the root of its repository.
+        path: if rel.is_empty() { ".".to_string() } else { rel },
+        revision: head_revision(repo),
+        integrity: integrity(path)?,
+    })
+}
+
+// ── Content hashing ─────────────────────────────────────────────
+
+/// `sha256-<hex>` of a file's bytes, or of a directory's files (relative
+/// path and content, in path order; `.git` is skipped).
+pub fn integrity(path: &Path) -> anyhow::Result<String> {
+    let mut hasher = Sha256::new();
+    if path.is_file() {
+        hasher.update(std::fs::read(path)?);
+    } else if path.is_dir() {
+        for (rel, file) in files_under(path)? {
+            hasher.update(rel.as_bytes());
+            hasher.update([0]);
+            hasher.update(std::fs::read(&file)?);
+            hasher.update([0]);
+        }
+    } else {
+        anyhow::bail!("{} not found", path.display());
+    }
+    let hex: String = hasher
+        .finalize()
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect();
+    Ok(format!("sha256-{hex}"))
+}
+
+/// Every file under `dir` as `(relative path with '/', absolute path)`,
+/// sorted by relative path.
+pub fn files_under(dir: &Path) -> anyhow::Result<Vec<(String, PathBuf)>> {
+    fn walk(root: &Path, dir: &Path, out: &mut Vec<(String, PathBuf)>) -> anyhow::Result<()> {
+        for entry in std::fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path.file_name().is_some_and(|n| n == ".git") {
+                continue;
+            }
+            if path.is_dir() {
+                walk(root, &path, out)?;
+            } else if path.is_file() {
+                let rel = path.strip_prefix(root).unwrap_or(&path);
+                out.push((rel.to_string_lossy().replace('\\', "/"), path.clone()));
+            }
+        }
+        Ok(())
+    }
+    let mut out = Vec::new();
+    walk(dir, dir, &mut out)?;
+    out.sort();
+    Ok(out)
+}
+
+// ── Git ─────────────────────────────────────────────────────────
+
+fn git(repo: &Path, args: &[&str]) -> anyhow::Result<Vec<u8>> {
+    let out = Command::new("git")
+        .arg("-C")
+        .arg(repo)
+        .args(args)
+        .output()?;
+    if !out.status.success() {
+        anyhow::bail!(
+            "git {} failed in {}: {}",
+            args.join(" "),
+            repo.display(),
+            String::from_utf8_lossy(&out.stderr).trim()
+        );
+    }
+    Ok(out.stdout)
+}
+
+fn git_line(repo: &Path, args: &[&str]) -> anyhow::Result<String> {
+    Ok(String::from_utf8_lossy(&git(repo, args)?)
+        .trim()
+        .to_string())
+}
+
+/// Commit checked out in `repo`; `None` when it is not a git clone.
+pub fn head_revision(repo: &Path) -> Option<String> {
+    if !repo.join(".git").exists() {
+        return None;
+    }
+    git_line(repo, &["rev-parse", "HEAD"]).ok()
+}
+
+/// Fetch the remote's current default-branch commit without touching the
+/// checkout; returns it.
+pub fn fetch_upstream(repo: &Path) -> anyhow::Result<String> {
+    git(repo, &["fetch", "-q", "--depth", "1", "origin", "HEAD"])?;
+    git_line(repo, &["rev-parse", "FETCH_HEAD"])
+}
+
+/// Make sure `rev` is in `repo`'s object store, fetching it if a shallow
+/// clone does not have it.
+fn ensure_revision(repo: &Path, rev: &str) -> anyhow::Result<()> {
+    check_revision(rev)?;
+    let object = format!("{rev}^{{commit}}");
+    if git(repo, &["cat-file", "-e", "--end-of-options", &object]).is_ok() {
+        return Ok(());
+    }
+    git(
+        repo,
+        &[
+            "fetch",
+            "-q",
+            "--depth",
+            "1",
+            "--end-of-options",
+            "origin",
+            rev,
+        ],
+    )
+    .map(drop)
+    .map_err(|e| anyhow::anyhow!("revision {} is no longer available: {e}", short(rev)))
+}
+
+/// Write the files of `path` at `rev` under `dest` (keeping their paths
+/// relative to the repository root) and return `dest.join(path)`.
+pub fn materialize(repo: &Path, rev: &str, path: &str, dest: &Path) -> anyhow::Result<PathBuf> {
+    ensure_revision(repo, rev)?;
+    let listing = git(
+        repo,
+        &[
+            "ls-tree",
+            "-r",
+            "-z",
+            "--name-only",
+            "--end-of-options",
+            rev,
+            "--",
+            path,
+        ],
+    )?;
+    let names: Vec<String> = listing
+        .split(|b| *b == 0)
+        .filter(|n| !n.is_empty())
+        .map(|n| String::from_utf8_lossy(n).into_owned())
+        .collect();
+    if names.is_empty() {
+        anyhow::bail!("'{path}' does not exist at revision {}", short(rev));
+    }
+    for name in &names {
+        let blob = git(
+            repo,
+            &[
+                "cat-file",
+                "blob",
+                "--end-of-options",
+                &format!("{rev}:{name}"),
+            ],
+        )?;
+        let target = dest.join(name);
+        if let Some(parent) = target.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(target, blob)?;
+    }
+    Ok(dest.join(path))
+}
+
+/// First 12 characters of a revision, for display.
+pub fn short(rev: &str) -> &str {
+    &rev[..rev.len().min(12)]
+}
+
+/// A scratch directory removed on drop.
+pub struct Staging(PathBuf);
+
+impl Staging {
+    pub fn new() -> anyhow::Result<Self> {
+        let dir = registry_cache_dir()
+            .join("lock-staging")
+            .join(uuid::Uuid::new_v4().to_string());
+        std::fs::create_dir_all(&dir)?;
+        Ok(Self(dir))
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for Staging {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
+
+// ── Per-kind sources and installs ──────────────────────────────
+
+/// Where `asset`'s source is cached locally.
+pub fn source_dir(asset: &LockedAsset) -> anyhow::Result<PathBuf> {
+    Ok(match asset.kind {
+        AssetKind::Agent => crate::registry::sync::source_dir(&asset.source),
+        AssetKind::Skill => {
+            let (owner, repo) = crate::skills_registry::sync::parse_source(&asset.source)
+                .ok_or_else(|| anyhow::anyhow!("invalid skill source '{}'", asset.source))?;
+            crate::skills_registry::sync::repo_dir(&owner, &repo)
+        }
+        AssetKind::Starter => crate::starters_registry::source_cache_dir(&asset.source),
+    })
+}
+
+/// [`source_dir`], cloned (or downloaded) first when it is not cached yet.
+/// An existing clone is left as is: locked revisions are fetched by id.
+pub fn ensure_source(asset: &LockedAsset) -> anyhow::Result<PathBuf> {
+    let dir = source_dir(asset)?;
+    if dir.exists() {
+        return Ok(dir);
+    }
+    match asset.kind {
+        AssetKind::Agent => crate::registry::sync::sync_source(&asset.source),
+        AssetKind::Skill => crate::skills_registry::sync::sync_repo(&asset.source),
+        AssetKind::Starter => {
+            crate::starters_registry::fetch_starter_source(&starter_source(asset))
+        }
+    }
+}
+
+/// The configured source of a starter `asset`: it carries the key its
+/// content is verified with. A source no longer configured pins none.
+fn starter_source(asset: &LockedAsset) -> RegistrySource {
+    crate::cli::registry::effective_starter_sources()
+        .into_iter()
+        .find(|s| s.url == asset.source)
+        .unwrap_or_else(|| RegistrySource {
+            url: asset.source.clone(),
+            ..Default::default()
+        })
+}
+
+/// Hold the packs in `content` to `source`'s signature policy, as a sync of
+/// the source itself does. Content without a revision was extracted from an
+/// archive, which was checked as a whole when fetched with a key.
+fn verify_starter(
+    source: &RegistrySource,
+    asset: &LockedAsset,
+    content: &Path,
+) -> anyhow::Result<()> {
+    use crate::starters_registry::verify::{Trust, verify_packs};
+    let trust = Trust::of(source)?;
+    verify_packs(content, &trust, asset.revision.is_none() && trust.has_key()).map_err(|e| {
+        anyhow::anyhow!(
+            "refusing starter '{}' from {}: {e}",
+            asset.name,
+            asset.source
+        )
+    })
+}
+
+/// Where an installed agent or skill lives in the user library; starter
+/// packs spread over several directories and have none.
+pub fn installed_path(asset: &LockedAsset) -> Option<PathBuf> {
+    match asset.kind {
+        AssetKind::Agent => {
+            Some(user_agents_dir().join(crate::registry::convert::library_file_name(&asset.path)))
+        }
+        AssetKind::Skill => Some(user_skills_dir().join(&asset.name)),
+        AssetKind::Starter => None,
+    }
+}
+
+/// Install `asset` from `content` (its upstream file or directory) into the
+/// user library, overwriting what is there with `force`. A starter pack is
+/// verified first and refused when it does not pass.
+pub fn install(asset: &LockedAsset, content: &Path, force: bool) -> anyhow::Result<()> {
+    asset.validate()?;
+    match asset.kind {
+        AssetKind::Agent => {
+            let dst = installed_path(asset).expect("agents have an install path");
+            if dst.exists() && !force {
+                return Ok(());
+            }
+            let stem = content
+                .file_stem()
+                .and_then(|s| s.to_str())
+                .unwrap_or("agent");
+            let converted = crate::registry::convert::convert_to_armadai(
+                &std::fs::read_to_string(content)?,
+                stem,
+            );
+            std::fs::create_dir_all(user_agents_dir())?;
+            std::fs::write(dst, converted)?;
+        }
+        AssetKind::Skill => {
+            let dst = installed_path(asset).expect("skills have an install path");
+            if dst.exists() {
+                if !force {
+                    return Ok(());
+                }
+                std::fs::remove_dir_all(&dst)?;
+            }
+            for (rel, file) in files_under(content)? {
+                let target = dst.join(rel);
+                if let Some(parent) = target.parent() {
+                    std::fs::create_dir_all(parent)?;
+                }
+                std::fs::copy(file, target)?;
+            }
+        }
+        AssetKind::Starter => {
+            verify_starter(&starter_source(asset), asset, content)?;
+            let pack = armadai_core::starter::StarterPack::load(content)?;
+            pack.install(content, force)?;
+            crate::pack_ledger::record_or_warn(&pack, content);
+        }
+    }
+    Ok(())
+}
+
+/// The upstream content of `asset` at its locked revision, under `staging`
+/// (or in the cached source for archives), checked against its integrity.
+pub fn locked_content(asset: &LockedAsset, staging: &Path) -> anyhow::Result<PathBuf> {
+    asset.validate()?;
+    let repo = ensure_source(asset)?;
+    let content = match &asset.revision {
+        Some(rev) => materialize(&repo, rev, &asset.path, staging)?,
+        None => repo.join(&asset.path),
+    };
+    let actual = integrity(&content)?;
+    if actual != asset.integrity {
+        anyhow::bail!(
+            "integrity mismatch for {} '{}': locked {}, got {actual}",
+            asset.kind.label(),
+            asset.name,
+            asset.integrity
+        );
+    }
+    Ok(content)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(repo: &Path, args: &[&str]) {
+        let out = Command::new("git")
+            .arg("-C")
+            .arg(repo)
+            .args(["-c", "user.name=t", "-c", "user.email=t@t"])
+            .args(args)
+            .output()
+            .unwrap();
+        assert!(out.status.success(), "{out:?}");
+    }
+
+    fn asset(name: &str) -> LockedAsset {
+        LockedAsset {
+            kind: AssetKind::Skill,
+            name: name.to_string(),
+            source: "https://github.com/o/r".to_string(),
+            path: format!("skills/{name}"),
+            revision: None,
+            integrity: "sha256-0".to_string(),
+        }
+    }
+
+    #[test]
+    fn upsert_replaces_by_kind_and_name_and_keeps_order() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(LOCK_FILE);
+        let mut lock = Lockfile::default();
+        lock.upsert(asset("web"));
+        lock.upsert(asset("api"));
+        lock.upsert(LockedAsset {
+            integrity: "sha256-1".into(),
+            ..asset("web")
+        });
+        lock.save(&path).unwrap();
+
+        let text = std::fs::read_to_string(&path).unwrap();
+        assert!(text.starts_with("# armadai.lock"), "{text}");
+        let back = Lockfile::load(&path).unwrap();
+        assert_eq!(back, lock);
+        let names: Vec<&str> = back.assets.iter().map(|a| a.name.as_str()).collect();
+        assert_eq!(names, ["api", "web"]);
+        assert_eq!(back.assets[1].integrity, "sha256-1");
+        assert_eq!(
+            Lockfile::load(&dir.path().join("none")).unwrap(),
+            Lockfile::default()
+        );
+    }
+
+    #[test]
+    fn starter_content_is_held_to_its_source_signature_policy() {
+        use crate::starters_registry::verify::{PACK_SIGNATURE, test_keys};
+        let dir = tempfile::tempdir().unwrap();
+        let content = dir.path().join("demo");
+        std::fs::create_dir_all(&content).unwrap();
+        std::fs::write(content.join("pack.yaml"), "name: demo\ndescription: d\n").unwrap();
+        let (key, public) = test_keys::keypair(1);
+        let source = RegistrySource {
+            url: "https://example.com/starters.git".into(),
+            public_key: Some(public),
+            require_signature: true,
+            ..Default::default()
+        };
+        let starter = LockedAsset {
+            kind: AssetKind::Starter,
+            source: source.url.clone(),
+            path: "demo".into(),
+            revision: Some("a".repeat(40)),
+            ..asset("demo")
+        };
+
+        let err = verify_starter(&source, &starter, &content).unwrap_err();
+        assert!(err.to_string().contains("not signed"), "{err}");
+        let manifest = std::fs::read(content.join("pack.yaml")).unwrap();
+        std::fs::write(
+            content.join(PACK_SIGNATURE),
+            test_keys::sign(&key, &manifest),
+        )
+        .unwrap();
+        verify_starter(&source, &starter, &content).unwrap();
+    }
+
+    #[test]
+    fn entries_that_escape_their_directories_are_rejected() {
+        let rev = "a".repeat(40);
+        let ok = LockedAsset {
+            revision: Some(rev.clone()),
+            ..asset("web")
+        };
+        assert!(ok.validate().is_ok());
+        for bad in [
+            LockedAsset {
+                name: "../evil".into(),
+                ..ok.clone()
+            },
+            LockedAsset {
+                name: "..".into(),
+                ..ok.clone()
+            },
+            LockedAsset {
+                name: "/tmp/evil".into(),
+                ..ok.clone()
+            },
+            LockedAsset {
+                path: "skills/../../etc".into(),
+                ..ok.clone()
+            },
+            LockedAsset {
+                path: "/etc/passwd".into(),
+                ..ok.clone()
+            },
+            LockedAsset {
+                revision: Some("--upload-pack=touch /tmp/x".into()),
+                ..ok.clone()
+            },
+            LockedAsset {
+                revision: Some("A".repeat(40)),
+                ..ok.clone()
+            },
+            LockedAsset {
+                revision: Some("main".into()),
+                ..ok.clone()
+            },
+            LockedAsset {
+                source: "--upload-pack=touch /tmp/x".into(),
+                ..ok.clone()
+            },
+            LockedAsset {
+                source: "ext::sh -c touch% /tmp/x".into(),
+                ..ok.clone()
+            },
+            LockedAsset {
+                source: "http://github.com/o/r".into(),
+                ..ok.clone()
+            },
+            LockedAsset {
+                source: "-oProxyCommand=x@host:o/r".into(),
+                ..ok.clone()
+            },
+        ] {
+            assert!(bad.validate().is_err(), "{bad:?}");
+        }
+        for source in [
+            "ssh://git@host/o/r.git",
+            "git@github.com:o/r.git",
+            "file:///srv/r",
+        ] {
+            let good = LockedAsset {
+                source: source.into(),
+                ..ok.clone()
+            };
+            assert!(good.validate().is_ok(), "{good:?}");
+        }
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(LOCK_FILE);
+        let mut lock = Lockfile::default();
+        lock.assets.push(LockedAsset {
+            name: "../evil".into(),
+            ..ok
+        });
+        lock.save(&path).unwrap();
+        assert!(Lockfile::load(&path).is_err());
+    }
+
+    #[test]
+    fn content_at_a_locked_revision_matches_its_hash_after_upstream_moves() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = dir.path().join("repo");
+        std::fs::create_dir_all(repo.join("skills/web")).unwrap();
+        std::fs::write(repo.join("skills/web/SKILL.md"), "v1\n").unwrap();
+        std::fs::write(repo.join("skills/web/notes.txt"), "n\n").unwrap();
+        std::fs::write(repo.join("README.md"), "r\n").unwrap();
+        run(&repo, &["init", "-q"]);
+        run(&repo, &["add", "."]);
+        run(&repo, &["commit", "-q", "-m", "one"]);
+
+        let locked = pin(
+            AssetKind::Skill,
+            "web",
+            "o/r",
+            &repo,
+            &repo.join("skills/web"),
+        )
+        .unwrap();
+        assert_eq!(locked.path, "skills/web");
+        let rev = locked.revision.clone().unwrap();
+
+        std::fs::write(repo.join("skills/web/SKILL.md"), "v2\n").unwrap();
+        run(&repo, &["commit", "-q", "-am", "two"]);
+        assert_ne!(
+            integrity(&repo.join("skills/web")).unwrap(),
+            locked.integrity
+        );
+
+        let staging = dir.path().join("staging");
+        let content = materialize(&repo, &rev, &locked.path, &staging).unwrap();
+        assert_eq!(integrity(&content).unwrap(), locked.integrity);
+        assert_eq!(
+            std::fs::read_to_string(content.join("SKILL.md")).unwrap(),
+            "v1\n"
+        );
+        assert!(!staging.join("README.md").exists());
+        assert!(materialize(&repo, &rev, "skills/none", &staging).is_err());
+    }
+}
@@ -1,5 +1,5 @@
 mod audit;
-// `watch` (the only consumer of `drive_session`/`Mapper` etc.) is gated behind
+// `watch` (the only consumer of `ClaudeTail`/`Mapper` etc.) is gated behind
 // `tui`; without it, most of `claude_adapter` would be flagged dead code even
 // though `register_from_stdin` (used unconditionally by
 // `__claude-register-session`) stays live. Only suppress the lint when `tui`
@@ -12,13 +12,25 @@ mod cli;
 mod db;
 #[cfg(feature = "storage")]
 mod es_log;
+mod eval;
+mod frameworks;
 mod linker;
+mod lockfile;
 mod logging;
+mod pack_ledger;
+#[cfg(feature = "storage")]
+mod redact;
 mod registry;
+mod search_index;
+// Same as `claude_adapter`: only `watch` follows sessions.
+#[cfg_attr(not(feature = "tui"), allow(dead_code))]
+mod session_adapter;
 #[allow(dead_code)]
 mod shell;
+mod skill_tools;
 mod skills_registry;
 mod starters_registry;
+mod text;
 #[cfg(feature = "tui")]
 mod theme;
 #[cfg(feature = "tui")]
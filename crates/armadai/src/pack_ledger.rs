@@ -0,0 +1,478 @@
+//! Ledger of installed starter packs.
+//!
+//! `StarterPack::install` copies a pack's agents, prompts and skills into
+//! the user library. The ledger (`installed-packs.yaml` in the config
+//! directory) records, per pack, the version installed and the hash of
+//! every file it put there, and a copy of each file is kept under
+//! `installed-packs/<pack>/` as the base of later merges. With it:
+//! - uninstalling removes only the files still as installed;
+//! - upgrading replaces untouched files, three-way merges locally modified
+//!   ones, and leaves conflict markers where both sides changed a line.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use armadai_core::starter::{StarterPack, is_unsafe_pack_component};
+use serde::{Deserialize, Serialize};
+
+use crate::lockfile::integrity;
+
+pub const LEDGER_FILE: &str = "installed-packs.yaml";
+const BASE_DIR: &str = "installed-packs";
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Ledger {
+    #[serde(default)]
+    pub packs: BTreeMap<String, InstalledPack>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct InstalledPack {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    /// Directory the pack was installed from.
+    pub source: String,
+    /// Installed files (relative to the config directory) and the hash of
+    /// the content the pack installed.
+    #[serde(default)]
+    pub files: BTreeMap<String, String>,
+}
+
+impl Ledger {
+    pub fn load(root: &Path) -> anyhow::Result<Self> {
+        match std::fs::read_to_string(root.join(LEDGER_FILE)) {
+            Ok(text) => {
+                let ledger: Self = serde_yaml_ng::from_str(&text)?;
+                for name in ledger.packs.keys() {
+                    check_name(name).map_err(|e| anyhow::anyhow!("invalid {LEDGER_FILE}: {e}"))?;
+                }
+                Ok(ledger)
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub fn save(&self, root: &Path) -> anyhow::Result<()> {
+        std::fs::create_dir_all(root)?;
+        std::fs::write(root.join(LEDGER_FILE), serde_yaml_ng::to_string(self)?)?;
+        Ok(())
+    }
+}
+
+/// Refuse a pack name that is not a plain directory name: it names the
+/// pack's directory of merge bases, which uninstalling removes.
+fn check_name(name: &str) -> anyhow::Result<()> {
+    if name.is_empty() || name == "." || is_unsafe_pack_component(name) {
+        anyhow::bail!("invalid starter pack name '{name}'");
+    }
+    Ok(())
+}
+
+fn base_path(root: &Path, pack: &str, rel: &str) -> PathBuf {
+    root.join(BASE_DIR).join(pack).join(rel)
+}
+
+fn source(pack_dir: &Path) -> String {
+    std::fs::canonicalize(pack_dir)
+        .unwrap_or_else(|_| pack_dir.to_path_buf())
+        .display()
+        .to_string()
+}
+
+fn hash(path: &Path) -> Option<String> {
+    integrity(path).ok()
+}
+
+fn copy(src: &Path, dst: &Path) -> anyhow::Result<()> {
+    if let Some(parent) = dst.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::copy(src, dst)?;
+    Ok(())
+}
+
+/// Record the files of `pack` now in the library under `root` as installed
+/// from `pack_dir`: those whose content is the pack's. Files `install`
+/// skipped because a different one already existed are not the pack's.
+pub fn record(root: &Path, pack: &StarterPack, pack_dir: &Path) -> anyhow::Result<()> {
+    check_name(&pack.name)?;
+    let mut ledger = Ledger::load(root)?;
+    let entry = ledger.packs.entry(pack.name.clone()).or_default();
+    entry.version = pack.version.clone();
+    entry.source = source(pack_dir);
+    for rel in pack.library_files(pack_dir) {
+        let src = pack_dir.join(&rel);
+        let Some(sum) = hash(&src) else { continue };
+        if hash(&root.join(&rel)).as_ref() == Some(&sum) {
+            copy(&src, &base_path(root, &pack.name, &rel))?;
+            entry.files.insert(rel, sum);
+        }
+    }
+    ledger.save(root)
+}
+
+/// [`record`] into the config directory, logging instead of failing: the
+/// pack is installed either way.
+pub fn record_or_warn(pack: &StarterPack, pack_dir: &Path) {
+    let root = armadai_core::config::config_dir();
+    if let Err(e) = record(&root, pack, pack_dir) {
+        tracing::warn!("pack '{}' not recorded in {LEDGER_FILE}: {e}", pack.name);
+    }
+}
+
+/// What happened to each file of a pack, by path.
+#[derive(Debug, Default, PartialEq)]
+pub struct Report {
+    pub removed: Vec<String>,
+    pub added: Vec<String>,
+    pub updated: Vec<String>,
+    pub merged: Vec<String>,
+    pub conflicts: Vec<String>,
+    /// Files changed locally, left as they are.
+    pub kept: Vec<String>,
+}
+
+/// Remove the files of pack `name` still as installed; modified ones stay.
+pub fn uninstall(root: &Path, name: &str) -> anyhow::Result<Report> {
+    check_name(name)?;
+    let mut ledger = Ledger::load(root)?;
+    let Some(entry) = ledger.packs.remove(name) else {
+        anyhow::bail!("starter pack '{name}' is not installed");
+    };
+    let mut report = Report::default();
+    for (rel, sum) in &entry.files {
+        let path = root.join(rel);
+        match hash(&path) {
+            Some(current) if &current == sum => {
+                std::fs::remove_file(&path)?;
+                prune_empty_dirs(root, &path);
+                report.removed.push(rel.clone());
+            }
+            Some(_) => report.kept.push(rel.clone()),
+            None => {}
+        }
+    }
+    let _ = std::fs::remove_dir_all(root.join(BASE_DIR).join(name));
+    ledger.save(root)?;
+    Ok(report)
+}
+
+/// Remove the now-empty directories between `path` and the library dirs
+/// (a skill's directory once its last file is gone).
+fn prune_empty_dirs(root: &Path, path: &Path) {
+    let mut dir = path.parent();
+    while let Some(d) = dir {
+        let depth = d.strip_prefix(root).map(|r| r.components().count());
+        if !matches!(depth, Ok(n) if n > 1) || std::fs::remove_dir(d).is_err() {
+            break;
+        }
+        dir = d.parent();
+    }
+}
+
+/// Upgrade the installed pack `pack.name` to the content of `pack_dir`.
+pub fn upgrade(root: &Path, pack: &StarterPack, pack_dir: &Path) -> anyhow::Result<Report> {
+    check_name(&pack.name)?;
+    let mut ledger = Ledger::load(root)?;
+    let Some(old) = ledger.packs.get(&pack.name).cloned() else {
+        anyhow::bail!(
+            "starter pack '{}' is not installed (install it with `armadai init --pack {}`)",
+            pack.name,
+            pack.name
+        );
+    };
+    let label = match &pack.version {
+        Some(v) => format!("{} {v}", pack.name),
+        None => pack.name.clone(),
+    };
+
+    let mut report = Report::default();
+    let mut files = BTreeMap::new();
+    let new_files = pack.library_files(pack_dir);
+    for rel in &new_files {
+        let src = pack_dir.join(rel);
+        let lib = root.join(rel);
+        let base = base_path(root, &pack.name, rel);
+        let Some(theirs) = hash(&src) else { continue };
+        let current = hash(&lib);
+        match (old.files.get(rel), current) {
+            // Deleted locally: stays deleted.
+            (Some(_), None) => continue,
+            (None, None) => {
+                copy(&src, &lib)?;
+                report.added.push(rel.clone());
+            }
+            // Someone else's file: left alone unless it already matches.
+            (None, Some(current)) => {
+                if current != theirs {
+                    report.kept.push(rel.clone());
+                    continue;
+                }
+            }
+            (Some(recorded), Some(current)) if &current == recorded => {
+                if current != theirs {
+                    copy(&src, &lib)?;
+                    report.updated.push(rel.clone());
+                }
+            }
+            (Some(recorded), Some(_)) => {
+                if recorded != &theirs {
+                    match merge_file(&base, &lib, &src, &label)? {
+                        Some(false) => report.merged.push(rel.clone()),
+                        Some(true) => report.conflicts.push(rel.clone()),
+                        None => {
+                            report.kept.push(rel.clone());
+                            continue;
+                        }
+                    }
+                }
+            }
+        }
+        copy(&src, &base)?;
+        files.insert(rel.clone(), theirs);
+    }
+
+    for (rel, recorded) in &old.files {
+        if new_files.contains(rel) {
+            continue;
+        }
+        let lib = root.join(rel);
+        match hash(&lib) {
+            Some(current) if &current == recorded => {
+                std::fs::remove_file(&lib)?;
+                prune_empty_dirs(root, &lib);
+                report.removed.push(rel.clone());
+            }
+            Some(_) => report.kept.push(rel.clone()),
+            None => {}
+        }
+        let _ = std::fs::remove_file(base_path(root, &pack.name, rel));
+    }
+
+    ledger.packs.insert(
+        pack.name.clone(),
+        InstalledPack {
+            version: pack.version.clone(),
+            source: source(pack_dir),
+            files,
+        },
+    );
+    ledger.save(root)?;
+    Ok(report)
+}
+
+/// Merge the pack's changes from `base` to `theirs` into the local file
+/// `lib`. Returns whether conflicts were left, or `None` when the file
+/// cannot be merged (binary, or no base kept) and was left alone.
+fn merge_file(base: &Path, lib: &Path, theirs: &Path, label: &str) -> anyhow::Result<Option<bool>> {
+    let read = |p: &Path| {
+        std::fs::read(p)
+            .ok()
+            .and_then(|b| String::from_utf8(b).ok())
+    };
+    let (Some(base), Some(ours), Some(theirs)) = (read(base), read(lib), read(theirs)) else {
+        return Ok(None);
+    };
+    let (merged, conflicts) = merge3(&base, &ours, &theirs, label);
+    std::fs::write(lib, merged)?;
+    Ok(Some(conflicts))
+}
+
+// ── Three-way merge ─────────────────────────────────────────────
+
+/// Line pairs `(a, b)` of a longest common subsequence of `a` and `b`.
+fn lcs(a: &[String], b: &[String]) -> Vec<(usize, usize)> {
+    let (n, m) = (a.len(), b.len());
+    let mut table = vec![vec![0u32; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            table[i][j] = if a[i] == b[j] {
+                table[i + 1][j + 1] + 1
+            } else {
+                table[i + 1][j].max(table[i][j + 1])
+            };
+        }
+    }
+    let (mut i, mut j, mut pairs) = (0, 0, Vec::new());
+    while i < n && j < m {
+        if a[i] == b[j] {
+            pairs.push((i, j));
+            i += 1;
+            j += 1;
+        } else if table[i + 1][j] >= table[i][j + 1] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    pairs
+}
+
+/// diff3-style merge of the changes `base → ours` and `base → theirs`.
+/// Regions changed on one side take that side; regions both sides changed
+/// differently are written between conflict markers. Returns the merged
+/// text and whether it has conflicts.
+fn merge3(base: &str, ours: &str, theirs: &str, label: &str) -> (String, bool) {
+    let split = |s: &str| -> Vec<String> { s.split_inclusive('\n').map(str::to_string).collect() };
+    let (b, o, t) = (split(base), split(ours), split(theirs));
+
+    let to_ours: BTreeMap<usize, usize> = lcs(&b, &o).into_iter().collect();
+    let to_theirs: BTreeMap<usize, usize> = lcs(&b, &t).into_iter().collect();
+    // Base lines kept by both sides anchor the merge.
+    let mut anchors: Vec<(usize, usize, usize)> = to_ours
+        .iter()
+        .filter_map(|(&bi, &oi)| to_theirs.get(&bi).map(|&ti| (bi, oi, ti)))
+        .collect();
+    anchors.push((b.len(), o.len(), t.len()));
+
+    let mut out = String::new();
+    let mut conflicts = false;
+    let (mut bi, mut oi, mut ti) = (0, 0, 0);
+    for (ba, oa, ta) in anchors {
+        let (bc, oc, tc) = (&b[bi..ba], &o[oi..oa], &t[ti..ta]);
+        if oc == bc {
+            out.extend(tc.iter().map(String::as_str));
+        } else if tc == bc || oc == tc {
+            out.extend(oc.iter().map(String::as_str));
+        } else {
+            conflicts = true;
+            let line = |chunk: &[String], out: &mut String| {
+                for l in chunk {
+                    out.push_str(l);
+                }
+                if chunk.last().is_some_and(|l| !l.ends_with('\n')) {
+                    out.push('\n');
+                }
+            };
+            out.push_str("<<<<<<< local\n");
+            line(oc, &mut out);
+            out.push_str("=======\n");
+            line(tc, &mut out);
+            out.push_str(&format!(">>>>>>> {label}\n"));
+        }
+        if ba < b.len() {
+            out.push_str(&b[ba]);
+        }
+        (bi, oi, ti) = (ba + 1, oa + 1, ta + 1);
+    }
+    (out, conflicts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn merge3_takes_each_side_and_marks_overlaps() {
+        let base = "a\nb\nc\nd\n";
+        let (merged, conflicts) = merge3(base, "a\nB\nc\nd\n", "a\nb\nc\nD\n", "p 2");
+        assert_eq!(merged, "a\nB\nc\nD\n");
+        assert!(!conflicts);
+
+        let (merged, conflicts) = merge3(base, "a\nmine\nc\nd\n", "a\ntheirs\nc\nd\n", "p 2");
+        assert!(conflicts);
+        assert_eq!(
+            merged,
+            "a\n<<<<<<< local\nmine\n=======\ntheirs\n>>>>>>> p 2\nc\nd\n"
+        );
+    }
+
+    fn write(path: &Path, content: &str) {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, content).unwrap();
+    }
+
+    fn pack_dir(root: &Path, version: &str, agent: &str, extra: bool) -> (StarterPack, PathBuf) {
+        let dir = root.join(format!("pack-{version}"));
+        let agents = if extra { "[a, b]" } else { "[a]" };
+        write(
+            &dir.join("pack.yaml"),
+            &format!(
+                "name: p\ndescription: d\nversion: {version}\nagents: {agents}\nskills: [s]\n"
+            ),
+        );
+        write(&dir.join("agents/a.md"), agent);
+        write(&dir.join("agents/b.md"), "# B\n");
+        write(&dir.join("skills/s/SKILL.md"), "skill\n");
+        (StarterPack::load(&dir).unwrap(), dir)
+    }
+
+    /// What `StarterPack::install` does, into `root`.
+    fn install(root: &Path, pack: &StarterPack, dir: &Path) {
+        for rel in pack.library_files(dir) {
+            if !root.join(&rel).exists() {
+                copy(&dir.join(&rel), &root.join(&rel)).unwrap();
+            }
+        }
+        record(root, pack, dir).unwrap();
+    }
+
+    #[test]
+    fn uninstall_removes_only_untouched_files() {
+        let tmp = tempfile::tempdir().unwrap();
+        let root = tmp.path().join("config");
+        write(&root.join("agents/b.md"), "# mine\n");
+        let (pack, dir) = pack_dir(tmp.path(), "1.0.0", "# A\n", true);
+        install(&root, &pack, &dir);
+        let ledger = Ledger::load(&root).unwrap();
+        let files: Vec<_> = ledger.packs["p"].files.keys().cloned().collect();
+        assert_eq!(
+            files,
+            ["agents/a.md", "skills/s/SKILL.md"],
+            "b.md was not ours"
+        );
+
+        write(&root.join("agents/a.md"), "# A, edited\n");
+        let report = uninstall(&root, "p").unwrap();
+        assert_eq!(report.removed, ["skills/s/SKILL.md"]);
+        assert_eq!(report.kept, ["agents/a.md"]);
+        assert!(!root.join("skills/s").exists());
+        assert!(root.join("skills").exists());
+        assert!(root.join("agents/b.md").exists());
+        assert!(Ledger::load(&root).unwrap().packs.is_empty());
+        assert!(uninstall(&root, "p").is_err());
+    }
+
+    #[test]
+    fn pack_names_that_leave_the_base_dir_are_refused() {
+        let tmp = tempfile::tempdir().unwrap();
+        let root = tmp.path().join("config");
+        let (mut pack, dir) = pack_dir(tmp.path(), "1.0.0", "# A\n", false);
+        pack.name = "../../victim".into();
+        assert!(record(&root, &pack, &dir).is_err());
+        assert!(uninstall(&root, "..").is_err());
+
+        write(
+            &root.join(LEDGER_FILE),
+            "packs:\n  ../x:\n    source: /tmp\n",
+        );
+        assert!(Ledger::load(&root).is_err());
+    }
+
+    #[test]
+    fn upgrade_updates_untouched_files_and_merges_edited_ones() {
+        let tmp = tempfile::tempdir().unwrap();
+        let root = tmp.path().join("config");
+        let (v1, dir1) = pack_dir(tmp.path(), "1.0.0", "# A\n\nintro\n\nrules\n", true);
+        install(&root, &v1, &dir1);
+        write(&root.join("agents/a.md"), "# A\n\nmy intro\n\nrules\n");
+
+        // 2.0.0 changes the rules, drops agent b.
+        let (v2, dir2) = pack_dir(tmp.path(), "2.0.0", "# A\n\nintro\n\nnew rules\n", false);
+        std::fs::write(dir2.join("skills/s/SKILL.md"), "skill v2\n").unwrap();
+        let report = upgrade(&root, &v2, &dir2).unwrap();
+        assert_eq!(report.merged, ["agents/a.md"]);
+        assert_eq!(report.updated, ["skills/s/SKILL.md"]);
+        assert_eq!(report.removed, ["agents/b.md"]);
+        assert_eq!(
+            std::fs::read_to_string(root.join("agents/a.md")).unwrap(),
+            "# A\n\nmy intro\n\nnew rules\n"
+        );
+        let ledger = Ledger::load(&root).unwrap();
+        assert_eq!(ledger.packs["p"].version.as_deref(), Some("2.0.0"));
+        // The merged file differs from 2.0.0's: uninstall keeps it.
+        let report = uninstall(&root, "p").unwrap();
+        assert_eq!(report.kept, ["agents/a.md"]);
+    }
+}
@@ -0,0 +1,197 @@
+//! Secret redaction applied before run content is persisted.
+//!
+//! Agents echo tokens they find in input files; without this pass those
+//! land verbatim in `execution_events`, `runs` and everything read from
+//! them (history, the web API, `runs export`). Two kinds of secrets are
+//! rewritten:
+//! - the plaintext-secret patterns audit rule A11 detects
+//!   (`audit::rules::references::secret_res`), per `storage.redaction`
+//!   (`off`, `mask` or `hash`);
+//! - the API keys known to `armadai-secrets` and the provider environment
+//!   variables, always masked, even with `off`.
+
+use std::sync::OnceLock;
+
+use armadai_core::config::RedactionMode;
+use armadai_core::orchestration::es::event::ExecutionEvent;
+use sha2::{Digest, Sha256};
+
+use crate::audit::rules::references::secret_res;
+
+/// Code of the `Warned` event recorded after an event that was redacted.
+pub const SECRET_REDACTED: &str = "secret_redacted";
+
+const MASK: &str = "[REDACTED]";
+
+/// Environment variables whose values are API keys.
+const KEY_VARS: [&str; 3] = ["ANTHROPIC_API_KEY", "OPENAI_API_KEY", "GOOGLE_API_KEY"];
+
+/// Known values shorter than this are not redacted: they would match
+/// ordinary words (and no real API key is that short).
+const MIN_KNOWN_LEN: usize = 8;
+
+pub struct Redactor {
+    mode: RedactionMode,
+    /// Known secret values, longest first so a key never leaves a suffix of
+    /// a longer one behind.
+    known: Vec<String>,
+}
+
+impl Redactor {
+    pub fn new(mode: RedactionMode, known: impl IntoIterator<Item = String>) -> Self {
+        let mut known: Vec<String> = known
+            .into_iter()
+            .filter(|k| k.len() >= MIN_KNOWN_LEN)
+            .collect();
+        known.sort_by(|a, b| b.len().cmp(&a.len()).then_with(|| a.cmp(b)));
+        known.dedup();
+        Self { mode, known }
+    }
+
+    /// The process-wide redactor: `storage.redaction` from config.yaml, and
+    /// the API keys of the secrets store (user and project layers) and of
+    /// the environment. Loaded once, on first use. Unit tests get
+    /// [`Redactor::off`] instead: they must not read the user's config.
+    pub fn global() -> &'static Redactor {
+        if cfg!(test) {
+            return Self::off();
+        }
+        static REDACTOR: OnceLock<Redactor> = OnceLock::new();
+        REDACTOR.get_or_init(|| {
+            let mode = armadai_core::config::load_user_config().storage.redaction;
+            let secrets = armadai_secrets::load_layered(
+                &armadai_core::config::config_dir(),
+                &armadai_core::config::secrets_overlay_dirs(),
+            );
+            let known = secrets
+                .providers
+                .values()
+                .filter_map(|c| c.get("api_key"))
+                .chain(KEY_VARS.iter().filter_map(|v| std::env::var(v).ok()));
+            Redactor::new(mode, known)
+        })
+    }
+
+    /// A redactor that knows no secrets and rewrites no patterns.
+    pub fn off() -> &'static Redactor {
+        static OFF: OnceLock<Redactor> = OnceLock::new();
+        OFF.get_or_init(|| Redactor::new(RedactionMode::Off, []))
+    }
+
+    /// This redactor with the patterns rewritten even when
+    /// `storage.redaction` is `off`: what an explicit `--redact` asks for.
+    pub fn masking(&self) -> Redactor {
+        Redactor {
+            mode: match self.mode {
+                RedactionMode::Off => RedactionMode::Mask,
+                mode => mode,
+            },
+            known: self.known.clone(),
+        }
+    }
+
+    fn replacement(&self, secret: &str) -> String {
+        match self.mode {
+            RedactionMode::Hash => {
+                let digest = Sha256::digest(secret.as_bytes());
+                let hex: String = digest[..6].iter().map(|b| format!("{b:02x}")).collect();
+                format!("[REDACTED:{hex}]")
+            }
+            RedactionMode::Off | RedactionMode::Mask => MASK.to_string(),
+        }
+    }
+
+    /// `text` with its secrets rewritten; `None` when it has none.
+    pub fn redact(&self, text: &str) -> Option<String> {
+        let mut out = text.to_string();
+        for key in &self.known {
+            if out.contains(key.as_str()) {
+                out = out.replace(key.as_str(), MASK);
+            }
+        }
+        if self.mode != RedactionMode::Off {
+            for re in secret_res() {
+                out = re
+                    .replace_all(&out, |c: &regex::Captures| self.replacement(&c[0]))
+                    .into_owned();
+            }
+        }
+        (out != text).then_some(out)
+    }
+
+    /// `event` with the free text it carries (run input, agent inputs and
+    /// outputs, delegated tasks, board entries, ring contributions, final
+    /// content) redacted; `None` when nothing changed.
+    pub fn redact_event(&self, event: &ExecutionEvent) -> Option<ExecutionEvent> {
+        let mut event = event.clone();
+        let text = match &mut event {
+            ExecutionEvent::RunStarted { input, .. }
+            | ExecutionEvent::AgentInvoked { input, .. } => input,
+            ExecutionEvent::Delegated { task, .. } => task,
+            ExecutionEvent::AgentObserved { content, .. }
+            | ExecutionEvent::BoardEntryAdded { content, .. }
+            | ExecutionEvent::ContributionAdded { content, .. }
+            | ExecutionEvent::Synthesized { content, .. }
+            | ExecutionEvent::Completed { content } => content,
+            _ => return None,
+        };
+        *text = self.redact(text)?;
+        Some(event)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY: &str = "sk-ant-REDACTED";
+
+    #[test]
+    fn patterns_follow_the_mode_and_known_keys_are_always_masked() {
+        let text = format!("found {KEY} and my-own-token-1234");
+        let known = || vec!["my-own-token-1234".to_string(), "short".to_string()];
+
+        let mask = Redactor::new(RedactionMode::Mask, known());
+        assert_eq!(
+            mask.redact(&text).as_deref(),
+            Some("found [REDACTED] and [REDACTED]")
+        );
+
+        let off = Redactor::new(RedactionMode::Off, known());
+        assert_eq!(
+            off.redact(&text).as_deref(),
+            Some(format!("found {KEY} and [REDACTED]").as_str())
+        );
+        assert_eq!(off.redact("a short note"), None);
+
+        let hash = Redactor::new(RedactionMode::Hash, known());
+        let hashed = hash.redact(&text).unwrap();
+        assert!(!hashed.contains(KEY), "{hashed}");
+        assert_eq!(hashed, hash.redact(&text).unwrap(), "hashes are stable");
+        assert!(hashed.starts_with("found [REDACTED:"), "{hashed}");
+        assert!(hashed.ends_with(" and [REDACTED]"), "{hashed}");
+    }
+
+    #[test]
+    fn only_content_carrying_events_are_rewritten() {
+        let r = Redactor::new(RedactionMode::Mask, []);
+        let observed = ExecutionEvent::AgentObserved {
+            agent: "a".into(),
+            content: format!("key: {KEY}"),
+            tokens_in: 1,
+            tokens_out: 1,
+            cost: 0.0,
+            model: "m".into(),
+        };
+        match r.redact_event(&observed) {
+            Some(ExecutionEvent::AgentObserved { content, .. }) => {
+                assert_eq!(content, "key: [REDACTED]")
+            }
+            other => panic!("expected a redacted AgentObserved, got {other:?}"),
+        }
+        let halted = ExecutionEvent::Halted {
+            reason: KEY.to_string(),
+        };
+        assert!(r.redact_event(&halted).is_none());
+    }
+}
@@ -81,6 +81,16 @@ pub fn convert_to_armadai(content: &str, fallback_name: &str) -> String {
     output
 }
 
+/// File name a registry agent gets in the library: `security.agent.md`
+/// becomes `security.md`.
+pub fn library_file_name(registry_path: &str) -> String {
+    let stem = Path::new(registry_path)
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("agent");
+    format!("{}.md", stem.trim_end_matches(".agent"))
+}
+
 /// Convert a registry agent and cache the result.
 ///
 /// `source` is the source key (see `sync::source_key`) the agent came from,
@@ -100,13 +110,11 @@ pub fn convert_cached(source: &str, registry_path: &str) -> anyhow::Result<PathB
     let cache_dir = converted_dir();
     std::fs::create_dir_all(&cache_dir)?;
 
-    // Derive output filename
     let stem = Path::new(registry_path)
         .file_stem()
         .and_then(|s| s.to_str())
         .unwrap_or("agent");
-    let out_name = format!("{}.md", stem.trim_end_matches(".agent"));
-    let dst = cache_dir.join(&out_name);
+    let dst = cache_dir.join(library_file_name(registry_path));
 
     let content = std::fs::read_to_string(&src)?;
     let converted = convert_to_armadai(&content, stem);
@@ -104,7 +104,7 @@ fn clone(url: &str, dest: &Path) -> anyhow::Result<()> {
     }
 
     let output = Command::new("git")
-        .args(["clone", "--depth", "1", url])
+        .args(["clone", "--depth", "1", "--", url])
         .arg(dest)
         .output()?;
 
@@ -350,6 +350,7 @@ mod tests {
             agents: vec![RegistrySource {
                 url: "https://example.com/custom-agents.git".to_string(),
                 kind: None,
+                ..Default::default()
             }],
             ..Default::default()
         };
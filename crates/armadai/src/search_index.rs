@@ -0,0 +1,555 @@
+//! Search index shared by the agent, skill and starter registries.
+//!
+//! Every entry of the cached registry indexes, plus every available starter
+//! pack, becomes a [`Document`]. Its name, tags, description and body are
+//! tokenized into one inverted index, ranked with BM25 (fields weighted:
+//! name > tags > description > body) and persisted to
+//! `registry/search-index.json`, so searching never touches the network.
+//! The index is rebuilt after each `registry sync` / `skills sync`, or on
+//! demand with `armadai search --rebuild`.
+//!
+//! Query terms are typo-tolerant: a term also matches indexed terms it
+//! prefixes, or that are within one edit (two for terms of 8 characters or
+//! more), at a discount. Every query term must match.
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use armadai_core::config::registry_cache_dir;
+use armadai_core::starter::{StarterPack, all_starters_dirs, starters_cache_dir};
+
+use crate::text::edit_distance;
+
+const INDEX_FILE: &str = "search-index.json";
+
+// BM25 parameters.
+const K1: f32 = 1.2;
+const B: f32 = 0.75;
+
+const NAME_WEIGHT: f32 = 3.0;
+const TAG_WEIGHT: f32 = 2.0;
+const DESCRIPTION_WEIGHT: f32 = 1.5;
+const BODY_WEIGHT: f32 = 1.0;
+
+const PREFIX_DISCOUNT: f32 = 0.8;
+const TYPO_DISCOUNT: f32 = 0.5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, clap::ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum Kind {
+    Agent,
+    Skill,
+    Starter,
+}
+
+impl Kind {
+    pub fn label(self) -> &'static str {
+        match self {
+            Kind::Agent => "agent",
+            Kind::Skill => "skill",
+            Kind::Starter => "starter",
+        }
+    }
+}
+
+/// One searchable registry entry.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Document {
+    pub kind: Kind,
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub stacks: Vec<String>,
+    /// Registry source: the agent source key, skill repo slug, or starter
+    /// source (`local` or the remote source's cache key).
+    pub source: String,
+    /// Where the entry lives within its source.
+    pub path: String,
+}
+
+/// Restricts a search to matching documents; `None` fields match anything.
+#[derive(Debug, Default)]
+pub struct Filter<'a> {
+    pub kind: Option<Kind>,
+    pub category: Option<&'a str>,
+    pub stack: Option<&'a str>,
+    /// Substring of the document's source.
+    pub source: Option<&'a str>,
+}
+
+impl Filter<'_> {
+    fn matches(&self, doc: &Document) -> bool {
+        self.kind.is_none_or(|k| doc.kind == k)
+            && self.category.is_none_or(|c| {
+                doc.category
+                    .as_deref()
+                    .is_some_and(|d| d.eq_ignore_ascii_case(c))
+            })
+            && self
+                .stack
+                .is_none_or(|s| doc.stacks.iter().any(|d| d.eq_ignore_ascii_case(s)))
+            && self
+                .source
+                .is_none_or(|s| doc.source.to_lowercase().contains(&s.to_lowercase()))
+    }
+}
+
+#[derive(Debug)]
+pub struct Hit<'a> {
+    pub doc: &'a Document,
+    pub score: f32,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct SearchIndex {
+    pub docs: Vec<Document>,
+    /// Weighted length of each document.
+    lengths: Vec<f32>,
+    avg_length: f32,
+    /// Term → (document, weighted term frequency).
+    postings: BTreeMap<String, Vec<(u32, f32)>>,
+}
+
+/// Lowercased alphanumeric words of `text` (`security-scanner` →
+/// `security`, `scanner`); single characters are dropped.
+fn tokenize(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|w| w.chars().count() > 1)
+        .map(str::to_lowercase)
+}
+
+/// Edits a query term of this length may be off by and still match.
+fn typo_budget(term: &str) -> usize {
+    match term.chars().count() {
+        0..=3 => 0,
+        4..=7 => 1,
+        _ => 2,
+    }
+}
+
+impl SearchIndex {
+    /// Index documents, each with the body text searched beyond its fields.
+    pub fn build(items: Vec<(Document, String)>) -> Self {
+        let mut index = SearchIndex::default();
+        for (id, (doc, body)) in items.into_iter().enumerate() {
+            let mut tf: HashMap<String, f32> = HashMap::new();
+            let mut add = |text: &str, weight: f32| {
+                for token in tokenize(text) {
+                    *tf.entry(token).or_default() += weight;
+                }
+            };
+            add(&doc.name, NAME_WEIGHT);
+            for tag in &doc.tags {
+                add(tag, TAG_WEIGHT);
+            }
+            add(doc.description.as_deref().unwrap_or(""), DESCRIPTION_WEIGHT);
+            add(&body, BODY_WEIGHT);
+
+            index.lengths.push(tf.values().sum());
+            for (term, freq) in tf {
+                index
+                    .postings
+                    .entry(term)
+                    .or_default()
+                    .push((id as u32, freq));
+            }
+            index.docs.push(doc);
+        }
+        let total: f32 = index.lengths.iter().sum();
+        index.avg_length = total / index.docs.len().max(1) as f32;
+        index
+    }
+
+    /// Documents matching every term of `query` and `filter`, best first.
+    pub fn search(&self, query: &str, filter: &Filter) -> Vec<Hit<'_>> {
+        let terms: Vec<String> = tokenize(query).collect();
+        if terms.is_empty() {
+            return Vec::new();
+        }
+        let n = self.docs.len() as f32;
+        let mut scores: HashMap<u32, (f32, usize)> = HashMap::new();
+        for term in &terms {
+            // Best contribution of this query term to each document.
+            let mut best: HashMap<u32, f32> = HashMap::new();
+            for (indexed, discount) in self.expand(term) {
+                let postings = &self.postings[indexed];
+                let df = postings.len() as f32;
+                let idf = (1.0 + (n - df + 0.5) / (df + 0.5)).ln();
+                for &(doc, tf) in postings {
+                    let norm = 1.0 - B + B * self.lengths[doc as usize] / self.avg_length;
+                    let score = discount * idf * tf * (K1 + 1.0) / (tf + K1 * norm);
+                    let slot = best.entry(doc).or_default();
+                    *slot = slot.max(score);
+                }
+            }
+            for (doc, score) in best {
+                let entry = scores.entry(doc).or_default();
+                entry.0 += score;
+                entry.1 += 1;
+            }
+        }
+
+        let mut hits: Vec<Hit> = scores
+            .into_iter()
+            .filter(|(_, (_, matched))| *matched == terms.len())
+            .map(|(doc, (score, _))| Hit {
+                doc: &self.docs[doc as usize],
+                score,
+            })
+            .filter(|hit| filter.matches(hit.doc))
+            .collect();
+        hits.sort_by(|a, b| {
+            b.score
+                .total_cmp(&a.score)
+                .then_with(|| a.doc.name.cmp(&b.doc.name))
+        });
+        hits
+    }
+
+    /// Indexed terms a query term matches, with the discount applied to
+    /// their score: exact, extended (prefix), or within the typo budget.
+    fn expand<'a>(&'a self, term: &str) -> Vec<(&'a String, f32)> {
+        let budget = typo_budget(term);
+        self.postings
+            .keys()
+            .filter_map(|indexed| {
+                if indexed == term {
+                    Some((indexed, 1.0))
+                } else if term.chars().count() >= 3 && indexed.starts_with(term) {
+                    Some((indexed, PREFIX_DISCOUNT))
+                } else if budget > 0
+                    && indexed.len().abs_diff(term.len()) <= budget
+                    && edit_distance(term, indexed) <= budget
+                {
+                    Some((indexed, TYPO_DISCOUNT))
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    pub fn path() -> PathBuf {
+        registry_cache_dir().join(INDEX_FILE)
+    }
+
+    pub fn load(path: &Path) -> anyhow::Result<Option<Self>> {
+        match std::fs::read_to_string(path) {
+            Ok(content) => Ok(Some(serde_json::from_str(&content)?)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, serde_json::to_string(self)?)?;
+        Ok(())
+    }
+}
+
+/// Rebuild the index from the cached registries and available starter
+/// packs, and persist it. Offline: only what is already synced is indexed.
+pub fn rebuild() -> anyhow::Result<SearchIndex> {
+    let mut items = agent_documents()?;
+    items.extend(skill_documents()?);
+    items.extend(starter_documents());
+    let index = SearchIndex::build(items);
+    index.save(&SearchIndex::path())?;
+    Ok(index)
+}
+
+/// The persisted index, or a fresh one if there is none yet (or it is
+/// unreadable, e.g. written by another version).
+pub fn load_or_rebuild() -> anyhow::Result<SearchIndex> {
+    match SearchIndex::load(&SearchIndex::path()) {
+        Ok(Some(index)) => Ok(index),
+        _ => rebuild(),
+    }
+}
+
+/// [`rebuild`] after a sync, logging instead of failing: the sync itself
+/// succeeded.
+pub fn rebuild_or_warn() {
+    if let Err(e) = rebuild() {
+        tracing::warn!("search index not rebuilt: {e}");
+    }
+}
+
+/// Values of a `key: [a, b]` / `- key: a, b` metadata line of `content`.
+fn list_field(content: &str, key: &str) -> Vec<String> {
+    for line in content.lines() {
+        let trimmed = line.trim().trim_start_matches("- ");
+        if let Some(rest) = trimmed.strip_prefix(key).and_then(|r| r.strip_prefix(':')) {
+            return rest
+                .trim()
+                .trim_start_matches('[')
+                .trim_end_matches(']')
+                .split(',')
+                .map(|s| s.trim().trim_matches('"').trim_matches('\'').to_string())
+                .filter(|s| !s.is_empty())
+                .collect();
+        }
+    }
+    Vec::new()
+}
+
+fn agent_documents() -> anyhow::Result<Vec<(Document, String)>> {
+    use crate::registry::{cache, sync};
+    let index = cache::load_or_build_index(&sync::effective_sources())?;
+    Ok(index
+        .entries
+        .into_iter()
+        .map(|e| {
+            let body = std::fs::read_to_string(sync::dir_for_key(&e.source).join(&e.path))
+                .unwrap_or_default();
+            let doc = Document {
+                kind: Kind::Agent,
+                stacks: list_field(&body, "stacks"),
+                name: e.name,
+                description: e.description,
+                tags: e.tags,
+                category: e.category,
+                source: e.source,
+                path: e.path,
+            };
+            (doc, body)
+        })
+        .collect())
+}
+
+fn skill_documents() -> anyhow::Result<Vec<(Document, String)>> {
+    use crate::skills_registry::{cache, sync};
+    let index = cache::load_or_build_index(&cache::effective_sources())?;
+    Ok(index
+        .entries
+        .into_iter()
+        .map(|e| {
+            let body = sync::parse_source(&e.source_repo)
+                .map(|(owner, repo)| sync::repo_dir(&owner, &repo).join(&e.path).join("SKILL.md"))
+                .and_then(|p| std::fs::read_to_string(p).ok())
+                .unwrap_or_default();
+            let doc = Document {
+                kind: Kind::Skill,
+                name: e.name,
+                description: e.description,
+                tags: e.tags,
+                category: None,
+                stacks: Vec::new(),
+                source: e.source_repo,
+                path: e.path,
+            };
+            (doc, body)
+        })
+        .collect())
+}
+
+/// Starter packs, local ones shadowing remote packs of the same name (as
+/// `load_all_packs` does). Tags and stacks are those of the pack's agents.
+fn starter_documents() -> Vec<(Document, String)> {
+    let mut dirs: Vec<(String, PathBuf)> = Vec::new();
+    for dir in all_starters_dirs() {
+        let Ok(entries) = std::fs::read_dir(&dir) else {
+            continue;
+        };
+        let mut packs: Vec<PathBuf> = entries
+            .flatten()
+            .map(|e| e.path())
+            .filter(|p| p.join("pack.yaml").is_file())
+            .collect();
+        packs.sort();
+        dirs.extend(packs.into_iter().map(|p| ("local".to_string(), p)));
+    }
+    let cache = starters_cache_dir();
+    for dir in armadai_core::starter::remote_starter_pack_dirs() {
+        let source = dir
+            .strip_prefix(&cache)
+            .ok()
+            .and_then(|rel| rel.components().next())
+            .map(|c| c.as_os_str().to_string_lossy().into_owned())
+            .unwrap_or_default();
+        dirs.push((source, dir));
+    }
+
+    let mut by_name: BTreeMap<String, (Document, String)> = BTreeMap::new();
+    for (source, dir) in dirs {
+        let Ok(pack) = StarterPack::load(&dir) else {
+            continue;
+        };
+        // Later local dirs override earlier ones; remote never overrides.
+        if source != "local" && by_name.contains_key(&pack.name) {
+            continue;
+        }
+        let mut body = String::new();
+        let (mut tags, mut stacks) = (Vec::new(), Vec::new());
+        for agent in &pack.agents {
+            let file = dir
+                .join("agents")
+                .join(format!("{}.md", agent.trim_end_matches(".md")));
+            let content = std::fs::read_to_string(file).unwrap_or_default();
+            for tag in list_field(&content, "tags") {
+                if !tags.contains(&tag) {
+                    tags.push(tag);
+                }
+            }
+            for stack in list_field(&content, "stacks") {
+                if !stacks.contains(&stack) {
+                    stacks.push(stack);
+                }
+            }
+            body.push_str(agent);
+            body.push('\n');
+            body.push_str(&content);
+        }
+        let doc = Document {
+            kind: Kind::Starter,
+            name: pack.name.clone(),
+            description: Some(pack.description),
+            tags,
+            category: None,
+            stacks,
+            source,
+            path: dir.display().to_string(),
+        };
+        by_name.insert(pack.name, (doc, body));
+    }
+    by_name.into_values().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn doc(kind: Kind, name: &str, desc: &str, tags: &[&str], body: &str) -> (Document, String) {
+        let doc = Document {
+            kind,
+            name: name.to_string(),
+            description: Some(desc.to_string()),
+            tags: tags.iter().map(|t| t.to_string()).collect(),
+            category: Some("official".to_string()),
+            stacks: list_field(body, "stacks"),
+            source: "awesome-copilot".to_string(),
+            path: format!("{name}.md"),
+        };
+        (doc, body.to_string())
+    }
+
+    fn index() -> SearchIndex {
+        SearchIndex::build(vec![
+            doc(
+                Kind::Agent,
+                "security-scanner",
+                "OWASP vulnerability scanner",
+                &["security"],
+                "- stacks: [rust, go]\nFinds injection flaws.",
+            ),
+            doc(
+                Kind::Agent,
+                "code-reviewer",
+                "General code review",
+                &["review"],
+                "Mentions security once.",
+            ),
+            doc(
+                Kind::Skill,
+                "webapp-testing",
+                "Test web applications",
+                &["playwright"],
+                "",
+            ),
+            doc(
+                Kind::Starter,
+                "rust-dev",
+                "Rust development pack",
+                &["review"],
+                "- stacks: [rust]\n",
+            ),
+        ])
+    }
+
+    fn names<'a>(hits: &[Hit<'a>]) -> Vec<&'a str> {
+        hits.iter().map(|h| h.doc.name.as_str()).collect()
+    }
+
+    #[test]
+    fn ranks_field_matches_above_body_matches() {
+        let index = index();
+        let hits = index.search("security", &Filter::default());
+        assert_eq!(names(&hits), ["security-scanner", "code-reviewer"]);
+        assert!(hits[0].score > hits[1].score);
+        // Every term must match.
+        let hits = index.search("security review", &Filter::default());
+        assert_eq!(names(&hits), ["code-reviewer"]);
+        assert!(index.search("", &Filter::default()).is_empty());
+        assert!(index.search("nonexistent", &Filter::default()).is_empty());
+    }
+
+    #[test]
+    fn tolerates_typos_and_prefixes() {
+        let index = index();
+        assert_eq!(
+            names(&index.search("securty", &Filter::default()))[0],
+            "security-scanner"
+        );
+        assert_eq!(
+            names(&index.search("playwrigth", &Filter::default())),
+            ["webapp-testing"]
+        );
+        assert_eq!(
+            names(&index.search("vuln", &Filter::default())),
+            ["security-scanner"]
+        );
+        // Short terms must match exactly.
+        assert!(index.search("ru", &Filter::default()).is_empty());
+    }
+
+    #[test]
+    fn filters_by_kind_stack_category_and_source() {
+        let index = index();
+        let filter = Filter {
+            kind: Some(Kind::Starter),
+            ..Default::default()
+        };
+        assert_eq!(names(&index.search("review", &filter)), ["rust-dev"]);
+        let filter = Filter {
+            stack: Some("Go"),
+            ..Default::default()
+        };
+        assert_eq!(
+            names(&index.search("security", &filter)),
+            ["security-scanner"]
+        );
+        let filter = Filter {
+            category: Some("community"),
+            ..Default::default()
+        };
+        assert!(index.search("security", &filter).is_empty());
+        let filter = Filter {
+            source: Some("copilot"),
+            ..Default::default()
+        };
+        assert_eq!(index.search("security", &filter).len(), 2);
+    }
+
+    #[test]
+    fn index_round_trips_through_disk() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("registry").join(INDEX_FILE);
+        assert!(SearchIndex::load(&path).unwrap().is_none());
+        index().save(&path).unwrap();
+        let loaded = SearchIndex::load(&path).unwrap().unwrap();
+        assert_eq!(
+            names(&loaded.search("scanner", &Filter::default())),
+            ["security-scanner"]
+        );
+    }
+}
@@ -0,0 +1,28 @@
+//! Claude Code sessions, registered by the armadai plugin's SessionStart
+//! hook into the session index.
+
+use super::{SessionAdapter, Tail};
+use crate::claude_adapter::ClaudeTail;
+use crate::claude_adapter::session_index::{self, SessionRef};
+
+pub struct ClaudeSessions;
+
+impl SessionAdapter for ClaudeSessions {
+    fn name(&self) -> &'static str {
+        "claude"
+    }
+
+    fn sessions(&self) -> anyhow::Result<Vec<SessionRef>> {
+        session_index::load()
+    }
+
+    fn tail(&self, session: &SessionRef) -> Box<dyn Tail> {
+        Box::new(ClaudeTail::new(session))
+    }
+
+    fn no_sessions_hint(&self) -> String {
+        "no Claude Code sessions registered — install the armadai-workroom plugin \
+         (see crates/armadai/assets/claude-plugin) and start a Claude Code session"
+            .to_string()
+    }
+}
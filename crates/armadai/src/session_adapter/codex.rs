@@ -0,0 +1,250 @@
+//! Codex sessions, read from its rollouts (`~/.codex/sessions/`).
+//!
+//! A rollout is appended to line by line, like a Claude Code transcript:
+//! `turn_context` lines carry the model, `response_item` lines the messages
+//! and tool calls, and `event_msg` lines the token counts and the
+//! `task_started`/`task_complete` pair bracketing each turn.
+
+use armadai_core::events::RunEvent;
+use serde_json::Value;
+
+use super::{LineTail, RunTracker, SessionAdapter, Tail};
+use crate::audit::usage::codex::{ROOT_AGENT, rollouts, session_meta};
+use crate::claude_adapter::session_index::SessionRef;
+
+/// Tool spawning a sub-agent; its role is the `agent_type` argument.
+const SPAWN_TOOL: &str = "spawn_agent";
+
+pub struct CodexSessions;
+
+impl SessionAdapter for CodexSessions {
+    fn name(&self) -> &'static str {
+        ROOT_AGENT
+    }
+
+    fn sessions(&self) -> anyhow::Result<Vec<SessionRef>> {
+        Ok(rollouts()
+            .into_iter()
+            .map(|path| {
+                let meta = session_meta(&path).unwrap_or(Value::Null);
+                let field = |k: &str| meta.get(k).and_then(Value::as_str).map(str::to_string);
+                SessionRef {
+                    session_id: field("id").unwrap_or_else(|| {
+                        path.file_stem()
+                            .map(|s| s.to_string_lossy().into_owned())
+                            .unwrap_or_default()
+                    }),
+                    cwd: field("cwd").unwrap_or_default(),
+                    started_at: field("timestamp").unwrap_or_default(),
+                    transcript_path: path,
+                }
+            })
+            .collect())
+    }
+
+    fn tail(&self, session: &SessionRef) -> Box<dyn Tail> {
+        Box::new(CodexTail {
+            lines: LineTail::new(&session.transcript_path),
+            run: RunTracker::new(&session.session_id, ROOT_AGENT),
+            turn_done: false,
+        })
+    }
+
+    fn no_sessions_hint(&self) -> String {
+        "no Codex sessions found under ~/.codex/sessions (set CODEX_HOME if it lives \
+         elsewhere) — start a Codex session first"
+            .to_string()
+    }
+}
+
+struct CodexTail {
+    lines: LineTail,
+    run: RunTracker,
+    /// The last turn read reached `task_complete`.
+    turn_done: bool,
+}
+
+impl CodexTail {
+    fn push(&mut self, v: &Value) -> Vec<RunEvent> {
+        let str_of = |v: &Value, k: &str| v.get(k).and_then(Value::as_str).map(str::to_string);
+        let Some(payload) = v.get("payload") else {
+            return Vec::new();
+        };
+        let kind = str_of(v, "type").unwrap_or_default();
+        if kind == "session_meta" {
+            return Vec::new();
+        }
+        let model = match kind.as_str() {
+            "turn_context" => str_of(payload, "model").unwrap_or_default(),
+            _ => String::new(),
+        };
+        // Turn brackets and token counts come before the turn's model is
+        // known; the run opens on the first line that states it, or acts.
+        let mut out = if kind == "event_msg" {
+            Vec::new()
+        } else {
+            self.run.start(&model)
+        };
+        match (kind.as_str(), str_of(payload, "type").as_deref()) {
+            ("event_msg", Some("task_started")) => self.turn_done = false,
+            ("event_msg", Some("task_complete")) => {
+                self.turn_done = true;
+                if let Some(text) = str_of(payload, "last_agent_message") {
+                    self.run.last_text = text;
+                }
+            }
+            ("event_msg", Some("agent_message")) => {
+                if let Some(text) = str_of(payload, "message") {
+                    self.run.last_text = text;
+                }
+            }
+            ("event_msg", Some("token_count")) => {
+                let total = payload.pointer("/info/total_token_usage");
+                let tokens = |k: &str| {
+                    total
+                        .and_then(|t| t.get(k))
+                        .and_then(Value::as_u64)
+                        .map(|n| u32::try_from(n).unwrap_or(u32::MAX))
+                };
+                if let (Some(tin), Some(tout)) = (tokens("input_tokens"), tokens("output_tokens")) {
+                    self.run.tin = tin;
+                    self.run.tout = tout;
+                }
+            }
+            ("response_item", Some("message"))
+                if str_of(payload, "role").as_deref() == Some("assistant") =>
+            {
+                let text: String = payload
+                    .get("content")
+                    .and_then(Value::as_array)
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|c| c.get("text").and_then(Value::as_str))
+                    .collect();
+                if !text.trim().is_empty() {
+                    self.run.last_text = text;
+                }
+            }
+            ("response_item", Some("function_call"))
+                if str_of(payload, "name").as_deref() == Some(SPAWN_TOOL) =>
+            {
+                let args: Value = str_of(payload, "arguments")
+                    .and_then(|a| serde_json::from_str(&a).ok())
+                    .unwrap_or(Value::Null);
+                let role = str_of(&args, "agent_type").unwrap_or_else(|| "default".to_string());
+                let id = str_of(payload, "call_id").unwrap_or_default();
+                out.extend(self.run.spawn(&id, &role));
+            }
+            ("response_item", Some("function_call_output")) => {
+                let id = str_of(payload, "call_id").unwrap_or_default();
+                let text = match payload.get("output") {
+                    Some(Value::String(s)) => s.clone(),
+                    Some(o) => str_of(o, "content").unwrap_or_else(|| o.to_string()),
+                    None => String::new(),
+                };
+                out.extend(self.run.end(&id, &text));
+            }
+            _ => {}
+        }
+        out
+    }
+}
+
+impl Tail for CodexTail {
+    fn poll(&mut self) -> anyhow::Result<Option<Vec<RunEvent>>> {
+        let lines = self.lines.read_lines()?;
+        if lines.is_empty() {
+            return Ok(None);
+        }
+        let mut out = Vec::new();
+        for line in lines {
+            if let Ok(v) = serde_json::from_str::<Value>(line.trim()) {
+                out.extend(self.push(&v));
+            }
+        }
+        Ok(Some(out))
+    }
+
+    fn turn_complete(&self) -> bool {
+        self.turn_done && !self.run.has_pending()
+    }
+
+    fn finish(&mut self) -> Vec<RunEvent> {
+        self.run.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_spawns_tokens_and_turn_completion() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("rollout-a.jsonl");
+        let lines = [
+            r#"{"type":"session_meta","payload":{"id":"abc","cwd":"/p","timestamp":"2026-09-01T10:00:00Z"}}"#,
+            r#"{"type":"event_msg","payload":{"type":"task_started"}}"#,
+            r#"{"type":"turn_context","payload":{"cwd":"/p","model":"gpt-5-codex"}}"#,
+            r#"{"type":"response_item","payload":{"type":"function_call","name":"spawn_agent","arguments":"{\"agent_type\":\"reviewer\"}","call_id":"c1"}}"#,
+            r#"{"type":"response_item","payload":{"type":"function_call","name":"shell","arguments":"{}","call_id":"c2"}}"#,
+        ];
+        std::fs::write(&path, lines.join("\n") + "\n").unwrap();
+        let session = SessionRef {
+            session_id: "abc".into(),
+            transcript_path: path.clone(),
+            cwd: "/p".into(),
+            started_at: String::new(),
+        };
+        let mut tail = CodexSessions.tail(&session);
+        let evs = tail.poll().unwrap().unwrap();
+        assert!(
+            matches!(&evs[0], RunEvent::RunStart { run_id, model, .. } if run_id == "abc" && model == "gpt-5-codex")
+        );
+        assert!(evs.iter().any(
+            |e| matches!(e, RunEvent::Delegate { from, to } if from == "codex" && to == "reviewer")
+        ));
+        assert!(!tail.turn_complete());
+        assert!(tail.poll().unwrap().is_none(), "nothing new");
+
+        let more = [
+            r#"{"type":"response_item","payload":{"type":"function_call_output","call_id":"c1","output":"looks good"}}"#,
+            r#"{"type":"event_msg","payload":{"type":"token_count","info":{"total_token_usage":{"input_tokens":120,"output_tokens":30}}}}"#,
+            r#"{"type":"event_msg","payload":{"type":"task_complete","last_agent_message":"Reviewed."}}"#,
+        ];
+        let mut text = std::fs::read_to_string(&path).unwrap();
+        text.push_str(&(more.join("\n") + "\n"));
+        std::fs::write(&path, text).unwrap();
+        let evs = tail.poll().unwrap().unwrap();
+        assert!(evs.iter().any(
+            |e| matches!(e, RunEvent::AgentEnd { agent, content, .. } if agent == "reviewer" && content == "looks good")
+        ));
+        assert!(tail.turn_complete());
+        let evs = tail.finish();
+        assert!(matches!(
+            evs.last().unwrap(),
+            RunEvent::Result { content, tin: 120, tout: 30, agents: 2, .. } if content == "Reviewed."
+        ));
+    }
+
+    #[test]
+    fn lists_rollouts_with_their_session_meta() {
+        let _lock = armadai_core::config::ENV_MUTEX.lock().unwrap();
+        let sessions = tempfile::tempdir().unwrap();
+        let day = sessions.path().join("2026/09/01");
+        std::fs::create_dir_all(&day).unwrap();
+        std::fs::write(
+            day.join("rollout-2026-09-01T10-00-00-abc.jsonl"),
+            r#"{"type":"session_meta","payload":{"id":"abc","cwd":"/p","timestamp":"2026-09-01T10:00:00Z"}}"#,
+        )
+        .unwrap();
+        // SAFETY: modifies the global environment; serialised via ENV_MUTEX.
+        unsafe { std::env::set_var("ARMADAI_CODEX_SESSIONS_DIR", sessions.path()) }
+        let found = CodexSessions.sessions().unwrap();
+        unsafe { std::env::remove_var("ARMADAI_CODEX_SESSIONS_DIR") }
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].session_id, "abc");
+        assert_eq!(found[0].cwd, "/p");
+        assert_eq!(found[0].started_at, "2026-09-01T10:00:00Z");
+    }
+}
@@ -0,0 +1,314 @@
+//! Gemini CLI sessions, read from its chat recordings
+//! (`~/.gemini/tmp/<project>/chats/session-*.json`).
+//!
+//! A recording is one JSON document rewritten in place as the session goes,
+//! so the tail re-reads it whole whenever it changes and emits only what it
+//! has not seen yet: tool calls are keyed by id, and a call's result lands
+//! in the same entry once the tool returns.
+
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use armadai_core::events::RunEvent;
+use serde_json::Value;
+
+use super::{RunTracker, SessionAdapter, Tail, stamp};
+use crate::audit::usage::gemini::{ROOT_AGENT, declared_agents, delegated_agent, tmp_root};
+use crate::claude_adapter::session_index::SessionRef;
+
+/// Tool call statuses meaning the tool has returned.
+const DONE_STATUSES: &[&str] = &["success", "error", "cancelled"];
+
+pub struct GeminiSessions;
+
+/// Every chat recording on this machine, with the project directory it
+/// sits in.
+fn recordings(tmp: &Path) -> Vec<(PathBuf, PathBuf)> {
+    let Ok(projects) = std::fs::read_dir(tmp) else {
+        return Vec::new();
+    };
+    let mut out = Vec::new();
+    for project in projects.flatten().map(|e| e.path()) {
+        let Ok(chats) = std::fs::read_dir(project.join("chats")) else {
+            continue;
+        };
+        for file in chats.flatten().map(|e| e.path()) {
+            let is_session = file.extension().is_some_and(|e| e == "json")
+                && file
+                    .file_name()
+                    .is_some_and(|n| n.to_string_lossy().starts_with("session-"));
+            if is_session {
+                out.push((project.clone(), file));
+            }
+        }
+    }
+    out
+}
+
+impl SessionAdapter for GeminiSessions {
+    fn name(&self) -> &'static str {
+        ROOT_AGENT
+    }
+
+    fn sessions(&self) -> anyhow::Result<Vec<SessionRef>> {
+        let Some(tmp) = tmp_root() else {
+            return Ok(Vec::new());
+        };
+        let mut sessions: Vec<SessionRef> = recordings(&tmp)
+            .into_iter()
+            .map(|(project, path)| {
+                let head: Value = std::fs::read_to_string(&path)
+                    .ok()
+                    .and_then(|raw| serde_json::from_str(&raw).ok())
+                    .unwrap_or(Value::Null);
+                let field = |k: &str| head.get(k).and_then(Value::as_str).map(str::to_string);
+                SessionRef {
+                    session_id: field("sessionId").unwrap_or_else(|| {
+                        path.file_stem()
+                            .map(|s| s.to_string_lossy().into_owned())
+                            .unwrap_or_default()
+                    }),
+                    cwd: std::fs::read_to_string(project.join(".project_root"))
+                        .map(|r| r.trim().to_string())
+                        .unwrap_or_default(),
+                    started_at: field("startTime").unwrap_or_default(),
+                    transcript_path: path,
+                }
+            })
+            .collect();
+        sessions.sort_by(|a, b| {
+            (&a.started_at, &a.transcript_path).cmp(&(&b.started_at, &b.transcript_path))
+        });
+        Ok(sessions)
+    }
+
+    fn tail(&self, session: &SessionRef) -> Box<dyn Tail> {
+        let declared = if session.cwd.is_empty() {
+            Vec::new()
+        } else {
+            declared_agents(Path::new(&session.cwd))
+        };
+        Box::new(GeminiTail {
+            path: session.transcript_path.clone(),
+            stamp: None,
+            run: RunTracker::new(&session.session_id, ROOT_AGENT),
+            declared,
+            spawned: HashSet::new(),
+            turn_done: false,
+        })
+    }
+
+    fn no_sessions_hint(&self) -> String {
+        "no Gemini CLI sessions found under ~/.gemini/tmp — start a Gemini CLI session first"
+            .to_string()
+    }
+}
+
+struct GeminiTail {
+    path: PathBuf,
+    /// Size and mtime of the recording at the last read.
+    stamp: Option<(u64, Option<std::time::SystemTime>)>,
+    run: RunTracker,
+    /// Sub-agents declared in the project, callable as tools.
+    declared: Vec<String>,
+    /// Ids of the delegating calls already reported.
+    spawned: HashSet<String>,
+    /// The last message is a model reply that called no tool.
+    turn_done: bool,
+}
+
+/// Text of a message's `content`: a string, or parts carrying `text`.
+fn content_text(message: &Value) -> String {
+    match message.get("content") {
+        Some(Value::String(s)) => s.clone(),
+        Some(Value::Array(parts)) => parts
+            .iter()
+            .filter_map(|p| p.get("text").and_then(Value::as_str))
+            .collect(),
+        _ => String::new(),
+    }
+}
+
+/// What a finished tool call returned, as the CLI displayed it.
+fn call_result(call: &Value) -> String {
+    match call.get("resultDisplay") {
+        Some(Value::String(s)) => s.clone(),
+        _ => call.get("result").map(Value::to_string).unwrap_or_default(),
+    }
+}
+
+impl GeminiTail {
+    fn scan(&mut self, session: &Value) -> Vec<RunEvent> {
+        let mut out = Vec::new();
+        let (mut tin, mut tout) = (0u32, 0u32);
+        let messages = session
+            .get("messages")
+            .and_then(Value::as_array)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        for (i, message) in messages.iter().enumerate() {
+            if message.get("type").and_then(Value::as_str) != Some("gemini") {
+                continue;
+            }
+            let model = message.get("model").and_then(Value::as_str).unwrap_or("");
+            out.extend(self.run.start(model));
+            let count = |k: &str| {
+                message
+                    .pointer(&format!("/tokens/{k}"))
+                    .and_then(Value::as_u64)
+                    .map_or(0, |n| u32::try_from(n).unwrap_or(u32::MAX))
+            };
+            tin = tin.saturating_add(count("input"));
+            tout = tout.saturating_add(count("output"));
+            let text = content_text(message);
+            if !text.trim().is_empty() {
+                self.run.last_text = text;
+            }
+            let calls = message.get("toolCalls").and_then(Value::as_array);
+            for (j, call) in calls.into_iter().flatten().enumerate() {
+                let Some(name) = call.get("name").and_then(Value::as_str) else {
+                    continue;
+                };
+                let args = call.get("args").unwrap_or(&Value::Null);
+                let Some(agent) = delegated_agent(name, args, &self.declared) else {
+                    continue;
+                };
+                let id = call
+                    .get("id")
+                    .and_then(Value::as_str)
+                    .map_or_else(|| format!("{i}:{j}"), str::to_string);
+                if self.spawned.insert(id.clone()) {
+                    out.extend(self.run.spawn(&id, agent));
+                }
+                let status = call.get("status").and_then(Value::as_str).unwrap_or("");
+                if DONE_STATUSES.contains(&status) {
+                    out.extend(self.run.end(&id, &call_result(call)));
+                }
+            }
+        }
+        self.run.tin = tin;
+        self.run.tout = tout;
+        self.turn_done = messages.last().is_some_and(|m| {
+            m.get("type").and_then(Value::as_str) == Some("gemini")
+                && m.get("toolCalls")
+                    .and_then(Value::as_array)
+                    .is_none_or(Vec::is_empty)
+        });
+        out
+    }
+}
+
+impl Tail for GeminiTail {
+    fn poll(&mut self) -> anyhow::Result<Option<Vec<RunEvent>>> {
+        let now = stamp(&self.path)?;
+        if self.stamp == Some(now) {
+            return Ok(None);
+        }
+        let raw = std::fs::read_to_string(&self.path)?;
+        // Caught mid-rewrite: leave the stamp alone so the next poll retries.
+        let Ok(session) = serde_json::from_str::<Value>(&raw) else {
+            return Ok(None);
+        };
+        self.stamp = Some(now);
+        Ok(Some(self.scan(&session)))
+    }
+
+    fn turn_complete(&self) -> bool {
+        self.turn_done && !self.run.has_pending()
+    }
+
+    fn finish(&mut self) -> Vec<RunEvent> {
+        self.run.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn recording(calls: &str, reply: Option<&str>) -> String {
+        let reply = reply
+            .map(|r| {
+                format!(
+                    r#",{{"type":"gemini","model":"gemini-2.5-pro","content":"{r}","tokens":{{"input":5,"output":2}}}}"#
+                )
+            })
+            .unwrap_or_default();
+        format!(
+            r#"{{"sessionId":"g1","startTime":"2026-09-01T10:00:00Z","messages":[
+                {{"type":"user","content":"go"}},
+                {{"type":"gemini","model":"gemini-2.5-pro","content":"","tokens":{{"input":10,"output":3}},"toolCalls":[{calls}]}}{reply}]}}"#
+        )
+    }
+
+    #[test]
+    fn follows_a_recording_rewritten_in_place() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("session-1.json");
+        std::fs::write(
+            &path,
+            recording(
+                r#"{"id":"t1","name":"delegate_to_agent","args":{"agent_name":"codebase_investigator"},"status":"executing"}"#,
+                None,
+            ),
+        )
+        .unwrap();
+        let session = SessionRef {
+            session_id: "g1".into(),
+            transcript_path: path.clone(),
+            cwd: String::new(),
+            started_at: String::new(),
+        };
+        let mut tail = GeminiSessions.tail(&session);
+        let evs = tail.poll().unwrap().unwrap();
+        assert!(matches!(&evs[0], RunEvent::RunStart { model, .. } if model == "gemini-2.5-pro"));
+        assert!(evs.iter().any(
+            |e| matches!(e, RunEvent::Delegate { from, to } if from == "gemini" && to == "codebase_investigator")
+        ));
+        assert!(tail.poll().unwrap().is_none(), "unchanged recording");
+        assert!(!tail.turn_complete());
+
+        std::fs::write(
+            &path,
+            recording(
+                r#"{"id":"t1","name":"delegate_to_agent","args":{"agent_name":"codebase_investigator"},"status":"success","resultDisplay":"found it"}"#,
+                Some("All set."),
+            ),
+        )
+        .unwrap();
+        let evs = tail.poll().unwrap().unwrap();
+        assert!(
+            !evs.iter().any(|e| matches!(e, RunEvent::Delegate { .. })),
+            "the delegation is reported once: {evs:?}"
+        );
+        assert!(evs.iter().any(
+            |e| matches!(e, RunEvent::AgentEnd { agent, content, .. } if agent == "codebase_investigator" && content == "found it")
+        ));
+        assert!(tail.turn_complete());
+        assert!(matches!(
+            tail.finish().last().unwrap(),
+            RunEvent::Result { content, tin: 15, tout: 5, agents: 2, .. } if content == "All set."
+        ));
+    }
+
+    #[test]
+    fn lists_recordings_with_their_project_root() {
+        let _lock = armadai_core::config::ENV_MUTEX.lock().unwrap();
+        let tmp = tempfile::tempdir().unwrap();
+        let project = tmp.path().join("my-project");
+        std::fs::create_dir_all(project.join("chats")).unwrap();
+        std::fs::write(project.join(".project_root"), "/work/p\n").unwrap();
+        std::fs::write(
+            project.join("chats/session-1.json"),
+            r#"{"sessionId":"g1","startTime":"2026-09-01T10:00:00Z","messages":[]}"#,
+        )
+        .unwrap();
+        // SAFETY: modifies the global environment; serialised via ENV_MUTEX.
+        unsafe { std::env::set_var("ARMADAI_GEMINI_TMP_DIR", tmp.path()) }
+        let found = GeminiSessions.sessions().unwrap();
+        unsafe { std::env::remove_var("ARMADAI_GEMINI_TMP_DIR") }
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].session_id, "g1");
+        assert_eq!(found[0].cwd, "/work/p");
+    }
+}
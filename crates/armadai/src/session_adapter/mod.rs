@@ -0,0 +1,354 @@
+//! Follow an assistant CLI's session in the Workroom, whichever CLI ran it.
+//!
+//! Each source knows where its CLI keeps sessions ([`SessionAdapter`]) and
+//! how to turn what a transcript gained since the last read into
+//! `RunEvent`s ([`Tail`]); [`drive`] is the polling loop they share.
+//! Claude Code's own reconstruction lives in `claude_adapter`.
+
+pub mod claude;
+pub mod codex;
+pub mod gemini;
+
+use std::collections::{HashMap, HashSet};
+use std::io::BufRead;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use armadai_core::events::{EventSink, RunEvent};
+
+use crate::claude_adapter::mapper::truncate_chars;
+use crate::claude_adapter::session_index::SessionRef;
+
+/// Sources accepted by `armadai watch --source`.
+pub const SOURCES: &[&str] = &["claude", "gemini", "codex"];
+
+/// Longest agent output carried by an `AgentEnd` or `Result`.
+const MAX_CONTENT: usize = 2000;
+
+/// Where a CLI keeps its sessions and how to follow one.
+pub trait SessionAdapter {
+    /// Source name, also the root agent of the reconstructed run.
+    fn name(&self) -> &'static str;
+    /// Known sessions, oldest first.
+    fn sessions(&self) -> anyhow::Result<Vec<SessionRef>>;
+    /// A reader positioned at the start of `session`'s transcript.
+    fn tail(&self, session: &SessionRef) -> Box<dyn Tail>;
+    /// What to do when no session is found.
+    fn no_sessions_hint(&self) -> String;
+}
+
+/// The adapter for `source`, one of [`SOURCES`].
+pub fn adapter(source: &str) -> anyhow::Result<Box<dyn SessionAdapter>> {
+    match source {
+        "claude" => Ok(Box::new(claude::ClaudeSessions)),
+        "gemini" => Ok(Box::new(gemini::GeminiSessions)),
+        "codex" => Ok(Box::new(codex::CodexSessions)),
+        other => anyhow::bail!(
+            "unknown session source '{other}' (expected one of: {})",
+            SOURCES.join(", ")
+        ),
+    }
+}
+
+/// Incremental reader of one transcript.
+pub trait Tail: Send {
+    /// Events for whatever the transcript gained since the last call;
+    /// `None` when it did not change.
+    fn poll(&mut self) -> anyhow::Result<Option<Vec<RunEvent>>>;
+    /// Whether the last turn read has ended, the CLI waiting on its user.
+    fn turn_complete(&self) -> bool;
+    /// Closing events, at end of replay or once the turn is complete.
+    fn finish(&mut self) -> Vec<RunEvent>;
+}
+
+/// How often [`drive`] polls and when it gives up on a silent session.
+#[derive(Debug, Clone, Copy)]
+pub struct Polling {
+    pub interval: std::time::Duration,
+    /// Polls without growth, mid-turn, before the session counts as
+    /// abandoned. A safety net only: see `claude_adapter::IDLE_ABANDON_POLLS`.
+    pub idle_abandon: u32,
+}
+
+/// The timings `armadai watch` follows every source with.
+pub const POLLING: Polling = Polling {
+    interval: crate::claude_adapter::POLL_INTERVAL,
+    idle_abandon: crate::claude_adapter::IDLE_ABANDON_POLLS,
+};
+
+/// Read `session` through `tail` and emit its events to `sink`.
+/// `follow=false` replays to the end then finishes; `follow=true` keeps
+/// polling until the tail reports a complete turn, or the session falls
+/// silent mid-turn for `polling.idle_abandon` polls.
+pub async fn drive(
+    session: &SessionRef,
+    mut tail: Box<dyn Tail>,
+    sink: Arc<dyn EventSink>,
+    follow: bool,
+    polling: Polling,
+) -> anyhow::Result<()> {
+    let mut idle_polls: u32 = 0;
+    loop {
+        let polled = match tail.poll() {
+            Ok(polled) => polled,
+            Err(e) => {
+                sink.emit(&RunEvent::Error {
+                    code: "transcript_unreadable".into(),
+                    msg: format!("{}: {e}", session.transcript_path.display()),
+                });
+                return Ok(());
+            }
+        };
+        let grew = polled.is_some();
+        for ev in polled.into_iter().flatten() {
+            sink.emit(&ev);
+        }
+        let done = if !follow {
+            true
+        } else if grew {
+            idle_polls = 0;
+            false
+        } else if tail.turn_complete() {
+            true
+        } else {
+            idle_polls += 1;
+            idle_polls >= polling.idle_abandon
+        };
+        if done {
+            for ev in tail.finish() {
+                sink.emit(&ev);
+            }
+            return Ok(());
+        }
+        tokio::time::sleep(polling.interval).await;
+    }
+}
+
+/// Complete lines appended to a JSONL transcript since the last read. A
+/// partially-written trailing line is left for the next read.
+pub struct LineTail {
+    path: PathBuf,
+    offset: u64,
+}
+
+impl LineTail {
+    pub fn new(path: &Path) -> Self {
+        Self {
+            path: path.to_path_buf(),
+            offset: 0,
+        }
+    }
+
+    /// A tail that skips what `path` already holds: only lines appended
+    /// from now on are read. A missing file starts at its beginning.
+    pub fn at_end(path: &Path) -> Self {
+        Self {
+            path: path.to_path_buf(),
+            offset: std::fs::metadata(path).map(|m| m.len()).unwrap_or(0),
+        }
+    }
+
+    pub fn read_lines(&mut self) -> anyhow::Result<Vec<String>> {
+        use std::io::Seek;
+        let file = std::fs::File::open(&self.path)?;
+        let mut reader = std::io::BufReader::new(file);
+        reader.seek(std::io::SeekFrom::Start(self.offset))?;
+        let mut lines = Vec::new();
+        let mut line = String::new();
+        loop {
+            line.clear();
+            let n = reader.read_line(&mut line)?;
+            if n == 0 || !line.ends_with('\n') {
+                break;
+            }
+            self.offset += n as u64;
+            lines.push(line.clone());
+        }
+        Ok(lines)
+    }
+}
+
+/// Run-level bookkeeping shared by the non-Claude tails: the root agent,
+/// the sub-agents in flight and the token totals.
+pub struct RunTracker {
+    session_id: String,
+    root: &'static str,
+    started: bool,
+    model: String,
+    pub tin: u32,
+    pub tout: u32,
+    pub last_text: String,
+    /// Call id -> agent label.
+    spawns: HashMap<String, String>,
+    agents_seen: HashSet<String>,
+    finished: bool,
+}
+
+impl RunTracker {
+    pub fn new(session_id: &str, root: &'static str) -> Self {
+        Self {
+            session_id: session_id.to_string(),
+            root,
+            started: false,
+            model: String::new(),
+            tin: 0,
+            tout: 0,
+            last_text: String::new(),
+            spawns: HashMap::new(),
+            agents_seen: HashSet::new(),
+            finished: false,
+        }
+    }
+
+    /// Record the model in use; the first call opens the run.
+    pub fn start(&mut self, model: &str) -> Vec<RunEvent> {
+        if !model.is_empty() {
+            self.model = model.to_string();
+        }
+        if self.started {
+            return Vec::new();
+        }
+        self.started = true;
+        self.agents_seen.insert(self.root.to_string());
+        vec![
+            RunEvent::RunStart {
+                run_id: self.session_id.clone(),
+                v: 1,
+                agents: vec![self.root.to_string()],
+                prov: self.root.to_string(),
+                model: self.model.clone(),
+                in_chars: 0,
+            },
+            RunEvent::AgentStart {
+                agent: self.root.to_string(),
+                prov: self.root.to_string(),
+                model: self.model.clone(),
+            },
+        ]
+    }
+
+    /// A sub-agent delegated to by call `id`. Parallel sub-agents sharing
+    /// a label get a numbered one, so they stay distinct Workroom nodes.
+    pub fn spawn(&mut self, id: &str, label: &str) -> Vec<RunEvent> {
+        if self.spawns.contains_key(id) {
+            return Vec::new();
+        }
+        let mut out = self.start("");
+        let mut agent = label.to_string();
+        let mut n = 1;
+        while self.spawns.values().any(|a| *a == agent) {
+            n += 1;
+            agent = format!("{label} #{n}");
+        }
+        self.spawns.insert(id.to_string(), agent.clone());
+        self.agents_seen.insert(agent.clone());
+        out.push(RunEvent::Delegate {
+            from: self.root.to_string(),
+            to: agent.clone(),
+        });
+        out.push(RunEvent::AgentStart {
+            agent,
+            prov: self.root.to_string(),
+            model: self.model.clone(),
+        });
+        out
+    }
+
+    /// The sub-agent spawned by call `id` returned `text`.
+    pub fn end(&mut self, id: &str, text: &str) -> Vec<RunEvent> {
+        let Some(agent) = self.spawns.remove(id) else {
+            return Vec::new();
+        };
+        vec![RunEvent::AgentEnd {
+            agent,
+            tin: 0,
+            tout: 0,
+            cost: 0.0,
+            content: truncate_chars(text, MAX_CONTENT),
+        }]
+    }
+
+    /// Whether a sub-agent is still running.
+    pub fn has_pending(&self) -> bool {
+        !self.spawns.is_empty()
+    }
+
+    pub fn finish(&mut self) -> Vec<RunEvent> {
+        if self.finished || !self.started {
+            return Vec::new();
+        }
+        self.finished = true;
+        let content = truncate_chars(&self.last_text, MAX_CONTENT);
+        vec![
+            RunEvent::AgentEnd {
+                agent: self.root.to_string(),
+                tin: self.tin,
+                tout: self.tout,
+                cost: 0.0,
+                content: content.clone(),
+            },
+            RunEvent::Result {
+                content,
+                tin: self.tin,
+                tout: self.tout,
+                cost: 0.0,
+                agents: self.agents_seen.len(),
+            },
+        ]
+    }
+}
+
+/// Modification stamp of `path`, to tell whether a rewritten file changed.
+fn stamp(path: &Path) -> std::io::Result<(u64, Option<std::time::SystemTime>)> {
+    let meta = std::fs::metadata(path)?;
+    Ok((meta.len(), meta.modified().ok()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tracker_numbers_parallel_agents_sharing_a_label() {
+        let mut run = RunTracker::new("s", "codex");
+        let mut evs = run.start("gpt-5");
+        evs.extend(run.spawn("c1", "worker"));
+        evs.extend(run.spawn("c2", "worker"));
+        assert!(run.has_pending());
+        evs.extend(run.end("c2", "second"));
+        evs.extend(run.end("c1", "first"));
+        run.last_text = "all done".into();
+        evs.extend(run.finish());
+
+        assert!(evs.iter().any(
+            |e| matches!(e, RunEvent::AgentEnd { agent, content, .. } if agent == "worker #2" && content == "second")
+        ));
+        assert!(evs.iter().any(
+            |e| matches!(e, RunEvent::AgentEnd { agent, content, .. } if agent == "worker" && content == "first")
+        ));
+        assert!(matches!(
+            evs.last().unwrap(),
+            RunEvent::Result { agents: 3, content, .. } if content == "all done"
+        ));
+    }
+
+    #[test]
+    fn line_tail_leaves_a_partial_line_for_later() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("t.jsonl");
+        std::fs::write(&path, "one\ntw").unwrap();
+        let mut tail = LineTail::new(&path);
+        assert_eq!(tail.read_lines().unwrap(), vec!["one\n"]);
+        std::fs::write(&path, "one\ntwo\n").unwrap();
+        assert_eq!(tail.read_lines().unwrap(), vec!["two\n"]);
+        assert!(tail.read_lines().unwrap().is_empty());
+    }
+
+    #[test]
+    fn adapter_rejects_unknown_sources() {
+        assert!(adapter("cursor").is_err());
+        for source in SOURCES {
+            assert_eq!(adapter(source).unwrap().name(), *source);
+        }
+    }
+}
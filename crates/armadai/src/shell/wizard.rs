@@ -273,6 +273,7 @@ fn run_init_with_pack(pack_name: &str) -> Result<()> {
     );
 
     let (agents, prompts, skills) = pack.install(&pack_dir, false)?;
+    crate::pack_ledger::record_or_warn(&pack, &pack_dir);
     println!(
         "Pack '{}' installed: {} agent(s), {} prompt(s), {} skill(s)",
         pack.name, agents, prompts, skills
@@ -543,6 +543,24 @@ impl Workroom {
                     self.set_action(&cur, format!("error: {msg}"));
                 }
             }
+            // Policy gate violations (`armadai watch`): shown on the agent at
+            // fault, or on the current one when the node is named otherwise.
+            RunEvent::Warning {
+                code,
+                from: Some(from),
+                to,
+            } if code.starts_with("policy_") => {
+                let agent = if self.agents.iter().any(|a| a.name == *from) {
+                    Some(from.clone())
+                } else {
+                    self.current_agent.clone()
+                };
+                if let Some(agent) = agent {
+                    let kind = code.trim_start_matches("policy_");
+                    let target = to.as_deref().unwrap_or("?");
+                    self.set_action(&agent, format!("⚠ policy {kind}: {target}"));
+                }
+            }
             RunEvent::Warning { .. } => {}
         }
     }
@@ -1840,6 +1858,30 @@ orchestration:
         );
     }
 
+    #[test]
+    fn on_run_event_policy_warning_sets_the_agent_action() {
+        let mut wr = Workroom::new();
+        let t = Instant::now();
+        wr.on_run_event_at(&rs(&["claude"]), t);
+        wr.on_run_event_at(
+            &RunEvent::Warning {
+                code: "policy_path".into(),
+                from: Some("claude".into()),
+                to: Some("docs/a.md".into()),
+            },
+            t,
+        );
+        assert_eq!(
+            wr.agents
+                .iter()
+                .find(|a| a.name == "claude")
+                .unwrap()
+                .last_action
+                .as_deref(),
+            Some("⚠ policy path: docs/a.md")
+        );
+    }
+
     #[test]
     fn on_run_event_unknown_variants_are_noops() {
         let mut wr = Workroom::new();
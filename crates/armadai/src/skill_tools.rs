@@ -0,0 +1,691 @@
+//! Skill scripts as tools for API-mode agents.
+//!
+//! An agent lists skills in its `skills` metadata; each skill allow-lists,
+//! in the `tools:` field of its `SKILL.md` frontmatter, the scripts of its
+//! `scripts/` directory that may be called. [`SkillToolsProvider`] wraps the
+//! agent's provider: it describes those tools in the system prompt, runs
+//! the `<tool_call>` blocks the model writes, and sends the results back
+//! until the model answers without calling anything. Like delegation, the
+//! protocol is plain text, so it works with every provider.
+//!
+//! A script gets its JSON arguments on stdin (and in `ARMADAI_TOOL_ARGS`),
+//! a scrubbed environment (no API keys), a timeout, and a working directory
+//! confined to the workspace — the project root, or the current directory
+//! outside a project.
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use serde::Deserialize;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWriteExt};
+
+use armadai_core::agent::Agent;
+use armadai_core::project::{self, SkillRef};
+use armadai_core::provider::{
+    ChatMessage, CompletionRequest, CompletionResponse, Provider, ProviderMetadata, TokenStream,
+};
+use armadai_core::skill::Skill;
+
+/// Seconds a script may run when its skill sets no `timeout`.
+const DEFAULT_TIMEOUT_SECS: u64 = 30;
+/// Tool rounds before the last response is taken as the answer.
+const MAX_ROUNDS: usize = 8;
+/// Bytes of stdout (and stderr) sent back to the model.
+const MAX_OUTPUT_BYTES: usize = 64 * 1024;
+/// Variables a script inherits; everything else is cleared.
+const KEPT_ENV: &[&str] = &["PATH", "HOME", "LANG", "LC_ALL", "TMPDIR", "TERM"];
+
+/// One allow-listed script.
+#[derive(Debug, Clone)]
+pub struct SkillTool {
+    /// `<skill>.<script stem>`, the name the model calls it by.
+    pub name: String,
+    /// First comment line of the script.
+    pub description: Option<String>,
+    script: PathBuf,
+    skill_dir: PathBuf,
+    timeout: Duration,
+}
+
+/// The tools of one agent, and the workspace they are confined to.
+#[derive(Debug, Clone)]
+pub struct ToolBox {
+    pub tools: Vec<SkillTool>,
+    workspace: PathBuf,
+}
+
+/// A `<tool_call>` block.
+#[derive(Debug, Deserialize, PartialEq)]
+struct ToolCall {
+    tool: String,
+    #[serde(default = "empty_object")]
+    arguments: serde_json::Value,
+    /// Working directory, relative to the workspace.
+    #[serde(default)]
+    cwd: Option<String>,
+}
+
+fn empty_object() -> serde_json::Value {
+    serde_json::Value::Object(Default::default())
+}
+
+/// What a call produced, as sent back to the model.
+#[derive(Debug)]
+struct ToolResult {
+    tool: String,
+    /// Exit code, or `timeout` / `error`.
+    status: String,
+    output: String,
+}
+
+impl ToolResult {
+    fn error(tool: &str, message: impl Into<String>) -> Self {
+        Self {
+            tool: tool.to_string(),
+            status: "error".to_string(),
+            output: message.into(),
+        }
+    }
+
+    fn render(&self) -> String {
+        format!(
+            "<tool_result tool=\"{}\" status=\"{}\">\n{}\n</tool_result>",
+            self.tool,
+            self.status,
+            self.output.trim_end()
+        )
+    }
+}
+
+impl ToolBox {
+    /// The allow-listed scripts of `agent`'s skills. A skill that cannot be
+    /// found fails, like any other missing piece of an agent.
+    pub fn for_agent(agent: &Agent, workspace: &Path) -> anyhow::Result<Self> {
+        let mut tools: Vec<SkillTool> = Vec::new();
+        for name in &agent.metadata.skills {
+            let skill_ref = SkillRef::Named { name: name.clone() };
+            let dir = project::resolve_skill(&skill_ref, workspace)
+                .map_err(|e| anyhow::anyhow!("agent '{}': {e}", agent.name))?;
+            let skill = Skill::load(&dir)?;
+            let Ok(scripts_dir) = dir.join("scripts").canonicalize() else {
+                continue;
+            };
+            let timeout = Duration::from_secs(skill.timeout.unwrap_or(DEFAULT_TIMEOUT_SECS));
+            for script in &skill.scripts {
+                let file = script.file_name().and_then(|f| f.to_str()).unwrap_or("");
+                let stem = script.file_stem().and_then(|f| f.to_str()).unwrap_or("");
+                if !skill.tools.iter().any(|t| t == file || t == stem) {
+                    continue;
+                }
+                // A symlink must not lead out of the skill's scripts.
+                let Ok(real) = script.canonicalize() else {
+                    continue;
+                };
+                if !real.starts_with(&scripts_dir) {
+                    tracing::warn!("skill '{name}': {file} points outside scripts/, not exposed");
+                    continue;
+                }
+                let tool_name = format!("{name}.{stem}");
+                if tools.iter().any(|t| t.name == tool_name) {
+                    continue;
+                }
+                tools.push(SkillTool {
+                    name: tool_name,
+                    description: script_description(&real),
+                    script: real,
+                    skill_dir: dir.clone(),
+                    timeout,
+                });
+            }
+        }
+        Ok(Self {
+            tools,
+            workspace: workspace.to_path_buf(),
+        })
+    }
+
+    /// The system prompt section that describes the tools and the protocol.
+    fn prompt(&self) -> String {
+        let mut out = String::from(
+            "## Tools\n\n\
+             You can run the tools below. To call one, write a block like this and \
+             stop there:\n\n\
+             <tool_call>{\"tool\": \"NAME\", \"arguments\": {}}</tool_call>\n\n\
+             Add \"cwd\": \"relative/dir\" to run it in a subdirectory of the workspace. \
+             Each result comes back in a <tool_result> block. When you have what you \
+             need, answer without any <tool_call>.\n",
+        );
+        for tool in &self.tools {
+            match &tool.description {
+                Some(d) => out.push_str(&format!("\n- `{}`: {d}", tool.name)),
+                None => out.push_str(&format!("\n- `{}`", tool.name)),
+            }
+        }
+        out
+    }
+
+    async fn call(&self, call: &ToolCall) -> ToolResult {
+        let Some(tool) = self.tools.iter().find(|t| t.name == call.tool) else {
+            let names: Vec<&str> = self.tools.iter().map(|t| t.name.as_str()).collect();
+            return ToolResult::error(
+                &call.tool,
+                format!("unknown tool; available: {}", names.join(", ")),
+            );
+        };
+        let cwd = match confine(&self.workspace, call.cwd.as_deref()) {
+            Ok(cwd) => cwd,
+            Err(e) => return ToolResult::error(&call.tool, e),
+        };
+        let args = call.arguments.to_string();
+
+        let mut command = script_command(&tool.script);
+        command
+            .current_dir(&cwd)
+            .env_clear()
+            .envs(
+                KEPT_ENV
+                    .iter()
+                    .filter_map(|k| Some((k, std::env::var_os(k)?))),
+            )
+            .env("ARMADAI_TOOL_ARGS", &args)
+            .env("ARMADAI_SKILL_DIR", &tool.skill_dir)
+            .env("ARMADAI_WORKSPACE", &self.workspace)
+            .stdin(std::process::Stdio::piped())
+            .stdout(std::process::Stdio::piped())
+            .stderr(std::process::Stdio::piped())
+            .kill_on_drop(true);
+        // Its own group, so a timeout also stops what the script started.
+        #[cfg(unix)]
+        command.process_group(0);
+        let mut child = match command.spawn() {
+            Ok(child) => child,
+            Err(e) => return ToolResult::error(&call.tool, format!("cannot start: {e}")),
+        };
+        if let Some(mut stdin) = child.stdin.take() {
+            // A script that never reads stdin must not block the call.
+            tokio::spawn(async move {
+                let _ = stdin.write_all(args.as_bytes()).await;
+            });
+        }
+
+        let pid = child.id();
+        let (stdout, stderr) = (child.stdout.take(), child.stderr.take());
+        let run = async {
+            let (out, err, status) =
+                tokio::join!(read_capped(stdout), read_capped(stderr), child.wait());
+            Ok::<_, std::io::Error>((out?, err?, status?))
+        };
+        let result = tokio::time::timeout(tool.timeout, run).await;
+        kill_group(pid);
+        match result {
+            Err(_) => ToolResult {
+                tool: call.tool.clone(),
+                status: "timeout".to_string(),
+                output: format!("killed after {}s", tool.timeout.as_secs()),
+            },
+            Ok(Err(e)) => ToolResult::error(&call.tool, e.to_string()),
+            Ok(Ok(((out, out_cut), (err, err_cut), status))) => {
+                let mut output = capped(&out, out_cut);
+                if !status.success() && !err.is_empty() {
+                    output.push_str(&format!("\n[stderr]\n{}", capped(&err, err_cut)));
+                }
+                ToolResult {
+                    tool: call.tool.clone(),
+                    status: status
+                        .code()
+                        .map_or("killed".to_string(), |c| c.to_string()),
+                    output,
+                }
+            }
+        }
+    }
+}
+
+/// The agent's provider, calling the agent's skill scripts as tools.
+pub struct SkillToolsProvider {
+    inner: Arc<dyn Provider>,
+    tools: Arc<ToolBox>,
+}
+
+#[async_trait]
+impl Provider for SkillToolsProvider {
+    async fn complete(&self, mut request: CompletionRequest) -> anyhow::Result<CompletionResponse> {
+        request.system_prompt = if request.system_prompt.trim().is_empty() {
+            self.tools.prompt()
+        } else {
+            format!("{}\n\n{}", request.system_prompt, self.tools.prompt())
+        };
+        let (mut tokens_in, mut tokens_out, mut cost) = (0, 0, 0.0);
+        let mut round = 0;
+        loop {
+            let response = self.inner.complete(request.clone()).await?;
+            tokens_in += response.tokens_in;
+            tokens_out += response.tokens_out;
+            cost += response.cost;
+
+            let calls = parse_calls(&response.content);
+            if calls.is_empty() || round == MAX_ROUNDS {
+                if !calls.is_empty() {
+                    tracing::warn!("stopped after {MAX_ROUNDS} rounds of tool calls");
+                }
+                return Ok(CompletionResponse {
+                    tokens_in,
+                    tokens_out,
+                    cost,
+                    ..response
+                });
+            }
+            round += 1;
+
+            let mut results = Vec::new();
+            for call in calls {
+                let result = match call {
+                    Ok(call) => {
+                        tracing::info!("tool call: {}", call.tool);
+                        self.tools.call(&call).await
+                    }
+                    Err(e) => ToolResult::error("?", e),
+                };
+                results.push(result.render());
+            }
+            request.messages.push(ChatMessage {
+                role: "assistant".to_string(),
+                content: response.content,
+            });
+            request.messages.push(ChatMessage {
+                role: "user".to_string(),
+                content: results.join("\n\n"),
+            });
+        }
+    }
+
+    async fn stream(&self, request: CompletionRequest) -> anyhow::Result<TokenStream> {
+        // Tool rounds need whole responses; the answer arrives as one chunk.
+        let response = self.complete(request).await?;
+        Ok(Box::pin(tokio_stream::once(Ok(response.content))))
+    }
+
+    fn metadata(&self) -> ProviderMetadata {
+        self.inner.metadata()
+    }
+}
+
+/// `provider`, able to call the scripts of `agent`'s skills. CLI providers
+/// are returned as they are: the native CLI runs the skills `armadai link`
+/// gave it with its own tools.
+pub fn wrap(agent: &Agent, provider: Box<dyn Provider>) -> anyhow::Result<Box<dyn Provider>> {
+    if agent.metadata.skills.is_empty() || provider.metadata().name.starts_with("cli:") {
+        return Ok(provider);
+    }
+    let workspace = match project::find_project_config() {
+        Some((root, _)) => root,
+        None => std::env::current_dir()?,
+    };
+    let tools = ToolBox::for_agent(agent, &workspace)?;
+    if tools.tools.is_empty() {
+        tracing::warn!(
+            "agent '{}': its skills allow no scripts (SKILL.md `tools:`)",
+            agent.name
+        );
+        return Ok(provider);
+    }
+    Ok(Box::new(SkillToolsProvider {
+        inner: Arc::from(provider),
+        tools: Arc::new(tools),
+    }))
+}
+
+/// The `<tool_call>` blocks of a response, in order.
+fn parse_calls(response: &str) -> Vec<Result<ToolCall, String>> {
+    const OPEN: &str = "<tool_call>";
+    const CLOSE: &str = "</tool_call>";
+    let mut calls = Vec::new();
+    let mut rest = response;
+    while let Some(start) = rest.find(OPEN) {
+        let after = &rest[start + OPEN.len()..];
+        let Some(end) = after.find(CLOSE) else {
+            calls.push(Err("unterminated <tool_call>".to_string()));
+            break;
+        };
+        // Models sometimes fence the JSON.
+        let body = after[..end]
+            .trim()
+            .trim_start_matches("```json")
+            .trim_matches('`')
+            .trim();
+        calls.push(serde_json::from_str(body).map_err(|e| format!("invalid tool call: {e}")));
+        rest = &after[end + CLOSE.len()..];
+    }
+    calls
+}
+
+/// The workspace, or `cwd` inside it.
+fn confine(workspace: &Path, cwd: Option<&str>) -> Result<PathBuf, String> {
+    let root = workspace
+        .canonicalize()
+        .map_err(|e| format!("workspace {}: {e}", workspace.display()))?;
+    let Some(rel) = cwd.filter(|c| !c.trim().is_empty()) else {
+        return Ok(root);
+    };
+    let dir = root
+        .join(rel)
+        .canonicalize()
+        .map_err(|_| format!("cwd '{rel}' does not exist"))?;
+    if !dir.starts_with(&root) {
+        return Err(format!("cwd '{rel}' is outside the workspace"));
+    }
+    if !dir.is_dir() {
+        return Err(format!("cwd '{rel}' is not a directory"));
+    }
+    Ok(dir)
+}
+
+/// Run a script through the interpreter its extension names, else directly.
+fn script_command(script: &Path) -> tokio::process::Command {
+    let interpreter = match script.extension().and_then(|e| e.to_str()) {
+        Some("sh") => Some("sh"),
+        Some("bash") => Some("bash"),
+        Some("py") => Some("python3"),
+        Some("js" | "mjs") => Some("node"),
+        Some("rb") => Some("ruby"),
+        _ => None,
+    };
+    match interpreter {
+        Some(interpreter) => {
+            let mut command = tokio::process::Command::new(interpreter);
+            command.arg(script);
+            command
+        }
+        None => tokio::process::Command::new(script),
+    }
+}
+
+/// The first comment line of a script, after any shebang.
+fn script_description(script: &Path) -> Option<String> {
+    let text = std::fs::read_to_string(script).ok()?;
+    text.lines()
+        .map(str::trim)
+        .filter(|l| !l.starts_with("#!") && !l.is_empty())
+        .take_while(|l| l.starts_with('#') || l.starts_with("//"))
+        .map(|l| l.trim_start_matches(['#', '/']).trim())
+        .find(|l| !l.is_empty())
+        .map(str::to_string)
+}
+
+/// Up to `MAX_OUTPUT_BYTES` of `stream`, and whether it had more. The rest
+/// is read and dropped, so a chatty script never blocks on a full pipe.
+async fn read_capped(stream: Option<impl AsyncRead + Unpin>) -> std::io::Result<(Vec<u8>, bool)> {
+    let Some(stream) = stream else {
+        return Ok((Vec::new(), false));
+    };
+    let mut kept = Vec::new();
+    let mut head = stream.take(MAX_OUTPUT_BYTES as u64);
+    head.read_to_end(&mut kept).await?;
+    let rest = tokio::io::copy(&mut head.into_inner(), &mut tokio::io::sink()).await?;
+    Ok((kept, rest > 0))
+}
+
+/// Kill the process group `pid` leads: the script and anything it started,
+/// which killing the script alone would leave running.
+#[cfg(unix)]
+fn kill_group(pid: Option<u32>) {
+    if let Some(pid) = pid {
+        let _ = std::process::Command::new("kill")
+            .args(["-KILL", "--", &format!("-{pid}")])
+            .stderr(std::process::Stdio::null())
+            .status();
+    }
+}
+
+/// Without process groups, dropping the child (`kill_on_drop`) kills the
+/// script itself.
+#[cfg(not(unix))]
+fn kill_group(_pid: Option<u32>) {}
+
+/// `bytes` as text, without a char the cap split in two.
+fn capped(bytes: &[u8], truncated: bool) -> String {
+    let end = match std::str::from_utf8(bytes) {
+        Err(e) if e.error_len().is_none() => e.valid_up_to(),
+        _ => bytes.len(),
+    };
+    let text = String::from_utf8_lossy(&bytes[..end]);
+    if truncated {
+        format!("{text}\n[output truncated]")
+    } else {
+        text.into_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    fn write(path: &Path, content: &str) {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, content).unwrap();
+    }
+
+    /// A workspace with a `repo` skill: `echo.sh` and `slow.sh` allowed,
+    /// `secret.sh` not.
+    fn workspace() -> (tempfile::TempDir, Agent) {
+        let tmp = tempfile::tempdir().unwrap();
+        let skill = tmp.path().join(".armadai/skills/repo");
+        write(
+            &skill.join("SKILL.md"),
+            "---\nname: repo\ntools: [echo.sh, slow]\ntimeout: 1\n---\n\nRepo tools.\n",
+        );
+        write(
+            &skill.join("scripts/echo.sh"),
+            "#!/bin/sh\n# Echo the arguments and the working directory.\ncat; echo; pwd\n",
+        );
+        write(
+            &skill.join("scripts/slow.sh"),
+            "sleep 5 &\necho $! > \"$ARMADAI_WORKSPACE/slow.pid\"\nwait\n",
+        );
+        write(&skill.join("scripts/secret.sh"), "echo secret\n");
+        std::fs::create_dir_all(tmp.path().join("src")).unwrap();
+
+        let mut agent = armadai_core::parser::parse_agent_file(&{
+            let path = tmp.path().join("agent.md");
+            write(
+                &path,
+                "# helper\n\n## Metadata\n- provider: anthropic\n- skills: [repo]\n\n\
+                 ## System Prompt\n\nHelp.\n",
+            );
+            path
+        })
+        .unwrap();
+        agent.name = "helper".to_string();
+        (tmp, agent)
+    }
+
+    #[test]
+    fn parse_calls_reads_every_block() {
+        let calls = parse_calls(
+            "Let me look.\n<tool_call>{\"tool\": \"repo.echo\", \"arguments\": {\"a\": 1}}</tool_call>\n\
+             <tool_call>```json\n{\"tool\": \"repo.slow\", \"cwd\": \"src\"}\n```</tool_call>\n\
+             <tool_call>not json</tool_call>",
+        );
+        assert_eq!(calls.len(), 3);
+        let first = calls[0].as_ref().unwrap();
+        assert_eq!(first.tool, "repo.echo");
+        assert_eq!(first.arguments, serde_json::json!({"a": 1}));
+        let second = calls[1].as_ref().unwrap();
+        assert_eq!(second.arguments, serde_json::json!({}));
+        assert_eq!(second.cwd.as_deref(), Some("src"));
+        assert!(calls[2].is_err());
+        assert!(parse_calls("No tools needed.").is_empty());
+    }
+
+    #[tokio::test]
+    async fn tools_are_allow_listed_confined_and_timed_out() {
+        let (tmp, agent) = workspace();
+        let tools = ToolBox::for_agent(&agent, tmp.path()).unwrap();
+        let names: Vec<&str> = tools.tools.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, ["repo.echo", "repo.slow"]);
+        assert_eq!(
+            tools.tools[0].description.as_deref(),
+            Some("Echo the arguments and the working directory.")
+        );
+
+        let call = |tool: &str, cwd: Option<&str>| ToolCall {
+            tool: tool.to_string(),
+            arguments: serde_json::json!({"path": "src"}),
+            cwd: cwd.map(str::to_string),
+        };
+        let result = tools.call(&call("repo.echo", Some("src"))).await;
+        assert_eq!(result.status, "0", "{}", result.output);
+        assert!(result.output.contains(r#"{"path":"src"}"#));
+        let src = tmp.path().join("src").canonicalize().unwrap();
+        assert!(result.output.contains(src.to_str().unwrap()));
+
+        let escaped = tools.call(&call("repo.echo", Some(".."))).await;
+        assert_eq!(escaped.status, "error");
+        assert!(escaped.output.contains("outside the workspace"));
+
+        let denied = tools.call(&call("repo.secret", None)).await;
+        assert_eq!(denied.status, "error");
+
+        let slow = tools.call(&call("repo.slow", None)).await;
+        assert_eq!(slow.status, "timeout");
+        #[cfg(unix)]
+        {
+            let pid = std::fs::read_to_string(tmp.path().join("slow.pid")).unwrap();
+            let pid = pid.trim();
+            let mut gone = false;
+            for _ in 0..40 {
+                if !running(pid) {
+                    gone = true;
+                    break;
+                }
+                tokio::time::sleep(Duration::from_millis(50)).await;
+            }
+            assert!(
+                gone,
+                "the script's background sleep {pid} outlived the timeout"
+            );
+        }
+    }
+
+    /// Whether `pid` is running: alive and not a zombie waiting to be reaped.
+    #[cfg(unix)]
+    fn running(pid: &str) -> bool {
+        if let Ok(stat) = std::fs::read_to_string(format!("/proc/{pid}/stat")) {
+            return !stat
+                .rsplit_once(") ")
+                .is_some_and(|(_, rest)| rest.starts_with('Z'));
+        }
+        std::process::Command::new("kill")
+            .args(["-0", pid])
+            .status()
+            .is_ok_and(|s| s.success())
+    }
+
+    #[tokio::test]
+    async fn output_is_capped_while_the_rest_is_drained() {
+        let (tmp, agent) = workspace();
+        write(
+            &tmp.path().join(".armadai/skills/repo/scripts/echo.sh"),
+            "head -c 1000000 /dev/zero | tr '\\0' a\necho done >&2\nexit 3\n",
+        );
+        let tools = ToolBox::for_agent(&agent, tmp.path()).unwrap();
+        let result = tools
+            .call(&ToolCall {
+                tool: "repo.echo".to_string(),
+                arguments: empty_object(),
+                cwd: None,
+            })
+            .await;
+        assert_eq!(result.status, "3", "{}", result.output);
+        let (stdout, stderr) = result.output.split_once("\n[stderr]\n").unwrap();
+        assert_eq!(
+            stdout,
+            format!("{}\n[output truncated]", "a".repeat(MAX_OUTPUT_BYTES))
+        );
+        assert_eq!(stderr.trim(), "done");
+    }
+
+    #[test]
+    fn capped_output_never_ends_in_half_a_char() {
+        assert_eq!(capped("é".as_bytes(), false), "é");
+        assert_eq!(capped(&"é".as_bytes()[..1], true), "\n[output truncated]");
+    }
+
+    /// Answers with a tool call first, then with what it was sent back.
+    struct Scripted(Mutex<Vec<CompletionRequest>>);
+
+    #[async_trait]
+    impl Provider for Scripted {
+        async fn complete(&self, request: CompletionRequest) -> anyhow::Result<CompletionResponse> {
+            let mut seen = self.0.lock().unwrap();
+            let content = if seen.is_empty() {
+                "<tool_call>{\"tool\": \"repo.echo\", \"arguments\": {\"x\": 2}}</tool_call>"
+                    .to_string()
+            } else {
+                format!("Done: {}", request.messages.last().unwrap().content)
+            };
+            seen.push(request);
+            Ok(CompletionResponse {
+                content,
+                model: "m".into(),
+                tokens_in: 10,
+                tokens_out: 5,
+                cost: 0.01,
+            })
+        }
+        async fn stream(&self, _r: CompletionRequest) -> anyhow::Result<TokenStream> {
+            anyhow::bail!("not used")
+        }
+        fn metadata(&self) -> ProviderMetadata {
+            ProviderMetadata {
+                name: "scripted".into(),
+                models: vec![],
+                supports_streaming: false,
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn provider_runs_tool_rounds_until_an_answer() {
+        let (tmp, agent) = workspace();
+        let inner = Arc::new(Scripted(Mutex::new(Vec::new())));
+        let provider = SkillToolsProvider {
+            inner: inner.clone(),
+            tools: Arc::new(ToolBox::for_agent(&agent, tmp.path()).unwrap()),
+        };
+        let response = provider
+            .complete(CompletionRequest {
+                model: "m".into(),
+                system_prompt: "Help.".into(),
+                messages: vec![ChatMessage {
+                    role: "user".into(),
+                    content: "go".into(),
+                }],
+                temperature: 0.0,
+                max_tokens: None,
+            })
+            .await
+            .unwrap();
+
+        assert!(
+            response
+                .content
+                .starts_with("Done: <tool_result tool=\"repo.echo\" status=\"0\">")
+        );
+        assert!(response.content.contains(r#"{"x":2}"#));
+        assert_eq!((response.tokens_in, response.tokens_out), (20, 10));
+
+        let seen = inner.0.lock().unwrap();
+        assert!(seen[0].system_prompt.starts_with("Help.\n\n## Tools"));
+        assert!(
+            seen[0]
+                .system_prompt
+                .contains("- `repo.echo`: Echo the arguments")
+        );
+        let roles: Vec<&str> = seen[1].messages.iter().map(|m| m.role.as_str()).collect();
+        assert_eq!(roles, ["user", "assistant", "user"]);
+    }
+}
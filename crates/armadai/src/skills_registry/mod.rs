@@ -1,3 +1,2 @@
 pub mod cache;
-pub mod search;
 pub mod sync;
@@ -79,7 +79,7 @@ fn clone(url: &str, dest: &Path) -> anyhow::Result<()> {
     }
 
     let output = Command::new("git")
-        .args(["clone", "--depth", "1", url])
+        .args(["clone", "--depth", "1", "--", url])
         .arg(dest)
         .output()?;
 
@@ -193,6 +193,7 @@ mod tests {
             skills: vec![RegistrySource {
                 url: "https://github.com/custom-org/custom-skills".to_string(),
                 kind: None,
+                ..Default::default()
             }],
             ..Default::default()
         };
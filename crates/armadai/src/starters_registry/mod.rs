@@ -6,6 +6,7 @@
 //! `discover_packs`) live in `armadai_core::starter` — pure filesystem/YAML
 //! logic with no coupling to fetching, re-exported here for the fetchers
 //! below (which own the git/archive sync path, gated behind `providers-api`).
+//! Sources that pin a public key are verified by [`verify`].
 
 use std::path::{Path, PathBuf};
 
@@ -14,6 +15,10 @@ use armadai_core::registries::{RegistrySource, SourceKind, cache_key};
 use armadai_core::starter::discover_packs;
 pub use armadai_core::starter::starters_cache_dir;
 
+pub mod verify;
+
+use verify::Trust;
+
 /// Cache dir for one source URL.
 pub fn source_cache_dir(url: &str) -> PathBuf {
     starters_cache_dir().join(cache_key(url))
@@ -45,6 +50,7 @@ impl StarterFetcher for GitFetcher {
                 "--depth",
                 "1",
                 "-q",
+                "--",
                 url,
                 dest.to_str().unwrap_or("."),
             ])?;
@@ -179,14 +185,29 @@ fn extract_archive(archive: &Path, dest: &Path) -> anyhow::Result<()> {
 }
 
 /// Archive-backed fetcher: download via reqwest, then extract via `tar`/`unzip`.
+///
+/// When the source pins a public key, the archive must match the detached
+/// signature published next to it (`<url>.minisig`) before anything is
+/// extracted, and the extracted packs are checked with [`verify::verify_packs`].
 #[cfg(feature = "providers-api")]
-pub struct ArchiveFetcher;
+pub struct ArchiveFetcher<'a> {
+    pub trust: &'a Trust,
+}
 
 #[cfg(feature = "providers-api")]
-impl StarterFetcher for ArchiveFetcher {
+impl StarterFetcher for ArchiveFetcher<'_> {
     fn fetch(&self, url: &str, dest: &Path) -> anyhow::Result<()> {
         // Download to a temp file, then extract into `dest`.
         let bytes = download_bytes(url)?;
+        let signature = if self.trust.has_key() {
+            download(&format!("{url}.minisig"))?
+        } else {
+            None
+        };
+        let signature = signature.map(|s| String::from_utf8_lossy(&s).into_owned());
+        let signed = self
+            .trust
+            .check(&format!("archive {url}"), &bytes, signature.as_deref())?;
         let tmp = std::env::temp_dir().join(format!("armadai-dl-{}", cache_key(url)));
         std::fs::write(&tmp, &bytes)?;
         // Preserve the extension so extract_archive picks tar vs unzip.
@@ -196,7 +217,7 @@ impl StarterFetcher for ArchiveFetcher {
         let _ = std::fs::remove_dir_all(dest); // fresh extract
         extract_archive(&src, dest)?;
         let _ = std::fs::remove_file(&src);
-        Ok(())
+        verify::verify_packs(dest, self.trust, signed)
     }
 }
 
@@ -214,21 +235,30 @@ fn archive_ext(url: &str) -> &'static str {
 
 #[cfg(feature = "providers-api")]
 fn download_bytes(url: &str) -> anyhow::Result<Vec<u8>> {
+    download(url)?.ok_or_else(|| anyhow::anyhow!("download {url} failed: HTTP 404 Not Found"))
+}
+
+/// Download `url`; `None` when it does not exist (HTTP 404).
+#[cfg(feature = "providers-api")]
+fn download(url: &str) -> anyhow::Result<Option<Vec<u8>>> {
     // `fetch` is a sync trait method but is called from WITHIN an async runtime
     // (`registry sync`, `init`). Creating a tokio runtime inline would panic
     // ("cannot start a runtime from within a runtime"). Run the async reqwest
     // download on a dedicated OS thread with its own current-thread runtime.
     let url = url.to_string();
-    std::thread::spawn(move || -> anyhow::Result<Vec<u8>> {
+    std::thread::spawn(move || -> anyhow::Result<Option<Vec<u8>>> {
         let rt = tokio::runtime::Builder::new_current_thread()
             .enable_all()
             .build()?;
         rt.block_on(async {
             let resp = reqwest::get(&url).await?;
+            if resp.status() == reqwest::StatusCode::NOT_FOUND {
+                return Ok(None);
+            }
             if !resp.status().is_success() {
                 anyhow::bail!("download {url} failed: HTTP {}", resp.status());
             }
-            Ok(resp.bytes().await?.to_vec())
+            Ok(Some(resp.bytes().await?.to_vec()))
         })
     })
     .join()
@@ -236,18 +266,33 @@ fn download_bytes(url: &str) -> anyhow::Result<Vec<u8>> {
 }
 
 /// Fetch one source into its cache dir. Git only in Lot 1; archive is Lot 2.
+///
+/// A source whose content fails verification (see [`verify`]) is refused:
+/// its cache dir is removed, so none of its packs can be installed.
 pub fn fetch_starter_source(source: &RegistrySource) -> anyhow::Result<PathBuf> {
+    let trust = Trust::of(source)?;
     let dest = source_cache_dir(&source.url);
+    let fetched = fetch_verified(source, &trust, &dest);
+    if let Err(e) = fetched {
+        if e.downcast_ref::<verify::Refused>().is_some() {
+            let _ = std::fs::remove_dir_all(&dest);
+            anyhow::bail!("refusing starter source {}: {e}", source.url);
+        }
+        return Err(e);
+    }
+    Ok(dest)
+}
+
+fn fetch_verified(source: &RegistrySource, trust: &Trust, dest: &Path) -> anyhow::Result<()> {
     match source.resolved_kind() {
         SourceKind::Git => {
-            GitFetcher.fetch(&source.url, &dest)?;
-            Ok(dest)
+            GitFetcher.fetch(&source.url, dest)?;
+            verify::verify_packs(dest, trust, false)
         }
         SourceKind::Archive => {
             #[cfg(feature = "providers-api")]
             {
-                ArchiveFetcher.fetch(&source.url, &dest)?;
-                Ok(dest)
+                ArchiveFetcher { trust }.fetch(&source.url, dest)
             }
             #[cfg(not(feature = "providers-api"))]
             {
@@ -325,6 +370,7 @@ mod tests {
         let src = RegistrySource {
             url: "https://x/p.tar.gz".to_string(),
             kind: None,
+            ..Default::default()
         };
         assert!(fetch_starter_source(&src).is_err());
     }
@@ -432,6 +478,57 @@ mod tests {
         );
         let _ = fs::remove_dir_all(&tmp);
     }
+
+    #[test]
+    fn unsigned_git_source_is_refused_and_its_cache_removed() {
+        let _guard = armadai_core::config::ENV_MUTEX.lock().unwrap();
+        let orig = std::env::var("ARMADAI_CONFIG_DIR").ok();
+        let tmp = tempfile::tempdir().unwrap();
+        // SAFETY: serialised via ENV_MUTEX; restored at end of test.
+        unsafe {
+            std::env::set_var("ARMADAI_CONFIG_DIR", tmp.path().join("config"));
+        }
+
+        let repo = tmp.path().join("origin");
+        write(&repo.join("demo/pack.yaml"), "name: demo\ndescription: d\n");
+        for args in [
+            vec!["init", "-q"],
+            vec!["add", "-A"],
+            vec![
+                "-c",
+                "user.email=t@t",
+                "-c",
+                "user.name=t",
+                "commit",
+                "-qm",
+                "x",
+            ],
+        ] {
+            std::process::Command::new("git")
+                .current_dir(&repo)
+                .args(&args)
+                .output()
+                .unwrap();
+        }
+        let (_, public) = verify::test_keys::keypair(1);
+        let mut source = RegistrySource {
+            url: repo.to_string_lossy().into_owned(),
+            public_key: Some(public),
+            ..Default::default()
+        };
+        // Unsigned packs are accepted while signatures are optional...
+        assert!(fetch_starter_source(&source).unwrap().is_dir());
+        // ...and refused once they are required.
+        source.require_signature = true;
+        let err = fetch_starter_source(&source).unwrap_err().to_string();
+        assert!(err.contains("refusing starter source"), "{err}");
+        assert!(!source_cache_dir(&source.url).exists());
+
+        match orig {
+            Some(v) => unsafe { std::env::set_var("ARMADAI_CONFIG_DIR", v) },
+            None => unsafe { std::env::remove_var("ARMADAI_CONFIG_DIR") },
+        }
+    }
 }
 
 #[cfg(all(test, feature = "providers-api"))]
@@ -21,6 +21,7 @@ pub enum Tab {
     ModelDetail,
     Orchestration,
     OrchestrationDetail,
+    RunDiff,
 }
 
 impl Tab {
@@ -52,6 +53,7 @@ impl Tab {
             Tab::ModelDetail => "Model",
             Tab::Orchestration => "Orchestration",
             Tab::OrchestrationDetail => "Run",
+            Tab::RunDiff => "Diff",
         }
     }
 
@@ -75,6 +77,8 @@ pub struct RunEntry {
     pub input_preview: String,
     pub output_preview: String,
     pub project: Option<String>,
+    /// Matching excerpt when this entry is a full-text search hit.
+    pub snippet: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -273,6 +277,9 @@ pub struct App {
     // History
     pub history: Vec<RunEntry>,
     pub selected_history: usize,
+    // Full-text query whose hits currently replace `history` (set by Enter
+    // in History search mode, cleared by Esc or a refresh).
+    pub history_search: Option<String>,
     // Costs
     pub costs: Vec<CostEntry>,
     pub selected_cost: usize,
@@ -284,6 +291,12 @@ pub struct App {
     pub orchestration_runs: Vec<OrchestrationEntry>,
     #[cfg(feature = "storage")]
     pub selected_orchestration: usize,
+    // Run diff: the run marked with `m` as baseline, and the diff opened
+    // with `d` against the selected run (shown by `Tab::RunDiff`).
+    #[cfg(feature = "storage")]
+    pub diff_mark: Option<String>,
+    #[cfg(feature = "storage")]
+    pub run_diff: Option<armadai_core::orchestration::es::diff::RunDiff>,
     // Command palette
     pub palette: CommandPalette,
     // Status message (bottom bar)
@@ -321,6 +334,7 @@ impl App {
             selected_starter: 0,
             history: Vec::new(),
             selected_history: 0,
+            history_search: None,
             costs: Vec::new(),
             selected_cost: 0,
             models_flat: Vec::new(),
@@ -329,6 +343,10 @@ impl App {
             orchestration_runs: Vec::new(),
             #[cfg(feature = "storage")]
             selected_orchestration: 0,
+            #[cfg(feature = "storage")]
+            diff_mark: None,
+            #[cfg(feature = "storage")]
+            run_diff: None,
             palette: CommandPalette::new(),
             status_msg: None,
             search_mode: false,
@@ -108,6 +108,14 @@ pub async fn run(ascii: bool) -> Result<()> {
                     }
                     KeyCode::Enter => {
                         app.search_mode = false;
+                        // History: Enter runs a full-text search over run
+                        // inputs/outputs, board entries and ring
+                        // contributions instead of the in-memory filter.
+                        if app.current_tab == app::Tab::History
+                            && !app.search_query.trim().is_empty()
+                        {
+                            search_history(&mut app);
+                        }
                     }
                     KeyCode::Backspace => {
                         app.search_query.pop();
@@ -124,6 +132,11 @@ pub async fn run(ascii: bool) -> Result<()> {
 
             // Detail view: Esc goes back to parent list
             if key.code == KeyCode::Esc {
+                // Full-text search results: Esc goes back to the full history.
+                if app.current_tab == app::Tab::History && app.history_search.is_some() {
+                    load_storage_data(&mut app);
+                    continue;
+                }
                 match app.current_tab {
                     app::Tab::AgentDetail => {
                         app.switch_tab(app::Tab::Dashboard);
@@ -146,7 +159,7 @@ pub async fn run(ascii: bool) -> Result<()> {
                         continue;
                     }
                     #[cfg(feature = "storage")]
-                    app::Tab::OrchestrationDetail => {
+                    app::Tab::OrchestrationDetail | app::Tab::RunDiff => {
                         app.switch_tab(app::Tab::Orchestration);
                         continue;
                     }
@@ -298,6 +311,14 @@ pub async fn run(ascii: bool) -> Result<()> {
                     }
                     _ => {}
                 },
+                #[cfg(feature = "storage")]
+                KeyCode::Char('m') if app.current_tab == app::Tab::Orchestration => {
+                    mark_for_diff(&mut app);
+                }
+                #[cfg(feature = "storage")]
+                KeyCode::Char('d') if app.current_tab == app::Tab::Orchestration => {
+                    open_run_diff(&mut app);
+                }
                 KeyCode::Char('i')
                     if matches!(
                         app.current_tab,
@@ -377,6 +398,7 @@ fn is_scrollable_detail(tab: app::Tab) -> bool {
             | app::Tab::PromptDetail
             | app::Tab::SkillDetail
             | app::Tab::OrchestrationDetail
+            | app::Tab::RunDiff
     )
 }
 
@@ -415,22 +437,10 @@ fn load_storage_data(app: &mut app::App) {
 
     // Load history
     if let Ok(records) = queries::get_history(&db, None, 100) {
-        app.history = records
-            .into_iter()
-            .map(|r| app::RunEntry {
-                input_preview: r.input.chars().take(40).collect(),
-                output_preview: r.output.chars().take(40).collect(),
-                agent: r.agent,
-                provider: r.provider,
-                model: r.model,
-                tokens_in: r.tokens_in,
-                tokens_out: r.tokens_out,
-                cost: r.cost,
-                duration_ms: r.duration_ms,
-                status: r.status,
-                project: r.project,
-            })
-            .collect();
+        app.history = records.into_iter().map(|r| run_entry(r, None)).collect();
+        if app.history_search.take().is_some() {
+            app.selected_history = 0;
+        }
     }
 
     // Load costs
@@ -451,11 +461,95 @@ fn load_storage_data(app: &mut app::App) {
     app.load_orchestration_runs();
 }
 
+/// Mark the selected orchestration run as the baseline of the next diff.
+#[cfg(feature = "storage")]
+fn mark_for_diff(app: &mut app::App) {
+    if let Some(run_id) = app.selected_orchestration_entry().map(|e| e.run_id.clone()) {
+        app.status_msg = Some(format!(
+            "Marked {run_id} as diff baseline — select another run and press d"
+        ));
+        app.diff_mark = Some(run_id);
+    }
+}
+
+/// Diff the marked baseline against the selected run and open the split view.
+#[cfg(feature = "storage")]
+fn open_run_diff(app: &mut app::App) {
+    let Some(selected) = app.selected_orchestration_entry().map(|e| e.run_id.clone()) else {
+        return;
+    };
+    let Some(baseline) = app.diff_mark.clone() else {
+        app.status_msg = Some("Press m on a run first to mark the diff baseline".to_string());
+        return;
+    };
+    if baseline == selected {
+        app.status_msg = Some("Select a different run to compare with the baseline".to_string());
+        return;
+    }
+    let diff =
+        crate::db::init_db().and_then(|db| crate::cli::runs::load_diff(&db, &baseline, &selected));
+    match diff {
+        Ok(diff) => {
+            app.run_diff = Some(diff);
+            app.switch_tab(app::Tab::RunDiff);
+        }
+        Err(e) => app.status_msg = Some(format!("Diff failed: {e}")),
+    }
+}
+
 #[cfg(not(feature = "storage"))]
 fn load_storage_data(_app: &mut app::App) {
     // No storage feature — data views will be empty
 }
 
+#[cfg(feature = "storage")]
+fn run_entry(r: armadai_storage::queries::RunRecord, snippet: Option<String>) -> app::RunEntry {
+    app::RunEntry {
+        input_preview: r.input.chars().take(40).collect(),
+        output_preview: r.output.chars().take(40).collect(),
+        agent: r.agent,
+        provider: r.provider,
+        model: r.model,
+        tokens_in: r.tokens_in,
+        tokens_out: r.tokens_out,
+        cost: r.cost,
+        duration_ms: r.duration_ms,
+        status: r.status,
+        project: r.project,
+        snippet,
+    }
+}
+
+/// Replace the History list with the full-text hits for the pending search
+/// query (see `queries::search_history`). The query moves to
+/// `App::history_search`, so the in-memory filter doesn't hide hits that
+/// matched on content rather than on agent/provider/model.
+#[cfg(feature = "storage")]
+fn search_history(app: &mut app::App) {
+    use crate::db::init_db;
+    use armadai_storage::queries;
+
+    let query = std::mem::take(&mut app.search_query);
+    let hits = init_db().and_then(|db| queries::search_history(&db, &query, None, 100));
+    match hits {
+        Ok(hits) => {
+            app.history = hits
+                .into_iter()
+                .map(|h| run_entry(h.run, Some(h.snippet)))
+                .collect();
+            app.selected_history = 0;
+            app.status_msg = Some(format!("{} runs match '{query}'", app.history.len()));
+            app.history_search = Some(query);
+        }
+        Err(e) => app.status_msg = Some(format!("Search failed: {e}")),
+    }
+}
+
+#[cfg(not(feature = "storage"))]
+fn search_history(_app: &mut app::App) {
+    // No storage feature — keep the in-memory filter
+}
+
 /// Force-refresh model registry from models.dev.
 #[cfg(feature = "providers-api")]
 async fn sync_models_online() -> anyhow::Result<usize> {
@@ -58,8 +58,10 @@ pub fn render(frame: &mut Frame, app: &mut App) {
         Tab::Orchestration => super::orchestration::render(frame, app, chunks[1]),
         #[cfg(feature = "storage")]
         Tab::OrchestrationDetail => super::orchestration::render_detail(frame, app, chunks[1]),
+        #[cfg(feature = "storage")]
+        Tab::RunDiff => super::run_diff::render(frame, app, chunks[1]),
         #[cfg(not(feature = "storage"))]
-        Tab::Orchestration | Tab::OrchestrationDetail => {
+        Tab::Orchestration | Tab::OrchestrationDetail | Tab::RunDiff => {
             let msg = Paragraph::new("Orchestration tab requires storage feature");
             frame.render_widget(
                 msg.block(
@@ -24,14 +24,18 @@ fn project_display(project: Option<&str>) -> String {
 
 pub fn render(frame: &mut Frame, app: &App, area: Rect) {
     if app.history.is_empty() {
-        let msg =
-            Paragraph::new("No execution history. Run an agent first: armadai run <agent> <input>")
-                .block(
-                    Block::default()
-                        .borders(Borders::ALL)
-                        .title(" History ")
-                        .style(theme::border_style()),
-                );
+        let text = match &app.history_search {
+            Some(query) => format!("No runs match '{query}'. Press Esc to show all runs."),
+            None => {
+                "No execution history. Run an agent first: armadai run <agent> <input>".to_string()
+            }
+        };
+        let msg = Paragraph::new(text).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(" History ")
+                .style(theme::border_style()),
+        );
         frame.render_widget(msg, area);
         return;
     }
@@ -113,23 +117,51 @@ pub fn render(frame: &mut Frame, app: &App, area: Rect) {
     .block(
         Block::default()
             .borders(Borders::ALL)
-            .title(format!(
-                " History — {} runs, {} shown{} ",
-                app.history.len(),
-                display_indices.len(),
-                app.sort_indicator()
-            ))
+            .title(match &app.history_search {
+                Some(query) => format!(
+                    " History — {} runs matching '{query}', {} shown{} ",
+                    app.history.len(),
+                    display_indices.len(),
+                    app.sort_indicator()
+                ),
+                None => format!(
+                    " History — {} runs, {} shown{} ",
+                    app.history.len(),
+                    display_indices.len(),
+                    app.sort_indicator()
+                ),
+            })
             .style(theme::border_style()),
     );
 
     frame.render_widget(table, area);
 
-    // Render search bar if in search mode
+    // Render search bar if in search mode, else the selected hit's excerpt
     if app.search_mode {
         search_bar(frame, &app.search_query, area);
+    } else if let Some(snippet) = display_indices
+        .get(app.selected_history)
+        .and_then(|&i| app.history[i].snippet.as_deref())
+    {
+        snippet_bar(frame, snippet, area);
     }
 }
 
+/// Render the selected full-text hit's matching excerpt as the last line of
+/// `list_area` (same slot as the search bar).
+fn snippet_bar(frame: &mut Frame, snippet: &str, list_area: Rect) {
+    let snippet_area = Rect {
+        x: list_area.x,
+        y: list_area.bottom() - 1,
+        width: list_area.width,
+        height: 1,
+    };
+    let line = Paragraph::new(format!("» {}", snippet.replace('\n', " ")))
+        .style(theme::muted())
+        .block(Block::default().style(theme::border_style()));
+    frame.render_widget(line, snippet_area);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -9,6 +9,8 @@ pub mod orchestration;
 pub mod palette;
 pub mod prompt_detail;
 pub mod prompts_list;
+#[cfg(feature = "storage")]
+pub mod run_diff;
 pub mod shortcuts;
 pub mod skill_detail;
 pub mod skills_list;
@@ -0,0 +1,168 @@
+#![cfg(feature = "storage")]
+
+//! Split view of `App::run_diff`: baseline run on the left, compared run on
+//! the right, with each step's lines aligned across both panes.
+
+use armadai_core::orchestration::es::diff::{DiffLine, RunDiff, RunSide};
+use ratatui::{
+    Frame,
+    layout::{Constraint, Direction, Layout, Rect},
+    style::Style,
+    text::Line,
+    widgets::{Block, Borders, Paragraph},
+};
+
+use crate::theme;
+use crate::tui::app::App;
+
+pub fn render(frame: &mut Frame, app: &mut App, area: Rect) {
+    let Some(diff) = &app.run_diff else {
+        app.set_detail_scroll_max(0);
+        let msg =
+            Paragraph::new("No diff open. In Orchestration, press m on a run, then d on another.")
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .title(" Diff ")
+                        .style(theme::border_style()),
+                );
+        frame.render_widget(msg, area);
+        return;
+    };
+
+    let (left, right) = aligned_lines(diff);
+    let titles = [side_title(&diff.a), side_title(&diff.b)];
+    let panes = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(area);
+
+    // Unwrapped on purpose: wrapping would break the line alignment between
+    // the two panes, so the scroll bound is the raw line count.
+    let inner_height = area.height.saturating_sub(2) as usize;
+    let total = left.len().max(1);
+    app.set_detail_scroll_max(total.saturating_sub(inner_height).min(u16::MAX as usize) as u16);
+    let scroll = app.detail_scroll;
+
+    for ((lines, title), pane) in [left, right].into_iter().zip(titles).zip(panes.iter()) {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(title)
+            .style(theme::border_style());
+        let paragraph = Paragraph::new(lines).block(block).scroll((scroll, 0));
+        frame.render_widget(paragraph, *pane);
+    }
+}
+
+fn side_title(side: &RunSide) -> String {
+    format!(
+        " {} ({}) — {}/{} tok, ${:.4} ",
+        side.run_id, side.pattern, side.tokens_in, side.tokens_out, side.cost
+    )
+}
+
+/// Build the left/right line lists, padded so row `i` of each pane belongs
+/// to the same step.
+fn aligned_lines(diff: &RunDiff) -> (Vec<Line<'static>>, Vec<Line<'static>>) {
+    let mut left = Vec::new();
+    let mut right = Vec::new();
+
+    for step in &diff.steps {
+        if step.is_unchanged() {
+            let line = Line::styled(format!("= {}", step.label()), theme::muted());
+            left.push(line.clone());
+            right.push(line);
+            continue;
+        }
+        let (din, dout, dcost) = step.deltas();
+        left.push(Line::styled(
+            format!("~ {}", step.label()),
+            theme::heading(),
+        ));
+        right.push(Line::styled(
+            format!("~ {}  ({din:+}/{dout:+} tok, {dcost:+.4}$)", step.label()),
+            theme::heading(),
+        ));
+        let detail = |s: &Option<armadai_core::orchestration::es::diff::StepOutput>| match s {
+            None => Line::styled("  (absent)", theme::muted()),
+            Some(s) => match &s.detail {
+                Some(d) => Line::styled(format!("  [{d}]"), theme::warning()),
+                None => Line::raw(""),
+            },
+        };
+        left.push(detail(&step.a));
+        right.push(detail(&step.b));
+        push_diff(&mut left, &mut right, &step.output_diff);
+        left.push(Line::raw(""));
+        right.push(Line::raw(""));
+    }
+
+    if diff.final_changed {
+        left.push(Line::styled("~ final result", theme::heading()));
+        right.push(Line::styled("~ final result", theme::heading()));
+        push_diff(&mut left, &mut right, &diff.final_diff);
+    } else {
+        let line = Line::styled("= final result unchanged", theme::muted());
+        left.push(line.clone());
+        right.push(line);
+    }
+    (left, right)
+}
+
+/// Append a line diff: shared lines on both sides, removed lines left only,
+/// added lines right only, padding the shorter side before each shared line
+/// and at the end.
+fn push_diff(left: &mut Vec<Line<'static>>, right: &mut Vec<Line<'static>>, lines: &[DiffLine]) {
+    let pad = |left: &mut Vec<Line<'static>>, right: &mut Vec<Line<'static>>| {
+        while left.len() < right.len() {
+            left.push(Line::raw(""));
+        }
+        while right.len() < left.len() {
+            right.push(Line::raw(""));
+        }
+    };
+    pad(left, right);
+    for line in lines {
+        match line {
+            DiffLine::Same(t) => {
+                pad(left, right);
+                left.push(Line::styled(format!("  {t}"), Style::default()));
+                right.push(Line::styled(format!("  {t}"), Style::default()));
+            }
+            DiffLine::Removed(t) => left.push(Line::styled(format!("- {t}"), theme::error())),
+            DiffLine::Added(t) => right.push(Line::styled(format!("+ {t}"), theme::done())),
+        }
+    }
+    pad(left, right);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn push_diff_keeps_both_panes_aligned() {
+        let mut left = Vec::new();
+        let mut right = Vec::new();
+        push_diff(
+            &mut left,
+            &mut right,
+            &[
+                DiffLine::Same("a".into()),
+                DiffLine::Removed("b".into()),
+                DiffLine::Added("c".into()),
+                DiffLine::Added("d".into()),
+                DiffLine::Same("e".into()),
+            ],
+        );
+        assert_eq!(left.len(), right.len());
+        // "e" lands on the same row in both panes.
+        let row = |lines: &[Line], text: &str| {
+            lines
+                .iter()
+                .position(|l| l.to_string().trim() == text)
+                .unwrap()
+        };
+        assert_eq!(row(&left, "e"), row(&right, "e"));
+    }
+}
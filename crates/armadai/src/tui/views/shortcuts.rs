@@ -89,9 +89,20 @@ pub fn render(frame: &mut Frame, app: &App, area: Rect) {
             ("r", "Refresh"),
             QUIT_TOP_LEVEL,
         ],
+        Tab::History if app.history_search.is_some() => vec![
+            ("j/k", "Navigate"),
+            ("/", "Full-text search"),
+            ("Esc", "All runs"),
+            ("s", "Sort"),
+            ("Tab", "Next tab"),
+            JUMP,
+            (":", "Commands"),
+            ("r", "Refresh"),
+            ("q / ^C", "Quit"),
+        ],
         Tab::History => vec![
             ("j/k", "Navigate"),
-            ("/", "Search"),
+            ("/", "Full-text search"),
             ("s", "Sort"),
             ("Tab", "Next tab"),
             JUMP,
@@ -125,6 +136,8 @@ pub fn render(frame: &mut Frame, app: &App, area: Rect) {
         Tab::Orchestration => vec![
             ("j/k", "Navigate"),
             ("Enter", "View detail"),
+            ("m", "Mark for diff"),
+            ("d", "Diff with mark"),
             ("/", "Search"),
             ("s", "Sort"),
             ("Tab", "Next tab"),
@@ -143,8 +156,18 @@ pub fn render(frame: &mut Frame, app: &App, area: Rect) {
             (":", "Commands"),
             QUIT_DETAIL,
         ],
+        #[cfg(feature = "storage")]
+        Tab::RunDiff => vec![
+            ("j/k", "Scroll"),
+            ("PgUp/PgDn", "Page"),
+            ("Esc", "Back to list"),
+            ("Tab", "Next tab"),
+            JUMP,
+            (":", "Commands"),
+            QUIT_DETAIL,
+        ],
         #[cfg(not(feature = "storage"))]
-        Tab::Orchestration | Tab::OrchestrationDetail => {
+        Tab::Orchestration | Tab::OrchestrationDetail | Tab::RunDiff => {
             vec![("Tab", "Next tab"), JUMP, (":", "Commands"), QUIT_TOP_LEVEL]
         }
     };
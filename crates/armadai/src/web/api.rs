@@ -1,8 +1,8 @@
 use axum::Json;
-use axum::extract::Path;
+use axum::extract::{Path, Query};
 use axum::http::{HeaderMap, HeaderValue, StatusCode, header};
 use axum::response::IntoResponse;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use armadai_core::agent::Agent;
 
@@ -86,6 +86,17 @@ pub struct HistoryEntry {
     cost: f64,
     duration_ms: i64,
     status: String,
+    /// Matching excerpt, only set for full-text search results (`?q=`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    snippet: Option<String>,
+}
+
+/// Query string of `GET /api/history`.
+#[derive(Deserialize)]
+pub struct HistoryParams {
+    /// Full-text search query; plain history when absent or blank.
+    #[cfg_attr(not(feature = "storage"), allow(dead_code))]
+    q: Option<String>,
 }
 
 #[derive(Serialize)]
@@ -291,7 +302,7 @@ pub async fn get_agent(Path(name): Path<String>) -> Json<serde_json::Value> {
 }
 
 #[cfg(feature = "storage")]
-pub async fn get_history() -> Json<Vec<HistoryEntry>> {
+pub async fn get_history(Query(params): Query<HistoryParams>) -> Json<Vec<HistoryEntry>> {
     use crate::db::init_db;
     use armadai_storage::queries;
 
@@ -300,11 +311,20 @@ pub async fn get_history() -> Json<Vec<HistoryEntry>> {
         Err(_) => return Json(vec![]),
     };
 
-    match queries::get_history(&db, None, 100) {
-        Ok(records) => Json(
-            records
-                .into_iter()
-                .map(|r| HistoryEntry {
+    let hits = match params.q.as_deref().map(str::trim).filter(|q| !q.is_empty()) {
+        Some(q) => queries::search_history(&db, q, None, 100).map(|hits| {
+            hits.into_iter()
+                .map(|h| (h.run, Some(h.snippet)))
+                .collect::<Vec<_>>()
+        }),
+        None => queries::get_history(&db, None, 100)
+            .map(|records| records.into_iter().map(|r| (r, None)).collect()),
+    };
+
+    match hits {
+        Ok(hits) => Json(
+            hits.into_iter()
+                .map(|(r, snippet)| HistoryEntry {
                     agent: r.agent,
                     provider: r.provider,
                     model: r.model,
@@ -313,6 +333,7 @@ pub async fn get_history() -> Json<Vec<HistoryEntry>> {
                     cost: r.cost,
                     duration_ms: r.duration_ms,
                     status: r.status,
+                    snippet,
                 })
                 .collect(),
         ),
@@ -321,7 +342,7 @@ pub async fn get_history() -> Json<Vec<HistoryEntry>> {
 }
 
 #[cfg(not(feature = "storage"))]
-pub async fn get_history() -> Json<Vec<HistoryEntry>> {
+pub async fn get_history(Query(_params): Query<HistoryParams>) -> Json<Vec<HistoryEntry>> {
     Json(vec![])
 }
 
@@ -1188,4 +1209,54 @@ mod tests {
             "nested child must not appear in the list"
         );
     }
+
+    #[tokio::test]
+    async fn test_get_history_q_runs_full_text_search() {
+        let _guard = TempStorageGuard::new();
+        let db = crate::db::init_db().unwrap();
+        for (id, output) in [
+            ("run-a", "flagged an SQL injection in the login form"),
+            ("run-b", "all good"),
+        ] {
+            insert_run_with_id(
+                &db,
+                id,
+                RunRecord {
+                    agent: "reviewer".to_string(),
+                    input: "review".to_string(),
+                    output: output.to_string(),
+                    provider: "anthropic".to_string(),
+                    model: "claude-sonnet".to_string(),
+                    tokens_in: 1,
+                    tokens_out: 1,
+                    cost: 0.0,
+                    duration_ms: 1,
+                    status: "success".to_string(),
+                    project: None,
+                },
+            )
+            .unwrap();
+        }
+        drop(db);
+
+        let all = get_history(Query(HistoryParams { q: None })).await.0;
+        assert_eq!(all.len(), 2);
+        assert!(all.iter().all(|e| e.snippet.is_none()));
+
+        let hits = get_history(Query(HistoryParams {
+            q: Some("injection".to_string()),
+        }))
+        .await
+        .0;
+        assert_eq!(hits.len(), 1);
+        assert!(hits[0].snippet.as_deref().unwrap().contains("[injection]"));
+
+        // A blank `q` is plain history, not an empty search.
+        let blank = get_history(Query(HistoryParams {
+            q: Some("  ".to_string()),
+        }))
+        .await
+        .0;
+        assert_eq!(blank.len(), 2);
+    }
 }
@@ -95,6 +95,7 @@ mod tests {
         let mut cmd = Command::cargo_bin("armadai").unwrap();
         cmd.arg("__claude-policy-gate")
             .env("RUST_LOG", "armadai=debug,armadai_core=debug")
+            .env("ARMADAI_POLICY_LOG", dir.path().join("policy.jsonl"))
             .write_stdin(gate_payload(dir.path(), "qa-specialist"));
         let out = cmd.output().unwrap();
 
@@ -156,4 +157,35 @@ mod tests {
             String::from_utf8_lossy(&out.stderr)
         );
     }
+
+    /// `warn` reports without refusing: stdout stays silent — any output
+    /// would be read as a verdict — and the violation lands in the log.
+    #[test]
+    fn policy_gate_in_warn_mode_logs_and_says_nothing() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join(".armadai")).unwrap();
+        std::fs::write(
+            dir.path().join(".armadai/config.yaml"),
+            "orchestration:\n  policy: warn\n  coordinator: dev-lead\n  \
+             teams:\n    - agents: [qa-specialist]\n",
+        )
+        .unwrap();
+        let log = dir.path().join("policy.jsonl");
+
+        let mut cmd = Command::cargo_bin("armadai").unwrap();
+        cmd.arg("__claude-policy-gate")
+            .env("RUST_LOG", "armadai=debug")
+            .env("ARMADAI_POLICY_LOG", &log)
+            .write_stdin(gate_payload(dir.path(), "qa-specialist"));
+        let out = cmd.output().unwrap();
+
+        assert!(out.status.success());
+        assert!(String::from_utf8_lossy(&out.stdout).trim().is_empty());
+        let logged = std::fs::read_to_string(&log).expect("the violation is logged");
+        let v: serde_json::Value = serde_json::from_str(logged.trim()).unwrap();
+        assert_eq!(v["mode"], "warn");
+        assert_eq!(v["kind"], "delegation");
+        assert_eq!(v["target"], "qa-specialist");
+        assert_eq!(v["refused"], false);
+    }
 }
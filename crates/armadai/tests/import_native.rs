@@ -0,0 +1,83 @@
+//! Black-box coverage for `armadai import`: a freshly linked agent is in
+//! sync, a native edit flows back into its agent file, and a native-only
+//! agent is declared in `.armadai/agents.yaml` with its own fragment.
+
+#[cfg(test)]
+mod tests {
+    use assert_cmd::Command;
+
+    fn armadai(dir: &std::path::Path, args: &[&str]) -> std::process::Output {
+        let config = dir.join("config");
+        std::fs::create_dir_all(&config).unwrap();
+        Command::cargo_bin("armadai")
+            .unwrap()
+            .current_dir(dir.join("project"))
+            .env("ARMADAI_CONFIG_DIR", config)
+            .args(args)
+            .output()
+            .unwrap()
+    }
+
+    #[test]
+    fn import_pulls_native_edits_and_new_agents() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path().join("project");
+        std::fs::create_dir_all(root.join("agents")).unwrap();
+        std::fs::write(
+            root.join("armadai.yaml"),
+            "agents:\n  - name: reviewer\nlink:\n  target: claude\n",
+        )
+        .unwrap();
+        let source = root.join("agents/reviewer.md");
+        std::fs::write(
+            &source,
+            "# reviewer\n\n## Metadata\n- provider: claude\n- model: latest:pro\n\n\
+             ## System Prompt\n\nReview the diff.\n\n## Instructions\n\nBe terse.\n",
+        )
+        .unwrap();
+        assert!(armadai(dir.path(), &["link"]).status.success());
+
+        let out = armadai(dir.path(), &["import", "--from", "claude"]);
+        let stdout = String::from_utf8_lossy(&out.stdout);
+        assert!(out.status.success(), "{out:?}");
+        assert!(stdout.contains("in sync"), "{stdout}");
+
+        let generated = root.join(".claude/agents/reviewer.md");
+        let text = std::fs::read_to_string(&generated).unwrap();
+        std::fs::write(&generated, text.replace("Be terse.", "Be terse and kind.")).unwrap();
+        std::fs::write(
+            root.join(".claude/agents/docs-writer.md"),
+            "---\nname: docs-writer\ndescription: Writes docs\nmodel: haiku\n---\n\nWrite the docs.\n",
+        )
+        .unwrap();
+
+        // Without --yes outside a terminal, nothing is written.
+        let out = armadai(dir.path(), &["import", "--from", "claude"]);
+        let stdout = String::from_utf8_lossy(&out.stdout);
+        assert!(stdout.contains("+ Be terse and kind."), "{stdout}");
+        assert!(!std::fs::read_to_string(&source).unwrap().contains("kind"));
+
+        let out = armadai(dir.path(), &["import", "--from", "claude", "--yes"]);
+        assert!(out.status.success(), "{out:?}");
+        let updated = std::fs::read_to_string(&source).unwrap();
+        assert!(
+            updated.contains("## Instructions\n\nBe terse and kind.\n"),
+            "{updated}"
+        );
+        assert!(updated.contains("- model: latest:pro"), "{updated}");
+
+        let decls = std::fs::read_to_string(root.join(".armadai/agents.yaml")).unwrap();
+        assert!(decls.contains("- name: docs-writer"), "{decls}");
+        assert!(decls.contains("model: latest:fast"), "{decls}");
+        let fragment =
+            std::fs::read_to_string(root.join(".armadai/prompts/docs-writer.md")).unwrap();
+        assert!(fragment.contains("Write the docs."), "{fragment}");
+
+        // Both agents now round-trip through link without drift.
+        assert!(armadai(dir.path(), &["link", "--force"]).status.success());
+        let out = armadai(dir.path(), &["import", "--from", "claude"]);
+        let stdout = String::from_utf8_lossy(&out.stdout);
+        assert!(out.status.success(), "{out:?}");
+        assert_eq!(stdout.matches("in sync").count(), 2, "{stdout}");
+    }
+}
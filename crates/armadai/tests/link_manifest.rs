@@ -0,0 +1,78 @@
+//! Black-box coverage for the link manifest: `link` records what it wrote,
+//! `link --check` fails on a hand edit, and `link --adopt` moves the edit
+//! back into the source agent so the check passes again.
+
+#[cfg(test)]
+mod tests {
+    use assert_cmd::Command;
+
+    fn armadai(dir: &std::path::Path, args: &[&str]) -> std::process::Output {
+        let config = dir.join("config");
+        std::fs::create_dir_all(&config).unwrap();
+        Command::cargo_bin("armadai")
+            .unwrap()
+            .current_dir(dir.join("project"))
+            .env("ARMADAI_CONFIG_DIR", config)
+            .args(args)
+            .output()
+            .unwrap()
+    }
+
+    #[test]
+    fn check_detects_drift_and_adopt_resolves_it() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path().join("project");
+        std::fs::create_dir_all(root.join("agents")).unwrap();
+        std::fs::write(
+            root.join("armadai.yaml"),
+            "agents:\n  - name: reviewer\nlink:\n  target: claude\n",
+        )
+        .unwrap();
+        let source = root.join("agents/reviewer.md");
+        std::fs::write(
+            &source,
+            "# reviewer\n\n## Metadata\n- provider: claude\n\n## System Prompt\n\nReview the diff.\n",
+        )
+        .unwrap();
+
+        let out = armadai(dir.path(), &["link"]);
+        assert!(out.status.success(), "{out:?}");
+        assert!(root.join(".armadai/link-manifest.json").is_file());
+        assert!(armadai(dir.path(), &["link", "--check"]).status.success());
+
+        let generated = root.join(".claude/agents/reviewer.md");
+        // Edit the body, not the frontmatter description derived from it.
+        let mut text = std::fs::read_to_string(&generated).unwrap();
+        let at = text.rfind("Review the diff.").unwrap();
+        text.replace_range(
+            at..at + "Review the diff.".len(),
+            "Review the diff carefully.",
+        );
+        std::fs::write(&generated, text).unwrap();
+
+        let out = armadai(dir.path(), &["link", "--check"]);
+        let stdout = String::from_utf8_lossy(&out.stdout);
+        assert!(!out.status.success(), "drift must fail the check");
+        assert!(stdout.contains("drifted"), "{stdout}");
+
+        // A plain re-link leaves the hand edit alone.
+        assert!(armadai(dir.path(), &["link"]).status.success());
+        assert!(
+            std::fs::read_to_string(&generated)
+                .unwrap()
+                .contains("carefully")
+        );
+
+        let out = armadai(dir.path(), &["link", "--adopt"]);
+        assert!(out.status.success(), "{out:?}");
+        assert!(
+            std::fs::read_to_string(&source)
+                .unwrap()
+                .contains("Review the diff carefully.")
+        );
+        // The description derived from the prompt changed with it, so the
+        // adopted file is stale until the next link regenerates it.
+        assert!(armadai(dir.path(), &["link"]).status.success());
+        assert!(armadai(dir.path(), &["link", "--check"]).status.success());
+    }
+}
@@ -0,0 +1,154 @@
+//! Black-box coverage for `armadai secrets`: values go in through stdin,
+//! come out masked in `list`, and a project's fields override the user's.
+
+#[cfg(test)]
+mod tests {
+    use assert_cmd::Command;
+
+    fn armadai(
+        dir: &std::path::Path,
+        cwd: &str,
+        args: &[&str],
+        stdin: &str,
+    ) -> std::process::Output {
+        Command::cargo_bin("armadai")
+            .unwrap()
+            .current_dir(dir.join(cwd))
+            .env("ARMADAI_CONFIG_DIR", dir.join("config"))
+            .env("HOME", dir)
+            .env("XDG_CONFIG_HOME", dir.join("xdg"))
+            .env_remove("SOPS_AGE_KEY")
+            .env_remove("SOPS_AGE_KEY_FILE")
+            .args(args)
+            .write_stdin(stdin)
+            .output()
+            .unwrap()
+    }
+
+    fn stdout(out: &std::process::Output) -> String {
+        assert!(out.status.success(), "{out:?}");
+        String::from_utf8_lossy(&out.stdout).into_owned()
+    }
+
+    #[test]
+    fn secrets_are_stored_encrypted_masked_and_layered_per_project() {
+        let dir = tempfile::tempdir().unwrap();
+        let d = dir.path();
+        let project = d.join("project");
+        std::fs::create_dir_all(project.join(".armadai")).unwrap();
+        std::fs::write(project.join(".armadai/config.yaml"), "agents: []\n").unwrap();
+        std::fs::create_dir_all(project.join(".git")).unwrap();
+
+        // Without a key there is nothing to encrypt a new layer with.
+        let out = armadai(
+            d,
+            "project",
+            &["secrets", "set", "anthropic"],
+            "sk-ant-user-0000000001\n",
+        );
+        assert!(!out.status.success());
+        assert!(String::from_utf8_lossy(&out.stderr).contains("config secrets init"));
+
+        stdout(&armadai(d, ".", &["config", "secrets", "init"], ""));
+        stdout(&armadai(
+            d,
+            "project",
+            &["secrets", "set", "anthropic"],
+            "sk-ant-user-0000000001\n",
+        ));
+        stdout(&armadai(
+            d,
+            "project",
+            &[
+                "secrets",
+                "set",
+                "anthropic",
+                "--field",
+                "base_url",
+                "--project",
+            ],
+            "https://proxy.internal\n",
+        ));
+        let layer = std::fs::read_to_string(project.join(".armadai/providers.sops.yaml")).unwrap();
+        assert!(!layer.contains("proxy.internal"), "{layer}");
+
+        let listed = stdout(&armadai(d, "project", &["secrets", "list"], ""));
+        assert!(listed.contains("sk-a…0001"), "{listed}");
+        assert!(!listed.contains("sk-ant-user-0000000001"), "{listed}");
+        assert!(listed.contains("anthropic (project)"), "{listed}");
+
+        let get = |args: &[&str]| stdout(&armadai(d, "project", args, "")).trim().to_string();
+        assert_eq!(
+            get(&["secrets", "get", "anthropic"]),
+            "sk-ant-user-0000000001"
+        );
+        // Without a key of its own, the project cannot redirect the user's.
+        assert_eq!(
+            get(&[
+                "secrets",
+                "get",
+                "anthropic",
+                "--field",
+                "base_url",
+                "--project"
+            ]),
+            "https://proxy.internal"
+        );
+        let out = armadai(
+            d,
+            "project",
+            &["secrets", "get", "anthropic", "--field", "base_url"],
+            "",
+        );
+        assert!(!out.status.success());
+
+        // A project api_key overrides the user's; removing it restores it.
+        stdout(&armadai(
+            d,
+            "project",
+            &["secrets", "set", "anthropic", "--project"],
+            "sk-ant-proj-0000000002\n",
+        ));
+        assert_eq!(
+            get(&["secrets", "get", "anthropic"]),
+            "sk-ant-proj-0000000002"
+        );
+        assert_eq!(
+            get(&["secrets", "get", "anthropic", "--field", "base_url"]),
+            "https://proxy.internal"
+        );
+        let listed = stdout(&armadai(d, "project", &["secrets", "list"], ""));
+        assert!(listed.contains("overridden by project"), "{listed}");
+
+        stdout(&armadai(
+            d,
+            "project",
+            &[
+                "secrets",
+                "remove",
+                "anthropic",
+                "--field",
+                "api_key",
+                "--project",
+            ],
+            "",
+        ));
+        assert_eq!(
+            get(&["secrets", "get", "anthropic"]),
+            "sk-ant-user-0000000001"
+        );
+        stdout(&armadai(
+            d,
+            "project",
+            &["secrets", "remove", "anthropic", "--project"],
+            "",
+        ));
+        let out = armadai(
+            d,
+            "project",
+            &["secrets", "get", "anthropic", "--field", "base_url"],
+            "",
+        );
+        assert!(!out.status.success());
+    }
+}